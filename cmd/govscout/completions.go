@@ -0,0 +1,243 @@
+package main
+
+import (
+	"bufio"
+	"bytes"
+	"fmt"
+	"io"
+	"log"
+	"os"
+	"os/exec"
+	"strings"
+)
+
+const completionsHelp = `Usage: govscout completions <bash|zsh|fish|powershell>
+
+Prints a completion script for the whole command tree to stdout.
+
+Install:
+  bash        govscout completions bash > /etc/bash_completion.d/govscout
+              (or: source <(govscout completions bash) in ~/.bashrc)
+  zsh         govscout completions zsh > "${fpath[1]}/_govscout"  (then restart zsh)
+  fish        govscout completions fish > ~/.config/fish/completions/govscout.fish
+  powershell  govscout completions powershell >> $PROFILE
+`
+
+// cmdFlag is one flag of a subcommand as listed by its -h output.
+type cmdFlag struct {
+	name  string
+	usage string
+	value bool // takes an argument (anything but a bool flag)
+}
+
+func cmdCompletions(args []string) {
+	if len(args) != 1 {
+		fmt.Fprint(os.Stderr, completionsHelp)
+		os.Exit(1)
+	}
+	var write func(io.Writer, map[string][]cmdFlag)
+	switch args[0] {
+	case "bash":
+		write = writeBashCompletion
+	case "zsh":
+		write = writeZshCompletion
+	case "fish":
+		write = writeFishCompletion
+	case "powershell":
+		write = writePowerShellCompletion
+	case "-h", "--help", "help":
+		fmt.Print(completionsHelp)
+		return
+	default:
+		fmt.Fprintf(os.Stderr, "completions: unknown shell %q\n\n%s", args[0], completionsHelp)
+		os.Exit(1)
+	}
+
+	exe, err := os.Executable()
+	if err != nil {
+		log.Fatal(err)
+	}
+	flags := make(map[string][]cmdFlag, len(commands))
+	for _, c := range commands {
+		flags[c.name] = subcommandFlags(exe, c.name)
+	}
+	w := bufio.NewWriter(os.Stdout)
+	write(w, flags)
+	if err := w.Flush(); err != nil {
+		log.Fatal(err)
+	}
+}
+
+// subcommandFlags runs `govscout <name> -h` and reads the flag list the
+// FlagSet prints, so completions always match the flags each command really
+// defines. Commands without a flag set (or unavailable in this build, like
+// tui without -tags tui) complete no flags.
+func subcommandFlags(exe, name string) []cmdFlag {
+	if name == "completions" {
+		return nil
+	}
+	cmd := exec.Command(exe, name, "-h")
+	var out bytes.Buffer
+	cmd.Stderr = &out
+	if err := cmd.Run(); err != nil {
+		return nil
+	}
+	return parseFlagDefaults(out.String())
+}
+
+// parseFlagDefaults reads flag.PrintDefaults output: "  -name type" (type
+// omitted for bools) followed by an indented, tab-prefixed usage line, or
+// the usage after a tab on the same line for one-letter names.
+func parseFlagDefaults(s string) []cmdFlag {
+	var flags []cmdFlag
+	for _, line := range strings.Split(s, "\n") {
+		switch {
+		case strings.HasPrefix(line, "  -"):
+			head, usage, _ := strings.Cut(strings.TrimPrefix(line, "  -"), "\t")
+			name, typ, _ := strings.Cut(strings.TrimSpace(head), " ")
+			flags = append(flags, cmdFlag{name: name, usage: strings.TrimSpace(usage), value: typ != ""})
+		case strings.HasPrefix(line, "    \t") && len(flags) > 0:
+			last := &flags[len(flags)-1]
+			if last.usage == "" {
+				last.usage = strings.TrimSpace(line)
+			}
+		}
+	}
+	return flags
+}
+
+func writeBashCompletion(w io.Writer, flags map[string][]cmdFlag) {
+	names := make([]string, len(commands))
+	for i, c := range commands {
+		names[i] = c.name
+	}
+	fmt.Fprintf(w, `# bash completion for govscout
+# Install: govscout completions bash > /etc/bash_completion.d/govscout
+
+_govscout() {
+    local cur=${COMP_WORDS[COMP_CWORD]} cmd="" i
+    for ((i = 1; i < COMP_CWORD; i++)); do
+        case ${COMP_WORDS[i]} in
+            --env-file|-env-file) ((i++)) ;;
+            -*) ;;
+            *) cmd=${COMP_WORDS[i]}; break ;;
+        esac
+    done
+    if [[ -z $cmd ]]; then
+        COMPREPLY=($(compgen -W "--env-file %s" -- "$cur"))
+        return
+    fi
+    [[ $cur == -* ]] || return
+    case $cmd in
+`, strings.Join(names, " "))
+	for _, c := range commands {
+		if len(flags[c.name]) == 0 {
+			continue
+		}
+		opts := make([]string, len(flags[c.name]))
+		for i, f := range flags[c.name] {
+			opts[i] = "--" + f.name
+		}
+		fmt.Fprintf(w, "        %s) COMPREPLY=($(compgen -W \"%s\" -- \"$cur\")) ;;\n", c.name, strings.Join(opts, " "))
+	}
+	fmt.Fprint(w, `    esac
+}
+complete -o default -F _govscout govscout
+`)
+}
+
+func writeZshCompletion(w io.Writer, flags map[string][]cmdFlag) {
+	fmt.Fprint(w, `#compdef govscout
+# Install: govscout completions zsh > "${fpath[1]}/_govscout", then restart zsh
+
+_govscout() {
+    local -a commands
+    commands=(
+`)
+	for _, c := range commands {
+		fmt.Fprintf(w, "        %s\n", zshQuote(c.name+":"+strings.ReplaceAll(c.summary, ":", `\:`)))
+	}
+	fmt.Fprint(w, `    )
+    if (( CURRENT == 2 )); then
+        _describe 'command' commands
+        return
+    fi
+    case $words[2] in
+`)
+	for _, c := range commands {
+		if len(flags[c.name]) == 0 {
+			continue
+		}
+		fmt.Fprintf(w, "        %s)\n            _arguments \\\n", c.name)
+		for _, f := range flags[c.name] {
+			desc := strings.NewReplacer("[", `\[`, "]", `\]`, ":", `\:`).Replace(f.usage)
+			spec := "--" + f.name + "[" + desc + "]"
+			if f.value {
+				spec += ":" + f.name + ":_files"
+			}
+			fmt.Fprintf(w, "                %s \\\n", zshQuote(spec))
+		}
+		fmt.Fprint(w, "                '*:file:_files'\n            ;;\n")
+	}
+	fmt.Fprint(w, `        *) _files ;;
+    esac
+}
+
+_govscout "$@"
+`)
+}
+
+func zshQuote(s string) string {
+	return "'" + strings.ReplaceAll(s, "'", `'\''`) + "'"
+}
+
+func writeFishCompletion(w io.Writer, flags map[string][]cmdFlag) {
+	fmt.Fprint(w, `# fish completion for govscout
+# Install: govscout completions fish > ~/.config/fish/completions/govscout.fish
+
+complete -c govscout -l env-file -r -d 'Load this dotenv file before .env'
+`)
+	for _, c := range commands {
+		fmt.Fprintf(w, "complete -c govscout -f -n __fish_use_subcommand -a %s -d %s\n", c.name, fishQuote(c.summary))
+	}
+	for _, c := range commands {
+		for _, f := range flags[c.name] {
+			req := ""
+			if f.value {
+				req = " -r"
+			}
+			fmt.Fprintf(w, "complete -c govscout -n '__fish_seen_subcommand_from %s' -l %s%s -d %s\n", c.name, f.name, req, fishQuote(f.usage))
+		}
+	}
+}
+
+func fishQuote(s string) string {
+	return "'" + strings.NewReplacer(`\`, `\\`, "'", `\'`).Replace(s) + "'"
+}
+
+func writePowerShellCompletion(w io.Writer, flags map[string][]cmdFlag) {
+	fmt.Fprint(w, `# PowerShell completion for govscout
+# Install: govscout completions powershell >> $PROFILE
+
+Register-ArgumentCompleter -Native -CommandName govscout -ScriptBlock {
+    param($wordToComplete, $commandAst, $cursorPosition)
+    $commands = [ordered]@{
+`)
+	for _, c := range commands {
+		opts := make([]string, len(flags[c.name]))
+		for i, f := range flags[c.name] {
+			opts[i] = "'--" + f.name + "'"
+		}
+		fmt.Fprintf(w, "        '%s' = @(%s)\n", c.name, strings.Join(opts, ", "))
+	}
+	fmt.Fprint(w, `    }
+    $words = @($commandAst.CommandElements | Select-Object -Skip 1 | ForEach-Object { $_.ToString() })
+    if ($wordToComplete) { $words = @($words | Select-Object -SkipLast 1) }
+    $sub = $words | Where-Object { $_ -notlike '-*' -and $commands.Contains($_) } | Select-Object -First 1
+    if ($sub) { $candidates = $commands[$sub] } else { $candidates = @($commands.Keys) + '--env-file' }
+    $candidates | Where-Object { $_ -like "$wordToComplete*" } | ForEach-Object {
+        [System.Management.Automation.CompletionResult]::new($_, $_, 'ParameterValue', $_)
+    }
+}
+`)
+}
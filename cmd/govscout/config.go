@@ -0,0 +1,252 @@
+package main
+
+import (
+	"bufio"
+	"errors"
+	"fmt"
+	"io"
+	"log"
+	"os"
+	"path/filepath"
+	"sort"
+	"strconv"
+	"strings"
+	"time"
+)
+
+// ConfigEnv points govscout at a config file other than the default
+// <user config dir>/govscout/config.toml.
+const ConfigEnv = "GOVSCOUT_CONFIG"
+
+// configKey is one setting `govscout config` stores. Each maps onto the
+// environment variable that already configures it, so the file is just the
+// lowest-precedence source: flags, then the environment, then --env-file
+// and .env, then config.toml.
+type configKey struct {
+	name     string
+	env      string
+	help     string
+	validate func(string) error
+}
+
+var configKeys = []configKey{
+	{"db", "GOVSCOUT_DB", "SQLite database path", nil},
+	{"api_key_file", "SAMGOV_API_KEY", "File holding the SAM.gov API key (the key itself stays out of config.toml)", validateKeyFile},
+	{"naics", "GOVSCOUT_NAICS", "Default --naics for list, export, and report (comma-separated)", nil},
+	{"page_size", "GOVSCOUT_PAGE_SIZE", "Default list --limit", validatePositiveInt},
+	{"connect_timeout", "GOVSCOUT_CONNECT_TIMEOUT", "SAM.gov connect timeout (e.g. 10s)", validateDuration},
+	{"request_timeout", "GOVSCOUT_REQUEST_TIMEOUT", "SAM.gov request timeout (e.g. 30s)", validateDuration},
+}
+
+func lookupConfigKey(name string) (configKey, bool) {
+	for _, k := range configKeys {
+		if k.name == name {
+			return k, true
+		}
+	}
+	return configKey{}, false
+}
+
+func validatePositiveInt(v string) error {
+	if n, err := strconv.Atoi(v); err != nil || n <= 0 {
+		return fmt.Errorf("want a positive integer, got %q", v)
+	}
+	return nil
+}
+
+func validateDuration(v string) error {
+	if d, err := time.ParseDuration(v); err != nil || d <= 0 {
+		return fmt.Errorf("want a positive duration like 30s, got %q", v)
+	}
+	return nil
+}
+
+func validateKeyFile(v string) error {
+	if _, err := os.Stat(v); err != nil {
+		return fmt.Errorf("api key file: %w", err)
+	}
+	return nil
+}
+
+// configPath is GOVSCOUT_CONFIG, else config.toml under the user config
+// directory (~/.config/govscout on Linux).
+func configPath() (string, error) {
+	if p := os.Getenv(ConfigEnv); p != "" {
+		return p, nil
+	}
+	dir, err := os.UserConfigDir()
+	if err != nil {
+		return "", err
+	}
+	return filepath.Join(dir, "govscout", "config.toml"), nil
+}
+
+// readConfig parses the flat subset of TOML config.toml uses: one
+// `key = "value"` (or bare number) per line, with # comments. A missing file
+// is an empty config.
+func readConfig(path string) (map[string]string, error) {
+	cfg := map[string]string{}
+	f, err := os.Open(path)
+	if errors.Is(err, os.ErrNotExist) {
+		return cfg, nil
+	}
+	if err != nil {
+		return nil, err
+	}
+	defer f.Close()
+
+	scanner := bufio.NewScanner(f)
+	for n := 1; scanner.Scan(); n++ {
+		line := strings.TrimSpace(scanner.Text())
+		if line == "" || strings.HasPrefix(line, "#") {
+			continue
+		}
+		key, val, ok := strings.Cut(line, "=")
+		if !ok {
+			return nil, fmt.Errorf("%s:%d: want key = value", path, n)
+		}
+		key, val = strings.TrimSpace(key), strings.TrimSpace(val)
+		if strings.HasPrefix(val, `"`) {
+			if val, err = strconv.Unquote(val); err != nil {
+				return nil, fmt.Errorf("%s:%d: bad string for %s", path, n, key)
+			}
+		} else if i := strings.Index(val, "#"); i >= 0 {
+			val = strings.TrimSpace(val[:i])
+		}
+		cfg[key] = val
+	}
+	return cfg, scanner.Err()
+}
+
+// writeConfig rewrites path with cfg's keys sorted, creating its directory.
+// The file is private to the user since it can point at credentials.
+func writeConfig(path string, cfg map[string]string) error {
+	if err := os.MkdirAll(filepath.Dir(path), 0o700); err != nil {
+		return err
+	}
+	names := make([]string, 0, len(cfg))
+	for name := range cfg {
+		names = append(names, name)
+	}
+	sort.Strings(names)
+	var b strings.Builder
+	b.WriteString("# govscout settings; see `govscout config list`\n")
+	for _, name := range names {
+		fmt.Fprintf(&b, "%s = %s\n", name, strconv.Quote(cfg[name]))
+	}
+	return os.WriteFile(path, []byte(b.String()), 0o600)
+}
+
+// applyConfig fills each unset environment variable from config.toml. It
+// runs after the dotenv files so they, and the real environment, win.
+func applyConfig() {
+	path, err := configPath()
+	if err != nil {
+		return
+	}
+	cfg, err := readConfig(path)
+	if err != nil {
+		log.Printf("load config: %v", err)
+		return
+	}
+	for _, k := range configKeys {
+		val, ok := cfg[k.name]
+		if !ok || val == "" || os.Getenv(k.env) != "" {
+			continue
+		}
+		if k.name == "api_key_file" {
+			b, err := os.ReadFile(val)
+			if err != nil {
+				log.Printf("config api_key_file: %v", err)
+				continue
+			}
+			val = strings.TrimSpace(string(b))
+		}
+		os.Setenv(k.env, val)
+	}
+}
+
+func cmdConfig(args []string) {
+	if len(args) == 0 {
+		fmt.Fprintln(os.Stderr, "usage: govscout config list | get <key> | set <key> <value> | unset <key> | path")
+		os.Exit(1)
+	}
+	path, err := configPath()
+	if err != nil {
+		log.Fatal(err)
+	}
+	cfg, err := readConfig(path)
+	if err != nil {
+		log.Fatal(err)
+	}
+
+	key := func() configKey {
+		if len(args) < 2 {
+			log.Fatalf("config %s: missing key", args[0])
+		}
+		k, ok := lookupConfigKey(args[1])
+		if !ok {
+			log.Fatalf("unknown config key %q (see `govscout config list`)", args[1])
+		}
+		return k
+	}
+
+	switch args[0] {
+	case "list":
+		writeConfigList(os.Stdout, cfg, path)
+	case "get":
+		k := key()
+		val, ok := cfg[k.name]
+		if !ok {
+			os.Exit(1)
+		}
+		fmt.Println(val)
+	case "set":
+		k := key()
+		if len(args) != 3 {
+			log.Fatalf("usage: govscout config set %s <value>", k.name)
+		}
+		if k.validate != nil {
+			if err := k.validate(args[2]); err != nil {
+				log.Fatalf("config %s: %v", k.name, err)
+			}
+		}
+		cfg[k.name] = args[2]
+		if err := writeConfig(path, cfg); err != nil {
+			log.Fatal(err)
+		}
+	case "unset":
+		k := key()
+		delete(cfg, k.name)
+		if err := writeConfig(path, cfg); err != nil {
+			log.Fatal(err)
+		}
+	case "path":
+		fmt.Println(path)
+	default:
+		log.Fatalf("unknown config command %q (want list, get, set, unset, or path)", args[0])
+	}
+}
+
+// writeConfigList prints every known key with its stored value, the
+// environment variable that overrides it, and what it sets.
+func writeConfigList(w io.Writer, cfg map[string]string, path string) {
+	fmt.Fprintf(w, "# %s\n", path)
+	for _, k := range configKeys {
+		val, ok := cfg[k.name]
+		if !ok {
+			val = "(unset)"
+		}
+		fmt.Fprintf(w, "%-16s %-24s %-27s %s\n", k.name, val, "$"+k.env, k.help)
+	}
+	var unknown []string
+	for name := range cfg {
+		if _, ok := lookupConfigKey(name); !ok {
+			unknown = append(unknown, name)
+		}
+	}
+	sort.Strings(unknown)
+	for _, name := range unknown {
+		fmt.Fprintf(w, "%-16s %-24s (unknown key, ignored)\n", name, cfg[name])
+	}
+}
@@ -0,0 +1,119 @@
+package main
+
+import (
+	"os"
+	"path/filepath"
+	"reflect"
+	"strings"
+	"testing"
+)
+
+func TestConfigRoundTrip(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "govscout", "config.toml")
+	want := map[string]string{"db": `/data/my "gov" scout.db`, "page_size": "50"}
+	if err := writeConfig(path, want); err != nil {
+		t.Fatal(err)
+	}
+	got, err := readConfig(path)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(got) != len(want) || got["db"] != want["db"] || got["page_size"] != "50" {
+		t.Errorf("readConfig = %v, want %v", got, want)
+	}
+
+	// Hand-edited files may use bare values and trailing comments.
+	if err := os.WriteFile(path, []byte("# mine\npage_size = 40 # rows\nnaics = \"541512,541511\"\n"), 0o600); err != nil {
+		t.Fatal(err)
+	}
+	if got, err = readConfig(path); err != nil || got["page_size"] != "40" || got["naics"] != "541512,541511" {
+		t.Errorf("readConfig = %v, %v", got, err)
+	}
+
+	if err := os.WriteFile(path, []byte("page_size\n"), 0o600); err != nil {
+		t.Fatal(err)
+	}
+	if _, err := readConfig(path); err == nil || !strings.Contains(err.Error(), ":1:") {
+		t.Errorf("readConfig of a bare key = %v, want a line-numbered error", err)
+	}
+
+	if got, err := readConfig(filepath.Join(t.TempDir(), "missing.toml")); err != nil || len(got) != 0 {
+		t.Errorf("missing file = %v, %v; want an empty config", got, err)
+	}
+}
+
+func TestApplyConfig(t *testing.T) {
+	dir := t.TempDir()
+	keyFile := filepath.Join(dir, "sam.key")
+	if err := os.WriteFile(keyFile, []byte("abc123\n"), 0o600); err != nil {
+		t.Fatal(err)
+	}
+	path := filepath.Join(dir, "config.toml")
+	if err := writeConfig(path, map[string]string{
+		"db":           "/from/config.db",
+		"naics":        "541512",
+		"api_key_file": keyFile,
+	}); err != nil {
+		t.Fatal(err)
+	}
+	t.Setenv(ConfigEnv, path)
+	t.Setenv("GOVSCOUT_DB", "/from/env.db")
+	t.Setenv("GOVSCOUT_NAICS", "")
+	t.Setenv("SAMGOV_API_KEY", "")
+
+	applyConfig()
+	if got := os.Getenv("GOVSCOUT_DB"); got != "/from/env.db" {
+		t.Errorf("GOVSCOUT_DB = %q, want the environment to win", got)
+	}
+	if got := os.Getenv("GOVSCOUT_NAICS"); got != "541512" {
+		t.Errorf("GOVSCOUT_NAICS = %q, want the config value", got)
+	}
+	if got := os.Getenv("SAMGOV_API_KEY"); got != "abc123" {
+		t.Errorf("SAMGOV_API_KEY = %q, want the key file's contents", got)
+	}
+}
+
+func TestExtractEnvFile_OnlyBeforeSubcommand(t *testing.T) {
+	tests := []struct {
+		args     []string
+		wantPath string
+		wantRest []string
+	}{
+		{[]string{"--env-file", ".env.prod", "serve"}, ".env.prod", []string{"serve"}},
+		{[]string{"--env-file=.env.prod", "sync", "--max-calls", "5"}, ".env.prod", []string{"sync", "--max-calls", "5"}},
+		// After the subcommand it belongs to the subcommand (which rejects it).
+		{[]string{"serve", "--env-file", ".env.prod"}, "", []string{"serve", "--env-file", ".env.prod"}},
+		{[]string{"list", "--search", "--env-file"}, "", []string{"list", "--search", "--env-file"}},
+	}
+	for _, tt := range tests {
+		path, rest := extractEnvFile(tt.args)
+		if path != tt.wantPath || !reflect.DeepEqual(rest, tt.wantRest) {
+			t.Errorf("extractEnvFile(%q) = %q, %q; want %q, %q", tt.args, path, rest, tt.wantPath, tt.wantRest)
+		}
+	}
+}
+
+func TestLoadEnv(t *testing.T) {
+	path := filepath.Join(t.TempDir(), ".env.prod")
+	if err := os.WriteFile(path, []byte("# prod\nGOVSCOUT_TEST_SET=from-file\nGOVSCOUT_TEST_UNSET = from-file\n"), 0o600); err != nil {
+		t.Fatal(err)
+	}
+	// t.Setenv restores both afterwards, including what loadEnv writes.
+	t.Setenv("GOVSCOUT_TEST_SET", "from-env")
+	t.Setenv("GOVSCOUT_TEST_UNSET", "")
+
+	if err := loadEnv(path); err != nil {
+		t.Fatal(err)
+	}
+	if got := os.Getenv("GOVSCOUT_TEST_SET"); got != "from-env" {
+		t.Errorf("already-set variable = %q, want the real environment to win", got)
+	}
+	if got := os.Getenv("GOVSCOUT_TEST_UNSET"); got != "from-file" {
+		t.Errorf("unset variable = %q, want %q", got, "from-file")
+	}
+
+	// A missing --env-file is an error (main only tolerates a missing base .env).
+	if err := loadEnv(filepath.Join(t.TempDir(), "missing.env")); !os.IsNotExist(err) {
+		t.Errorf("loadEnv of a missing file = %v, want a not-exist error", err)
+	}
+}
@@ -0,0 +1,100 @@
+package main
+
+import (
+	"errors"
+	"fmt"
+	"log"
+	"strings"
+	"time"
+
+	"github.com/theognis1002/govscout/internal/db"
+	"github.com/theognis1002/govscout/internal/samgov"
+)
+
+// parseFlagDate reads a --from/--to value, accepting partial dates as well
+// as the full MM/DD/YYYY or YYYY-MM-DD forms: YYYY means that year and
+// MM/YYYY (or YYYY-MM) that month. A partial date expands to the period's
+// first day, or its last when end is set, so `--from 2025 --to 2025` covers
+// the whole year. Two-part dates without a four-digit year, like 01/25, are
+// rejected rather than guessed at.
+func parseFlagDate(s string, end bool) (samgov.Date, error) {
+	s = strings.TrimSpace(s)
+	if d, err := samgov.ParseDate(s); err == nil {
+		return d, nil
+	}
+
+	for _, p := range []struct {
+		layout string
+		months int // length of the period a partial date names
+	}{
+		{"2006", 12},
+		{"01/2006", 1},
+		{"1/2006", 1},
+		{"2006-01", 1},
+	} {
+		t, err := time.Parse(p.layout, s)
+		if err != nil {
+			continue
+		}
+		if end {
+			t = t.AddDate(0, p.months, -1)
+		}
+		return samgov.NewDate(t), nil
+	}
+
+	parts := strings.FieldsFunc(s, func(r rune) bool { return r == '/' || r == '-' })
+	if len(parts) == 2 && len(parts[0]) != 4 && len(parts[1]) != 4 {
+		return samgov.Date{}, fmt.Errorf("ambiguous date %q: give a four-digit year (MM/YYYY) or a full MM/DD/YYYY date", s)
+	}
+	return samgov.Date{}, fmt.Errorf("invalid date %q (want YYYY, MM/YYYY, MM/DD/YYYY, or YYYY-MM-DD)", s)
+}
+
+// flagDate is parseFlagDate for an optional flag: empty stays empty, and
+// anything else comes back as the MM/DD/YYYY SAM.gov and the filters use.
+// Invalid input is fatal, naming the flag.
+func flagDate(name, s string, end bool) string {
+	if s == "" {
+		return ""
+	}
+	d, err := parseFlagDate(s, end)
+	if err != nil {
+		log.Fatalf("--%s: %v", name, err)
+	}
+	return d.String()
+}
+
+// applyPostedShortcuts sets f's posted-date range from --posted-today,
+// --posted-this-week or --posted-last-days, ending today in now's location.
+// With no shortcut f is left alone; more than one, or one alongside
+// --from/--to, is an error.
+func applyPostedShortcuts(f *db.ListFilters, now time.Time, today, thisWeek bool, lastDays int) error {
+	if lastDays < 0 {
+		return errors.New("--posted-last-days must be positive")
+	}
+	n := 0
+	for _, set := range []bool{today, thisWeek, lastDays > 0} {
+		if set {
+			n++
+		}
+	}
+	switch {
+	case n == 0:
+		return nil
+	case n > 1:
+		return errors.New("use only one of --posted-today, --posted-this-week, --posted-last-days")
+	}
+	if f.DateFrom != "" || f.DateTo != "" {
+		return errors.New("--posted-today, --posted-this-week and --posted-last-days cannot be combined with --from/--to")
+	}
+
+	start := now
+	switch {
+	case thisWeek:
+		// Weeks start on Monday; Sunday is day 6 of the week.
+		start = now.AddDate(0, 0, -((int(now.Weekday()) + 6) % 7))
+	case lastDays > 0:
+		start = now.AddDate(0, 0, -(lastDays - 1))
+	}
+	f.DateFrom, f.DateTo = samgov.NewDate(start).String(), samgov.NewDate(now).String()
+	return nil
+}
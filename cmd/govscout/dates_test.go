@@ -0,0 +1,94 @@
+package main
+
+import (
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/theognis1002/govscout/internal/db"
+)
+
+func TestParseFlagDate(t *testing.T) {
+	tests := []struct {
+		in         string
+		start, end string
+	}{
+		{"03/15/2025", "03/15/2025", "03/15/2025"},
+		{"2025-03-15", "03/15/2025", "03/15/2025"},
+		{"2025", "01/01/2025", "12/31/2025"},
+		{"02/2024", "02/01/2024", "02/29/2024"},
+		{"2/2025", "02/01/2025", "02/28/2025"},
+		{"2025-12", "12/01/2025", "12/31/2025"},
+		{" 2025 ", "01/01/2025", "12/31/2025"},
+	}
+	for _, tt := range tests {
+		for _, end := range []bool{false, true} {
+			want := tt.start
+			if end {
+				want = tt.end
+			}
+			got, err := parseFlagDate(tt.in, end)
+			if err != nil || got.String() != want {
+				t.Errorf("parseFlagDate(%q, end=%v) = %q, %v; want %q", tt.in, end, got, err, want)
+			}
+		}
+	}
+}
+
+func TestParseFlagDate_Rejects(t *testing.T) {
+	for in, want := range map[string]string{
+		"01/25":      "ambiguous",
+		"25-01":      "ambiguous",
+		"13/2025":    "invalid date",
+		"2025-13":    "invalid date",
+		"02/30/2025": "invalid date",
+		"yesterday":  "invalid date",
+		"":           "invalid date",
+	} {
+		if _, err := parseFlagDate(in, false); err == nil || !strings.Contains(err.Error(), want) {
+			t.Errorf("parseFlagDate(%q) error = %v, want %q", in, err, want)
+		}
+	}
+}
+
+func TestApplyPostedShortcuts(t *testing.T) {
+	at := func(day int) time.Time { return time.Date(2026, 3, day, 15, 0, 0, 0, time.UTC) }
+	tests := []struct {
+		name           string
+		now            time.Time
+		today, week    bool
+		lastDays       int
+		from, to       string // --from/--to already set
+		wantFrom, want string
+		wantErr        string
+	}{
+		{name: "none leaves explicit dates", now: at(18), from: "03/01/2026", wantFrom: "03/01/2026"},
+		{name: "today", now: at(18), today: true, wantFrom: "03/18/2026", want: "03/18/2026"},
+		{name: "this week midweek", now: at(18), week: true, wantFrom: "03/16/2026", want: "03/18/2026"},
+		{name: "this week on Monday", now: at(16), week: true, wantFrom: "03/16/2026", want: "03/16/2026"},
+		{name: "this week on Sunday", now: at(22), week: true, wantFrom: "03/16/2026", want: "03/22/2026"},
+		{name: "this week across a month", now: time.Date(2026, 4, 1, 9, 0, 0, 0, time.UTC), week: true, wantFrom: "03/30/2026", want: "04/01/2026"},
+		{name: "last 7 days", now: at(18), lastDays: 7, wantFrom: "03/12/2026", want: "03/18/2026"},
+		{name: "last 1 day", now: at(18), lastDays: 1, wantFrom: "03/18/2026", want: "03/18/2026"},
+		{name: "negative days", now: at(18), lastDays: -1, wantErr: "must be positive"},
+		{name: "today and week", now: at(18), today: true, week: true, wantErr: "only one"},
+		{name: "week and last days", now: at(18), week: true, lastDays: 3, wantErr: "only one"},
+		{name: "today with --from", now: at(18), today: true, from: "03/01/2026", wantErr: "--from/--to"},
+		{name: "last days with --to", now: at(18), lastDays: 3, to: "03/31/2026", wantErr: "--from/--to"},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			f := db.ListFilters{DateFrom: tt.from, DateTo: tt.to}
+			err := applyPostedShortcuts(&f, tt.now, tt.today, tt.week, tt.lastDays)
+			if tt.wantErr != "" {
+				if err == nil || !strings.Contains(err.Error(), tt.wantErr) {
+					t.Errorf("error = %v, want %q", err, tt.wantErr)
+				}
+				return
+			}
+			if err != nil || f.DateFrom != tt.wantFrom || f.DateTo != tt.want {
+				t.Errorf("range = %q to %q, %v; want %q to %q", f.DateFrom, f.DateTo, err, tt.wantFrom, tt.want)
+			}
+		})
+	}
+}
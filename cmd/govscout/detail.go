@@ -0,0 +1,183 @@
+package main
+
+import (
+	"fmt"
+	"io"
+	"strings"
+
+	"github.com/theognis1002/govscout/internal/db"
+)
+
+// detailSection is one headed block of the `get --format text|markdown`
+// view, mirroring the sections of the web detail page.
+type detailSection struct {
+	title  string
+	fields [][2]string // label, value
+}
+
+func (s *detailSection) add(label, value string) {
+	if value != "" {
+		s.fields = append(s.fields, [2]string{label, value})
+	}
+}
+
+// opportunityDetail lays out a SAM.gov-shaped record (as GetOpportunityFull
+// returns it) for the text and markdown formats. Empty fields and sections
+// are dropped; desc is the description with HTML stripped.
+func opportunityDetail(opp map[string]any) (title string, sections []detailSection, desc string) {
+	title = detailString(opp["title"])
+	if title == "" {
+		title = "Untitled Opportunity"
+	}
+
+	basic := detailSection{title: "Basic Information"}
+	basic.add("Notice ID", detailString(opp["noticeId"]))
+	basic.add("Solicitation #", detailString(opp["solicitationNumber"]))
+	basic.add("Type", detailString(opp["type"]))
+	basic.add("Base Type", detailString(opp["baseType"]))
+	setAside := detailString(opp["typeOfSetAside"])
+	if d := detailString(opp["typeOfSetAsideDescription"]); d != "" {
+		setAside = strings.TrimSpace(setAside + " - " + d)
+	}
+	basic.add("Set-Aside", setAside)
+	basic.add("NAICS Code", detailString(opp["naicsCode"]))
+	basic.add("Classification (PSC)", detailString(opp["classificationCode"]))
+	basic.add("Active", detailString(opp["active"]))
+
+	org := detailSection{title: "Organization"}
+	org.add("Department", detailString(opp["department"]))
+	org.add("Sub-Tier", detailString(opp["subTier"]))
+	org.add("Office", detailString(opp["office"]))
+	org.add("Full Path", detailString(opp["fullParentPathName"]))
+
+	dates := detailSection{title: "Dates"}
+	dates.add("Posted", detailString(opp["postedDate"]))
+	dates.add("Response Deadline", detailString(opp["responseDeadline"]))
+	dates.add("Archive Date", detailString(opp["archiveDate"]))
+
+	pop := detailSection{title: "Place of Performance"}
+	popMap, _ := opp["placeOfPerformance"].(map[string]any)
+	for _, part := range []struct{ key, label string }{{"state", "State"}, {"city", "City"}, {"country", "Country"}} {
+		m, _ := popMap[part.key].(map[string]any)
+		v := detailString(m["name"])
+		if v == "" {
+			v = detailString(m["code"])
+		}
+		pop.add(part.label, v)
+	}
+	pop.add("ZIP", detailString(popMap["zip"]))
+
+	contacts := detailSection{title: "Contacts"}
+	pocs, _ := opp["pointOfContact"].([]any)
+	for _, p := range pocs {
+		c, _ := p.(map[string]any)
+		label := detailString(c["type"])
+		if label == "" {
+			label = "Contact"
+		} else {
+			label = strings.ToUpper(label[:1]) + label[1:]
+		}
+		parts := []string{detailString(c["fullName"])}
+		if t := detailString(c["title"]); t != "" {
+			parts[0] += " (" + t + ")"
+		}
+		if e := detailString(c["email"]); e != "" {
+			parts = append(parts, e)
+		}
+		if ph := detailString(c["phone"]); ph != "" {
+			parts = append(parts, ph)
+		}
+		contacts.add(label, strings.TrimPrefix(strings.Join(parts, ", "), ", "))
+	}
+
+	award := detailSection{title: "Award"}
+	awardMap, _ := opp["award"].(map[string]any)
+	awardee, _ := awardMap["awardee"].(map[string]any)
+	award.add("Amount", detailString(awardMap["amount"]))
+	award.add("Date", detailString(awardMap["date"]))
+	award.add("Number", detailString(awardMap["number"]))
+	award.add("Awardee", detailString(awardee["name"]))
+	award.add("UEI", detailString(awardee["ueiSAM"]))
+
+	links := detailSection{title: "Links"}
+	links.add("SAM.gov", detailString(opp["uiLink"]))
+	resources, _ := opp["resourceLinks"].([]any)
+	for i, l := range resources {
+		links.add(fmt.Sprintf("Attachment %d", i+1), detailString(l))
+	}
+
+	for _, s := range []detailSection{basic, org, dates, pop, contacts, award, links} {
+		if len(s.fields) > 0 {
+			sections = append(sections, s)
+		}
+	}
+	desc = strings.TrimSpace(db.StripHTML(detailString(opp["description"])))
+	return title, sections, desc
+}
+
+// detailString renders a scalar from a decoded record; API records carry
+// some numbers (award amounts) as JSON numbers rather than strings.
+func detailString(v any) string {
+	switch v := v.(type) {
+	case nil:
+		return ""
+	case string:
+		return strings.TrimSpace(v)
+	default:
+		return fmt.Sprint(v)
+	}
+}
+
+// writeOpportunityText prints the plain-text detail view. highlightTerm, when
+// set, is highlighted in the description.
+func writeOpportunityText(w io.Writer, opp map[string]any, highlightTerm string, color bool) {
+	title, sections, desc := opportunityDetail(opp)
+	fmt.Fprintf(w, "%s\n%s\n", title, strings.Repeat("=", len([]rune(title))))
+	for _, s := range sections {
+		width := 0
+		for _, f := range s.fields {
+			width = max(width, len(f[0]))
+		}
+		fmt.Fprintf(w, "\n%s\n", s.title)
+		for _, f := range s.fields {
+			fmt.Fprintf(w, "  %-*s  %s\n", width+1, f[0]+":", f[1])
+		}
+	}
+	if desc == "" {
+		desc = "(no description)"
+	}
+	if highlightTerm != "" {
+		desc = highlight(desc, highlightTerm, color)
+	}
+	fmt.Fprintf(w, "\nDescription\n%s\n", desc)
+}
+
+// writeOpportunityMarkdown prints the detail view as Markdown for pasting
+// into docs and tickets, with the description as a blockquote.
+func writeOpportunityMarkdown(w io.Writer, opp map[string]any) {
+	title, sections, desc := opportunityDetail(opp)
+	fmt.Fprintf(w, "# %s\n", title)
+	for _, s := range sections {
+		fmt.Fprintf(w, "\n## %s\n\n", s.title)
+		for _, f := range s.fields {
+			value := f[1]
+			if strings.HasPrefix(value, "https://") || strings.HasPrefix(value, "http://") {
+				value = "<" + value + ">"
+			}
+			fmt.Fprintf(w, "- **%s:** %s\n", f[0], value)
+		}
+	}
+	fmt.Fprint(w, "\n## Description\n\n")
+	if desc == "" {
+		fmt.Fprintln(w, "_No description._")
+		return
+	}
+	for _, line := range strings.Split(desc, "\n") {
+		line = strings.TrimRight(line, " \t\r")
+		if line == "" {
+			fmt.Fprintln(w, ">")
+		} else {
+			fmt.Fprintf(w, "> %s\n", line)
+		}
+	}
+}
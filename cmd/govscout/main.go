@@ -4,13 +4,18 @@ import (
 	"bufio"
 	"context"
 	"database/sql"
+	"encoding/json"
 	"flag"
 	"fmt"
 	"log"
 	"os"
 	"os/signal"
+	"regexp"
+	"slices"
+	"strconv"
 	"strings"
 	"syscall"
+	"time"
 
 	"github.com/resend/resend-go/v3"
 	"github.com/theognis1002/govscout/internal/alerts"
@@ -20,10 +25,13 @@ import (
 	"github.com/theognis1002/govscout/internal/web"
 )
 
-func loadEnv(path string) {
+// loadEnv sets variables from a dotenv file without overriding ones that are
+// already set, so earlier-loaded files and the real environment take
+// precedence.
+func loadEnv(path string) error {
 	f, err := os.Open(path)
 	if err != nil {
-		return
+		return err
 	}
 	defer f.Close()
 	scanner := bufio.NewScanner(f)
@@ -42,10 +50,49 @@ func loadEnv(path string) {
 			os.Setenv(key, val)
 		}
 	}
+	return scanner.Err()
+}
+
+// extractEnvFile consumes a global --env-file flag ("--env-file path" or
+// "--env-file=path") given before the subcommand, and returns its value and
+// the remaining args. Arguments from the subcommand on are left alone, so a
+// subcommand's own flags or values are never taken for it.
+func extractEnvFile(args []string) (string, []string) {
+	var path string
+	for len(args) > 0 {
+		a := args[0]
+		switch {
+		case a == "--env-file" || a == "-env-file":
+			if len(args) < 2 {
+				fmt.Fprintln(os.Stderr, "--env-file requires a path")
+				os.Exit(1)
+			}
+			path, args = args[1], args[2:]
+		case strings.HasPrefix(a, "--env-file="):
+			path, args = strings.TrimPrefix(a, "--env-file="), args[1:]
+		case strings.HasPrefix(a, "-env-file="):
+			path, args = strings.TrimPrefix(a, "-env-file="), args[1:]
+		default:
+			return path, args
+		}
+	}
+	return path, args
 }
 
 func main() {
-	loadEnv(".env")
+	// Precedence: real environment, then --env-file, then the base .env,
+	// then config.toml (see applyConfig).
+	envFile, args := extractEnvFile(os.Args[1:])
+	os.Args = append(os.Args[:1], args...)
+	if envFile != "" {
+		if err := loadEnv(envFile); err != nil {
+			log.Fatalf("--env-file: %v", err)
+		}
+	}
+	if err := loadEnv(".env"); err != nil && !os.IsNotExist(err) {
+		log.Printf("load .env: %v", err)
+	}
+	applyConfig()
 	if len(os.Args) < 2 {
 		usage()
 		os.Exit(1)
@@ -66,25 +113,84 @@ func main() {
 		cmdTestEmail(os.Args[2:])
 	case "migrate":
 		cmdMigrate(os.Args[2:])
+	case "report":
+		cmdReport(os.Args[2:])
+	case "log":
+		cmdLog(os.Args[2:])
+	case "check":
+		cmdCheck(os.Args[2:])
+	case "doctor":
+		cmdDoctor(os.Args[2:])
+	case "get":
+		cmdGet(os.Args[2:])
+	case "list":
+		cmdList(os.Args[2:])
+	case "import":
+		cmdImport(os.Args[2:])
+	case "stats":
+		cmdStats(os.Args[2:])
+	case "merge":
+		cmdMerge(os.Args[2:])
+	case "export-db":
+		cmdExportDB(os.Args[2:])
+	case "awardee":
+		cmdAwardee(os.Args[2:])
+	case "refresh":
+		cmdRefresh(os.Args[2:])
+	case "watch":
+		cmdWatch(os.Args[2:])
+	case "tui":
+		cmdTUI(os.Args[2:])
+	case "tag":
+		cmdTag(os.Args[2:])
+	case "reindex":
+		cmdReindex(os.Args[2:])
+	case "completions":
+		cmdCompletions(os.Args[2:])
+	case "config":
+		cmdConfig(os.Args[2:])
 	default:
 		usage()
 		os.Exit(1)
 	}
 }
 
-func usage() {
-	fmt.Fprintf(os.Stderr, `Usage: govscout <command> [flags]
-
-Commands:
-  serve     Start the web server
-  sync      Run sync (incremental + backfill)
-  export    Export opportunities to CSV
-  useradd   Create a new user
-  passwd    Update a user's password
-  testemail Send a test email via Resend to TEST_EMAIL_TO
-  migrate   Import data from old (Rust) DB
+// commands lists the subcommands in the order usage prints them; completions
+// generates scripts from the same list.
+var commands = []struct{ name, summary string }{
+	{"serve", "Start the web server"},
+	{"sync", "Run sync (incremental + backfill)"},
+	{"export", "Export opportunities to CSV"},
+	{"report", "Write a self-contained HTML report (--html out.html)"},
+	{"useradd", "Create a new user"},
+	{"passwd", "Update a user's password"},
+	{"testemail", "Send a test email via Resend to TEST_EMAIL_TO"},
+	{"migrate", "Import data from old (Rust) DB"},
+	{"log", "Show recent sync runs (--failed-only, --context, --limit N, --offset N)"},
+	{"check", "Verify database integrity (--repair deletes orphaned rows)"},
+	{"doctor", "Show which database file is in use, its size, row counts, and last sync"},
+	{"get", "Print one opportunity as SAM.gov-shaped JSON"},
+	{"list", "Show matching opportunities as a table (export's filter flags)"},
+	{"import", `Upsert opportunities from a JSON array or NDJSON file ("-" for stdin)`},
+	{"stats", "Show distinct values per filter column (--completeness for fill rates)"},
+	{"merge", "Fold another GovScout database into this one (newer modified_at wins)"},
+	{"export-db", "Write a single-file, checkpointed copy of the database for sharing"},
+	{"awardee", "List stored awards to a company (name substring or exact UEI) with the total"},
+	{"refresh", "Re-fetch stored opportunities not updated recently (--older-than 30d)"},
+	{"watch", "Sync on an interval and print new matches (--watch-file reloads filters on change)"},
+	{"tui", "Browse local opportunities interactively (build with -tags tui)"},
+	{"reindex", "Rebuild the opportunity indexes and refresh planner stats (after bulk imports)"},
+	{"tag", "Mark an opportunity interested/pursuing/passed with a note (list --tag filters)"},
+	{"completions", "Print a shell completion script (bash, zsh, fish, powershell)"},
+	{"config", "View and set persistent defaults in config.toml (list, get, set, unset, path)"},
+}
 
-`)
+func usage() {
+	fmt.Fprint(os.Stderr, "Usage: govscout [--env-file path] <command> [flags]\n\nCommands:\n")
+	for _, c := range commands {
+		fmt.Fprintf(os.Stderr, "  %-9s %s\n", c.name, c.summary)
+	}
+	fmt.Fprintln(os.Stderr)
 }
 
 func cmdServe(args []string) {
@@ -100,9 +206,29 @@ func cmdServe(args []string) {
 	}
 	defer database.Close()
 
+	if n, err := db.CountOpportunities(database); err != nil {
+		log.Printf("count opportunities: %v", err)
+	} else {
+		log.Printf("database: %s (%d opportunities)", db.ResolvePath(*dbPath), n)
+		if n == 0 {
+			log.Println("WARNING: this database has no opportunities. If `govscout sync` has run, it wrote to a different file;")
+			log.Println("WARNING: give serve and sync the same --db or GOVSCOUT_DB (compare with `govscout doctor`).")
+		}
+	}
+
 	ctx, stop := signal.NotifyContext(context.Background(), os.Interrupt, syscall.SIGTERM)
 	defer stop()
 
+	if warm, ok, err := gosync.WarmOptionsFromEnv(); err != nil {
+		log.Fatal(err)
+	} else if ok {
+		client, err := samgov.NewSourceFromEnv(os.Getenv("SAMGOV_API_KEY"))
+		if err != nil {
+			log.Fatalf("%s is set but: %v", gosync.WarmIntervalEnv, err)
+		}
+		go gosync.RunWarmer(ctx, database, client, warm)
+	}
+
 	srv := web.NewServer(database, web.WithDevMode(*dev))
 	if err := srv.Run(ctx, *addr); err != nil {
 		log.Fatal(err)
@@ -115,17 +241,146 @@ func cmdSync(args []string) {
 	dbPath := fs.String("db", "", "SQLite database path")
 	maxCalls := fs.Int("max-calls", 18, "Max API calls for this run")
 	dryRun := fs.Bool("dry-run", false, "Preview what would be fetched")
-	from := fs.String("from", "", "Backfill target start date (MM/DD/YYYY)")
+	skipEmpty := fs.Duration("skip-empty-windows", 0, "Remember backfill windows that returned nothing and skip them on later backfills for this long (e.g. 720h; 0 = always fetch)")
+	plan := fs.Bool("plan", false, "Estimate the windows and API calls left to backfill to --from (forward: to today), then exit without fetching")
+	audit := fs.Bool("audit", false, "Report posted-date ranges between the earliest stored posting (or --from) and today that no completed sync window fetched, then exit")
+	from := fs.String("from", "", "Backfill target start date (MM/DD/YYYY, or YYYY / MM/YYYY for the period's first day); with --backfill-direction forward, where backfill begins")
+	direction := fs.String("backfill-direction", "backward", "Backfill order: backward (newest first, from today) or forward (oldest first, from --from)")
+	incrDays := fs.Int("incremental-days", 0, "Incremental lookback in days, 1-365 (default 0: derived from last sync, 3-30)")
+	onlyNew := fs.Bool("only-new", false, "Count only newly inserted records in sync history")
+	strict := fs.Bool("strict", false, "Abort the run and exit non-zero on the first record that fails to store (default: log and skip it)")
+	batchSize := fs.Int("batch-size", 0, "Commit upserts in transactions of up to N records, flushed after every page (faster on large backfills; 0 = one transaction per record)")
+	naics := fs.String("naics", "", "Only sync notices with this NAICS code; the filtered sync keeps its own cursors and last sync date")
+	setAside := fs.String("set-aside", "", "Only sync notices with this set-aside code (e.g. SBA, 8A, SDVOSBC); combines with --naics and --state")
+	state := fs.String("state", "", "Only sync notices performed in this state (e.g. VA)")
+	activeOnly := fs.Bool("active-only", false, "Only store active notices (SAM.gov can't filter on this, so pages are filtered locally and still cost calls)")
+	inactiveOnly := fs.Bool("inactive-only", false, "Only store inactive (archived or cancelled) notices; filtered locally like --active-only")
+	compress := fs.Bool("compress-descriptions", false, "Store descriptions gzip-compressed, compressing already-stored ones first (reads decompress transparently)")
+	cacheTTL := fs.Duration("cache-ttl", 10*time.Minute, "Reuse cached SAM.gov responses younger than this")
+	noCache := fs.Bool("no-cache", false, "Bypass the response cache")
+	userAgent := fs.String("user-agent", "", "User-Agent for SAM.gov requests (default: GOVSCOUT_USER_AGENT or govscout/<version>)")
+	breakerThreshold := fs.Int("breaker-threshold", 3, "Consecutive rate-limited runs before sync pauses (negative disables)")
+	breakerCooldown := fs.Duration("breaker-cooldown", time.Hour, "First pause once the breaker trips; doubles per further rate-limited run (max 24h)")
+	delayMs := fs.Int("delay-ms", 0, "Pause before each backfill window, in milliseconds (skipped with --dry-run)")
+	adaptiveDelay := fs.Bool("adaptive-delay", false, "Grow the window pause when SAM.gov slows down, shrink it back as it recovers (max 30s)")
+	connectTimeout := fs.Duration("connect-timeout", 0, "Give up connecting to SAM.gov after this long (default: GOVSCOUT_CONNECT_TIMEOUT or 10s)")
+	requestTimeout := fs.Duration("request-timeout", 0, "Give up on a whole SAM.gov request after this long (default: GOVSCOUT_REQUEST_TIMEOUT or 30s)")
+	showCursor := fs.Bool("show-cursor", false, "Print the backfill cursors and last sync date, then exit")
+	resetCursor := fs.Bool("reset-cursor", false, "Clear the --backfill-direction cursor so the next sync restarts backfill, then exit")
+	jsonOut := fs.Bool("json", false, "Print a JSON summary (totals plus per-window stats) to stdout; progress stays on stderr")
 	fs.Parse(args)
 
+	if *direction != "backward" && *direction != "forward" {
+		log.Fatalf("--backfill-direction: want backward or forward, got %q", *direction)
+	}
+	forward := *direction == "forward"
+	// SAM.gov rejects postedFrom/postedTo spans over a year.
+	if *incrDays < 0 || *incrDays > 365 {
+		log.Fatalf("--incremental-days: want 1-365, or 0 to derive it from the last sync; got %d", *incrDays)
+	}
+	if *activeOnly && *inactiveOnly {
+		log.Fatal("--active-only and --inactive-only are mutually exclusive")
+	}
+	// Cursor keys depend on the filter (gosync.StateKey), so --show-cursor and
+	// --reset-cursor act on the filtered sync's cursors when one is given.
+	scope := gosync.Options{Filter: samgov.SearchParams{NAICS: *naics, SetAside: *setAside, State: *state}}
+	switch {
+	case *activeOnly:
+		scope.Filter.Active = "Yes"
+	case *inactiveOnly:
+		scope.Filter.Active = "No"
+	}
+
 	database, err := db.Open(*dbPath)
 	if err != nil {
 		log.Fatal(err)
 	}
 	defer database.Close()
 
+	if *resetCursor {
+		key, msg := "backfill_cursor", "backfill cursor cleared; next sync resumes from the earliest stored posted date (or today)"
+		if forward {
+			key, msg = "backfill_cursor_forward", "forward backfill cursor cleared; next forward sync starts at --from"
+		}
+		if err := db.DeleteSyncState(database, gosync.StateKey(key, scope)); err != nil {
+			log.Fatal(err)
+		}
+		fmt.Println(msg)
+	}
+	if *showCursor || *resetCursor {
+		for _, key := range []string{
+			gosync.StateKey("backfill_cursor", scope), gosync.StateKey("backfill_cursor_forward", scope),
+			gosync.StateKey("last_sync", scope), "rate_limit_streak", "rate_limit_cooldown_until",
+		} {
+			v, err := db.GetSyncState(database, key)
+			if err != nil {
+				log.Fatal(err)
+			}
+			if v == "" {
+				v = "(unset)"
+			}
+			fmt.Printf("%-27s %s\n", key+":", v)
+		}
+		return
+	}
+
+	var backfillFloor samgov.Date
+	if *from != "" {
+		if backfillFloor, err = parseFlagDate(*from, false); err != nil {
+			log.Fatalf("--from: %v", err)
+		}
+	}
+
+	if *audit {
+		a, err := gosync.AuditCoverage(database, backfillFloor)
+		if err != nil {
+			log.Fatal(err)
+		}
+		if *jsonOut {
+			writeJSONSummary(a)
+		} else {
+			writeSyncAudit(os.Stdout, a)
+		}
+		return
+	}
+
+	if *plan {
+		p, err := gosync.PlanBackfill(database, gosync.Options{
+			MaxCalls:        *maxCalls,
+			From:            backfillFloor,
+			Forward:         forward,
+			IncrementalDays: *incrDays,
+			Filter:          scope.Filter,
+		})
+		if err != nil {
+			log.Fatal(err)
+		}
+		if *jsonOut {
+			writeJSONSummary(p)
+		} else {
+			writeSyncPlan(os.Stdout, p)
+		}
+		return
+	}
+
+	var clientOpts []samgov.ClientOption
+	if flagWasSet(fs, "user-agent") {
+		clientOpts = append(clientOpts, samgov.WithUserAgent(*userAgent))
+	}
+	if *connectTimeout > 0 || *requestTimeout > 0 {
+		clientOpts = append(clientOpts, samgov.WithTimeouts(*connectTimeout, *requestTimeout))
+	}
+	if !*noCache && *cacheTTL > 0 {
+		clientOpts = append(clientOpts, samgov.WithResponseCache(db.ResponseCache{DB: database}, *cacheTTL))
+		if n, err := db.PruneResponseCache(database, *cacheTTL); err != nil {
+			log.Printf("prune response cache: %v", err)
+		} else if n > 0 {
+			log.Printf("pruned %d expired cached responses", n)
+		}
+	}
+
 	apiKey := os.Getenv("SAMGOV_API_KEY")
-	client, err := samgov.NewClient(apiKey)
+	client, err := samgov.NewSourceFromEnv(apiKey, clientOpts...)
 	if err != nil {
 		log.Fatal(err)
 	}
@@ -133,14 +388,47 @@ func cmdSync(args []string) {
 	ctx, stop := signal.NotifyContext(context.Background(), os.Interrupt, syscall.SIGTERM)
 	defer stop()
 
-	if err := gosync.RunCtx(ctx, database, client, gosync.Options{
-		MaxCalls: *maxCalls,
-		DryRun:   *dryRun,
-		From:     *from,
-	}); err != nil {
+	if *compress && !*dryRun {
+		stats, err := db.CompressDescriptions(database)
+		if err != nil {
+			log.Fatalf("compress descriptions: %v", err)
+		}
+		if stats.Rows > 0 {
+			log.Printf("compressed %d stored descriptions: %.1f MB -> %.1f MB (run `govscout export-db` or VACUUM to shrink the file)",
+				stats.Rows, float64(stats.PlainBytes)/(1<<20), float64(stats.CompressedBytes)/(1<<20))
+		}
+	}
+
+	summary, err := gosync.RunWithSummary(ctx, database, client, gosync.Options{
+		MaxCalls:         *maxCalls,
+		DryRun:           *dryRun,
+		From:             backfillFloor,
+		Forward:          forward,
+		IncrementalDays:  *incrDays,
+		OnlyNew:          *onlyNew,
+		BreakerThreshold: *breakerThreshold,
+		BreakerCooldown:  *breakerCooldown,
+		WindowDelay:      time.Duration(*delayMs) * time.Millisecond,
+		AdaptiveDelay:    *adaptiveDelay,
+		SkipEmptyWindows: *skipEmpty,
+		Strict:           *strict,
+		BatchSize:        *batchSize,
+		// Compression is a storage choice, so it isn't remembered: a later
+		// sync without the flag writes the records it touches plain.
+		CompressDescriptions: *compress,
+		Filter:               scope.Filter,
+	})
+	if err != nil {
 		log.Printf("sync error: %v", err)
+		if *jsonOut {
+			summary.Error = err.Error()
+			writeJSONSummary(summary)
+		}
 		os.Exit(1)
 	}
+	if summary.UpsertErrors > 0 {
+		log.Printf("WARNING: %d records failed to store and were skipped (--strict fails the run instead)", summary.UpsertErrors)
+	}
 
 	if !*dryRun {
 		if err := alerts.RunMatcherCtx(ctx, database); err != nil {
@@ -148,20 +436,108 @@ func cmdSync(args []string) {
 			log.Printf("alert matcher error: %v", err)
 		}
 	}
+	if *jsonOut {
+		writeJSONSummary(summary)
+	}
 }
 
-func cmdExport(args []string) {
-	fs := flag.NewFlagSet("export", flag.ExitOnError)
-	dbPath := fs.String("db", "", "SQLite database path")
+func writeJSONSummary(v any) {
+	enc := json.NewEncoder(os.Stdout)
+	enc.SetIndent("", "  ")
+	if err := enc.Encode(v); err != nil {
+		log.Fatal(err)
+	}
+}
+
+// flagWasSet reports whether name was passed explicitly, so an explicit empty
+// value can be told apart from the default.
+func flagWasSet(fs *flag.FlagSet, name string) bool {
+	set := false
+	fs.Visit(func(f *flag.Flag) {
+		if f.Name == name {
+			set = true
+		}
+	})
+	return set
+}
+
+// addFilterFlags registers the opportunity filter flags shared by export,
+// report, and list. The returned func builds the filters after fs.Parse.
+func addFilterFlags(fs *flag.FlagSet) func() db.ListFilters {
 	search := fs.String("search", "", "Text search")
-	naics := fs.String("naics", "", "NAICS codes (comma-separated)")
+	solNumber := fs.String("sol-number", "", "Exact solicitation number(s), case-insensitive (comma-separated)")
+	naics := fs.String("naics", os.Getenv("GOVSCOUT_NAICS"), "NAICS codes (comma-separated; default GOVSCOUT_NAICS or config naics)")
+	psc := fs.String("psc", "", "Product Service (classification) codes (comma-separated)")
 	oppType := fs.String("type", "", "Opportunity types (comma-separated)")
 	setAside := fs.String("set-aside", "", "Set-aside codes (comma-separated)")
 	state := fs.String("state", "", "State code")
 	department := fs.String("department", "", "Department (comma-separated)")
-	activeOnly := fs.Bool("active-only", false, "Only active opportunities")
+	activeOnly := fs.Bool("active-only", false, "Only active opportunities (filters the local database; default includes all)")
+	inactiveOnly := fs.Bool("inactive-only", false, "Only inactive (archived or cancelled) opportunities")
+	latestOnly := fs.Bool("latest-only", false, "Only the newest notice per solicitation number (drop superseded amendments)")
+	hasDocs := fs.Bool("has-docs", false, "Only opportunities with attached documents (resource links)")
+	tag := fs.String("tag", "", "Only opportunities with these local tags (comma-separated, see `govscout tag`)")
+	minDays := fs.Int("min-days-to-respond", 0, "Only opportunities whose response deadline is at least N days away (excludes ones without a deadline)")
+	addedSince := fs.String("added-since", "", "Only opportunities first stored since (e.g. 24h, 7d, 2026-01-31)")
+	from := fs.String("from", "", "Posted on or after (MM/DD/YYYY; YYYY or MM/YYYY means the period's first day)")
+	to := fs.String("to", "", "Posted on or before (MM/DD/YYYY; YYYY or MM/YYYY means the period's last day)")
+	postedToday := fs.Bool("posted-today", false, "Posted today (local time)")
+	postedThisWeek := fs.Bool("posted-this-week", false, "Posted since Monday (local time)")
+	postedLastDays := fs.Int("posted-last-days", 0, "Posted in the last N days, including today")
+
+	return func() db.ListFilters {
+		f := db.ListFilters{
+			Search:             *search,
+			SolicitationNumber: *solNumber,
+			NAICSCode:          *naics,
+			ClassificationCode: *psc,
+			OppType:            *oppType,
+			SetAside:           *setAside,
+			State:              *state,
+			Department:         *department,
+			DateFrom:           flagDate("from", *from, false),
+			DateTo:             flagDate("to", *to, true),
+			MinDaysToRespond:   *minDays,
+			ActiveOnly:         *activeOnly,
+			InactiveOnly:       *inactiveOnly,
+			LatestOnly:         *latestOnly,
+			HasDocuments:       *hasDocs,
+			Tag:                *tag,
+		}
+		if f.ActiveOnly && f.InactiveOnly {
+			log.Fatal("--active-only and --inactive-only are mutually exclusive")
+		}
+		if err := applyPostedShortcuts(&f, time.Now(), *postedToday, *postedThisWeek, *postedLastDays); err != nil {
+			log.Fatal(err)
+		}
+		if *addedSince != "" {
+			ts, err := db.ParseSince(*addedSince, time.Now())
+			if err != nil {
+				log.Fatalf("--added-since: %v", err)
+			}
+			f.AddedSince = *addedSince
+			f.CreatedAfter = ts
+		}
+		return f
+	}
+}
+
+func cmdExport(args []string) {
+	fs := flag.NewFlagSet("export", flag.ExitOnError)
+	dbPath := fs.String("db", "", "SQLite database path")
+	filterFlags := addFilterFlags(fs)
 	out := fs.String("out", "", "Output file path (default: stdout)")
+	parquetOut := fs.String("parquet", "", "Write Parquet to this path instead of CSV (build with -tags parquet)")
+	withContacts := fs.Bool("with-contacts", false, "Write NDJSON of full SAM.gov-shaped records, contacts included, instead of CSV")
 	fs.Parse(args)
+	filters := filterFlags()
+
+	if *parquetOut != "" && !db.ParquetSupported {
+		log.Fatal("--parquet: this binary was built without parquet support (go build -tags parquet)")
+	}
+	if *parquetOut != "" && *withContacts {
+		log.Fatal("--with-contacts and --parquet are mutually exclusive")
+	}
 
 	database, err := db.Open(*dbPath)
 	if err != nil {
@@ -169,21 +545,27 @@ func cmdExport(args []string) {
 	}
 	defer database.Close()
 
-	filters := db.ListFilters{
-		Search:     *search,
-		NAICSCode:  *naics,
-		OppType:    *oppType,
-		SetAside:   *setAside,
-		State:      *state,
-		Department: *department,
-		ActiveOnly: *activeOnly,
-	}
-
 	items, err := db.ExportOpportunities(database, filters)
 	if err != nil {
 		log.Fatal(err)
 	}
 
+	if *parquetOut != "" {
+		f, err := os.Create(*parquetOut)
+		if err != nil {
+			log.Fatal(err)
+		}
+		if err := db.WriteParquet(f, items); err != nil {
+			f.Close()
+			log.Fatal(err)
+		}
+		if err := f.Close(); err != nil {
+			log.Fatal(err)
+		}
+		fmt.Fprintf(os.Stderr, "exported %d opportunities to %s\n", len(items), *parquetOut)
+		return
+	}
+
 	var w *os.File
 	if *out != "" {
 		w, err = os.Create(*out)
@@ -195,11 +577,699 @@ func cmdExport(args []string) {
 		w = os.Stdout
 	}
 
-	if err := db.WriteCSV(w, items); err != nil {
+	n := len(items)
+	if *withContacts {
+		ids := make([]string, len(items))
+		for i, it := range items {
+			ids[i] = it.ID
+		}
+		n, err = db.WriteNDJSON(w, database, ids)
+	} else {
+		err = db.WriteCSV(w, items)
+	}
+	if err != nil {
 		log.Fatal(err)
 	}
 	if *out != "" {
-		fmt.Fprintf(os.Stderr, "exported %d opportunities to %s\n", len(items), *out)
+		fmt.Fprintf(os.Stderr, "exported %d opportunities to %s\n", n, *out)
+	}
+}
+
+// defaultPageSize is list's --limit default: GOVSCOUT_PAGE_SIZE when it is a
+// positive integer (capped at db.MaxPageSize), else 25.
+func defaultPageSize() int {
+	if n, err := strconv.Atoi(os.Getenv("GOVSCOUT_PAGE_SIZE")); err == nil && n > 0 {
+		return min(n, db.MaxPageSize())
+	}
+	return 25
+}
+
+// checkListLimit rejects a --limit the database layer would otherwise
+// quietly replace with its default page size.
+func checkListLimit(limit int) error {
+	if most := db.MaxPageSize(); limit < 1 || limit > most {
+		return fmt.Errorf("--limit must be between 1 and %d (raise the cap with %s), got %d", most, db.MaxPageSizeEnv, limit)
+	}
+	return nil
+}
+
+func cmdList(args []string) {
+	fs := flag.NewFlagSet("list", flag.ExitOnError)
+	dbPath := fs.String("db", "", "SQLite database path")
+	filterFlags := addFilterFlags(fs)
+	limit := fs.Int("limit", defaultPageSize(), "Number of opportunities to show (max 100, or GOVSCOUT_MAX_PAGE_SIZE; default GOVSCOUT_PAGE_SIZE or config page_size, else 25)")
+	offset := fs.Int("offset", 0, "Skip this many matches, to page through them with --limit")
+	sortBy := fs.String("sort", "posted_date", "Sort by posted_date, response_deadline, title, department, or freshness")
+	sortDir := fs.String("sort-dir", "desc", "Sort direction: asc or desc")
+	width := fs.Int("width", 0, "Table width in columns (default: terminal width, or fixed widths when not a terminal)")
+	naicsLabels := fs.Bool("naics-labels", false, "Add a NAICS column with the industry title")
+	oneline := fs.Bool("oneline", false, "Print notice_id<TAB>posted_date<TAB>title per line, for fzf and cut (every match unless --limit is set)")
+	fs.Parse(args)
+	filters := filterFlags()
+	if err := checkListLimit(*limit); err != nil {
+		log.Fatal(err)
+	}
+	filters.Limit = *limit
+	if *offset < 0 {
+		log.Fatal("--offset must not be negative")
+	}
+	filters.Offset = *offset
+	if !slices.Contains(db.SortKeys(), *sortBy) {
+		log.Fatalf("--sort: want one of %s, got %q", strings.Join(db.SortKeys(), ", "), *sortBy)
+	}
+	if *sortDir != "asc" && *sortDir != "desc" {
+		log.Fatalf("--sort-dir: want asc or desc, got %q", *sortDir)
+	}
+	filters.SortBy, filters.SortDir = *sortBy, *sortDir
+
+	database, err := db.Open(*dbPath)
+	if err != nil {
+		log.Fatal(err)
+	}
+	defer database.Close()
+
+	if *oneline {
+		var items []db.OpportunityListItem
+		if flagWasSet(fs, "limit") {
+			var result *db.ListResult
+			if result, err = db.ListOpportunities(database, filters); err == nil {
+				items = result.Opportunities
+			}
+		} else if items, err = db.ExportOpportunities(database, filters); err == nil {
+			items = items[min(*offset, len(items)):]
+		}
+		if err != nil {
+			log.Fatal(err)
+		}
+		w := bufio.NewWriter(os.Stdout)
+		writeOneline(w, items)
+		if err := w.Flush(); err != nil {
+			log.Fatal(err)
+		}
+		return
+	}
+
+	result, err := db.ListOpportunities(database, filters)
+	if err != nil {
+		log.Fatal(err)
+	}
+	if len(result.Opportunities) == 0 {
+		if result.Total > 0 {
+			fmt.Printf("no opportunities at --offset %d (%d match)\n", *offset, result.Total)
+		} else {
+			fmt.Println("no matching opportunities")
+		}
+		return
+	}
+
+	total := tableWidth(*width, os.Stdout)
+	naicsWidth := 0
+	if *naicsLabels {
+		naicsWidth = naicsColumnWidth
+		if total > 0 {
+			total = max(total-naicsWidth-2, 1)
+		}
+	}
+	titleWidth, orgWidth := columnWidths(total)
+	if err := writeOpportunityTable(os.Stdout, result.Opportunities, titleWidth, orgWidth, naicsWidth); err != nil {
+		log.Fatal(err)
+	}
+	fmt.Print("\n" + pageFooter(*offset, len(result.Opportunities), result.Total))
+}
+
+func cmdReport(args []string) {
+	fs := flag.NewFlagSet("report", flag.ExitOnError)
+	dbPath := fs.String("db", "", "SQLite database path")
+	filterFlags := addFilterFlags(fs)
+	limit := fs.Int("limit", 200, "Maximum opportunities in the table (newest first, 0 = all)")
+	out := fs.String("html", "", "Output HTML file path (required)")
+	fs.Parse(args)
+	filters := filterFlags()
+
+	if *out == "" {
+		fmt.Fprintln(os.Stderr, "Usage: govscout report --html out.html [filter flags]")
+		os.Exit(1)
+	}
+
+	database, err := db.Open(*dbPath)
+	if err != nil {
+		log.Fatal(err)
+	}
+	defer database.Close()
+
+	items, err := db.ExportOpportunities(database, filters)
+	if err != nil {
+		log.Fatal(err)
+	}
+
+	f, err := os.Create(*out)
+	if err != nil {
+		log.Fatal(err)
+	}
+	if err := web.WriteReport(f, items, filters, *limit, time.Now()); err != nil {
+		f.Close()
+		log.Fatal(err)
+	}
+	if err := f.Close(); err != nil {
+		log.Fatal(err)
+	}
+	shown := len(items)
+	if *limit > 0 && shown > *limit {
+		shown = *limit
+	}
+	fmt.Fprintf(os.Stderr, "wrote report of %d opportunities (%d listed) to %s\n", len(items), shown, *out)
+}
+
+func cmdLog(args []string) {
+	fs := flag.NewFlagSet("log", flag.ExitOnError)
+	dbPath := fs.String("db", "", "SQLite database path")
+	limit := fs.Int("limit", 20, "Number of recent sync runs to show")
+	failedOnly := fs.Bool("failed-only", false, "Only runs that errored or were rate limited")
+	offset := fs.Int("offset", 0, "Skip this many of the newest matching runs (page back through history)")
+	runContext := fs.String("context", "", "Only runs of this phase: incremental, backfill, or refresh")
+	fs.Parse(args)
+
+	database, err := db.Open(*dbPath)
+	if err != nil {
+		log.Fatal(err)
+	}
+	defer database.Close()
+
+	runs, total, err := db.QuerySyncRuns(database, db.SyncRunQuery{
+		Limit:      *limit,
+		Offset:     *offset,
+		Context:    *runContext,
+		FailedOnly: *failedOnly,
+	})
+	if err != nil {
+		log.Fatal(err)
+	}
+	if len(runs) == 0 {
+		fmt.Println("no sync runs recorded")
+		return
+	}
+
+	if err := writeSyncRunTable(os.Stdout, runs, useColor(os.Stdout)); err != nil {
+		log.Fatal(err)
+	}
+	if int64(*offset+len(runs)) < total {
+		fmt.Printf("\nshowing %d-%d of %d (--offset %d for older)\n", *offset+1, *offset+len(runs), total, *offset+len(runs))
+	}
+}
+
+// useColor reports whether f is an interactive terminal and NO_COLOR is unset.
+func useColor(f *os.File) bool {
+	return os.Getenv("NO_COLOR") == "" && isTerminal(f)
+}
+
+func cmdDoctor(args []string) {
+	fs := flag.NewFlagSet("doctor", flag.ExitOnError)
+	dbPath := fs.String("db", "", "SQLite database path")
+	fs.Parse(args)
+
+	d, err := db.Diagnose(*dbPath)
+	if err != nil {
+		log.Fatal(err)
+	}
+	writeDiagnosis(os.Stdout, d, *dbPath)
+	if !d.Exists {
+		os.Exit(1)
+	}
+}
+
+func cmdCheck(args []string) {
+	fs := flag.NewFlagSet("check", flag.ExitOnError)
+	dbPath := fs.String("db", "", "SQLite database path")
+	repair := fs.Bool("repair", false, "Delete orphaned contacts")
+	fs.Parse(args)
+
+	database, err := db.Open(*dbPath)
+	if err != nil {
+		log.Fatal(err)
+	}
+	defer database.Close()
+
+	report, err := db.CheckIntegrity(database)
+	if err != nil {
+		log.Fatal(err)
+	}
+
+	if *repair && report.OrphanedContacts > 0 {
+		n, err := db.DeleteOrphanedContacts(database)
+		if err != nil {
+			log.Fatalf("delete orphaned contacts: %v", err)
+		}
+		fmt.Printf("repair: deleted %d orphaned contacts\n", n)
+		if report, err = db.CheckIntegrity(database); err != nil {
+			log.Fatal(err)
+		}
+	}
+
+	for _, msg := range report.IntegrityErrors {
+		fmt.Printf("integrity: %s\n", msg)
+	}
+	for _, v := range report.ForeignKeyViolations {
+		if v.RowID != nil {
+			fmt.Printf("foreign key: %s rowid %d references missing %s\n", v.Table, *v.RowID, v.Parent)
+		} else {
+			fmt.Printf("foreign key: %s references missing %s\n", v.Table, v.Parent)
+		}
+	}
+	if report.OrphanedContacts > 0 {
+		fmt.Printf("orphaned contacts: %d (run with --repair to delete)\n", report.OrphanedContacts)
+	}
+
+	if !report.OK() {
+		fmt.Println("NOT OK")
+		os.Exit(1)
+	}
+	fmt.Println("OK")
+}
+
+func cmdMerge(args []string) {
+	fs := flag.NewFlagSet("merge", flag.ExitOnError)
+	dbPath := fs.String("db", "", "SQLite database path (merge target)")
+	fs.Parse(args)
+
+	if fs.NArg() != 1 {
+		fmt.Fprintln(os.Stderr, "Usage: govscout merge [--db path] <other.db>")
+		os.Exit(1)
+	}
+
+	database, err := db.Open(*dbPath)
+	if err != nil {
+		log.Fatal(err)
+	}
+	defer database.Close()
+
+	stats, err := db.MergeDatabase(database, fs.Arg(0))
+	if err != nil {
+		log.Fatal(err)
+	}
+	fmt.Printf("inserted %d, updated %d, skipped %d (local copy as new or newer), %d contacts copied\n",
+		stats.Inserted, stats.Updated, stats.Skipped, stats.Contacts)
+}
+
+func cmdExportDB(args []string) {
+	fs := flag.NewFlagSet("export-db", flag.ExitOnError)
+	dbPath := fs.String("db", "", "SQLite database path")
+	force := fs.Bool("force", false, "Overwrite the destination if it exists")
+	fs.Parse(args)
+
+	if fs.NArg() != 1 {
+		fmt.Fprintln(os.Stderr, "Usage: govscout export-db [--db path] [--force] <dest.db>")
+		os.Exit(1)
+	}
+
+	database, err := db.Open(*dbPath)
+	if err != nil {
+		log.Fatal(err)
+	}
+	defer database.Close()
+
+	size, err := db.ExportDatabase(database, fs.Arg(0), *force)
+	if err != nil {
+		log.Fatal(err)
+	}
+	fmt.Printf("wrote %s (%.1f MB, journal_mode=delete, integrity ok)\n", fs.Arg(0), float64(size)/(1<<20))
+}
+
+func cmdAwardee(args []string) {
+	fs := flag.NewFlagSet("awardee", flag.ExitOnError)
+	dbPath := fs.String("db", "", "SQLite database path")
+	limit := fs.Int("limit", 50, "Max awards to list (the total covers every match)")
+	jsonOut := fs.Bool("json", false, "Print the rollup as JSON")
+	fs.Parse(args)
+
+	if fs.NArg() != 1 {
+		fmt.Fprintln(os.Stderr, "Usage: govscout awardee [--db path] [--limit N] [--json] <name | UEI>")
+		os.Exit(1)
+	}
+
+	database, err := db.Open(*dbPath)
+	if err != nil {
+		log.Fatal(err)
+	}
+	defer database.Close()
+
+	rollup, err := db.SearchAwardees(database, fs.Arg(0), *limit, 0)
+	if err != nil {
+		log.Fatal(err)
+	}
+	if *jsonOut {
+		writeJSONSummary(rollup)
+		return
+	}
+	if rollup.Total == 0 {
+		fmt.Printf("no stored awards match %q\n", rollup.Query)
+		return
+	}
+	if err := writeAwardeeRollup(os.Stdout, rollup, defaultTitleWidth); err != nil {
+		log.Fatal(err)
+	}
+}
+
+func cmdRefresh(args []string) {
+	fs := flag.NewFlagSet("refresh", flag.ExitOnError)
+	dbPath := fs.String("db", "", "SQLite database path")
+	olderThan := fs.String("older-than", "30d", "Re-fetch opportunities not changed or re-checked within this window (e.g. 30d, 72h)")
+	limit := fs.Int("limit", 100, "Max opportunities to re-fetch")
+	maxCalls := fs.Int("max-calls", 18, "Max API calls for this run (one per opportunity)")
+	fs.Parse(args)
+
+	cutoff, err := db.ParseSince(*olderThan, time.Now())
+	if err != nil || cutoff == "" {
+		log.Fatalf("--older-than: invalid value %q", *olderThan)
+	}
+
+	database, err := db.Open(*dbPath)
+	if err != nil {
+		log.Fatal(err)
+	}
+	defer database.Close()
+
+	client, err := samgov.NewSourceFromEnv(os.Getenv("SAMGOV_API_KEY"))
+	if err != nil {
+		log.Fatal(err)
+	}
+
+	ctx, stop := signal.NotifyContext(context.Background(), os.Interrupt, syscall.SIGTERM)
+	defer stop()
+
+	res, err := gosync.Refresh(ctx, database, client, cutoff, *limit, *maxCalls)
+	if res != nil {
+		fmt.Printf("refreshed %d: %d changed, %d unchanged, %d no longer listed, %d failed (%d api calls)\n",
+			res.Checked, res.Changed, res.Unchanged, res.Missing, res.Failed, res.APICalls)
+		if res.RateLimited {
+			fmt.Println("stopped early: rate limited")
+		}
+		if res.Maintenance {
+			fmt.Println("stopped early: SAM.gov is down for maintenance")
+		}
+	}
+	if err != nil {
+		log.Printf("refresh error: %v", err)
+		os.Exit(1)
+	}
+}
+
+func cmdTag(args []string) {
+	fs := flag.NewFlagSet("tag", flag.ExitOnError)
+	dbPath := fs.String("db", "", "SQLite database path")
+	status := fs.String("status", "", "Tag to set: "+strings.Join(db.TagStatuses, ", "))
+	note := fs.String("note", "", "Note to keep with the tag (replaces any earlier note)")
+	remove := fs.Bool("remove", false, "Remove the --status tag instead of setting it")
+	fs.Parse(args)
+	// Allow flags after the notice ID too (govscout tag <id> --status pursuing).
+	if fs.NArg() > 0 {
+		id := fs.Arg(0)
+		fs.Parse(fs.Args()[1:])
+		if fs.NArg() == 0 {
+			fs.Parse([]string{id})
+		}
+	}
+
+	if fs.NArg() != 1 || (*remove && *status == "") {
+		fmt.Fprintln(os.Stderr, "Usage: govscout tag [--db path] <notice-id> [--status interested|pursuing|passed [--note text] [--remove]]")
+		os.Exit(1)
+	}
+	noticeID := fs.Arg(0)
+
+	database, err := db.Open(*dbPath)
+	if err != nil {
+		log.Fatal(err)
+	}
+	defer database.Close()
+
+	switch {
+	case *remove:
+		ok, err := db.DeleteTag(database, noticeID, *status)
+		if err != nil {
+			log.Fatal(err)
+		}
+		if !ok {
+			fmt.Fprintf(os.Stderr, "%s is not tagged %s\n", noticeID, *status)
+			os.Exit(1)
+		}
+	case *status != "":
+		if err := db.SetTag(database, noticeID, *status, *note); err != nil {
+			log.Fatal(err)
+		}
+		if d, err := db.GetOpportunity(database, noticeID); err == nil && d == nil {
+			log.Printf("note: %s isn't stored locally yet; the tag applies once it syncs", noticeID)
+		}
+	}
+
+	tags, err := db.ListTags(database, noticeID)
+	if err != nil {
+		log.Fatal(err)
+	}
+	if len(tags) == 0 {
+		fmt.Printf("%s: no tags\n", noticeID)
+		return
+	}
+	for _, t := range tags {
+		fmt.Printf("%-10s %s  %s\n", t.Tag, t.CreatedAt, t.Note)
+	}
+}
+
+func cmdReindex(args []string) {
+	fs := flag.NewFlagSet("reindex", flag.ExitOnError)
+	dbPath := fs.String("db", "", "SQLite database path")
+	fs.Parse(args)
+
+	database, err := db.Open(*dbPath)
+	if err != nil {
+		log.Fatal(err)
+	}
+	defer database.Close()
+
+	start := time.Now()
+	timings, analyze, err := db.Reindex(database)
+	if err != nil {
+		log.Fatal(err)
+	}
+	for _, t := range timings {
+		fmt.Printf("%-32s %s\n", t.Name, t.Duration.Round(time.Millisecond))
+	}
+	fmt.Printf("%-32s %s\n", "ANALYZE", analyze.Round(time.Millisecond))
+	fmt.Printf("rebuilt %d indexes in %s\n", len(timings), time.Since(start).Round(time.Millisecond))
+}
+
+func cmdStats(args []string) {
+	fs := flag.NewFlagSet("stats", flag.ExitOnError)
+	dbPath := fs.String("db", "", "SQLite database path")
+	completeness := fs.Bool("completeness", false, "Show the share of rows with each key column filled in")
+	fs.Parse(args)
+
+	database, err := db.Open(*dbPath)
+	if err != nil {
+		log.Fatal(err)
+	}
+	defer database.Close()
+
+	if *completeness {
+		c, err := db.GetCompleteness(database)
+		if err != nil {
+			log.Fatal(err)
+		}
+		writeCompleteness(os.Stdout, c)
+		return
+	}
+
+	stats, err := db.GetFilterStats(database)
+	if err != nil {
+		log.Fatal(err)
+	}
+	writeFilterStats(os.Stdout, stats)
+}
+
+func cmdGet(args []string) {
+	fs := flag.NewFlagSet("get", flag.ExitOnError)
+	dbPath := fs.String("db", "", "SQLite database path")
+	format := fs.String("format", "json", "Output format: json, text, or markdown")
+	compact := fs.Bool("compact", false, "Print single-line JSON instead of indented")
+	highlightTerm := fs.String("highlight", "", "Highlight this term in the description (json: printed after the JSON; text: inline)")
+	filterFlags := addFilterFlags(fs)
+	fs.Parse(args)
+	// Allow flags after the target too (govscout get latest --naics 541512).
+	var target string
+	if fs.NArg() > 0 {
+		target = fs.Arg(0)
+		fs.Parse(fs.Args()[1:])
+	}
+
+	if target == "" || fs.NArg() != 0 {
+		fmt.Fprintln(os.Stderr, "Usage: govscout get [--db path] [--format json|text|markdown] [--compact] [--highlight term] <notice-id | latest [filter flags]>")
+		os.Exit(1)
+	}
+	switch *format {
+	case "json", "text":
+	case "markdown":
+		if *highlightTerm != "" {
+			log.Fatal("--highlight applies to the json and text formats")
+		}
+	default:
+		log.Fatalf("--format: want json, text, or markdown, got %q", *format)
+	}
+
+	database, err := db.Open(*dbPath)
+	if err != nil {
+		log.Fatal(err)
+	}
+	defer database.Close()
+
+	var opp map[string]any
+	if target == "latest" {
+		opp, err = latestOpportunity(database, filterFlags())
+	} else {
+		opp, err = db.GetOpportunityFull(database, target)
+	}
+	if err != nil {
+		log.Fatal(err)
+	}
+	if opp == nil {
+		if target == "latest" {
+			fmt.Fprintln(os.Stderr, "no opportunity matches those filters")
+		} else {
+			fmt.Fprintf(os.Stderr, "opportunity %s not found\n", target)
+		}
+		os.Exit(1)
+	}
+	switch *format {
+	case "text":
+		writeOpportunityText(os.Stdout, opp, *highlightTerm, useColor(os.Stdout))
+		return
+	case "markdown":
+		writeOpportunityMarkdown(os.Stdout, opp)
+		return
+	}
+	enc := json.NewEncoder(os.Stdout)
+	if !*compact {
+		enc.SetIndent("", "  ")
+	}
+	if err := enc.Encode(opp); err != nil {
+		log.Fatal(err)
+	}
+
+	if *highlightTerm != "" {
+		desc, _ := opp["description"].(string)
+		desc = strings.TrimSpace(db.StripHTML(desc))
+		if desc == "" {
+			desc = "(no description)"
+		}
+		fmt.Printf("\ndescription:\n%s\n", highlight(desc, *highlightTerm, useColor(os.Stdout)))
+	}
+}
+
+// latestLookbackDays is how far back the SAM.gov fallback in
+// latestOpportunity searches.
+const latestLookbackDays = 30
+
+// latestOpportunity returns the most recently posted stored opportunity
+// matching filters, or nil if none does. Only when the database holds no
+// opportunities at all does it fall back to one page of recent SAM.gov
+// results, which are returned but not stored.
+func latestOpportunity(database *sql.DB, filters db.ListFilters) (map[string]any, error) {
+	filters.Limit, filters.Offset = 1, 0
+	filters.SortBy, filters.SortDir = "posted_date", "desc"
+	filters.OmitDescription = true
+	res, err := db.ListOpportunities(database, filters)
+	if err != nil {
+		return nil, err
+	}
+	if len(res.Opportunities) > 0 {
+		return db.GetOpportunityFull(database, res.Opportunities[0].ID)
+	}
+
+	all, err := db.ListOpportunities(database, db.ListFilters{Limit: 1, OmitDescription: true})
+	if err != nil {
+		return nil, err
+	}
+	if all.Total > 0 {
+		return nil, nil
+	}
+
+	log.Printf("database is empty; searching SAM.gov for the last %d days", latestLookbackDays)
+	client, err := samgov.NewSourceFromEnv(os.Getenv("SAMGOV_API_KEY"))
+	if err != nil {
+		return nil, err
+	}
+	today := samgov.NewDate(time.Now())
+	resp, err := client.SearchCtx(context.Background(), samgov.SearchParams{
+		Limit:              1000,
+		PostedFrom:         today.AddDays(-latestLookbackDays),
+		PostedTo:           today,
+		Title:              filters.Search,
+		Type:               filters.OppType,
+		NAICS:              filters.NAICSCode,
+		ClassificationCode: filters.ClassificationCode,
+		State:              filters.State,
+		SetAside:           filters.SetAside,
+		SolicitationNumber: filters.SolicitationNumber,
+	})
+	if err != nil {
+		return nil, err
+	}
+
+	// SAM.gov doesn't sort by posted date, so pick the newest from the page.
+	var latest map[string]any
+	var latestPosted samgov.Date
+	for _, rec := range resp.OpportunitiesData {
+		s, _ := rec["postedDate"].(string)
+		if len(s) > 10 {
+			s = s[:10]
+		}
+		posted, _ := samgov.ParseDate(s)
+		if latest == nil || posted.After(latestPosted) {
+			latest, latestPosted = rec, posted
+		}
+	}
+	return latest, nil
+}
+
+// highlight wraps case-insensitive matches of term in bold underline. With
+// color off (not a terminal, or NO_COLOR) s is returned unchanged.
+func highlight(s, term string, color bool) string {
+	if !color || term == "" {
+		return s
+	}
+	re := regexp.MustCompile("(?i)" + regexp.QuoteMeta(term))
+	return re.ReplaceAllStringFunc(s, func(m string) string {
+		return "\x1b[1;4m" + m + "\x1b[0m"
+	})
+}
+
+func cmdImport(args []string) {
+	fs := flag.NewFlagSet("import", flag.ExitOnError)
+	dbPath := fs.String("db", "", "SQLite database path")
+	fs.Parse(args)
+
+	if fs.NArg() != 1 {
+		fmt.Fprintln(os.Stderr, "Usage: govscout import [--db path] <dump.json|dump.ndjson|->")
+		os.Exit(1)
+	}
+
+	in := os.Stdin
+	if path := fs.Arg(0); path != "-" {
+		f, err := os.Open(path)
+		if err != nil {
+			log.Fatal(err)
+		}
+		defer f.Close()
+		in = f
+	}
+
+	database, err := db.Open(*dbPath)
+	if err != nil {
+		log.Fatal(err)
+	}
+	defer database.Close()
+
+	stats, err := db.ImportOpportunities(database, in)
+	fmt.Printf("inserted %d, updated %d, unchanged %d, skipped %d\n", stats.Inserted, stats.Updated, stats.Unchanged, stats.Skipped)
+	if err != nil {
+		log.Fatal(err)
 	}
 }
 
@@ -359,6 +1429,12 @@ func cmdMigrate(args []string) {
 	oppRows, _ := res.RowsAffected()
 	log.Printf("migrated %d opportunities", oppRows)
 
+	// The copy skips the upserts, so derive the normalized columns
+	// (response_deadline_utc, description_text, word counts) here.
+	if err := db.BackfillDerivedColumns(database); err != nil {
+		log.Fatalf("backfill migrated opportunities: %v", err)
+	}
+
 	// Migrate contacts
 	res, err = database.Exec(`INSERT OR IGNORE INTO contacts (
 		id, notice_id, contact_type, full_name, email, phone, title, created_at
@@ -0,0 +1,96 @@
+package main
+
+import (
+	"database/sql"
+	"fmt"
+	"os"
+	"path/filepath"
+	"testing"
+	"time"
+
+	"github.com/theognis1002/govscout/internal/db"
+	"github.com/theognis1002/govscout/internal/samgov"
+)
+
+func openLatestTestDB(t *testing.T) *sql.DB {
+	t.Helper()
+	d, err := db.Open(filepath.Join(t.TempDir(), "test.db"))
+	if err != nil {
+		t.Fatal(err)
+	}
+	t.Cleanup(func() { d.Close() })
+	return d
+}
+
+func TestLatestOpportunity_NewestStoredMatch(t *testing.T) {
+	d := openLatestTestDB(t)
+	// An empty fixtures dir makes any SAM.gov search fail.
+	t.Setenv(samgov.MockDirEnv, t.TempDir())
+	if _, err := d.Exec(`INSERT INTO opportunities (id, title, posted_date, naics_code) VALUES
+		('old', 'Old IT', '01/05/2026', '541512'),
+		('new', 'New IT', '02/10/2026', '541512'),
+		('newest', 'Janitorial', '03/01/2026', '561720')`); err != nil {
+		t.Fatal(err)
+	}
+
+	opp, err := latestOpportunity(d, db.ListFilters{NAICSCode: "541512"})
+	if err != nil {
+		t.Fatal(err)
+	}
+	if opp == nil || opp["noticeId"] != "new" {
+		t.Errorf("latest 541512 = %v, want 'new'", opp)
+	}
+
+	// No stored match: the database isn't empty, so SAM.gov isn't asked.
+	opp, err = latestOpportunity(d, db.ListFilters{NAICSCode: "999999"})
+	if err != nil || opp != nil {
+		t.Errorf("latest 999999 = %v, %v; want nil, nil without a search", opp, err)
+	}
+}
+
+func TestLatestOpportunity_FallsBackToNewestFromSAM(t *testing.T) {
+	d := openLatestTestDB(t)
+	dir := t.TempDir()
+	today := samgov.NewDate(time.Now())
+	fixture := fmt.Sprintf(`{"totalRecords": 3, "opportunitiesData": [
+		{"noticeId": "mid", "postedDate": %q},
+		{"noticeId": "newest", "postedDate": %q},
+		{"noticeId": "oldest", "postedDate": %q}
+	]}`, today.AddDays(-5).String(), today.AddDays(-1).String(), today.AddDays(-20).String())
+	if err := os.WriteFile(filepath.Join(dir, "page.json"), []byte(fixture), 0o600); err != nil {
+		t.Fatal(err)
+	}
+	t.Setenv(samgov.MockDirEnv, dir)
+
+	opp, err := latestOpportunity(d, db.ListFilters{})
+	if err != nil {
+		t.Fatal(err)
+	}
+	if opp == nil || opp["noticeId"] != "newest" {
+		t.Errorf("latest from SAM.gov = %v, want 'newest'", opp)
+	}
+}
+
+func TestCheckListLimit(t *testing.T) {
+	t.Setenv(db.MaxPageSizeEnv, "")
+	for _, tt := range []struct {
+		limit int
+		ok    bool
+	}{
+		{1, true},
+		{100, true},
+		{101, false}, // ListOpportunities would silently serve 25
+		{500, false},
+		{0, false},
+		{-5, false},
+	} {
+		if err := checkListLimit(tt.limit); (err == nil) != tt.ok {
+			t.Errorf("checkListLimit(%d) = %v, want ok=%v", tt.limit, err, tt.ok)
+		}
+	}
+
+	t.Setenv(db.MaxPageSizeEnv, "500")
+	if err := checkListLimit(500); err != nil {
+		t.Errorf("checkListLimit(500) with a raised cap = %v", err)
+	}
+}
@@ -0,0 +1,279 @@
+package main
+
+import (
+	"fmt"
+	"io"
+	"os"
+	"path/filepath"
+	"strconv"
+	"strings"
+	"text/tabwriter"
+
+	"github.com/theognis1002/govscout/internal/codes"
+	"github.com/theognis1002/govscout/internal/db"
+	gosync "github.com/theognis1002/govscout/internal/sync"
+)
+
+// Fixed column widths used when the terminal width is unknown (pipes,
+// redirects) and no --width was given.
+const (
+	defaultTitleWidth = 50
+	defaultOrgWidth   = 40
+	minTitleWidth     = 20
+	minOrgWidth       = 10
+	// naicsColumnWidth is the width of the optional NAICS column (code plus
+	// title, truncated).
+	naicsColumnWidth = 36
+)
+
+// fixedColumnsWidth is the room taken by POSTED, DEADLINE, and NOTICE ID
+// (10 + 10 + 32) plus the two-space padding between the five columns.
+const fixedColumnsWidth = 10 + 10 + 32 + 4*2
+
+// tableWidth picks the total width to lay the table out in: an explicit
+// override, else the terminal width (COLUMNS if set), else 0 for the fixed
+// defaults.
+func tableWidth(override int, f *os.File) int {
+	if override > 0 {
+		return override
+	}
+	if !isTerminal(f) {
+		return 0
+	}
+	if n, err := strconv.Atoi(os.Getenv("COLUMNS")); err == nil && n > 0 {
+		return n
+	}
+	return terminalWidth(f)
+}
+
+// columnWidths splits the width left after the fixed columns between title
+// and organization, 5:4, never going below the minimums. A zero total keeps
+// the historical fixed widths.
+func columnWidths(total int) (title, org int) {
+	if total <= 0 {
+		return defaultTitleWidth, defaultOrgWidth
+	}
+	avail := total - fixedColumnsWidth
+	title = max(avail*5/9, minTitleWidth)
+	org = max(avail-title, minOrgWidth)
+	return title, org
+}
+
+// writeOpportunityTable renders items as an aligned table, truncating title
+// and organization to the given widths. A positive naicsWidth adds a NAICS
+// column showing "code — title".
+func writeOpportunityTable(w io.Writer, items []db.OpportunityListItem, titleWidth, orgWidth, naicsWidth int) error {
+	tw := tabwriter.NewWriter(w, 0, 0, 2, ' ', 0)
+	header := "POSTED\tDEADLINE\tTITLE\tORGANIZATION"
+	if naicsWidth > 0 {
+		header += "\tNAICS"
+	}
+	fmt.Fprintln(tw, header+"\tNOTICE ID")
+	for _, o := range items {
+		deadline := derefStr(o.ResponseDeadline)
+		if len(deadline) > 10 {
+			deadline = deadline[:10]
+		}
+		fmt.Fprintf(tw, "%s\t%s\t%s\t%s\t",
+			derefStr(o.PostedDate), deadline,
+			truncateRunes(derefStr(o.Title), titleWidth),
+			truncateRunes(derefStr(o.Department), orgWidth))
+		if naicsWidth > 0 {
+			fmt.Fprintf(tw, "%s\t", truncateRunes(codes.NAICSLabel(derefStr(o.NAICSCode)), naicsWidth))
+		}
+		fmt.Fprintf(tw, "%s\n", o.ID)
+	}
+	return tw.Flush()
+}
+
+// writeSyncRunTable renders `govscout log`. With color, errored runs are red
+// and rate-limited ones yellow.
+func writeSyncRunTable(w io.Writer, runs []db.SyncRunRow, color bool) error {
+	// Every row starts with a same-width color code so tabwriter alignment
+	// is unaffected by highlighting.
+	paint := func(code string) string {
+		if !color {
+			return ""
+		}
+		return code
+	}
+	reset := paint("\x1b[0m")
+
+	tw := tabwriter.NewWriter(w, 0, 0, 2, ' ', 0)
+	fmt.Fprintf(tw, "%sID\tSTARTED\tCONTEXT\tWINDOW\tCALLS\tRECORDS\tSTATUS%s\n", paint("\x1b[39m"), reset)
+	for _, r := range runs {
+		prefix, status := paint("\x1b[39m"), "ok"
+		switch {
+		case r.ErrorMessage != nil:
+			prefix, status = paint("\x1b[31m"), "error: "+*r.ErrorMessage
+		case r.RateLimited:
+			prefix, status = paint("\x1b[33m"), "rate limited"
+		}
+		window := ""
+		if r.PostedFrom != nil && r.PostedTo != nil && *r.PostedFrom != "" {
+			window = *r.PostedFrom + " - " + *r.PostedTo
+		}
+		fmt.Fprintf(tw, "%s%d\t%s\t%s\t%s\t%d\t%d\t%s%s\n",
+			prefix, r.ID, r.StartedAt, r.Context, window, r.APICalls, r.RecordsFetched, status, reset)
+	}
+	return tw.Flush()
+}
+
+// pageFooter is the line under a `list` table: the 1-based range shown out
+// of every match, with the --offset of the next page when there is one.
+func pageFooter(offset, shown int, total int64) string {
+	line := fmt.Sprintf("showing %d-%d of %d", offset+1, offset+shown, total)
+	if next := offset + shown; int64(next) < total {
+		line += fmt.Sprintf(" (--offset %d for more)", next)
+	}
+	return line + "\n"
+}
+
+// writeOneline renders `list --oneline`: notice_id, posted_date, and title
+// separated by tabs, one opportunity per line, so `cut -f1` recovers the ID.
+func writeOneline(w io.Writer, items []db.OpportunityListItem) {
+	for _, it := range items {
+		fmt.Fprintf(w, "%s\t%s\t%s\n", it.ID, derefStr(it.PostedDate), strings.Join(strings.Fields(derefStr(it.Title)), " "))
+	}
+}
+
+// writeAwardeeRollup renders `govscout awardee`: one row per award, then the
+// total across every match.
+func writeAwardeeRollup(w io.Writer, r *db.AwardeeRollup, titleWidth int) error {
+	tw := tabwriter.NewWriter(w, 0, 0, 2, ' ', 0)
+	fmt.Fprintln(tw, "AWARD DATE\tAMOUNT\tAWARDEE\tTITLE\tNOTICE ID")
+	for _, a := range r.Awards {
+		amount := derefStr(a.Amount)
+		if a.AmountValue != nil {
+			amount = fmt.Sprintf("%.2f", *a.AmountValue)
+		}
+		fmt.Fprintf(tw, "%s\t%s\t%s\t%s\t%s\n", derefStr(a.Date), amount, derefStr(a.AwardeeName),
+			truncateRunes(derefStr(a.Title), titleWidth), a.NoticeID)
+	}
+	if err := tw.Flush(); err != nil {
+		return err
+	}
+	shown := ""
+	if int64(len(r.Awards)) < r.Total {
+		shown = fmt.Sprintf(" (showing %d)", len(r.Awards))
+	}
+	_, err := fmt.Fprintf(w, "\n%d awards%s, total %.2f\n", r.Total, shown, r.TotalAmount)
+	return err
+}
+
+// writeDiagnosis renders `govscout doctor`. dbFlag is the --db value, to
+// say where the path came from.
+func writeDiagnosis(w io.Writer, d *db.Diagnosis, dbFlag string) {
+	source := "default ./govscout.db in " + filepath.Dir(d.Path)
+	switch {
+	case dbFlag != "":
+		source = "--db"
+	case os.Getenv("GOVSCOUT_DB") != "":
+		source = "GOVSCOUT_DB"
+	}
+	fmt.Fprintf(w, "path:            %s (from %s)\n", d.Path, source)
+	if !d.Exists {
+		fmt.Fprintln(w, "exists:          no")
+		fmt.Fprintln(w, "\nNo database here yet. Run `govscout sync` with the same --db/GOVSCOUT_DB and working directory as `govscout serve`.")
+		return
+	}
+	fmt.Fprintf(w, "size:            %.1f MB (+%.1f MB WAL)\n", float64(d.Size)/(1<<20), float64(d.WALSize)/(1<<20))
+	for _, t := range d.Tables {
+		fmt.Fprintf(w, "%-16s %d rows\n", t.Table+":", t.Rows)
+	}
+	fmt.Fprintf(w, "last_sync:       %s\n", orUnset(d.LastSync))
+	fmt.Fprintf(w, "backfill_cursor: %s\n", orUnset(d.BackfillCursor))
+	if len(d.Tables) > 0 && d.Tables[0].Rows == 0 {
+		fmt.Fprintln(w, "\nNo opportunities stored. If sync has run, it wrote to a different file; compare this path with the one `govscout serve` logs at startup.")
+	}
+}
+
+func orUnset(s string) string {
+	if s == "" {
+		return "(unset)"
+	}
+	return s
+}
+
+// writeFilterStats renders `govscout stats`: distinct values per filter column.
+func writeFilterStats(w io.Writer, stats *db.Stats) {
+	fmt.Fprintf(w, "%d opportunities\n", stats.Total)
+	for _, column := range []string{"naics_code", "opp_type", "set_aside", "pop_state_code", "department"} {
+		fmt.Fprintf(w, "  %-18s %d distinct\n", column, stats.Distinct[column])
+	}
+}
+
+// writeCompleteness renders `govscout stats --completeness`.
+func writeCompleteness(w io.Writer, c *db.Completeness) {
+	fmt.Fprintf(w, "%d opportunities\n", c.Total)
+	for _, col := range c.Columns {
+		fmt.Fprintf(w, "  %-18s %6.1f%%  (%d)\n", col.Column, col.Percent, col.Filled)
+	}
+}
+
+// writeSyncPlan renders `govscout sync --plan`.
+func writeSyncPlan(w io.Writer, p *gosync.Plan) {
+	fmt.Fprintf(w, "%s backfill plan: %s -> %s\n", p.Direction, p.Cursor, p.Target)
+	if p.Windows == 0 {
+		fmt.Fprintln(w, "  nothing to backfill; the cursor already reached the target")
+		return
+	}
+	sample := "no backfill history, assuming 1 page"
+	if p.SampleRuns > 0 {
+		sample = fmt.Sprintf("averaged over the last %d backfill windows", p.SampleRuns)
+	}
+	fmt.Fprintf(w, "  windows:          %d of %d days\n", p.Windows, p.WindowDays)
+	fmt.Fprintf(w, "  pages per window: %.1f (%s)\n", p.PagesPerWindow, sample)
+	fmt.Fprintf(w, "  estimated calls:  %d\n", p.EstimatedCalls)
+	if p.Runs > 0 {
+		fmt.Fprintf(w, "  sync runs:        %d at --max-calls %d\n", p.Runs, p.MaxCalls)
+	} else {
+		fmt.Fprintf(w, "  sync runs:        never; --max-calls %d leaves no budget after the incremental window\n", p.MaxCalls)
+	}
+}
+
+func writeSyncAudit(w io.Writer, a *gosync.Audit) {
+	fmt.Fprintf(w, "backfill coverage: %s -> %s\n", a.Start, a.End)
+	fmt.Fprintf(w, "  completed windows: %d\n", a.Windows)
+	fmt.Fprintf(w, "  days covered:      %d of %d\n", a.CoveredDays, a.TotalDays)
+	if len(a.Gaps) == 0 {
+		fmt.Fprintln(w, "  no gaps")
+		return
+	}
+	fmt.Fprintf(w, "  gaps:              %d\n", len(a.Gaps))
+	for _, g := range a.Gaps {
+		fmt.Fprintf(w, "    %s - %s  (%d days)\n", g.From, g.To, g.Days)
+	}
+	fmt.Fprintln(w, "To refetch from the oldest gap forward:")
+	fmt.Fprintln(w, "  govscout sync --backfill-direction forward --reset-cursor")
+	fmt.Fprintf(w, "  govscout sync --backfill-direction forward --from %s\n", a.Gaps[0].From)
+}
+
+// truncateRunes shortens s to at most n runes, marking the cut with "…".
+func truncateRunes(s string, n int) string {
+	s = strings.Join(strings.Fields(s), " ")
+	r := []rune(s)
+	if n <= 0 || len(r) <= n {
+		return s
+	}
+	if n == 1 {
+		return "…"
+	}
+	return string(r[:n-1]) + "…"
+}
+
+func derefStr(s *string) string {
+	if s == nil {
+		return ""
+	}
+	return *s
+}
+
+// isTerminal reports whether f is an interactive terminal.
+func isTerminal(f *os.File) bool {
+	info, err := f.Stat()
+	if err != nil {
+		return false
+	}
+	return info.Mode()&os.ModeCharDevice != 0
+}
@@ -0,0 +1,226 @@
+package main
+
+import (
+	"bytes"
+	"strings"
+	"testing"
+
+	"github.com/theognis1002/govscout/internal/db"
+	gosync "github.com/theognis1002/govscout/internal/sync"
+)
+
+func strPtr(s string) *string { return &s }
+
+func TestWriteOpportunityTable(t *testing.T) {
+	items := []db.OpportunityListItem{{
+		ID:               "abc123",
+		Title:            strPtr("Network   Modernization Services"),
+		Department:       strPtr("DEPT OF DEFENSE"),
+		PostedDate:       strPtr("01/15/2026"),
+		ResponseDeadline: strPtr("2026-02-15T17:00:00-05:00"),
+		NAICSCode:        strPtr("541512"),
+	}}
+
+	var buf bytes.Buffer
+	if err := writeOpportunityTable(&buf, items, 12, 40, 0); err != nil {
+		t.Fatal(err)
+	}
+	lines := strings.Split(strings.TrimRight(buf.String(), "\n"), "\n")
+	if len(lines) != 2 {
+		t.Fatalf("got %d lines, want header + 1 row:\n%s", len(lines), buf.String())
+	}
+	if fields := strings.Fields(lines[0]); strings.Join(fields, " ") != "POSTED DEADLINE TITLE ORGANIZATION NOTICE ID" {
+		t.Errorf("header = %q", lines[0])
+	}
+	for _, want := range []string{"01/15/2026", "2026-02-15 ", "Network Mod…", "DEPT OF DEFENSE", "abc123"} {
+		if !strings.Contains(lines[1], want) {
+			t.Errorf("row %q missing %q", lines[1], want)
+		}
+	}
+
+	buf.Reset()
+	if err := writeOpportunityTable(&buf, items, 50, 40, naicsColumnWidth); err != nil {
+		t.Fatal(err)
+	}
+	if !strings.Contains(buf.String(), "NAICS") || !strings.Contains(buf.String(), "541512") {
+		t.Errorf("NAICS column missing:\n%s", buf.String())
+	}
+}
+
+func TestWriteSyncRunTable(t *testing.T) {
+	runs := []db.SyncRunRow{
+		{ID: 2, StartedAt: "2026-01-02 00:00:00", Context: "backfill", PostedFrom: strPtr("10/01/2025"), PostedTo: strPtr("12/30/2025"), APICalls: 3, RateLimited: true},
+		{ID: 1, StartedAt: "2026-01-01 00:00:00", Context: "incremental", ErrorMessage: strPtr("boom")},
+	}
+
+	var buf bytes.Buffer
+	if err := writeSyncRunTable(&buf, runs, false); err != nil {
+		t.Fatal(err)
+	}
+	out := buf.String()
+	if strings.Contains(out, "\x1b[") {
+		t.Errorf("color codes without color:\n%q", out)
+	}
+	for _, want := range []string{"10/01/2025 - 12/30/2025", "rate limited", "error: boom"} {
+		if !strings.Contains(out, want) {
+			t.Errorf("output missing %q:\n%s", want, out)
+		}
+	}
+
+	buf.Reset()
+	if err := writeSyncRunTable(&buf, runs, true); err != nil {
+		t.Fatal(err)
+	}
+	if !strings.Contains(buf.String(), "\x1b[33m2") || !strings.Contains(buf.String(), "\x1b[31m1") {
+		t.Errorf("want yellow rate-limited and red errored rows:\n%q", buf.String())
+	}
+}
+
+func TestWriteOpportunityMarkdown(t *testing.T) {
+	opp := map[string]any{
+		"noticeId":    "abc123",
+		"title":       "Network Modernization",
+		"postedDate":  "01/15/2026",
+		"uiLink":      "https://sam.gov/opp/abc123/view",
+		"award":       map[string]any{"amount": 125000.0},
+		"description": "<p>First paragraph.</p>\n\nSecond:\n  - indented item",
+	}
+	opp["pointOfContact"] = []any{
+		map[string]any{"type": "primary", "fullName": "Jo Smith", "email": "jo@example.gov"},
+	}
+
+	var buf bytes.Buffer
+	writeOpportunityMarkdown(&buf, opp)
+	out := buf.String()
+	for _, want := range []string{
+		"# Network Modernization\n",
+		"## Basic Information\n\n- **Notice ID:** abc123\n",
+		"## Contacts\n\n- **Primary:** Jo Smith, jo@example.gov\n",
+		"- **Amount:** 125000\n",
+		"- **SAM.gov:** <https://sam.gov/opp/abc123/view>\n",
+		"## Description\n\n> First paragraph.\n>\n> Second:\n>   - indented item\n",
+	} {
+		if !strings.Contains(out, want) {
+			t.Errorf("markdown missing %q:\n%s", want, out)
+		}
+	}
+	if strings.Contains(out, "## Organization") {
+		t.Errorf("empty sections should be dropped:\n%s", out)
+	}
+}
+
+func TestWriteOpportunityText_NoDescription(t *testing.T) {
+	var buf bytes.Buffer
+	writeOpportunityText(&buf, map[string]any{"noticeId": "x"}, "", false)
+	out := buf.String()
+	if !strings.HasPrefix(out, "Untitled Opportunity\n====================\n") {
+		t.Errorf("title block = %q", out)
+	}
+	if !strings.Contains(out, "  Notice ID:  x\n") || !strings.HasSuffix(out, "Description\n(no description)\n") {
+		t.Errorf("unexpected text view:\n%s", out)
+	}
+}
+
+func TestWriteSyncPlan(t *testing.T) {
+	var buf bytes.Buffer
+	writeSyncPlan(&buf, &gosync.Plan{
+		Direction: "backward", Cursor: "01/01/2026", Target: "01/01/2025", WindowDays: 90,
+		Windows: 5, PagesPerWindow: 1.5, SampleRuns: 2, EstimatedCalls: 8, MaxCalls: 5, Runs: 2,
+	})
+	for _, want := range []string{
+		"backward backfill plan: 01/01/2026 -> 01/01/2025\n",
+		"windows:          5 of 90 days\n",
+		"pages per window: 1.5 (averaged over the last 2 backfill windows)\n",
+		"estimated calls:  8\n",
+		"sync runs:        2 at --max-calls 5\n",
+	} {
+		if !strings.Contains(buf.String(), want) {
+			t.Errorf("plan missing %q:\n%s", want, buf.String())
+		}
+	}
+}
+
+func TestWriteSyncAudit(t *testing.T) {
+	var buf bytes.Buffer
+	writeSyncAudit(&buf, &gosync.Audit{
+		Start: "01/01/2025", End: "03/31/2025", Windows: 2, TotalDays: 90, CoveredDays: 80,
+		Gaps: []gosync.Gap{{From: "02/01/2025", To: "02/10/2025", Days: 10}},
+	})
+	for _, want := range []string{
+		"backfill coverage: 01/01/2025 -> 03/31/2025\n",
+		"days covered:      80 of 90\n",
+		"02/01/2025 - 02/10/2025  (10 days)\n",
+		"--backfill-direction forward --from 02/01/2025\n",
+	} {
+		if !strings.Contains(buf.String(), want) {
+			t.Errorf("audit missing %q:\n%s", want, buf.String())
+		}
+	}
+}
+
+func TestWriteAwardeeRollup(t *testing.T) {
+	amount := 1250.5
+	r := &db.AwardeeRollup{Query: "acme", Total: 3, TotalAmount: 1300.5, Awards: []db.Award{
+		{NoticeID: "a2", Title: strPtr("New award"), AwardeeName: strPtr("ACME LLC"), Date: strPtr("2025-02-10"), Amount: strPtr("$1,250.50"), AmountValue: &amount},
+		{NoticeID: "a3", Title: strPtr("Odd amount"), AwardeeName: strPtr("ACME LLC"), Amount: strPtr("see attachment")},
+	}}
+
+	var buf bytes.Buffer
+	if err := writeAwardeeRollup(&buf, r, 40); err != nil {
+		t.Fatal(err)
+	}
+	out := buf.String()
+	for _, want := range []string{"2025-02-10  1250.50", "see attachment", "3 awards (showing 2), total 1300.50\n"} {
+		if !strings.Contains(out, want) {
+			t.Errorf("output missing %q:\n%s", want, out)
+		}
+	}
+}
+
+func TestWriteOneline(t *testing.T) {
+	var buf bytes.Buffer
+	writeOneline(&buf, []db.OpportunityListItem{
+		{ID: "abc123", PostedDate: strPtr("01/15/2026"), Title: strPtr("Network\tModernization\n Services")},
+		{ID: "def456"},
+	})
+	want := "abc123\t01/15/2026\tNetwork Modernization Services\ndef456\t\t\n"
+	if buf.String() != want {
+		t.Errorf("oneline = %q, want %q", buf.String(), want)
+	}
+}
+
+func TestPageFooter(t *testing.T) {
+	tests := []struct {
+		offset, shown int
+		total         int64
+		want          string
+	}{
+		{0, 25, 60, "showing 1-25 of 60 (--offset 25 for more)\n"},
+		{50, 10, 60, "showing 51-60 of 60\n"},
+		{0, 3, 3, "showing 1-3 of 3\n"},
+	}
+	for _, tt := range tests {
+		if got := pageFooter(tt.offset, tt.shown, tt.total); got != tt.want {
+			t.Errorf("pageFooter(%d, %d, %d) = %q, want %q", tt.offset, tt.shown, tt.total, got, tt.want)
+		}
+	}
+}
+
+func TestWriteDiagnosis(t *testing.T) {
+	var buf bytes.Buffer
+	writeDiagnosis(&buf, &db.Diagnosis{Path: "/srv/govscout.db"}, "/srv/govscout.db")
+	if out := buf.String(); !strings.Contains(out, "(from --db)") || !strings.Contains(out, "exists:          no") {
+		t.Errorf("missing-file diagnosis:\n%s", out)
+	}
+
+	buf.Reset()
+	writeDiagnosis(&buf, &db.Diagnosis{
+		Path: "/srv/govscout.db", Exists: true, Size: 1 << 20,
+		Tables: []db.TableCount{{Table: "opportunities"}, {Table: "sync_runs", Rows: 4}},
+	}, "x")
+	for _, want := range []string{"size:            1.0 MB (+0.0 MB WAL)\n", "sync_runs:       4 rows\n", "last_sync:       (unset)\n", "No opportunities stored."} {
+		if !strings.Contains(buf.String(), want) {
+			t.Errorf("diagnosis missing %q:\n%s", want, buf.String())
+		}
+	}
+}
@@ -0,0 +1,11 @@
+//go:build !unix
+
+package main
+
+import "os"
+
+// terminalWidth is unsupported here; callers fall back to COLUMNS or the
+// fixed widths.
+func terminalWidth(*os.File) int {
+	return 0
+}
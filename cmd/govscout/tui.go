@@ -0,0 +1,239 @@
+//go:build tui
+
+package main
+
+import (
+	"database/sql"
+	"flag"
+	"fmt"
+	"log"
+	"os/exec"
+	"runtime"
+	"strings"
+
+	"github.com/gdamore/tcell/v2"
+	"github.com/rivo/tview"
+
+	"github.com/theognis1002/govscout/internal/codes"
+	"github.com/theognis1002/govscout/internal/db"
+)
+
+// tuiPageSize is how many rows the list pane loads; ListOpportunities caps
+// a page at 100.
+const tuiPageSize = 100
+
+const tuiHelp = " [::b]↑/↓[::-] move  [::b]Enter[::-] details  [::b]/[::-] filter  [::b]o[::-] open in browser  [::b]Esc[::-] back  [::b]q[::-] quit"
+
+// cmdTUI browses local opportunities interactively: a scrollable list, `/`
+// to filter (the same text search as list --search), Enter for details, and
+// `o` to open the SAM.gov page. Reads only from the local DB.
+func cmdTUI(args []string) {
+	fs := flag.NewFlagSet("tui", flag.ExitOnError)
+	dbPath := fs.String("db", "", "SQLite database path")
+	filterFlags := addFilterFlags(fs)
+	fs.Parse(args)
+	filters := filterFlags()
+	filters.Limit = tuiPageSize
+	filters.OmitDescription = true
+
+	database, err := db.Open(*dbPath)
+	if err != nil {
+		log.Fatal(err)
+	}
+	defer database.Close()
+
+	if err := newBrowser(database, filters).run(); err != nil {
+		log.Fatal(err)
+	}
+}
+
+type browser struct {
+	database *sql.DB
+	filters  db.ListFilters
+	items    []db.OpportunityListItem
+
+	app    *tview.Application
+	pages  *tview.Pages
+	table  *tview.Table
+	filter *tview.InputField
+	detail *tview.TextView
+	status *tview.TextView
+}
+
+func newBrowser(database *sql.DB, filters db.ListFilters) *browser {
+	b := &browser{
+		database: database,
+		filters:  filters,
+		app:      tview.NewApplication(),
+		pages:    tview.NewPages(),
+		table:    tview.NewTable().SetSelectable(true, false).SetFixed(1, 0),
+		filter:   tview.NewInputField().SetLabel("/"),
+		detail:   tview.NewTextView().SetDynamicColors(true).SetWordWrap(true),
+		status:   tview.NewTextView().SetDynamicColors(true),
+	}
+	b.table.SetBorder(true)
+	b.detail.SetBorder(true)
+
+	b.table.SetSelectedFunc(func(row, _ int) { b.showDetail(row - 1) })
+	b.table.SetInputCapture(func(ev *tcell.EventKey) *tcell.EventKey {
+		switch ev.Rune() {
+		case '/':
+			b.filter.SetText(b.filters.Search)
+			b.app.SetFocus(b.filter)
+			return nil
+		case 'o':
+			row, _ := b.table.GetSelection()
+			b.open(row - 1)
+			return nil
+		case 'q':
+			b.app.Stop()
+			return nil
+		}
+		return ev
+	})
+	b.filter.SetDoneFunc(func(key tcell.Key) {
+		if key == tcell.KeyEnter {
+			b.filters.Search = strings.TrimSpace(b.filter.GetText())
+			b.reload()
+		}
+		b.filter.SetText("")
+		b.app.SetFocus(b.table)
+	})
+	b.detail.SetInputCapture(func(ev *tcell.EventKey) *tcell.EventKey {
+		if ev.Key() == tcell.KeyEscape || ev.Rune() == 'q' {
+			b.pages.SwitchToPage("list")
+			b.app.SetFocus(b.table)
+			return nil
+		}
+		if ev.Rune() == 'o' {
+			row, _ := b.table.GetSelection()
+			b.open(row - 1)
+			return nil
+		}
+		return ev
+	})
+
+	list := tview.NewFlex().SetDirection(tview.FlexRow).
+		AddItem(b.table, 0, 1, true).
+		AddItem(b.filter, 1, 0, false).
+		AddItem(b.status, 1, 0, false)
+	b.pages.AddPage("list", list, true, true)
+	b.pages.AddPage("detail", b.detail, true, false)
+	return b
+}
+
+func (b *browser) run() error {
+	b.reload()
+	return b.app.SetRoot(b.pages, true).SetFocus(b.table).Run()
+}
+
+// reload re-runs the list query with the current filters and redraws the
+// table from the top.
+func (b *browser) reload() {
+	result, err := db.ListOpportunities(b.database, b.filters)
+	if err != nil {
+		b.setStatus("[red]" + tview.Escape(err.Error()))
+		return
+	}
+	b.items = result.Opportunities
+
+	b.table.Clear()
+	for col, h := range []string{"POSTED", "DEADLINE", "TITLE", "AGENCY"} {
+		b.table.SetCell(0, col, tview.NewTableCell(h).SetAttributes(tcell.AttrBold).SetSelectable(false))
+	}
+	for i, o := range b.items {
+		deadline := derefStr(o.ResponseDeadline)
+		if len(deadline) > 10 {
+			deadline = deadline[:10]
+		}
+		b.table.SetCell(i+1, 0, tview.NewTableCell(derefStr(o.PostedDate)))
+		b.table.SetCell(i+1, 1, tview.NewTableCell(deadline))
+		b.table.SetCell(i+1, 2, tview.NewTableCell(tview.Escape(derefStr(o.Title))).SetExpansion(3).SetMaxWidth(80))
+		b.table.SetCell(i+1, 3, tview.NewTableCell(tview.Escape(derefStr(o.Department))).SetExpansion(1).SetMaxWidth(40))
+	}
+	b.table.Select(1, 0).ScrollToBeginning()
+
+	title := " opportunities "
+	if b.filters.Search != "" {
+		title = fmt.Sprintf(" opportunities matching %q ", b.filters.Search)
+	}
+	b.table.SetTitle(title)
+	b.setStatus(fmt.Sprintf("showing %d of %d  |%s", len(b.items), result.Total, tuiHelp))
+}
+
+func (b *browser) setStatus(s string) {
+	b.status.SetText(s)
+}
+
+// showDetail loads the full row for items[i] and switches to the detail page.
+func (b *browser) showDetail(i int) {
+	if i < 0 || i >= len(b.items) {
+		return
+	}
+	d, err := db.GetOpportunity(b.database, b.items[i].ID)
+	if err != nil || d == nil {
+		b.setStatus(fmt.Sprintf("[red]load %s: %v", b.items[i].ID, err))
+		return
+	}
+	o := d.Opp
+
+	var sb strings.Builder
+	field := func(label, value string) {
+		if value != "" {
+			fmt.Fprintf(&sb, "[::b]%-14s[::-] %s\n", label+":", tview.Escape(value))
+		}
+	}
+	fmt.Fprintf(&sb, "[::b]%s[::-]\n\n", tview.Escape(derefStr(o.Title)))
+	field("Notice ID", o.ID)
+	field("Solicitation", derefStr(o.SolicitationNumber))
+	field("Type", derefStr(o.OppType))
+	field("Agency", derefStr(o.FullParentPathName))
+	field("Posted", derefStr(o.PostedDate))
+	field("Deadline", derefStr(o.ResponseDeadline))
+	field("NAICS", codes.NAICSLabel(derefStr(o.NAICSCode)))
+	field("Set-aside", derefStr(o.SetAsideDescription))
+	field("State", derefStr(o.PopStateName))
+	field("Awardee", derefStr(o.AwardeeName))
+	field("Award", derefStr(o.AwardAmount))
+	field("Link", derefStr(o.UILink))
+	for _, c := range d.Contacts {
+		field("Contact", strings.TrimSpace(derefStr(c.FullName)+" "+derefStr(c.Email)+" "+derefStr(c.Phone)))
+	}
+	if desc := strings.TrimSpace(db.StripHTML(derefStr(o.Description))); desc != "" {
+		fmt.Fprintf(&sb, "\n%s\n", tview.Escape(desc))
+	}
+	fmt.Fprintf(&sb, "\n[::d]Esc back  o open in browser[::-]")
+
+	b.detail.SetText(sb.String()).ScrollToBeginning()
+	b.detail.SetTitle(" " + o.ID + " ")
+	b.pages.SwitchToPage("detail")
+	b.app.SetFocus(b.detail)
+}
+
+// open launches the system browser on items[i]'s SAM.gov page.
+func (b *browser) open(i int) {
+	if i < 0 || i >= len(b.items) {
+		return
+	}
+	link := derefStr(b.items[i].UILink)
+	if link == "" {
+		b.setStatus("[yellow]no SAM.gov link for " + b.items[i].ID)
+		return
+	}
+	if err := openBrowser(link); err != nil {
+		b.setStatus("[red]open: " + tview.Escape(err.Error()))
+	}
+}
+
+func openBrowser(url string) error {
+	var cmd *exec.Cmd
+	switch runtime.GOOS {
+	case "darwin":
+		cmd = exec.Command("open", url)
+	case "windows":
+		cmd = exec.Command("rundll32", "url.dll,FileProtocolHandler", url)
+	default:
+		cmd = exec.Command("xdg-open", url)
+	}
+	return cmd.Start()
+}
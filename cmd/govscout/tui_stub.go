@@ -0,0 +1,15 @@
+//go:build !tui
+
+package main
+
+import (
+	"fmt"
+	"os"
+)
+
+// cmdTUI is unavailable in the default build, which stays free of the
+// tview/tcell dependency.
+func cmdTUI([]string) {
+	fmt.Fprintln(os.Stderr, "tui: this binary was built without TUI support (go build -tags tui)")
+	os.Exit(1)
+}
@@ -0,0 +1,299 @@
+package main
+
+import (
+	"bufio"
+	"context"
+	"encoding/json"
+	"errors"
+	"flag"
+	"fmt"
+	"log"
+	"os"
+	"os/signal"
+	"path/filepath"
+	"sort"
+	"strconv"
+	"strings"
+	"syscall"
+	"time"
+
+	"github.com/theognis1002/govscout/internal/alerts"
+	"github.com/theognis1002/govscout/internal/db"
+	"github.com/theognis1002/govscout/internal/samgov"
+	gosync "github.com/theognis1002/govscout/internal/sync"
+)
+
+// watchOwnFlags are the watch flags that aren't filters, so --watch-file can
+// reject filter flags it would silently override.
+var watchOwnFlags = map[string]bool{"db": true, "interval": true, "max-calls": true, "no-sync": true, "watch-file": true}
+
+func cmdWatch(args []string) {
+	fs := flag.NewFlagSet("watch", flag.ExitOnError)
+	dbPath := fs.String("db", "", "SQLite database path")
+	interval := fs.Duration("interval", 15*time.Minute, "Time between cycles")
+	maxCalls := fs.Int("max-calls", 18, "Max API calls per cycle's sync")
+	noSync := fs.Bool("no-sync", false, "Only watch the database (another process syncs it)")
+	watchPath := fs.String("watch-file", "", "JSON or TOML file of filters, reloaded when its mtime changes (replaces the filter flags)")
+	filterFlags := addFilterFlags(fs)
+	fs.Parse(args)
+	if *interval <= 0 {
+		log.Fatal("--interval must be positive")
+	}
+
+	filters := filterFlags()
+	var wf *watchFile
+	if *watchPath != "" {
+		fs.Visit(func(f *flag.Flag) {
+			if !watchOwnFlags[f.Name] {
+				log.Fatalf("--%s cannot be combined with --watch-file; put it in the file", f.Name)
+			}
+		})
+		wf = &watchFile{path: *watchPath}
+		if _, err := wf.reload(); err != nil {
+			log.Fatalf("--watch-file: %v", err)
+		}
+		filters = wf.filters
+	}
+
+	database, err := db.Open(*dbPath)
+	if err != nil {
+		log.Fatal(err)
+	}
+	defer database.Close()
+
+	var client samgov.Source
+	if !*noSync {
+		if client, err = samgov.NewSourceFromEnv(os.Getenv("SAMGOV_API_KEY")); err != nil {
+			log.Fatalf("%v (or pass --no-sync)", err)
+		}
+	}
+
+	ctx, stop := signal.NotifyContext(context.Background(), os.Interrupt, syscall.SIGTERM)
+	defer stop()
+
+	log.Printf("watch: every %s; new matches are printed as notice_id<TAB>posted_date<TAB>title", *interval)
+	since := time.Now().UTC()
+	var shown map[string]bool
+	for {
+		if wf != nil {
+			// A bad edit keeps the last good filters until the file is fixed.
+			if changed, err := wf.reload(); err != nil {
+				log.Printf("watch: %s: %v; keeping the previous filters", wf.path, err)
+			} else if changed {
+				log.Printf("watch: reloaded filters from %s", wf.path)
+			}
+			filters = wf.filters
+		}
+
+		if client != nil {
+			if _, err := gosync.RunWithSummary(ctx, database, client, gosync.Options{MaxCalls: *maxCalls}); err != nil {
+				log.Printf("watch: sync error: %v", err)
+			} else if err := alerts.RunMatcherCtx(ctx, database); err != nil {
+				log.Printf("watch: alert matcher error: %v", err)
+			}
+		}
+		if ctx.Err() != nil {
+			return
+		}
+
+		// created_at has one-second resolution, so the window overlaps the
+		// previous one by a second; shown drops the repeats.
+		next := time.Now().UTC()
+		f := filters
+		f.CreatedAfter = since.Format("2006-01-02 15:04:05")
+		items, err := db.ExportOpportunities(database, f)
+		if err != nil {
+			log.Printf("watch: %v", err)
+		} else {
+			var fresh []db.OpportunityListItem
+			for _, it := range items {
+				if !shown[it.ID] {
+					fresh = append(fresh, it)
+				}
+			}
+			if len(fresh) > 0 {
+				log.Printf("watch: %d new matching opportunities", len(fresh))
+				w := bufio.NewWriter(os.Stdout)
+				writeOneline(w, fresh)
+				if err := w.Flush(); err != nil {
+					log.Fatal(err)
+				}
+			}
+			shown = make(map[string]bool, len(items))
+			for _, it := range items {
+				shown[it.ID] = true
+			}
+			since = next
+		}
+
+		select {
+		case <-ctx.Done():
+			return
+		case <-time.After(*interval):
+		}
+	}
+}
+
+// watchFile is a `watch --watch-file` filter file and the filters last read
+// from it without error.
+type watchFile struct {
+	path    string
+	modTime time.Time
+	filters db.ListFilters
+}
+
+// reload re-reads the file when its mtime differs from the last read,
+// reporting whether the filters changed. On a parse error the previous
+// filters stay in place; the mtime is still recorded, so one bad edit is
+// reported once rather than every cycle.
+func (wf *watchFile) reload() (bool, error) {
+	info, err := os.Stat(wf.path)
+	if err != nil {
+		return false, err
+	}
+	if info.ModTime().Equal(wf.modTime) {
+		return false, nil
+	}
+	wf.modTime = info.ModTime()
+	f, err := loadWatchFilters(wf.path)
+	if err != nil {
+		return false, err
+	}
+	wf.filters = f
+	return true, nil
+}
+
+// loadWatchFilters reads filter criteria from path: a JSON object when the
+// name ends in .json, otherwise config.toml's flat `key = "value"` TOML.
+// Keys are the filter flag names (naics, set-aside, active-only, from, ...);
+// JSON values may also be booleans, numbers, or arrays of codes.
+func loadWatchFilters(path string) (db.ListFilters, error) {
+	values := map[string]string{}
+	if strings.EqualFold(filepath.Ext(path), ".json") {
+		b, err := os.ReadFile(path)
+		if err != nil {
+			return db.ListFilters{}, err
+		}
+		var raw map[string]any
+		if err := json.Unmarshal(b, &raw); err != nil {
+			return db.ListFilters{}, fmt.Errorf("parse %s: %w", path, err)
+		}
+		for key, v := range raw {
+			s, err := watchValueString(v)
+			if err != nil {
+				return db.ListFilters{}, fmt.Errorf("%s: %w", key, err)
+			}
+			values[key] = s
+		}
+	} else {
+		cfg, err := readConfig(path)
+		if err != nil {
+			return db.ListFilters{}, err
+		}
+		values = cfg
+	}
+
+	// Sorted so the first bad key reported is stable.
+	keys := make([]string, 0, len(values))
+	for key := range values {
+		keys = append(keys, key)
+	}
+	sort.Strings(keys)
+	var f db.ListFilters
+	for _, key := range keys {
+		if err := setWatchFilter(&f, key, values[key]); err != nil {
+			return db.ListFilters{}, err
+		}
+	}
+	if f.ActiveOnly && f.InactiveOnly {
+		return db.ListFilters{}, errors.New("active-only and inactive-only are mutually exclusive")
+	}
+	return f, nil
+}
+
+// watchValueString flattens a JSON filter value into the string the
+// matching flag would take; arrays become comma-separated lists.
+func watchValueString(v any) (string, error) {
+	switch v := v.(type) {
+	case string:
+		return v, nil
+	case bool:
+		return strconv.FormatBool(v), nil
+	case float64:
+		return strconv.FormatFloat(v, 'f', -1, 64), nil
+	case []any:
+		parts := make([]string, len(v))
+		for i, e := range v {
+			s, err := watchValueString(e)
+			if err != nil {
+				return "", err
+			}
+			parts[i] = s
+		}
+		return strings.Join(parts, ","), nil
+	}
+	return "", fmt.Errorf("unsupported value %v", v)
+}
+
+// setWatchFilter applies one watch-file key to f, validating it the way the
+// matching filter flag would.
+func setWatchFilter(f *db.ListFilters, key, val string) error {
+	boolVal := func(dst *bool) error {
+		b, err := strconv.ParseBool(val)
+		if err != nil {
+			return fmt.Errorf("%s: want true or false, got %q", key, val)
+		}
+		*dst = b
+		return nil
+	}
+	dateVal := func(dst *string, end bool) error {
+		d, err := parseFlagDate(val, end)
+		if err != nil {
+			return fmt.Errorf("%s: %w", key, err)
+		}
+		*dst = d.String()
+		return nil
+	}
+
+	switch key {
+	case "search":
+		f.Search = val
+	case "sol-number":
+		f.SolicitationNumber = val
+	case "naics":
+		f.NAICSCode = val
+	case "psc":
+		f.ClassificationCode = val
+	case "type":
+		f.OppType = val
+	case "set-aside":
+		f.SetAside = val
+	case "state":
+		f.State = val
+	case "department":
+		f.Department = val
+	case "tag":
+		f.Tag = val
+	case "active-only":
+		return boolVal(&f.ActiveOnly)
+	case "inactive-only":
+		return boolVal(&f.InactiveOnly)
+	case "latest-only":
+		return boolVal(&f.LatestOnly)
+	case "has-docs":
+		return boolVal(&f.HasDocuments)
+	case "min-days-to-respond":
+		n, err := strconv.Atoi(val)
+		if err != nil || n < 0 {
+			return fmt.Errorf("%s: want a non-negative integer, got %q", key, val)
+		}
+		f.MinDaysToRespond = n
+	case "from":
+		return dateVal(&f.DateFrom, false)
+	case "to":
+		return dateVal(&f.DateTo, true)
+	default:
+		return fmt.Errorf("unknown filter %q", key)
+	}
+	return nil
+}
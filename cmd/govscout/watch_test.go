@@ -0,0 +1,90 @@
+package main
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+	"time"
+)
+
+func TestLoadWatchFilters(t *testing.T) {
+	dir := t.TempDir()
+	write := func(name, body string) string {
+		t.Helper()
+		path := filepath.Join(dir, name)
+		if err := os.WriteFile(path, []byte(body), 0o600); err != nil {
+			t.Fatal(err)
+		}
+		return path
+	}
+
+	f, err := loadWatchFilters(write("f.json", `{"naics": ["541511", "541512"], "active-only": true, "min-days-to-respond": 7, "from": "2026"}`))
+	if err != nil {
+		t.Fatal(err)
+	}
+	if f.NAICSCode != "541511,541512" || !f.ActiveOnly || f.MinDaysToRespond != 7 || f.DateFrom != "01/01/2026" {
+		t.Errorf("json filters = %+v", f)
+	}
+
+	f, err = loadWatchFilters(write("f.toml", "# mine\nset-aside = \"SBA\"\nhas-docs = true\n"))
+	if err != nil {
+		t.Fatal(err)
+	}
+	if f.SetAside != "SBA" || !f.HasDocuments {
+		t.Errorf("toml filters = %+v", f)
+	}
+
+	for name, body := range map[string]string{
+		"unknown.json":   `{"colour": "red"}`,
+		"bad-bool.json":  `{"active-only": "sometimes"}`,
+		"bad-date.toml":  `to = "01/25"`,
+		"exclusive.json": `{"active-only": true, "inactive-only": true}`,
+		"broken.json":    `{"naics": `,
+	} {
+		if _, err := loadWatchFilters(write(name, body)); err == nil {
+			t.Errorf("%s: want an error", name)
+		}
+	}
+}
+
+func TestWatchFileReload(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "filters.json")
+	mtime := time.Now().Add(-time.Hour)
+	edit := func(body string) {
+		t.Helper()
+		if err := os.WriteFile(path, []byte(body), 0o600); err != nil {
+			t.Fatal(err)
+		}
+		// Step the mtime explicitly; back-to-back writes can share one.
+		mtime = mtime.Add(time.Minute)
+		if err := os.Chtimes(path, mtime, mtime); err != nil {
+			t.Fatal(err)
+		}
+	}
+
+	wf := &watchFile{path: path}
+	edit(`{"naics": "541511"}`)
+	if changed, err := wf.reload(); err != nil || !changed || wf.filters.NAICSCode != "541511" {
+		t.Fatalf("first load = %v, %v, %+v", changed, err, wf.filters)
+	}
+	if changed, err := wf.reload(); err != nil || changed {
+		t.Errorf("unchanged mtime: reload = %v, %v; want no reload", changed, err)
+	}
+
+	edit(`{"naics": "541511,336411"}`)
+	if changed, err := wf.reload(); err != nil || !changed || wf.filters.NAICSCode != "541511,336411" {
+		t.Errorf("after edit = %v, %v, %+v", changed, err, wf.filters)
+	}
+
+	// A broken edit is reported once and the last good filters stay.
+	edit(`{"naics": `)
+	if _, err := wf.reload(); err == nil {
+		t.Error("broken file: want an error")
+	}
+	if changed, err := wf.reload(); err != nil || changed {
+		t.Errorf("broken file, same mtime: reload = %v, %v; want no retry", changed, err)
+	}
+	if wf.filters.NAICSCode != "541511,336411" {
+		t.Errorf("filters after a bad edit = %+v, want the last good ones", wf.filters)
+	}
+}
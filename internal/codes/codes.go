@@ -1,4 +1,8 @@
-package web
+// Package codes maps SAM.gov codes (NAICS, PSC, set-aside, notice type) to
+// human-readable labels.
+package codes
+
+import "sort"
 
 var naicsLabels = map[string]string{
 	"111110": "Soybean Farming",
@@ -217,6 +221,30 @@ var naicsLabels = map[string]string{
 	"928120": "International Affairs",
 }
 
+// setAsideLabels are SAM.gov's official set-aside names, as the API returns
+// them in typeOfSetAsideDescription.
+var setAsideLabels = map[string]string{
+	"SBA":      "Total Small Business Set-Aside (FAR 19.5)",
+	"SBP":      "Partial Small Business Set-Aside (FAR 19.5)",
+	"8A":       "8(a) Set-Aside (FAR 19.8)",
+	"8AN":      "8(a) Sole Source (FAR 19.8)",
+	"HZC":      "Historically Underutilized Business (HUBZone) Set-Aside (FAR 19.13)",
+	"HZS":      "Historically Underutilized Business (HUBZone) Sole Source (FAR 19.13)",
+	"SDVOSBC":  "Service-Disabled Veteran-Owned Small Business (SDVOSB) Set-Aside (FAR 19.14)",
+	"SDVOSBS":  "Service-Disabled Veteran-Owned Small Business (SDVOSB) Sole Source (FAR 19.14)",
+	"WOSB":     "Women-Owned Small Business (WOSB) Program Set-Aside (FAR 19.15)",
+	"WOSBSS":   "Women-Owned Small Business (WOSB) Program Sole Source (FAR 19.15)",
+	"EDWOSB":   "Economically Disadvantaged WOSB (EDWOSB) Program Set-Aside (FAR 19.15)",
+	"EDWOSBSS": "Economically Disadvantaged WOSB (EDWOSB) Program Sole Source (FAR 19.15)",
+	"LAS":      "Local Area Set-Aside (FAR 26.2)",
+	"IEE":      "Indian Economic Enterprise (IEE) Set-Aside (specific to Department of Interior and Indian Health Services)",
+	"ISBEE":    "Indian Small Business Economic Enterprise (ISBEE) Set-Aside (specific to Department of Interior and Indian Health Services)",
+	"BICiv":    "Buy Indian Set-Aside (specific to Department of Health and Human Services, Indian Health Services)",
+	"VSA":      "Veteran-Owned Small Business Set-Aside (specific to Department of Veterans Affairs)",
+	"VSS":      "Veteran-Owned Small Business Sole Source (specific to Department of Veterans Affairs)",
+}
+
+// setAsideDescriptions are plain-language explanations shown in the UI.
 var setAsideDescriptions = map[string]string{
 	"SBA":      "Small Business Set-Aside — reserved for small businesses",
 	"SBP":      "Small Business Set-Aside — partial, some portions reserved for small businesses",
@@ -251,23 +279,44 @@ var oppTypeDescriptions = map[string]string{
 	"m": "Modification/Amendment — change to an existing solicitation or award",
 }
 
-func naicsLabel(code string) string {
-	if name, ok := naicsLabels[code]; ok {
+// NAICSName returns the title of a 6-digit NAICS code, or "" when the code
+// isn't in the bundled table.
+func NAICSName(code string) string {
+	return naicsLabels[code]
+}
+
+// NAICSLabel returns "code — name" for known codes, or the bare code.
+func NAICSLabel(code string) string {
+	if name := NAICSName(code); name != "" {
 		return code + " — " + name
 	}
 	return code
 }
 
-func setAsideDesc(code string) string {
-	if desc, ok := setAsideDescriptions[code]; ok {
-		return desc
-	}
-	return ""
+// SetAsideLabel returns SAM.gov's official name for a set-aside code, or ""
+// when the code is unknown.
+func SetAsideLabel(code string) string {
+	return setAsideLabels[code]
+}
+
+// SetAsideDescription returns a plain-language explanation of a set-aside
+// code, or "" when the code is unknown.
+func SetAsideDescription(code string) string {
+	return setAsideDescriptions[code]
+}
+
+// OppTypeDescription returns a plain-language explanation of a notice type
+// code, or "" when the code is unknown.
+func OppTypeDescription(code string) string {
+	return oppTypeDescriptions[code]
 }
 
-func oppTypeDesc(code string) string {
-	if desc, ok := oppTypeDescriptions[code]; ok {
-		return desc
+// SetAsideCodes returns every set-aside code with an official label.
+func SetAsideCodes() []string {
+	out := make([]string, 0, len(setAsideLabels))
+	for code := range setAsideLabels {
+		out = append(out, code)
 	}
-	return ""
+	sort.Strings(out)
+	return out
 }
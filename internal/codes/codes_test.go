@@ -0,0 +1,18 @@
+package codes
+
+import "testing"
+
+func TestNAICSNameAndLabel(t *testing.T) {
+	if got := NAICSName("541512"); got != "Computer Systems Design Services" {
+		t.Errorf("NAICSName(541512) = %q", got)
+	}
+	if got := NAICSName("999999"); got != "" {
+		t.Errorf("NAICSName(999999) = %q, want empty", got)
+	}
+	if got := NAICSLabel("541512"); got != "541512 — Computer Systems Design Services" {
+		t.Errorf("NAICSLabel(541512) = %q", got)
+	}
+	if got := NAICSLabel("999999"); got != "999999" {
+		t.Errorf("NAICSLabel(999999) = %q, want the bare code", got)
+	}
+}
@@ -0,0 +1,152 @@
+package codes
+
+import "strings"
+
+// pscLabels names individual Product Service Codes that show up often enough
+// to be worth spelling out. Everything else falls back to its group.
+var pscLabels = map[string]string{
+	"7010": "IT Equipment System Configuration",
+	"7025": "IT Input/Output and Storage Devices",
+	"7030": "Information Technology Software",
+	"6515": "Medical and Surgical Instruments, Equipment, and Supplies",
+	"R408": "Program Management/Support Services",
+	"R425": "Engineering and Technical Services",
+	"R499": "Other Professional Services",
+	"R699": "Other Administrative Support Services",
+	"R706": "Logistics Support Services",
+	"S201": "Custodial Janitorial Services",
+	"S206": "Guard Services",
+	"S208": "Landscaping/Groundskeeping Services",
+}
+
+// pscServiceCategories are the service and R&D categories, keyed by the
+// code's first letter.
+var pscServiceCategories = map[string]string{
+	"A": "Research and Development",
+	"B": "Special Studies and Analyses (Not R&D)",
+	"C": "Architect and Engineering Services",
+	"D": "IT and Telecommunications Services",
+	"E": "Purchase of Structures and Facilities",
+	"F": "Natural Resources and Conservation Services",
+	"G": "Social Services",
+	"H": "Quality Control, Testing, and Inspection Services",
+	"J": "Maintenance, Repair, and Rebuilding of Equipment",
+	"K": "Modification of Equipment",
+	"L": "Technical Representative Services",
+	"M": "Operation of Government-Owned Facilities",
+	"N": "Installation of Equipment",
+	"P": "Salvage Services",
+	"Q": "Medical Services",
+	"R": "Professional, Administrative, and Management Support Services",
+	"S": "Utilities and Housekeeping Services",
+	"T": "Photographic, Mapping, Printing, and Publication Services",
+	"U": "Education and Training Services",
+	"V": "Transportation, Travel, and Relocation Services",
+	"W": "Lease or Rental of Equipment",
+	"X": "Lease or Rental of Facilities",
+	"Y": "Construction of Structures and Facilities",
+	"Z": "Maintenance, Repair, and Alteration of Real Property",
+}
+
+// pscProductGroups are the Federal Supply Groups, keyed by the first two
+// digits of a product code.
+var pscProductGroups = map[string]string{
+	"10": "Weapons",
+	"12": "Fire Control Equipment",
+	"13": "Ammunition and Explosives",
+	"14": "Guided Missiles",
+	"15": "Aircraft and Airframe Structural Components",
+	"16": "Aircraft Components and Accessories",
+	"17": "Aircraft Launching, Landing, and Ground Handling Equipment",
+	"18": "Space Vehicles",
+	"19": "Ships, Small Craft, Pontoons, and Floating Docks",
+	"20": "Ship and Marine Equipment",
+	"22": "Railway Equipment",
+	"23": "Motor Vehicles, Trailers, and Cycles",
+	"24": "Tractors",
+	"25": "Vehicular Equipment Components",
+	"26": "Tires and Tubes",
+	"28": "Engines, Turbines, and Components",
+	"29": "Engine Accessories",
+	"30": "Mechanical Power Transmission Equipment",
+	"31": "Bearings",
+	"32": "Woodworking Machinery and Equipment",
+	"34": "Metalworking Machinery",
+	"35": "Service and Trade Equipment",
+	"36": "Special Industry Machinery",
+	"37": "Agricultural Machinery and Equipment",
+	"38": "Construction, Mining, Excavating, and Highway Maintenance Equipment",
+	"39": "Materials Handling Equipment",
+	"40": "Rope, Cable, Chain, and Fittings",
+	"41": "Refrigeration, Air Conditioning, and Air Circulating Equipment",
+	"42": "Fire Fighting, Rescue, and Safety Equipment",
+	"43": "Pumps and Compressors",
+	"44": "Furnace, Steam Plant, and Drying Equipment",
+	"45": "Plumbing, Heating, and Waste Disposal Equipment",
+	"46": "Water Purification and Sewage Treatment Equipment",
+	"47": "Pipe, Tubing, Hose, and Fittings",
+	"48": "Valves",
+	"49": "Maintenance and Repair Shop Equipment",
+	"51": "Hand Tools",
+	"52": "Measuring Tools",
+	"53": "Hardware and Abrasives",
+	"54": "Prefabricated Structures and Scaffolding",
+	"55": "Lumber, Millwork, Plywood, and Veneer",
+	"56": "Construction and Building Materials",
+	"58": "Communication, Detection, and Coherent Radiation Equipment",
+	"59": "Electrical and Electronic Equipment Components",
+	"60": "Fiber Optics Materials, Components, and Accessories",
+	"61": "Electric Wire, and Power and Distribution Equipment",
+	"62": "Lighting Fixtures and Lamps",
+	"63": "Alarm, Signal, and Security Detection Systems",
+	"65": "Medical, Dental, and Veterinary Equipment and Supplies",
+	"66": "Instruments and Laboratory Equipment",
+	"67": "Photographic Equipment",
+	"68": "Chemicals and Chemical Products",
+	"69": "Training Aids and Devices",
+	"70": "Information Technology Equipment",
+	"71": "Furniture",
+	"72": "Household and Commercial Furnishings and Appliances",
+	"73": "Food Preparation and Serving Equipment",
+	"74": "Office Machines and Visible Record Equipment",
+	"75": "Office Supplies and Devices",
+	"76": "Books, Maps, and Other Publications",
+	"77": "Musical Instruments and Home-Type Radios",
+	"78": "Recreational and Athletic Equipment",
+	"79": "Cleaning Equipment and Supplies",
+	"80": "Brushes, Paints, Sealers, and Adhesives",
+	"81": "Containers, Packaging, and Packing Supplies",
+	"83": "Textiles, Leather, Furs, Tents, and Flags",
+	"84": "Clothing, Individual Equipment, and Insignia",
+	"85": "Toiletries",
+	"87": "Agricultural Supplies",
+	"88": "Live Animals",
+	"89": "Subsistence",
+	"91": "Fuels, Lubricants, Oils, and Waxes",
+	"93": "Nonmetallic Fabricated Materials",
+	"94": "Nonmetallic Crude Materials",
+	"95": "Metal Bars, Sheets, and Shapes",
+	"96": "Ores, Minerals, and Their Primary Products",
+	"99": "Miscellaneous",
+}
+
+// PSCLabel returns "code — name" for a Product Service Code. Codes without
+// their own entry are named after their supply group (products) or category
+// (services); unrecognized codes are returned bare.
+func PSCLabel(code string) string {
+	code = strings.ToUpper(strings.TrimSpace(code))
+	if name, ok := pscLabels[code]; ok {
+		return code + " — " + name
+	}
+	if len(code) >= 2 {
+		if name, ok := pscProductGroups[code[:2]]; ok {
+			return code + " — " + name
+		}
+	}
+	if len(code) >= 1 {
+		if name, ok := pscServiceCategories[code[:1]]; ok {
+			return code + " — " + name
+		}
+	}
+	return code
+}
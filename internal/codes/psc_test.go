@@ -0,0 +1,21 @@
+package codes
+
+import "testing"
+
+func TestPSCLabel(t *testing.T) {
+	tests := []struct {
+		code, want string
+	}{
+		{"R425", "R425 — Engineering and Technical Services"},
+		{"r410", "R410 — Professional, Administrative, and Management Support Services"},
+		{"7035", "7035 — Information Technology Equipment"},
+		{"AJ11", "AJ11 — Research and Development"},
+		{"", ""},
+		{"??", "??"},
+	}
+	for _, tt := range tests {
+		if got := PSCLabel(tt.code); got != tt.want {
+			t.Errorf("PSCLabel(%q) = %q, want %q", tt.code, got, tt.want)
+		}
+	}
+}
@@ -0,0 +1,78 @@
+package codes
+
+import "strings"
+
+// State is a U.S. state or territory with an approximate geographic center,
+// enough to place a marker or label on a map.
+type State struct {
+	Code string
+	Name string
+	Lat  float64
+	Lon  float64
+}
+
+// states is keyed by the USPS code SAM.gov uses for pop_state_code.
+var states = map[string]State{
+	"AL": {"AL", "Alabama", 32.806671, -86.791130},
+	"AK": {"AK", "Alaska", 61.370716, -152.404419},
+	"AZ": {"AZ", "Arizona", 33.729759, -111.431221},
+	"AR": {"AR", "Arkansas", 34.969704, -92.373123},
+	"CA": {"CA", "California", 36.116203, -119.681564},
+	"CO": {"CO", "Colorado", 39.059811, -105.311104},
+	"CT": {"CT", "Connecticut", 41.597782, -72.755371},
+	"DE": {"DE", "Delaware", 39.318523, -75.507141},
+	"DC": {"DC", "District of Columbia", 38.897438, -77.026817},
+	"FL": {"FL", "Florida", 27.766279, -81.686783},
+	"GA": {"GA", "Georgia", 33.040619, -83.643074},
+	"HI": {"HI", "Hawaii", 21.094318, -157.498337},
+	"ID": {"ID", "Idaho", 44.240459, -114.478828},
+	"IL": {"IL", "Illinois", 40.349457, -88.986137},
+	"IN": {"IN", "Indiana", 39.849426, -86.258278},
+	"IA": {"IA", "Iowa", 42.011539, -93.210526},
+	"KS": {"KS", "Kansas", 38.526600, -96.726486},
+	"KY": {"KY", "Kentucky", 37.668140, -84.670067},
+	"LA": {"LA", "Louisiana", 31.169546, -91.867805},
+	"ME": {"ME", "Maine", 44.693947, -69.381927},
+	"MD": {"MD", "Maryland", 39.063946, -76.802101},
+	"MA": {"MA", "Massachusetts", 42.230171, -71.530106},
+	"MI": {"MI", "Michigan", 43.326618, -84.536095},
+	"MN": {"MN", "Minnesota", 45.694454, -93.900192},
+	"MS": {"MS", "Mississippi", 32.741646, -89.678696},
+	"MO": {"MO", "Missouri", 38.456085, -92.288368},
+	"MT": {"MT", "Montana", 46.921925, -110.454353},
+	"NE": {"NE", "Nebraska", 41.125370, -98.268082},
+	"NV": {"NV", "Nevada", 38.313515, -117.055374},
+	"NH": {"NH", "New Hampshire", 43.452492, -71.563896},
+	"NJ": {"NJ", "New Jersey", 40.298904, -74.521011},
+	"NM": {"NM", "New Mexico", 34.840515, -106.248482},
+	"NY": {"NY", "New York", 42.165726, -74.948051},
+	"NC": {"NC", "North Carolina", 35.630066, -79.806419},
+	"ND": {"ND", "North Dakota", 47.528912, -99.784012},
+	"OH": {"OH", "Ohio", 40.388783, -82.764915},
+	"OK": {"OK", "Oklahoma", 35.565342, -96.928917},
+	"OR": {"OR", "Oregon", 44.572021, -122.070938},
+	"PA": {"PA", "Pennsylvania", 40.590752, -77.209755},
+	"RI": {"RI", "Rhode Island", 41.680893, -71.511780},
+	"SC": {"SC", "South Carolina", 33.856892, -80.945007},
+	"SD": {"SD", "South Dakota", 44.299782, -99.438828},
+	"TN": {"TN", "Tennessee", 35.747845, -86.692345},
+	"TX": {"TX", "Texas", 31.054487, -97.563461},
+	"UT": {"UT", "Utah", 40.150032, -111.862434},
+	"VT": {"VT", "Vermont", 44.045876, -72.710686},
+	"VA": {"VA", "Virginia", 37.769337, -78.169968},
+	"WA": {"WA", "Washington", 47.400902, -121.490494},
+	"WV": {"WV", "West Virginia", 38.491226, -80.954453},
+	"WI": {"WI", "Wisconsin", 44.268543, -89.616508},
+	"WY": {"WY", "Wyoming", 42.755966, -107.302490},
+	"AS": {"AS", "American Samoa", -14.270972, -170.132217},
+	"GU": {"GU", "Guam", 13.444304, 144.793731},
+	"MP": {"MP", "Northern Mariana Islands", 15.097900, 145.673900},
+	"PR": {"PR", "Puerto Rico", 18.220833, -66.590149},
+	"VI": {"VI", "U.S. Virgin Islands", 18.335765, -64.896335},
+}
+
+// LookupState returns the state or territory for a USPS code, ignoring case.
+func LookupState(code string) (State, bool) {
+	s, ok := states[strings.ToUpper(strings.TrimSpace(code))]
+	return s, ok
+}
@@ -0,0 +1,16 @@
+package codes
+
+import "testing"
+
+func TestLookupState(t *testing.T) {
+	va, ok := LookupState(" va")
+	if !ok || va.Code != "VA" || va.Name != "Virginia" {
+		t.Errorf("LookupState(va) = %+v, %v", va, ok)
+	}
+	if va.Lat < 36 || va.Lat > 40 || va.Lon < -84 || va.Lon > -75 {
+		t.Errorf("Virginia centroid %v,%v is outside the state", va.Lat, va.Lon)
+	}
+	if _, ok := LookupState("ZZ"); ok {
+		t.Error("LookupState(ZZ) should not resolve")
+	}
+}
@@ -0,0 +1,83 @@
+package db
+
+import (
+	"database/sql"
+	"errors"
+	"fmt"
+	"strings"
+)
+
+// awardAmountClean strips the formatting SAM.gov sometimes leaves in the TEXT
+// award_amount column ("$1,250,000.00").
+const awardAmountClean = `REPLACE(REPLACE(TRIM(award_amount), ',', ''), '$', '')`
+
+// awardAmountExpr is award_amount as a REAL, or NULL when the cleaned text
+// isn't a plain number, so garbage never sums as zero-valued awards.
+var awardAmountExpr = fmt.Sprintf(`(CASE WHEN %[1]s <> '' AND %[1]s NOT GLOB '*[^0-9.-]*' THEN CAST(%[1]s AS REAL) END)`, awardAmountClean)
+
+// Award is one awarded opportunity for the awardee rollup.
+type Award struct {
+	NoticeID    string   `json:"notice_id"`
+	Title       *string  `json:"title"`
+	Department  *string  `json:"department"`
+	AwardeeName *string  `json:"awardee_name"`
+	AwardeeUEI  *string  `json:"awardee_uei_sam"`
+	Number      *string  `json:"award_number"`
+	Date        *string  `json:"award_date"`
+	Amount      *string  `json:"award_amount"`       // as stored
+	AmountValue *float64 `json:"award_amount_value"` // parsed; null when unparseable
+}
+
+// AwardeeRollup is every stored award matching an awardee query.
+type AwardeeRollup struct {
+	Query       string  `json:"query"`
+	Total       int64   `json:"total"`
+	TotalAmount float64 `json:"total_amount"` // sum over all matches, not just this page
+	Limit       int     `json:"limit"`
+	Offset      int     `json:"offset"`
+	Awards      []Award `json:"awards"`
+}
+
+// SearchAwardees finds opportunities awarded to query: a case-insensitive
+// substring of awardee_name, or an exact awardee_uei_sam. Awards are newest
+// award_date first.
+func SearchAwardees(database *sql.DB, query string, limit, offset int) (*AwardeeRollup, error) {
+	query = strings.TrimSpace(query)
+	if query == "" {
+		return nil, errors.New("awardee name or UEI is required")
+	}
+	if limit <= 0 {
+		limit = 50
+	}
+	offset = max(offset, 0)
+	where := `WHERE awardee_name LIKE ? ESCAPE '\' OR awardee_uei_sam = ? COLLATE NOCASE`
+	args := []any{likeContains(query), query}
+
+	r := AwardeeRollup{Query: query, Limit: limit, Offset: offset, Awards: []Award{}}
+	if err := database.QueryRow(`SELECT COUNT(*), COALESCE(SUM(`+awardAmountExpr+`), 0)
+		FROM opportunities `+where, args...).Scan(&r.Total, &r.TotalAmount); err != nil {
+		return nil, fmt.Errorf("sum awards: %w", err)
+	}
+
+	rows, err := database.Query(`SELECT id, title, department, awardee_name, awardee_uei_sam,
+		award_number, award_date, award_amount, `+awardAmountExpr+`
+		FROM opportunities `+where+`
+		ORDER BY award_date IS NULL OR award_date = '', award_date DESC, id
+		LIMIT ? OFFSET ?`, append(args, limit, offset)...)
+	if err != nil {
+		return nil, fmt.Errorf("list awards: %w", err)
+	}
+	defer rows.Close()
+	for rows.Next() {
+		var a Award
+		if err := rows.Scan(&a.NoticeID, &a.Title, &a.Department, &a.AwardeeName, &a.AwardeeUEI,
+			&a.Number, &a.Date, &a.Amount, &a.AmountValue); err != nil {
+			return nil, err
+		}
+		r.Awards = append(r.Awards, a)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return &r, nil
+}
@@ -0,0 +1,39 @@
+package db
+
+import "testing"
+
+func TestSearchAwardees(t *testing.T) {
+	d := openTestDB(t)
+	if _, err := d.Exec(`INSERT INTO opportunities (id, title, awardee_name, awardee_uei_sam, award_date, award_amount) VALUES
+		('a1', 'Old award', 'ACME Federal LLC', 'UEI111', '2024-05-01', '$1,000,000.50'),
+		('a2', 'New award', 'Acme Federal LLC', 'UEI111', '2025-02-10', '250000'),
+		('a3', 'Odd amount', 'ACME FEDERAL LLC', NULL, NULL, 'see attachment'),
+		('b1', 'Other firm', 'Beta Corp', 'UEI222', '2025-03-01', '99'),
+		('c1', 'Wildcard bait', 'Gamma 100% Solutions', 'UEI333', '2025-01-01', '5')`); err != nil {
+		t.Fatal(err)
+	}
+
+	r, err := SearchAwardees(d, "acme federal", 0, 0)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if r.Total != 3 || r.TotalAmount != 1250000.50 {
+		t.Errorf("total = %d, amount = %v; want 3 awards summing 1250000.50", r.Total, r.TotalAmount)
+	}
+	if len(r.Awards) != 3 || r.Awards[0].NoticeID != "a2" || r.Awards[2].NoticeID != "a3" {
+		t.Fatalf("awards = %+v, want newest award_date first and undated last", r.Awards)
+	}
+	if r.Awards[2].AmountValue != nil {
+		t.Errorf("unparseable amount should have a nil value, got %v", *r.Awards[2].AmountValue)
+	}
+
+	if r, err := SearchAwardees(d, "uei222", 10, 0); err != nil || r.Total != 1 || r.Awards[0].NoticeID != "b1" {
+		t.Errorf("UEI lookup = %+v, %v; want b1", r, err)
+	}
+	if r, err := SearchAwardees(d, "0%", 10, 0); err != nil || r.Total != 1 || r.Awards[0].NoticeID != "c1" {
+		t.Errorf("literal %% search = %+v, %v; want only c1", r, err)
+	}
+	if _, err := SearchAwardees(d, "  ", 10, 0); err == nil {
+		t.Error("blank query should fail")
+	}
+}
@@ -0,0 +1,89 @@
+package db
+
+import (
+	"database/sql"
+	"errors"
+	"fmt"
+)
+
+// UpsertBatch writes API records through shared transactions of up to size
+// records instead of UpsertOpportunityFromAPI's one transaction per record.
+// Each record runs under its own savepoint, so a record that fails rolls back
+// alone and the rest of the batch still commits.
+//
+// Batching trades durability for throughput: records written since the last
+// Flush are lost if the process dies, and an open batch holds the
+// database's only connection, so callers should Flush at natural
+// checkpoints (sync flushes after every page). A size of 1 or less writes
+// each record in its own transaction.
+type UpsertBatch struct {
+	db      *sql.DB
+	size    int
+	tx      *sql.Tx
+	pending int
+	// CompressDescriptions stores descriptions gzip-compressed in
+	// description_blob (see CompressDescriptions).
+	CompressDescriptions bool
+}
+
+// NewUpsertBatch starts an empty batch that commits every size records.
+func NewUpsertBatch(db *sql.DB, size int) *UpsertBatch {
+	return &UpsertBatch{db: db, size: size}
+}
+
+// Upsert writes opp into the open batch, beginning one if needed. It never
+// commits; call Full and Flush.
+func (b *UpsertBatch) Upsert(opp map[string]any) (UpsertOutcome, error) {
+	if b.size <= 1 {
+		return upsertOne(b.db, opp, b.CompressDescriptions)
+	}
+	if id, _ := opp["noticeId"].(string); id == "" {
+		return UpsertSkipped, nil
+	}
+	if b.tx == nil {
+		tx, err := b.db.Begin()
+		if err != nil {
+			return UpsertSkipped, err
+		}
+		b.tx = tx
+	}
+
+	if _, err := b.tx.Exec("SAVEPOINT upsert_record"); err != nil {
+		return UpsertSkipped, err
+	}
+	outcome, err := upsertFromAPI(b.tx, opp, b.CompressDescriptions)
+	if err != nil {
+		if _, rbErr := b.tx.Exec("ROLLBACK TO upsert_record"); rbErr != nil {
+			return UpsertSkipped, errors.Join(err, rbErr)
+		}
+	}
+	if _, relErr := b.tx.Exec("RELEASE upsert_record"); relErr != nil {
+		return UpsertSkipped, errors.Join(err, relErr)
+	}
+	if err != nil {
+		return UpsertSkipped, err
+	}
+	if outcome != UpsertUnchanged {
+		b.pending++
+	}
+	return outcome, nil
+}
+
+// Full reports whether the batch holds size writes and should be flushed.
+func (b *UpsertBatch) Full() bool {
+	return b.tx != nil && b.pending >= b.size
+}
+
+// Flush commits the open batch, if any. After an error the batch's writes
+// are gone, but the batch can be reused.
+func (b *UpsertBatch) Flush() error {
+	if b.tx == nil {
+		return nil
+	}
+	err := b.tx.Commit()
+	b.tx, b.pending = nil, 0
+	if err != nil {
+		return fmt.Errorf("flush upsert batch: %w", err)
+	}
+	return nil
+}
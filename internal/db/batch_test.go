@@ -0,0 +1,109 @@
+package db
+
+import (
+	"fmt"
+	"path/filepath"
+	"testing"
+)
+
+func countOpportunities(t *testing.T, b *UpsertBatch) int {
+	t.Helper()
+	var n int
+	if err := b.db.QueryRow("SELECT COUNT(*) FROM opportunities").Scan(&n); err != nil {
+		t.Fatal(err)
+	}
+	return n
+}
+
+func TestUpsertBatch(t *testing.T) {
+	d := openTestDB(t)
+	if _, err := d.Exec(`CREATE TRIGGER reject_bad BEFORE INSERT ON opportunities
+		WHEN NEW.id = 'bad' BEGIN SELECT RAISE(ABORT, 'rejected'); END`); err != nil {
+		t.Fatal(err)
+	}
+	b := NewUpsertBatch(d, 2)
+
+	for _, id := range []string{"a", "bad", "b"} {
+		_, err := b.Upsert(map[string]any{"noticeId": id, "title": "t " + id, "pointOfContact": []any{map[string]any{"fullName": "Jo"}}})
+		if (err != nil) != (id == "bad") {
+			t.Fatalf("Upsert(%s) error = %v", id, err)
+		}
+	}
+	if !b.Full() {
+		t.Fatal("Full() = false after two writes to a batch of 2")
+	}
+	if err := b.Flush(); err != nil {
+		t.Fatal(err)
+	}
+	if n := countOpportunities(t, b); n != 2 {
+		t.Errorf("after flush: %d opportunities, want 2 (the bad record rolls back alone)", n)
+	}
+	var contacts int
+	d.QueryRow("SELECT COUNT(*) FROM contacts").Scan(&contacts)
+	if contacts != 2 {
+		t.Errorf("contacts = %d, want 2", contacts)
+	}
+
+	// Re-upserting identical records writes nothing, so the batch never fills.
+	if outcome, err := b.Upsert(map[string]any{"noticeId": "a", "title": "t a", "pointOfContact": []any{map[string]any{"fullName": "Jo"}}}); err != nil || outcome != UpsertUnchanged {
+		t.Fatalf("re-upsert = %v, %v; want unchanged", outcome, err)
+	}
+	if b.Full() {
+		t.Error("Full() = true after an unchanged record")
+	}
+	if outcome, _ := b.Upsert(map[string]any{"title": "no id"}); outcome != UpsertSkipped {
+		t.Errorf("record without noticeId = %v, want skipped", outcome)
+	}
+	if err := b.Flush(); err != nil {
+		t.Fatal(err)
+	}
+	if err := b.Flush(); err != nil {
+		t.Errorf("Flush on an empty batch = %v", err)
+	}
+}
+
+func TestUpsertBatch_SizeOneCommitsEachRecord(t *testing.T) {
+	d := openTestDB(t)
+	b := NewUpsertBatch(d, 1)
+	if _, err := b.Upsert(map[string]any{"noticeId": "a", "title": "x"}); err != nil {
+		t.Fatal(err)
+	}
+	if b.Full() {
+		t.Error("Full() = true for an unbatched write")
+	}
+	if n := countOpportunities(t, b); n != 1 {
+		t.Errorf("%d opportunities before any flush, want 1", n)
+	}
+}
+
+// BenchmarkUpsertBatch compares per-record transactions (size 1) with
+// batched commits:
+//
+//	go test ./internal/db -run '^$' -bench UpsertBatch
+func BenchmarkUpsertBatch(b *testing.B) {
+	for _, size := range []int{1, 50, 500} {
+		b.Run(fmt.Sprintf("size=%d", size), func(b *testing.B) {
+			d, err := Open(filepath.Join(b.TempDir(), "bench.db"))
+			if err != nil {
+				b.Fatal(err)
+			}
+			defer d.Close()
+			batch := NewUpsertBatch(d, size)
+			b.ResetTimer()
+			for i := 0; i < b.N; i++ {
+				opp := map[string]any{"noticeId": fmt.Sprintf("n-%d", i), "title": "Widgets", "postedDate": "2026-01-02"}
+				if _, err := batch.Upsert(opp); err != nil {
+					b.Fatal(err)
+				}
+				if batch.Full() {
+					if err := batch.Flush(); err != nil {
+						b.Fatal(err)
+					}
+				}
+			}
+			if err := batch.Flush(); err != nil {
+				b.Fatal(err)
+			}
+		})
+	}
+}
@@ -0,0 +1,51 @@
+package db
+
+import (
+	"database/sql"
+	"fmt"
+	"log"
+	"time"
+)
+
+// ResponseCache is a samgov.ResponseCache backed by the response_cache table.
+type ResponseCache struct {
+	DB *sql.DB
+}
+
+func (c ResponseCache) Get(key string, maxAge time.Duration) ([]byte, bool) {
+	var body []byte
+	err := c.DB.QueryRow(`SELECT body FROM response_cache
+		WHERE key = ? AND fetched_at >= datetime('now', ?)`,
+		key, sqliteAge(maxAge)).Scan(&body)
+	if err != nil {
+		if err != sql.ErrNoRows {
+			log.Printf("response cache get: %v", err)
+		}
+		return nil, false
+	}
+	return body, true
+}
+
+func (c ResponseCache) Put(key string, body []byte) error {
+	_, err := c.DB.Exec(`INSERT INTO response_cache (key, body, fetched_at)
+		VALUES (?, ?, datetime('now'))
+		ON CONFLICT(key) DO UPDATE SET body=excluded.body, fetched_at=excluded.fetched_at`,
+		key, body)
+	return err
+}
+
+// PruneResponseCache deletes cached responses older than maxAge and returns
+// how many were removed.
+func PruneResponseCache(database *sql.DB, maxAge time.Duration) (int64, error) {
+	res, err := database.Exec(`DELETE FROM response_cache WHERE fetched_at < datetime('now', ?)`,
+		sqliteAge(maxAge))
+	if err != nil {
+		return 0, err
+	}
+	return res.RowsAffected()
+}
+
+// sqliteAge formats a duration as a datetime() modifier, e.g. "-900 seconds".
+func sqliteAge(d time.Duration) string {
+	return fmt.Sprintf("-%d seconds", int64(d/time.Second))
+}
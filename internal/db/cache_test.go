@@ -0,0 +1,36 @@
+package db
+
+import (
+	"testing"
+	"time"
+)
+
+func TestResponseCache_FreshAndExpired(t *testing.T) {
+	d := openTestDB(t)
+	c := ResponseCache{DB: d}
+
+	if _, ok := c.Get("k", time.Minute); ok {
+		t.Fatal("expected miss on empty cache")
+	}
+	if err := c.Put("k", []byte(`{"totalRecords":0}`)); err != nil {
+		t.Fatal(err)
+	}
+	body, ok := c.Get("k", time.Minute)
+	if !ok || string(body) != `{"totalRecords":0}` {
+		t.Fatalf("Get = %q, %v; want cached body", body, ok)
+	}
+
+	if _, err := d.Exec(`UPDATE response_cache SET fetched_at = datetime('now', '-2 hours')`); err != nil {
+		t.Fatal(err)
+	}
+	if _, ok := c.Get("k", time.Hour); ok {
+		t.Error("expected stale entry to be ignored")
+	}
+	n, err := PruneResponseCache(d, time.Hour)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if n != 1 {
+		t.Errorf("pruned %d, want 1", n)
+	}
+}
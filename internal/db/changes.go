@@ -0,0 +1,129 @@
+package db
+
+import (
+	"database/sql"
+	"fmt"
+	"strings"
+	"time"
+)
+
+// The changes feed lets a client mirror the table by polling: rows whose
+// modified_at is after its cursor, oldest change first. Inserts set
+// modified_at too, so new rows appear alongside changed ones. modified_at
+// has one-second resolution, so the cursor pairs it with the row id, and
+// rows from the still-running second are held back until it has passed;
+// otherwise a write later in that second could land behind a cursor
+// already handed out.
+
+// sqliteDatetime is the layout of datetime('now') values such as
+// modified_at.
+const sqliteDatetime = "2006-01-02 15:04:05"
+
+// ChangeCursor is a position in the changes feed: after the row modified
+// at ModifiedAt (SQLite datetime, UTC) with id AfterID. An empty AfterID
+// means after every row modified at ModifiedAt.
+type ChangeCursor struct {
+	ModifiedAt string
+	AfterID    string
+}
+
+// ChangedOpportunity is a list item with the time it last changed.
+type ChangedOpportunity struct {
+	OpportunityListItem
+	ModifiedAt string `json:"modified_at"` // RFC 3339 UTC
+}
+
+// ChangesPage is one page of the changes feed. Next is where the following
+// poll resumes: the last row's position, or the request's cursor when
+// nothing changed. More is true when the page came back full.
+type ChangesPage struct {
+	Opportunities []ChangedOpportunity
+	Next          ChangeCursor
+	More          bool
+}
+
+// ListChanges returns up to f.Limit opportunities matching f that changed
+// after cursor, ordered by modified_at then id. Sorting and paging fields
+// in f other than Limit are ignored.
+func ListChanges(database *sql.DB, f ListFilters, cursor ChangeCursor) (*ChangesPage, error) {
+	limit := f.Limit
+	if limit <= 0 || limit > MaxPageSize() {
+		limit = 25
+	}
+
+	qb := filterQuery(f)
+	if cursor.AfterID == "" {
+		qb.and("modified_at > ?", cursor.ModifiedAt)
+	} else {
+		qb.and("(modified_at > ? OR (modified_at = ? AND id > ?))", cursor.ModifiedAt, cursor.ModifiedAt, cursor.AfterID)
+	}
+	qb.and("modified_at < ?", time.Now().UTC().Format(sqliteDatetime))
+	rows, err := database.Query(`SELECT id, modified_at FROM opportunities `+qb.whereSQL()+
+		` ORDER BY modified_at, id LIMIT ?`, append(qb.params, limit)...)
+	if err != nil {
+		return nil, fmt.Errorf("changes query: %w", err)
+	}
+	var ids []string
+	modified := map[string]string{}
+	for rows.Next() {
+		var id, at string
+		if err := rows.Scan(&id, &at); err != nil {
+			rows.Close()
+			return nil, fmt.Errorf("scan change: %w", err)
+		}
+		ids = append(ids, id)
+		modified[id] = at
+	}
+	rows.Close()
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+
+	page := &ChangesPage{Opportunities: []ChangedOpportunity{}, Next: cursor, More: len(ids) == limit}
+	if len(ids) == 0 {
+		return page, nil
+	}
+
+	// The second query reads the full list columns for just this page.
+	placeholders := strings.TrimSuffix(strings.Repeat("?,", len(ids)), ",")
+	args := make([]any, len(ids))
+	for i, id := range ids {
+		args[i] = id
+	}
+	itemRows, err := database.Query(fmt.Sprintf("SELECT %s FROM opportunities WHERE id IN (%s)",
+		selectListColumns(f), placeholders), args...)
+	if err != nil {
+		return nil, fmt.Errorf("changes items: %w", err)
+	}
+	items, err := scanListItems(itemRows, f.Freshness)
+	if err != nil {
+		return nil, err
+	}
+	byID := make(map[string]OpportunityListItem, len(items))
+	for _, it := range items {
+		byID[it.ID] = it
+	}
+	for _, id := range ids {
+		it, ok := byID[id]
+		if !ok {
+			continue // deleted between the two queries
+		}
+		page.Opportunities = append(page.Opportunities, ChangedOpportunity{
+			OpportunityListItem: it,
+			ModifiedAt:          sqliteToRFC3339(modified[id]),
+		})
+	}
+	last := ids[len(ids)-1]
+	page.Next = ChangeCursor{ModifiedAt: modified[last], AfterID: last}
+	return page, nil
+}
+
+// sqliteToRFC3339 reformats a SQLite datetime('now') value as RFC 3339 UTC,
+// leaving anything else as stored.
+func sqliteToRFC3339(s string) string {
+	t, err := time.Parse(sqliteDatetime, s)
+	if err != nil {
+		return s
+	}
+	return t.Format(time.RFC3339)
+}
@@ -0,0 +1,62 @@
+package db
+
+import "testing"
+
+func TestListChanges(t *testing.T) {
+	d := openTestDB(t)
+	if _, err := d.Exec(`INSERT INTO opportunities (id, title, naics_code, modified_at) VALUES
+		('old', 'Old', '541511', '2026-01-01 00:00:00'),
+		('b', 'B', '541511', '2026-02-01 12:00:00'),
+		('a', 'A', '541511', '2026-02-01 12:00:00'),
+		('c', 'C', '236220', '2026-02-01 12:00:00'),
+		('late', 'Late', '541511', '2026-03-01 08:30:00'),
+		('future', 'Future', '541511', datetime('now', '+1 hour'))`); err != nil {
+		t.Fatal(err)
+	}
+	ids := func(p *ChangesPage) []string {
+		var out []string
+		for _, o := range p.Opportunities {
+			out = append(out, o.ID)
+		}
+		return out
+	}
+
+	// Paging splits the three rows sharing a second; the id tiebreak
+	// resumes inside it without repeating or skipping any.
+	page, err := ListChanges(d, ListFilters{Limit: 2}, ChangeCursor{ModifiedAt: "2026-01-15 00:00:00"})
+	if err != nil {
+		t.Fatal(err)
+	}
+	if got := ids(page); len(got) != 2 || got[0] != "a" || got[1] != "b" || !page.More {
+		t.Fatalf("first page = %v (more %v), want [a b] and more", got, page.More)
+	}
+	if page.Opportunities[0].ModifiedAt != "2026-02-01T12:00:00Z" {
+		t.Errorf("modified_at = %q, want RFC 3339", page.Opportunities[0].ModifiedAt)
+	}
+	if page.Next != (ChangeCursor{ModifiedAt: "2026-02-01 12:00:00", AfterID: "b"}) {
+		t.Errorf("next = %+v", page.Next)
+	}
+
+	page, err = ListChanges(d, ListFilters{Limit: 10}, page.Next)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if got := ids(page); len(got) != 2 || got[0] != "c" || got[1] != "late" || page.More {
+		t.Fatalf("second page = %v (more %v), want [c late], the future row held back", got, page.More)
+	}
+
+	// Nothing new: the cursor comes back unchanged.
+	cursor := page.Next
+	if page, err = ListChanges(d, ListFilters{Limit: 10}, cursor); err != nil || len(page.Opportunities) != 0 || page.Next != cursor {
+		t.Errorf("caught up = %v, next %+v, err %v; want empty with the same cursor", ids(page), page.Next, err)
+	}
+
+	// Filters narrow the feed; without an id the cursor's second is excluded.
+	page, err = ListChanges(d, ListFilters{NAICSCode: "541511", Limit: 10}, ChangeCursor{ModifiedAt: "2026-01-01 00:00:00"})
+	if err != nil {
+		t.Fatal(err)
+	}
+	if got := ids(page); len(got) != 3 || got[0] != "a" || got[2] != "late" {
+		t.Errorf("filtered = %v, want [a b late]", got)
+	}
+}
@@ -0,0 +1,58 @@
+package db
+
+import (
+	"database/sql"
+	"fmt"
+	"strings"
+)
+
+// CompletenessColumns are the opportunity columns whose fill rate is worth
+// watching: when SAM.gov stops populating one of them, filters built on it
+// quietly start missing rows.
+var CompletenessColumns = []string{
+	"naics_code",
+	"set_aside",
+	"response_deadline",
+	"award_amount",
+	"pop_state_code",
+}
+
+// ColumnCompleteness is how many opportunities have a non-null, non-empty
+// value for Column.
+type ColumnCompleteness struct {
+	Column  string  `json:"column"`
+	Filled  int64   `json:"filled"`
+	Percent float64 `json:"percent"`
+}
+
+// Completeness is the per-column fill rate across every stored opportunity.
+type Completeness struct {
+	Total   int64                `json:"total"`
+	Columns []ColumnCompleteness `json:"columns"`
+}
+
+// GetCompleteness reports, for each of CompletenessColumns, the share of rows
+// with a non-null, non-empty value. Percent is 0 when the table is empty.
+func GetCompleteness(database *sql.DB) (*Completeness, error) {
+	exprs := make([]string, len(CompletenessColumns))
+	for i, col := range CompletenessColumns {
+		exprs[i] = fmt.Sprintf("COUNT(NULLIF(%s, ''))", col)
+	}
+	query := "SELECT COUNT(*), " + strings.Join(exprs, ", ") + " FROM opportunities"
+
+	c := Completeness{Columns: make([]ColumnCompleteness, len(CompletenessColumns))}
+	dest := []any{&c.Total}
+	for i, col := range CompletenessColumns {
+		c.Columns[i].Column = col
+		dest = append(dest, &c.Columns[i].Filled)
+	}
+	if err := database.QueryRow(query).Scan(dest...); err != nil {
+		return nil, err
+	}
+	if c.Total > 0 {
+		for i := range c.Columns {
+			c.Columns[i].Percent = float64(c.Columns[i].Filled) * 100 / float64(c.Total)
+		}
+	}
+	return &c, nil
+}
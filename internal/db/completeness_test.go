@@ -0,0 +1,40 @@
+package db
+
+import "testing"
+
+func TestGetCompleteness(t *testing.T) {
+	d := openTestDB(t)
+
+	c, err := GetCompleteness(d)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if c.Total != 0 || c.Columns[0].Percent != 0 {
+		t.Errorf("empty table: got %+v", c)
+	}
+
+	if _, err := d.Exec(`INSERT INTO opportunities (id, naics_code, set_aside) VALUES
+		('a', '541511', 'SBA'), ('b', '541512', ''), ('c', NULL, NULL), ('d', '336411', NULL)`); err != nil {
+		t.Fatal(err)
+	}
+	c, err = GetCompleteness(d)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if c.Total != 4 {
+		t.Fatalf("Total = %d, want 4", c.Total)
+	}
+	got := map[string]ColumnCompleteness{}
+	for _, col := range c.Columns {
+		got[col.Column] = col
+	}
+	if n := got["naics_code"]; n.Filled != 3 || n.Percent != 75 {
+		t.Errorf("naics_code = %+v, want 3 filled / 75%%", n)
+	}
+	if s := got["set_aside"]; s.Filled != 1 || s.Percent != 25 {
+		t.Errorf("set_aside = %+v, want 1 filled / 25%% (empty string counts as missing)", s)
+	}
+	if a := got["award_amount"]; a.Filled != 0 {
+		t.Errorf("award_amount = %+v, want 0 filled", a)
+	}
+}
@@ -0,0 +1,132 @@
+package db
+
+import (
+	"bytes"
+	"compress/gzip"
+	"database/sql"
+	"fmt"
+	"io"
+)
+
+// Descriptions are most of a stored opportunity's bytes. With compression
+// on, the HTML is stored gzip-compressed in description_blob and the plain
+// description column is NULL; a non-NULL description_blob is the flag
+// readers check (see inflateDescription). description_text and the word
+// count stay plain, since list views and searches read them.
+
+// compressDescription gzips a description for description_blob.
+func compressDescription(description string) ([]byte, error) {
+	var buf bytes.Buffer
+	zw, err := gzip.NewWriterLevel(&buf, gzip.BestCompression)
+	if err != nil {
+		return nil, err
+	}
+	if _, err := io.WriteString(zw, description); err != nil {
+		return nil, err
+	}
+	if err := zw.Close(); err != nil {
+		return nil, err
+	}
+	return buf.Bytes(), nil
+}
+
+// inflateDescription is a row's description whichever way it is stored:
+// desc when stored plain, otherwise blob decompressed. Nil when neither is
+// set.
+func inflateDescription(desc *string, blob []byte) (*string, error) {
+	if desc != nil || blob == nil {
+		return desc, nil
+	}
+	zr, err := gzip.NewReader(bytes.NewReader(blob))
+	if err != nil {
+		return nil, fmt.Errorf("decompress description: %w", err)
+	}
+	b, err := io.ReadAll(zr)
+	if err != nil {
+		return nil, fmt.Errorf("decompress description: %w", err)
+	}
+	s := string(b)
+	return &s, nil
+}
+
+// storeCompressedDescription moves id's just-written description into
+// description_blob. Empty descriptions stay plain; there is nothing to save.
+func storeCompressedDescription(tx *sql.Tx, id string, description *string) error {
+	if description == nil || *description == "" {
+		return nil
+	}
+	blob, err := compressDescription(*description)
+	if err != nil {
+		return fmt.Errorf("compress description %s: %w", id, err)
+	}
+	_, err = tx.Exec("UPDATE opportunities SET description = NULL, description_blob = ? WHERE id = ?", blob, id)
+	return err
+}
+
+// CompressionStats reports what CompressDescriptions rewrote.
+type CompressionStats struct {
+	Rows            int
+	PlainBytes      int64 // the descriptions' size before
+	CompressedBytes int64 // and after
+}
+
+// compressChunk is how many rows CompressDescriptions rewrites per
+// transaction, bounding how much description text it holds in memory.
+const compressChunk = 500
+
+// CompressDescriptions compresses every description still stored plain,
+// for databases synced before compression was turned on. The file keeps its
+// size until the freed pages are reclaimed (VACUUM, or `govscout export-db`
+// for a compacted copy).
+func CompressDescriptions(database *sql.DB) (CompressionStats, error) {
+	var stats CompressionStats
+	for {
+		n, err := compressDescriptionChunk(database, &stats)
+		if err != nil || n < compressChunk {
+			return stats, err
+		}
+	}
+}
+
+// compressDescriptionChunk compresses up to compressChunk plain
+// descriptions in one transaction. Compressed rows no longer match the
+// SELECT, so each call picks up where the last left off.
+func compressDescriptionChunk(database *sql.DB, stats *CompressionStats) (int, error) {
+	rows, err := database.Query(`SELECT id, description FROM opportunities
+		WHERE description IS NOT NULL AND description != '' LIMIT ?`, compressChunk)
+	if err != nil {
+		return 0, fmt.Errorf("select descriptions: %w", err)
+	}
+	descriptions := map[string]string{}
+	for rows.Next() {
+		var id, description string
+		if err := rows.Scan(&id, &description); err != nil {
+			rows.Close()
+			return 0, fmt.Errorf("scan description: %w", err)
+		}
+		descriptions[id] = description
+	}
+	rows.Close()
+	if err := rows.Err(); err != nil {
+		return 0, err
+	}
+
+	tx, err := database.Begin()
+	if err != nil {
+		return 0, err
+	}
+	defer tx.Rollback()
+	for id, description := range descriptions {
+		blob, err := compressDescription(description)
+		if err != nil {
+			return 0, fmt.Errorf("compress description %s: %w", id, err)
+		}
+		if _, err := tx.Exec("UPDATE opportunities SET description = NULL, description_blob = ? WHERE id = ?", blob, id); err != nil {
+			return 0, fmt.Errorf("store compressed description %s: %w", id, err)
+		}
+		stats.Rows++
+		stats.PlainBytes += int64(len(description))
+		stats.CompressedBytes += int64(len(blob))
+	}
+	return len(descriptions), tx.Commit()
+}
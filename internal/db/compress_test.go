@@ -0,0 +1,94 @@
+package db
+
+import (
+	"strings"
+	"testing"
+)
+
+// storedDescription reads a row's description columns as stored.
+func storedDescription(t *testing.T, b *UpsertBatch, id string) (plain *string, blob []byte) {
+	t.Helper()
+	if err := b.db.QueryRow("SELECT description, description_blob FROM opportunities WHERE id = ?", id).Scan(&plain, &blob); err != nil {
+		t.Fatal(err)
+	}
+	return plain, blob
+}
+
+func TestCompressedDescriptionsReadBack(t *testing.T) {
+	d := openTestDB(t)
+	html := "<p>" + strings.Repeat("Provide widget maintenance services. ", 200) + "</p>"
+	b := NewUpsertBatch(d, 1)
+	b.CompressDescriptions = true
+	if _, err := b.Upsert(map[string]any{"noticeId": "z", "title": "Widgets", "description": html}); err != nil {
+		t.Fatal(err)
+	}
+
+	plain, blob := storedDescription(t, b, "z")
+	if plain != nil || len(blob) == 0 || len(blob) >= len(html)/10 {
+		t.Fatalf("stored plain=%v, blob %d bytes for %d bytes of HTML; want only a small blob", plain != nil, len(blob), len(html))
+	}
+
+	detail, err := GetOpportunity(d, "z")
+	if err != nil {
+		t.Fatal(err)
+	}
+	if detail.Opp.Description == nil || *detail.Opp.Description != html {
+		t.Error("GetOpportunity did not return the original description")
+	}
+	full, err := GetOpportunityFull(d, "z")
+	if err != nil || full["description"] != html {
+		t.Errorf("GetOpportunityFull description mismatch (err %v)", err)
+	}
+	res, err := ListOpportunities(d, ListFilters{Limit: 10})
+	if err != nil {
+		t.Fatal(err)
+	}
+	if got := res.Opportunities[0].Description; got == nil || *got != html {
+		t.Error("ListOpportunities did not return the original description")
+	}
+	if res.Opportunities[0].DescriptionWordCount == nil || *res.Opportunities[0].DescriptionWordCount != 800 {
+		t.Errorf("word count = %v, want 800 (derived from the plain text)", res.Opportunities[0].DescriptionWordCount)
+	}
+	if res, err = ListOpportunities(d, ListFilters{Limit: 10, OmitDescription: true}); err != nil || res.Opportunities[0].Description != nil {
+		t.Errorf("OmitDescription still returned a description (err %v)", err)
+	}
+
+	// A plain write of a changed record replaces the blob.
+	b.CompressDescriptions = false
+	if _, err := b.Upsert(map[string]any{"noticeId": "z", "title": "Widgets v2", "description": "short"}); err != nil {
+		t.Fatal(err)
+	}
+	if plain, blob = storedDescription(t, b, "z"); plain == nil || *plain != "short" || blob != nil {
+		t.Errorf("after plain rewrite: plain=%v blob=%d bytes", plain, len(blob))
+	}
+}
+
+func TestCompressDescriptions(t *testing.T) {
+	d := openTestDB(t)
+	long := strings.Repeat("<li>Line item</li>", 100)
+	if _, err := d.Exec(`INSERT INTO opportunities (id, description) VALUES
+		('a', ?), ('b', ?), ('empty', ''), ('none', NULL)`, long, long); err != nil {
+		t.Fatal(err)
+	}
+
+	stats, err := CompressDescriptions(d)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if stats.Rows != 2 || stats.PlainBytes != int64(2*len(long)) || stats.CompressedBytes >= stats.PlainBytes {
+		t.Errorf("stats = %+v", stats)
+	}
+	for _, id := range []string{"a", "b"} {
+		detail, err := GetOpportunity(d, id)
+		if err != nil || detail.Opp.Description == nil || *detail.Opp.Description != long {
+			t.Errorf("%s: description not restored (err %v)", id, err)
+		}
+	}
+	if detail, _ := GetOpportunity(d, "empty"); detail.Opp.Description == nil || *detail.Opp.Description != "" {
+		t.Error("empty description should stay plain")
+	}
+
+	if stats, err = CompressDescriptions(d); err != nil || stats.Rows != 0 {
+		t.Errorf("second pass = %+v, %v; want nothing left to compress", stats, err)
+	}
+}
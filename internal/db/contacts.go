@@ -0,0 +1,67 @@
+package db
+
+import (
+	"database/sql"
+	"fmt"
+)
+
+// ContactSummary is one distinct contact across all opportunities, keyed by
+// email (case-insensitive) or, when the email is blank, by full name.
+type ContactSummary struct {
+	FullName         *string `json:"full_name"`
+	Email            *string `json:"email"`
+	Phone            *string `json:"phone"`
+	Title            *string `json:"title"`
+	Opportunities    int     `json:"opportunity_count"`
+	LatestNoticeID   string  `json:"latest_notice_id"`
+	LatestTitle      *string `json:"latest_title"`
+	LatestPostedDate *string `json:"latest_posted_date"`
+}
+
+// contactsKeyed tags every contact row with its grouping key and the sortable
+// posted date of its opportunity. Rows with neither email nor name are dropped.
+const contactsKeyed = `WITH keyed AS (
+	SELECT c.full_name, c.email, c.phone, c.title, c.notice_id,
+	       o.title AS opp_title, o.posted_date,
+	       COALESCE(NULLIF(lower(trim(c.email)), ''), lower(trim(c.full_name))) AS contact_key,
+	       ` + postedDateSortKey + ` || c.notice_id AS recency
+	FROM contacts c
+	JOIN opportunities o ON o.id = c.notice_id
+	WHERE COALESCE(NULLIF(trim(c.email), ''), NULLIF(trim(c.full_name), '')) IS NOT NULL
+)`
+
+// ListContacts returns distinct contacts, most-used first, with the number of
+// opportunities each appears on and their most recently posted one.
+func ListContacts(db *sql.DB, limit, offset int) ([]ContactSummary, int64, error) {
+	var total int64
+	if err := db.QueryRow(contactsKeyed + ` SELECT COUNT(DISTINCT contact_key) FROM keyed`).Scan(&total); err != nil {
+		return nil, 0, fmt.Errorf("count contacts: %w", err)
+	}
+
+	// With a single MAX() aggregate, SQLite takes the bare columns from the
+	// row holding the maximum, i.e. the contact's newest opportunity.
+	rows, err := db.Query(contactsKeyed+`
+		SELECT full_name, email, phone, title, notice_id, opp_title, posted_date,
+		       MAX(recency), COUNT(DISTINCT notice_id) AS n
+		FROM keyed
+		GROUP BY contact_key
+		ORDER BY n DESC, contact_key
+		LIMIT ? OFFSET ?`, limit, offset)
+	if err != nil {
+		return nil, 0, fmt.Errorf("list contacts: %w", err)
+	}
+	defer rows.Close()
+
+	var out []ContactSummary
+	for rows.Next() {
+		var c ContactSummary
+		var recency string
+		if err := rows.Scan(&c.FullName, &c.Email, &c.Phone, &c.Title,
+			&c.LatestNoticeID, &c.LatestTitle, &c.LatestPostedDate,
+			&recency, &c.Opportunities); err != nil {
+			return nil, 0, err
+		}
+		out = append(out, c)
+	}
+	return out, total, rows.Err()
+}
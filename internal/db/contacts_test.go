@@ -0,0 +1,42 @@
+package db
+
+import "testing"
+
+func TestListContacts_GroupsByEmailThenName(t *testing.T) {
+	d := openTestDB(t)
+	opp := func(id, posted string, pocs ...map[string]any) {
+		t.Helper()
+		list := make([]any, len(pocs))
+		for i, p := range pocs {
+			list[i] = p
+		}
+		if _, err := UpsertOpportunityFromAPI(d, map[string]any{
+			"noticeId":       id,
+			"title":          "Opp " + id,
+			"postedDate":     posted,
+			"pointOfContact": list,
+		}); err != nil {
+			t.Fatal(err)
+		}
+	}
+	jo := map[string]any{"fullName": "Jo Smith", "email": "jo@example.gov"}
+	joUpper := map[string]any{"fullName": "Jo Smith", "email": "JO@example.gov"}
+	noEmail := map[string]any{"fullName": "Pat Lee"}
+	opp("a", "01/10/2026", jo)
+	opp("b", "03/05/2026", joUpper, noEmail)
+	opp("c", "02/01/2026", jo, map[string]any{"phone": "555-0100"})
+
+	got, total, err := ListContacts(d, 10, 0)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if total != 2 || len(got) != 2 {
+		t.Fatalf("got %d contacts (total %d), want 2: %+v", len(got), total, got)
+	}
+	if got[0].Opportunities != 3 || got[0].LatestNoticeID != "b" {
+		t.Errorf("first contact = %+v, want 3 opportunities, latest b", got[0])
+	}
+	if got[1].FullName == nil || *got[1].FullName != "Pat Lee" || got[1].Opportunities != 1 {
+		t.Errorf("second contact = %+v, want Pat Lee on 1 opportunity", got[1])
+	}
+}
@@ -3,10 +3,14 @@ package db
 import (
 	"database/sql"
 	_ "embed"
+	"errors"
 	"fmt"
 	"os"
+	"path/filepath"
+	"strconv"
 	"strings"
 
+	"github.com/theognis1002/govscout/internal/codes"
 	_ "modernc.org/sqlite"
 )
 
@@ -25,15 +29,76 @@ var migration004SQL string
 //go:embed migrations/005_delivery_status.sql
 var migration005SQL string
 
-func Open(path string) (*sql.DB, error) {
+//go:embed migrations/006_created_at_index.sql
+var migration006SQL string
+
+//go:embed migrations/007_response_cache.sql
+var migration007SQL string
+
+//go:embed migrations/008_classification_code_index.sql
+var migration008SQL string
+
+//go:embed migrations/009_search_views.sql
+var migration009SQL string
+
+//go:embed migrations/010_refreshed_at.sql
+var migration010SQL string
+
+//go:embed migrations/011_tags.sql
+var migration011SQL string
+
+//go:embed migrations/012_filter_indexes.sql
+var migration012SQL string
+
+//go:embed migrations/013_description_text.sql
+var migration013SQL string
+
+//go:embed migrations/014_response_deadline_utc.sql
+var migration014SQL string
+
+//go:embed migrations/015_description_word_count.sql
+var migration015SQL string
+
+//go:embed migrations/016_description_blob.sql
+var migration016SQL string
+
+//go:embed migrations/017_modified_at_index.sql
+var migration017SQL string
+
+//go:embed migrations/018_posted_date_sort_index.sql
+var migration018SQL string
+
+//go:embed migrations/019_drop_award_amount_index.sql
+var migration019SQL string
+
+// SchemaVersion is the number of migrations/*.sql files this build applies.
+// Bump it with every new migration file; Open records it in sync_state and
+// refuses databases stamped with a higher one.
+const SchemaVersion = 19
+
+// ErrNewerSchema is returned by Open for a database written by a newer
+// govscout, whose queries may rely on columns this build doesn't know.
+var ErrNewerSchema = errors.New("database was written by a newer govscout")
+
+// ResolvePath returns the absolute file Open uses for path: path itself,
+// else GOVSCOUT_DB, else ./govscout.db relative to the working directory.
+func ResolvePath(path string) string {
 	if path == "" {
 		path = os.Getenv("GOVSCOUT_DB")
 	}
 	if path == "" {
 		path = "./govscout.db"
 	}
+	if abs, err := filepath.Abs(path); err == nil {
+		return abs
+	}
+	return path
+}
 
-	dsn := path + "?_pragma=journal_mode(WAL)&_pragma=synchronous(NORMAL)&_pragma=foreign_keys(ON)&_pragma=busy_timeout(30000)"
+func Open(path string) (*sql.DB, error) {
+	path = ResolvePath(path)
+
+	dsn := path + "?_pragma=journal_mode(WAL)&_pragma=synchronous(NORMAL)&_pragma=foreign_keys(ON)&_pragma=busy_timeout(30000)" + tuningPragmas()
 	db, err := sql.Open("sqlite", dsn)
 	if err != nil {
 		return nil, fmt.Errorf("open db: %w", err)
@@ -41,6 +106,11 @@ func Open(path string) (*sql.DB, error) {
 
 	db.SetMaxOpenConns(1)
 
+	if err := checkSchemaVersion(db); err != nil {
+		db.Close()
+		return nil, fmt.Errorf("%s: %w", path, err)
+	}
+
 	if _, err := db.Exec(migrationSQL); err != nil {
 		db.Close()
 		return nil, fmt.Errorf("migrate 001: %w", err)
@@ -75,9 +145,298 @@ func Open(path string) (*sql.DB, error) {
 		}
 	}
 
+	if _, err := db.Exec(migration006SQL); err != nil {
+		db.Close()
+		return nil, fmt.Errorf("migrate 006: %w", err)
+	}
+
+	if _, err := db.Exec(migration007SQL); err != nil {
+		db.Close()
+		return nil, fmt.Errorf("migrate 007: %w", err)
+	}
+
+	if _, err := db.Exec(migration008SQL); err != nil {
+		db.Close()
+		return nil, fmt.Errorf("migrate 008: %w", err)
+	}
+
+	if _, err := db.Exec(migration009SQL); err != nil {
+		db.Close()
+		return nil, fmt.Errorf("migrate 009: %w", err)
+	}
+
+	if _, err := db.Exec(migration010SQL); err != nil {
+		if !isDuplicateColumn(err) {
+			db.Close()
+			return nil, fmt.Errorf("migrate 010: %w", err)
+		}
+	}
+
+	if _, err := db.Exec(migration011SQL); err != nil {
+		db.Close()
+		return nil, fmt.Errorf("migrate 011: %w", err)
+	}
+
+	if _, err := db.Exec(migration012SQL); err != nil {
+		db.Close()
+		return nil, fmt.Errorf("migrate 012: %w", err)
+	}
+
+	if _, err := db.Exec(migration013SQL); err != nil {
+		if !isDuplicateColumn(err) {
+			db.Close()
+			return nil, fmt.Errorf("migrate 013: %w", err)
+		}
+	}
+
+	if _, err := db.Exec(migration014SQL); err != nil {
+		if !isDuplicateColumn(err) {
+			db.Close()
+			return nil, fmt.Errorf("migrate 014: %w", err)
+		}
+	}
+
+	if _, err := db.Exec(migration015SQL); err != nil {
+		if !isDuplicateColumn(err) {
+			db.Close()
+			return nil, fmt.Errorf("migrate 015: %w", err)
+		}
+	}
+
+	if _, err := db.Exec(migration016SQL); err != nil {
+		if !isDuplicateColumn(err) {
+			db.Close()
+			return nil, fmt.Errorf("migrate 016: %w", err)
+		}
+	}
+
+	if _, err := db.Exec(migration017SQL); err != nil {
+		db.Close()
+		return nil, fmt.Errorf("migrate 017: %w", err)
+	}
+
+	if _, err := db.Exec(migration018SQL); err != nil {
+		db.Close()
+		return nil, fmt.Errorf("migrate 018: %w", err)
+	}
+
+	if _, err := db.Exec(migration019SQL); err != nil {
+		db.Close()
+		return nil, fmt.Errorf("migrate 019: %w", err)
+	}
+
+	if err := runDataMigrations(db); err != nil {
+		db.Close()
+		return nil, err
+	}
+
+	if err := SetSyncState(db, "schema_version", strconv.Itoa(SchemaVersion)); err != nil {
+		db.Close()
+		return nil, fmt.Errorf("record schema version: %w", err)
+	}
+
 	return db, nil
 }
 
+// checkSchemaVersion fails with ErrNewerSchema when the stored schema
+// version or PRAGMA user_version (the data migration count) is ahead of
+// this build, before any migration touches the file. Databases from before
+// the version was recorded read as 0, and a new file has no sync_state yet.
+func checkSchemaVersion(database *sql.DB) error {
+	var stored string
+	err := database.QueryRow("SELECT value FROM sync_state WHERE key = 'schema_version'").Scan(&stored)
+	if err != nil && err != sql.ErrNoRows && !strings.Contains(err.Error(), "no such table") {
+		return fmt.Errorf("read schema version: %w", err)
+	}
+	schema, _ := strconv.Atoi(stored)
+
+	var data int
+	if err := database.QueryRow("PRAGMA user_version").Scan(&data); err != nil {
+		return fmt.Errorf("read user_version: %w", err)
+	}
+
+	if schema > SchemaVersion || data > len(dataMigrations) {
+		return fmt.Errorf("%w (schema version %d, data migration %d; this build knows %d and %d); upgrade govscout, or point --db/GOVSCOUT_DB at another file",
+			ErrNewerSchema, schema, data, SchemaVersion, len(dataMigrations))
+	}
+	return nil
+}
+
+// dataMigrations are one-time Go backfills that run after the schema files.
+// Each runs once, in order; PRAGMA user_version records how many have been
+// applied. Append only — never reorder or remove entries.
+var dataMigrations = []func(*sql.DB) error{
+	backfillSetAsideDescriptions,
+	backfillDescriptionText,
+	backfillResponseDeadlineUTC,
+	backfillDescriptionWordCount,
+	normalizePostedDates,
+}
+
+func runDataMigrations(database *sql.DB) error {
+	var version int
+	if err := database.QueryRow("PRAGMA user_version").Scan(&version); err != nil {
+		return fmt.Errorf("read user_version: %w", err)
+	}
+	for i := version; i < len(dataMigrations); i++ {
+		if err := dataMigrations[i](database); err != nil {
+			return fmt.Errorf("data migration %d: %w", i+1, err)
+		}
+		// PRAGMA doesn't accept bound parameters.
+		if _, err := database.Exec(fmt.Sprintf("PRAGMA user_version = %d", i+1)); err != nil {
+			return fmt.Errorf("set user_version: %w", err)
+		}
+	}
+	return nil
+}
+
+// BackfillDerivedColumns reruns every data migration. Each only touches rows
+// whose derived columns are missing or stale, so this is safe on a current
+// database; bulk imports that bypass the upserts call it to fill
+// response_deadline_utc, description_text and the like.
+func BackfillDerivedColumns(database *sql.DB) error {
+	for i, migrate := range dataMigrations {
+		if err := migrate(database); err != nil {
+			return fmt.Errorf("data migration %d: %w", i+1, err)
+		}
+	}
+	return nil
+}
+
+// backfillSetAsideDescriptions labels rows stored before upserts started
+// filling set_aside_description from the set-aside code.
+func backfillSetAsideDescriptions(database *sql.DB) error {
+	for _, code := range codes.SetAsideCodes() {
+		if _, err := database.Exec(`UPDATE opportunities SET set_aside_description = ?
+			WHERE set_aside = ? AND (set_aside_description IS NULL OR set_aside_description = '')`,
+			codes.SetAsideLabel(code), code); err != nil {
+			return fmt.Errorf("backfill set-aside %s: %w", code, err)
+		}
+	}
+	return nil
+}
+
+// backfillDescriptionText fills description_text for rows stored before
+// upserts started deriving it from description.
+func backfillDescriptionText(database *sql.DB) error {
+	rows, err := database.Query(`SELECT id, description FROM opportunities
+		WHERE description IS NOT NULL AND description_text IS NULL`)
+	if err != nil {
+		return fmt.Errorf("select descriptions: %w", err)
+	}
+	texts := map[string]*string{}
+	for rows.Next() {
+		var id, description string
+		if err := rows.Scan(&id, &description); err != nil {
+			rows.Close()
+			return fmt.Errorf("scan description: %w", err)
+		}
+		texts[id] = DescriptionText(&description)
+	}
+	rows.Close()
+	if err := rows.Err(); err != nil {
+		return err
+	}
+
+	tx, err := database.Begin()
+	if err != nil {
+		return err
+	}
+	defer tx.Rollback()
+	for id, text := range texts {
+		if _, err := tx.Exec("UPDATE opportunities SET description_text = ? WHERE id = ?", text, id); err != nil {
+			return fmt.Errorf("backfill description_text %s: %w", id, err)
+		}
+	}
+	return tx.Commit()
+}
+
+// backfillResponseDeadlineUTC fills response_deadline_utc for rows stored
+// before upserts started deriving it from response_deadline.
+func backfillResponseDeadlineUTC(database *sql.DB) error {
+	rows, err := database.Query(`SELECT id, response_deadline FROM opportunities
+		WHERE response_deadline IS NOT NULL AND response_deadline_utc IS NULL`)
+	if err != nil {
+		return fmt.Errorf("select deadlines: %w", err)
+	}
+	deadlines := map[string]*string{}
+	for rows.Next() {
+		var id, deadline string
+		if err := rows.Scan(&id, &deadline); err != nil {
+			rows.Close()
+			return fmt.Errorf("scan deadline: %w", err)
+		}
+		if utc := deadlineUTC(&deadline); utc != nil {
+			deadlines[id] = utc
+		}
+	}
+	rows.Close()
+	if err := rows.Err(); err != nil {
+		return err
+	}
+
+	tx, err := database.Begin()
+	if err != nil {
+		return err
+	}
+	defer tx.Rollback()
+	for id, utc := range deadlines {
+		if _, err := tx.Exec("UPDATE opportunities SET response_deadline_utc = ? WHERE id = ?", utc, id); err != nil {
+			return fmt.Errorf("backfill response_deadline_utc %s: %w", id, err)
+		}
+	}
+	return tx.Commit()
+}
+
+// backfillDescriptionWordCount fills description_word_count for rows stored
+// before upserts started counting. description_text is already
+// whitespace-collapsed, so counting single spaces matches WordCount.
+func backfillDescriptionWordCount(database *sql.DB) error {
+	_, err := database.Exec(`UPDATE opportunities SET description_word_count =
+		CASE WHEN description_text = '' THEN 0
+		ELSE length(description_text) - length(replace(description_text, ' ', '')) + 1 END
+		WHERE description_text IS NOT NULL AND description_word_count IS NULL`)
+	return err
+}
+
+// normalizePostedDates rewrites posted_date values stored as ISO dates or
+// datetimes, before upserts started normalizing them, as MM/DD/YYYY.
+func normalizePostedDates(database *sql.DB) error {
+	rows, err := database.Query(`SELECT id, posted_date FROM opportunities
+		WHERE posted_date IS NOT NULL AND posted_date != ''
+		AND posted_date NOT GLOB '[0-9][0-9]/[0-9][0-9]/[0-9][0-9][0-9][0-9]'`)
+	if err != nil {
+		return fmt.Errorf("select posted dates: %w", err)
+	}
+	dates := map[string]*string{}
+	for rows.Next() {
+		var id, posted string
+		if err := rows.Scan(&id, &posted); err != nil {
+			rows.Close()
+			return fmt.Errorf("scan posted date: %w", err)
+		}
+		if norm := normalizePostedDate(&posted); *norm != posted {
+			dates[id] = norm
+		}
+	}
+	rows.Close()
+	if err := rows.Err(); err != nil {
+		return err
+	}
+
+	tx, err := database.Begin()
+	if err != nil {
+		return err
+	}
+	defer tx.Rollback()
+	for id, posted := range dates {
+		if _, err := tx.Exec("UPDATE opportunities SET posted_date = ? WHERE id = ?", posted, id); err != nil {
+			return fmt.Errorf("normalize posted_date %s: %w", id, err)
+		}
+	}
+	return tx.Commit()
+}
+
 // Checkpoint runs a WAL truncate checkpoint. Safe to call while other writes
 // are in flight; on busy DB it returns the attempted-checkpoint result, not an
 // error.
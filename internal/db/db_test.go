@@ -0,0 +1,96 @@
+package db
+
+import (
+	"errors"
+	"path/filepath"
+	"strconv"
+	"testing"
+)
+
+func TestSchemaVersionMatchesMigrations(t *testing.T) {
+	files, err := filepath.Glob("migrations/*.sql")
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(files) != SchemaVersion {
+		t.Errorf("%d migration files but SchemaVersion = %d; bump it with each new file", len(files), SchemaVersion)
+	}
+}
+
+func TestOpen_RefusesNewerSchema(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "test.db")
+	d, err := Open(path)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if v, err := GetSyncState(d, "schema_version"); err != nil || v != strconv.Itoa(SchemaVersion) {
+		t.Errorf("schema_version = %q, %v; want %d", v, err, SchemaVersion)
+	}
+	if err := SetSyncState(d, "schema_version", strconv.Itoa(SchemaVersion+1)); err != nil {
+		t.Fatal(err)
+	}
+	d.Close()
+
+	if _, err := Open(path); !errors.Is(err, ErrNewerSchema) {
+		t.Fatalf("Open of a newer schema = %v, want ErrNewerSchema", err)
+	}
+
+	// A data migration this build doesn't know is refused the same way.
+	path = filepath.Join(t.TempDir(), "data.db")
+	if d, err = Open(path); err != nil {
+		t.Fatal(err)
+	}
+	if _, err := d.Exec("PRAGMA user_version = " + strconv.Itoa(len(dataMigrations)+1)); err != nil {
+		t.Fatal(err)
+	}
+	d.Close()
+	if _, err := Open(path); !errors.Is(err, ErrNewerSchema) {
+		t.Errorf("Open with an unknown data migration = %v, want ErrNewerSchema", err)
+	}
+}
+
+func TestOpen_AcceptsUnversionedDatabase(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "old.db")
+	d, err := Open(path)
+	if err != nil {
+		t.Fatal(err)
+	}
+	// Databases from before the version was recorded have no key.
+	if err := DeleteSyncState(d, "schema_version"); err != nil {
+		t.Fatal(err)
+	}
+	d.Close()
+
+	if d, err = Open(path); err != nil {
+		t.Fatalf("Open of an unversioned database: %v", err)
+	}
+	defer d.Close()
+	if v, _ := GetSyncState(d, "schema_version"); v != strconv.Itoa(SchemaVersion) {
+		t.Errorf("schema_version after reopening = %q, want %d", v, SchemaVersion)
+	}
+}
+
+func TestBackfillDerivedColumns_ImportedRows(t *testing.T) {
+	d := openTestDB(t)
+	// A raw copy like `govscout migrate` does, bypassing the upserts.
+	if _, err := d.Exec(`INSERT INTO opportunities (id, posted_date, response_deadline, description) VALUES
+		('due', '01/05/2026', '2026-03-10T17:00:00-05:00', '<p>Two words</p>'),
+		('later', '01/06/2026', '04/01/2026', NULL)`); err != nil {
+		t.Fatal(err)
+	}
+	if err := BackfillDerivedColumns(d); err != nil {
+		t.Fatal(err)
+	}
+
+	res, err := ListOpportunities(d, ListFilters{ResponseDeadlineFrom: "03/01/2026", ResponseDeadlineTo: "03/31/2026", Limit: 10})
+	if err != nil {
+		t.Fatal(err)
+	}
+	if res.Total != 1 || res.Opportunities[0].ID != "due" {
+		t.Fatalf("deadline in March = %+v, want only 'due'", res.Opportunities)
+	}
+	var words int64
+	if err := d.QueryRow("SELECT description_word_count FROM opportunities WHERE id = 'due'").Scan(&words); err != nil || words != 2 {
+		t.Errorf("description_word_count = %d, %v; want 2", words, err)
+	}
+}
@@ -0,0 +1,112 @@
+package db
+
+import (
+	"strings"
+	"time"
+)
+
+// samTimeLayouts are the date shapes SAM.gov returns: RFC 3339 with or
+// without seconds, the same without an offset, a space-separated datetime
+// with or without an hour offset, YYYY-MM-DD, and MM/DD/YYYY. Forms without
+// an offset parse as UTC.
+var samTimeLayouts = []string{
+	time.RFC3339, "2006-01-02T15:04-07:00",
+	"2006-01-02T15:04:05", "2006-01-02T15:04", "2006-01-02 15:04:05-07", "2006-01-02 15:04:05",
+	"2006-01-02", "01/02/2006",
+}
+
+// postedDateLayout is how posted_date is stored; the date filters, sort
+// keys, and timeline reshape it with substr, so every row must use it.
+const postedDateLayout = "01/02/2006"
+
+// deadlineUTCLayout is how response_deadline_utc is stored: RFC 3339 in UTC,
+// so string comparison orders instants.
+const deadlineUTCLayout = "2006-01-02T15:04:05Z"
+
+// parseSAMDate parses s in any of samTimeLayouts, keeping its own offset.
+func parseSAMDate(s string) (time.Time, bool) {
+	s = strings.TrimSpace(s)
+	for _, layout := range samTimeLayouts {
+		if t, err := time.Parse(layout, s); err == nil {
+			return t, true
+		}
+	}
+	return time.Time{}, false
+}
+
+// parseSAMTime is parseSAMDate for nullable columns, normalized to UTC.
+func parseSAMTime(s *string) *time.Time {
+	if s == nil {
+		return nil
+	}
+	t, ok := parseSAMDate(*s)
+	if !ok {
+		return nil
+	}
+	t = t.UTC()
+	return &t
+}
+
+// deadlineUTC is the response_deadline_utc stored alongside a raw
+// response_deadline: the instant in UTC, with date-only and naive times read
+// as UTC. Nil when the deadline is missing or unparseable, so the row simply
+// drops out of deadline filters instead of matching by accident.
+func deadlineUTC(deadline *string) *string {
+	t := parseSAMTime(deadline)
+	if t == nil {
+		return nil
+	}
+	s := t.Format(deadlineUTCLayout)
+	return &s
+}
+
+// normalizePostedDate rewrites a postedDate in any of samTimeLayouts as
+// MM/DD/YYYY. SAM.gov sends both bare dates and ISO datetimes; a datetime
+// keeps the calendar date written in its own offset. Unparseable values
+// are stored as-is rather than dropped.
+func normalizePostedDate(posted *string) *string {
+	if posted == nil {
+		return nil
+	}
+	t, ok := parseSAMDate(*posted)
+	if !ok {
+		return posted
+	}
+	s := t.Format(postedDateLayout)
+	return &s
+}
+
+// deadlineBound turns a MM/DD/YYYY filter date into a response_deadline_utc
+// bound covering that whole UTC day: its first second, or its last when
+// endOfDay is set. Anything else is passed through unchanged.
+func deadlineBound(date string, endOfDay bool) string {
+	t, ok := parseSAMDate(date)
+	if !ok {
+		return date
+	}
+	y, m, d := t.Date()
+	day := time.Date(y, m, d, 0, 0, 0, 0, time.UTC)
+	if endOfDay {
+		day = day.Add(24*time.Hour - time.Second)
+	}
+	return day.Format(deadlineUTCLayout)
+}
+
+// daysToDeadline is the number of calendar days from now's date to the
+// deadline's date as written (in its own time zone); negative once it has
+// passed. Nil when the deadline is missing or unparseable.
+func daysToDeadline(deadline *string, now time.Time) *int64 {
+	if deadline == nil {
+		return nil
+	}
+	due, ok := parseSAMDate(*deadline)
+	if !ok {
+		return nil
+	}
+	day := func(t time.Time) time.Time {
+		y, m, d := t.Date()
+		return time.Date(y, m, d, 0, 0, 0, 0, time.UTC)
+	}
+	n := int64(day(due).Sub(day(now)).Hours() / 24)
+	return &n
+}
@@ -0,0 +1,108 @@
+package db
+
+import (
+	"testing"
+	"time"
+)
+
+func TestDaysToDeadline(t *testing.T) {
+	// 11pm on the 10th in New York is already the 11th in UTC; the deadline
+	// dates below are compared with the server's local date, the 10th.
+	now := time.Date(2026, 3, 10, 23, 0, 0, 0, time.FixedZone("EST", -5*3600))
+	tests := []struct {
+		deadline *string
+		want     *int64
+	}{
+		{strPtr("2026-03-15T17:00:00-05:00"), int64Ptr(5)},
+		{strPtr("2026-03-10T17:00:00-05:00"), int64Ptr(0)},
+		{strPtr("2026-03-08"), int64Ptr(-2)},
+		{strPtr("04/09/2026"), int64Ptr(30)},
+		{strPtr("2026-03-11T09:30-04:00"), int64Ptr(1)},
+		{strPtr("next week"), nil},
+		{strPtr(""), nil},
+		{nil, nil},
+	}
+	for _, tt := range tests {
+		got := daysToDeadline(tt.deadline, now)
+		if (got == nil) != (tt.want == nil) || (got != nil && *got != *tt.want) {
+			in := "<nil>"
+			if tt.deadline != nil {
+				in = *tt.deadline
+			}
+			t.Errorf("daysToDeadline(%q) = %v, want %v", in, fmtInt(got), fmtInt(tt.want))
+		}
+	}
+}
+
+func TestDeadlineUTC(t *testing.T) {
+	tests := []struct {
+		deadline *string
+		want     *string
+	}{
+		{strPtr("2026-02-15T17:00:00-05:00"), strPtr("2026-02-15T22:00:00Z")},
+		{strPtr("2026-02-15T17:00-05:00"), strPtr("2026-02-15T22:00:00Z")},
+		{strPtr("2026-02-15T23:30:00+01:00"), strPtr("2026-02-15T22:30:00Z")},
+		{strPtr("2026-02-15T17:00:00"), strPtr("2026-02-15T17:00:00Z")},
+		{strPtr("2026-02-15 17:00:00"), strPtr("2026-02-15T17:00:00Z")},
+		{strPtr("2026-02-15"), strPtr("2026-02-15T00:00:00Z")},
+		{strPtr(" 02/15/2026 "), strPtr("2026-02-15T00:00:00Z")},
+		{strPtr("upon award"), nil},
+		{strPtr(""), nil},
+		{nil, nil},
+	}
+	for _, tt := range tests {
+		got := deadlineUTC(tt.deadline)
+		if (got == nil) != (tt.want == nil) || (got != nil && *got != *tt.want) {
+			t.Errorf("deadlineUTC(%v) = %v, want %v", fmtStr(tt.deadline), fmtStr(got), fmtStr(tt.want))
+		}
+	}
+}
+
+func TestNormalizePostedDate(t *testing.T) {
+	tests := []struct {
+		posted *string
+		want   *string
+	}{
+		{strPtr("02/15/2026"), strPtr("02/15/2026")},
+		{strPtr("2026-02-15"), strPtr("02/15/2026")},
+		{strPtr("2026-02-15T23:30:00-05:00"), strPtr("02/15/2026")},
+		{strPtr("2026-02-15 09:00:00-05"), strPtr("02/15/2026")},
+		{strPtr("2026-02-15T09:00:00"), strPtr("02/15/2026")},
+		{strPtr("sometime"), strPtr("sometime")},
+		{nil, nil},
+	}
+	for _, tt := range tests {
+		got := normalizePostedDate(tt.posted)
+		if (got == nil) != (tt.want == nil) || (got != nil && *got != *tt.want) {
+			t.Errorf("normalizePostedDate(%v) = %v, want %v", fmtStr(tt.posted), fmtStr(got), fmtStr(tt.want))
+		}
+	}
+}
+
+func TestDeadlineBound(t *testing.T) {
+	if got := deadlineBound("02/15/2026", false); got != "2026-02-15T00:00:00Z" {
+		t.Errorf("start bound = %q", got)
+	}
+	if got := deadlineBound("02/15/2026", true); got != "2026-02-15T23:59:59Z" {
+		t.Errorf("end bound = %q", got)
+	}
+	if got := deadlineBound("", true); got != "" {
+		t.Errorf("empty bound = %q, want empty (no filter)", got)
+	}
+}
+
+func fmtStr(s *string) any {
+	if s == nil {
+		return nil
+	}
+	return *s
+}
+
+func int64Ptr(n int64) *int64 { return &n }
+
+func fmtInt(n *int64) any {
+	if n == nil {
+		return nil
+	}
+	return *n
+}
@@ -0,0 +1,65 @@
+package db
+
+import (
+	"errors"
+	"fmt"
+	"os"
+)
+
+// doctorTables are the tables `govscout doctor` counts, data first.
+var doctorTables = []string{"opportunities", "contacts", "sync_runs", "users", "saved_searches", "alerts", "tags"}
+
+// TableCount is the row count of one table.
+type TableCount struct {
+	Table string
+	Rows  int64
+}
+
+// Diagnosis describes the database file a command would use, to spot a CLI
+// and server pointed at different files.
+type Diagnosis struct {
+	Path           string // absolute, as resolved by ResolvePath
+	Exists         bool
+	Size           int64 // main file bytes
+	WALSize        int64 // -wal sidecar bytes (not yet checkpointed)
+	Tables         []TableCount
+	LastSync       string
+	BackfillCursor string
+}
+
+// Diagnose resolves path like Open and reports on the file. A missing file
+// is reported rather than created.
+func Diagnose(path string) (*Diagnosis, error) {
+	d := &Diagnosis{Path: ResolvePath(path)}
+	info, err := os.Stat(d.Path)
+	if errors.Is(err, os.ErrNotExist) {
+		return d, nil
+	}
+	if err != nil {
+		return nil, err
+	}
+	d.Exists, d.Size = true, info.Size()
+	if wal, err := os.Stat(d.Path + "-wal"); err == nil {
+		d.WALSize = wal.Size()
+	}
+
+	database, err := Open(d.Path)
+	if err != nil {
+		return nil, err
+	}
+	defer database.Close()
+	for _, table := range doctorTables {
+		var n int64
+		if err := database.QueryRow("SELECT COUNT(*) FROM " + table).Scan(&n); err != nil {
+			return nil, fmt.Errorf("count %s: %w", table, err)
+		}
+		d.Tables = append(d.Tables, TableCount{Table: table, Rows: n})
+	}
+	if d.LastSync, err = GetSyncState(database, "last_sync"); err != nil {
+		return nil, err
+	}
+	if d.BackfillCursor, err = GetSyncState(database, "backfill_cursor"); err != nil {
+		return nil, err
+	}
+	return d, nil
+}
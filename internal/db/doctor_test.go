@@ -0,0 +1,53 @@
+package db
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+)
+
+func TestResolvePath(t *testing.T) {
+	dir := t.TempDir()
+	t.Setenv("GOVSCOUT_DB", filepath.Join(dir, "env.db"))
+	if got := ResolvePath(""); got != filepath.Join(dir, "env.db") {
+		t.Errorf("ResolvePath(\"\") = %q, want GOVSCOUT_DB", got)
+	}
+	t.Chdir(dir)
+	if got := ResolvePath("rel.db"); got != filepath.Join(dir, "rel.db") {
+		t.Errorf("ResolvePath(rel.db) = %q, want it made absolute", got)
+	}
+}
+
+func TestDiagnose(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "govscout.db")
+	d, err := Diagnose(path)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if d.Exists || d.Tables != nil {
+		t.Fatalf("missing file diagnosed as %+v", d)
+	}
+	if _, err := os.Stat(path); !os.IsNotExist(err) {
+		t.Fatalf("Diagnose created the missing file (stat err %v)", err)
+	}
+
+	database, err := Open(path)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if _, err := database.Exec(`INSERT INTO opportunities (id, title) VALUES ('a', 'x'), ('b', 'y')`); err != nil {
+		t.Fatal(err)
+	}
+	SetSyncState(database, "last_sync", "03/01/2026")
+	database.Close()
+
+	if d, err = Diagnose(path); err != nil {
+		t.Fatal(err)
+	}
+	if !d.Exists || d.Size == 0 || d.LastSync != "03/01/2026" {
+		t.Errorf("diagnosis = %+v", d)
+	}
+	if d.Tables[0] != (TableCount{Table: "opportunities", Rows: 2}) {
+		t.Errorf("first table = %+v, want 2 opportunities", d.Tables[0])
+	}
+}
@@ -0,0 +1,71 @@
+package db
+
+import (
+	"database/sql"
+	"errors"
+	"fmt"
+	"os"
+)
+
+// ExportDatabase writes a self-contained copy of database to dest for
+// sharing: the WAL is checkpointed, VACUUM INTO writes a compacted
+// snapshot, and the copy is switched to journal_mode=DELETE so it travels
+// as one file with no -wal/-shm sidecars. The copy is integrity-checked
+// before returning. dest must not already exist unless overwrite is set.
+func ExportDatabase(database *sql.DB, dest string, overwrite bool) (int64, error) {
+	if _, err := os.Stat(dest); err == nil {
+		if !overwrite {
+			return 0, fmt.Errorf("%s already exists", dest)
+		}
+		for _, p := range []string{dest, dest + "-wal", dest + "-shm", dest + "-journal"} {
+			if err := os.Remove(p); err != nil && !errors.Is(err, os.ErrNotExist) {
+				return 0, err
+			}
+		}
+	} else if !errors.Is(err, os.ErrNotExist) {
+		return 0, err
+	}
+
+	if err := Checkpoint(database); err != nil {
+		return 0, fmt.Errorf("checkpoint: %w", err)
+	}
+	if _, err := database.Exec("VACUUM INTO ?", dest); err != nil {
+		return 0, fmt.Errorf("vacuum into %s: %w", dest, err)
+	}
+	if err := finishPortableCopy(dest); err != nil {
+		os.Remove(dest)
+		return 0, err
+	}
+	info, err := os.Stat(dest)
+	if err != nil {
+		return 0, err
+	}
+	return info.Size(), nil
+}
+
+// finishPortableCopy opens the exported file without Open's WAL pragma,
+// forces rollback journaling, and verifies the result.
+func finishPortableCopy(path string) error {
+	copyDB, err := sql.Open("sqlite", path)
+	if err != nil {
+		return fmt.Errorf("open export: %w", err)
+	}
+	defer copyDB.Close()
+	copyDB.SetMaxOpenConns(1)
+
+	var mode string
+	if err := copyDB.QueryRow("PRAGMA journal_mode=DELETE").Scan(&mode); err != nil {
+		return fmt.Errorf("set journal mode: %w", err)
+	}
+	if mode != "delete" {
+		return fmt.Errorf("export journal mode is %q, want delete", mode)
+	}
+	var check string
+	if err := copyDB.QueryRow("PRAGMA integrity_check").Scan(&check); err != nil {
+		return fmt.Errorf("integrity check: %w", err)
+	}
+	if check != "ok" {
+		return fmt.Errorf("export failed integrity check: %s", check)
+	}
+	return copyDB.Close()
+}
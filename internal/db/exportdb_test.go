@@ -0,0 +1,50 @@
+package db
+
+import (
+	"database/sql"
+	"os"
+	"path/filepath"
+	"testing"
+)
+
+func TestExportDatabase(t *testing.T) {
+	d := openTestDB(t)
+	if _, err := d.Exec(`INSERT INTO opportunities (id, title) VALUES ('opp-1', 'Only in the WAL')`); err != nil {
+		t.Fatal(err)
+	}
+
+	dest := filepath.Join(t.TempDir(), "shared.db")
+	size, err := ExportDatabase(d, dest, false)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if size == 0 {
+		t.Error("export reported an empty file")
+	}
+	for _, sidecar := range []string{dest + "-wal", dest + "-shm"} {
+		if _, err := os.Stat(sidecar); !os.IsNotExist(err) {
+			t.Errorf("%s should not exist after export (stat err %v)", filepath.Base(sidecar), err)
+		}
+	}
+
+	copyDB, err := sql.Open("sqlite", dest)
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer copyDB.Close()
+	var mode, title string
+	if err := copyDB.QueryRow("PRAGMA journal_mode").Scan(&mode); err != nil || mode != "delete" {
+		t.Errorf("journal_mode = %q (%v), want delete", mode, err)
+	}
+	if err := copyDB.QueryRow("SELECT title FROM opportunities WHERE id = 'opp-1'").Scan(&title); err != nil || title != "Only in the WAL" {
+		t.Errorf("exported row = %q (%v)", title, err)
+	}
+	copyDB.Close()
+
+	if _, err := ExportDatabase(d, dest, false); err == nil {
+		t.Error("exporting over an existing file without overwrite should fail")
+	}
+	if _, err := ExportDatabase(d, dest, true); err != nil {
+		t.Errorf("overwrite export: %v", err)
+	}
+}
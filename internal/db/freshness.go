@@ -0,0 +1,95 @@
+package db
+
+import (
+	"database/sql"
+	"fmt"
+	"sort"
+	"strings"
+	"time"
+)
+
+const (
+	// freshnessWindowDays is how far both time signals reach: recency decays
+	// to zero this many days after posting, and deadline room stops counting
+	// beyond this many days out.
+	freshnessWindowDays = 30
+	// freshnessCandidateCap bounds how many of the newest matches
+	// sort_by=freshness ranks in Go.
+	freshnessCandidateCap = 2000
+)
+
+// FreshnessWeights tune freshness_score. The zero value means
+// DefaultFreshnessWeights.
+type FreshnessWeights struct {
+	Recency   float64 // posted recently
+	Deadline  float64 // deadline still open, with room to respond
+	Documents float64 // has at least one attachment
+}
+
+// DefaultFreshnessWeights favor recency, then an open deadline, then
+// attachments; they sum to 1, so scores run from 0 to 1.
+var DefaultFreshnessWeights = FreshnessWeights{Recency: 0.5, Deadline: 0.3, Documents: 0.2}
+
+func (w FreshnessWeights) orDefault() FreshnessWeights {
+	if w == (FreshnessWeights{}) {
+		return DefaultFreshnessWeights
+	}
+	return w
+}
+
+// freshnessScore is
+//
+//	Recency   × max(0, 1 − days since posted / 30)
+//	+ Deadline  × min(days to deadline, 30) / 30   (0 once passed or without one)
+//	+ Documents × (1 if it has attachments, else 0)
+//
+// using calendar days against now. Rows without a usable posted date get no
+// recency credit.
+func freshnessScore(o *OpportunityListItem, w FreshnessWeights, now time.Time) float64 {
+	var score float64
+	// daysToDeadline works for any SAM.gov date; negated, it is days since.
+	if posted := daysToDeadline(o.PostedDate, now); posted != nil {
+		age := max(-*posted, 0)
+		score += w.Recency * max(0, 1-float64(age)/freshnessWindowDays)
+	}
+	if o.DaysToDeadline != nil && *o.DaysToDeadline >= 0 {
+		score += w.Deadline * float64(min(*o.DaysToDeadline, freshnessWindowDays)) / freshnessWindowDays
+	}
+	if o.HasDocuments {
+		score += w.Documents
+	}
+	return score
+}
+
+// listByFreshness is ListOpportunities for sort_by=freshness: the newest
+// freshnessCandidateCap matches, ranked by score (highest first unless
+// f.SortDir is asc), then paged in Go. Total still counts every match.
+func listByFreshness(database *sql.DB, f ListFilters, qb QueryBuilder, total int64, limit, offset int) (*ListResult, error) {
+	query := fmt.Sprintf(`SELECT %s FROM opportunities %s ORDER BY %s DESC, id DESC LIMIT ?`,
+		selectListColumns(f), qb.whereSQL(), postedDateSortKey)
+	rows, err := database.Query(query, append(qb.params, freshnessCandidateCap)...)
+	if err != nil {
+		return nil, fmt.Errorf("freshness query: %w", err)
+	}
+	items, err := scanListItems(rows, f.Freshness)
+	if err != nil {
+		return nil, fmt.Errorf("freshness: %w", err)
+	}
+
+	sortByFreshness(items, f.SortDir)
+	start := min(offset, len(items))
+	end := min(start+limit, len(items))
+	return &ListResult{Total: total, Opportunities: items[start:end]}, nil
+}
+
+// sortByFreshness orders items by FreshnessScore, highest first unless dir
+// is asc. Ties keep their posted-date order.
+func sortByFreshness(items []OpportunityListItem, dir string) {
+	asc := strings.EqualFold(dir, "asc")
+	sort.SliceStable(items, func(i, j int) bool {
+		if asc {
+			return items[i].FreshnessScore < items[j].FreshnessScore
+		}
+		return items[i].FreshnessScore > items[j].FreshnessScore
+	})
+}
@@ -0,0 +1,76 @@
+package db
+
+import (
+	"math"
+	"reflect"
+	"testing"
+	"time"
+)
+
+func TestFreshnessScore(t *testing.T) {
+	now := time.Date(2026, 3, 31, 12, 0, 0, 0, time.UTC)
+	days := func(n int64) *int64 { return &n }
+	tests := []struct {
+		name string
+		o    OpportunityListItem
+		want float64
+	}{
+		{name: "posted today, 30 days to respond, docs", o: OpportunityListItem{PostedDate: strPtr("03/31/2026"), DaysToDeadline: days(30), HasDocuments: true}, want: 1},
+		{name: "half decayed, deadline capped", o: OpportunityListItem{PostedDate: strPtr("03/16/2026"), DaysToDeadline: days(90)}, want: 0.25 + 0.3},
+		{name: "old, deadline passed", o: OpportunityListItem{PostedDate: strPtr("01/01/2026"), DaysToDeadline: days(-1)}, want: 0},
+		{name: "no dates, docs only", o: OpportunityListItem{HasDocuments: true}, want: 0.2},
+		{name: "deadline today", o: OpportunityListItem{DaysToDeadline: days(0)}, want: 0},
+		{name: "deadline in 3 days", o: OpportunityListItem{DaysToDeadline: days(3)}, want: 0.03},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := freshnessScore(&tt.o, DefaultFreshnessWeights, now); math.Abs(got-tt.want) > 1e-9 {
+				t.Errorf("freshnessScore = %v, want %v", got, tt.want)
+			}
+		})
+	}
+
+	docsOnly := FreshnessWeights{Documents: 1}
+	o := OpportunityListItem{PostedDate: strPtr("03/31/2026"), HasDocuments: true}
+	if got := freshnessScore(&o, docsOnly, now); got != 1 {
+		t.Errorf("documents-only weights = %v, want 1", got)
+	}
+}
+
+func TestListOpportunities_SortByFreshness(t *testing.T) {
+	d := openTestDB(t)
+	today := time.Now().Format("01/02/2006")
+	old := time.Now().AddDate(0, 0, -60).Format("01/02/2006")
+	if _, err := d.Exec(`INSERT INTO opportunities (id, posted_date, resource_links) VALUES
+		('fresh', ?, NULL), ('fresh-docs', ?, '["https://sam.gov/a"]'), ('stale', ?, '[]'), ('stale-docs', ?, '["https://sam.gov/b"]')`,
+		today, today, old, old); err != nil {
+		t.Fatal(err)
+	}
+
+	ids := func(f ListFilters) []string {
+		t.Helper()
+		f.SortBy = "freshness"
+		res, err := ListOpportunities(d, f)
+		if err != nil {
+			t.Fatal(err)
+		}
+		if res.Total != 4 {
+			t.Errorf("total = %d, want 4", res.Total)
+		}
+		var got []string
+		for _, o := range res.Opportunities {
+			got = append(got, o.ID)
+		}
+		return got
+	}
+	if got, want := ids(ListFilters{Limit: 10}), []string{"fresh-docs", "fresh", "stale-docs", "stale"}; !reflect.DeepEqual(got, want) {
+		t.Errorf("default weights = %v, want %v", got, want)
+	}
+	if got, want := ids(ListFilters{Limit: 2, Offset: 1}), []string{"fresh", "stale-docs"}; !reflect.DeepEqual(got, want) {
+		t.Errorf("second page = %v, want %v", got, want)
+	}
+	docsFirst := FreshnessWeights{Recency: 0.1, Documents: 1}
+	if got, want := ids(ListFilters{Limit: 10, Freshness: docsFirst}), []string{"fresh-docs", "stale-docs", "fresh", "stale"}; !reflect.DeepEqual(got, want) {
+		t.Errorf("documents-heavy weights = %v, want %v", got, want)
+	}
+}
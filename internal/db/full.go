@@ -0,0 +1,128 @@
+package db
+
+import (
+	"database/sql"
+	"encoding/json"
+	"fmt"
+	"io"
+)
+
+// GetOpportunityFull reassembles an opportunity in the SAM.gov v2 response
+// shape (nested award, placeOfPerformance, and pointOfContact) from the flat
+// columns and the contacts table. It uses the same keys
+// UpsertOpportunityFromAPI reads, so the result can be upserted again
+// unchanged. Returns nil when the notice isn't stored.
+func GetOpportunityFull(database *sql.DB, noticeID string) (map[string]any, error) {
+	detail, err := GetOpportunity(database, noticeID)
+	if err != nil || detail == nil {
+		return nil, err
+	}
+	o := detail.Opp
+
+	opp := map[string]any{"noticeId": o.ID}
+	set := func(m map[string]any, key string, v *string) {
+		if v != nil {
+			m[key] = *v
+		}
+	}
+	set(opp, "title", o.Title)
+	set(opp, "solicitationNumber", o.SolicitationNumber)
+	set(opp, "department", o.Department)
+	set(opp, "subTier", o.SubTier)
+	set(opp, "office", o.Office)
+	set(opp, "fullParentPathName", o.FullParentPathName)
+	set(opp, "organizationType", o.OrganizationType)
+	set(opp, "type", o.OppType)
+	set(opp, "baseType", o.BaseType)
+	set(opp, "postedDate", o.PostedDate)
+	set(opp, "responseDeadline", o.ResponseDeadline)
+	set(opp, "archiveDate", o.ArchiveDate)
+	set(opp, "naicsCode", o.NAICSCode)
+	set(opp, "classificationCode", o.ClassificationCode)
+	set(opp, "typeOfSetAside", o.SetAside)
+	set(opp, "typeOfSetAsideDescription", o.SetAsideDescription)
+	set(opp, "description", o.Description)
+	set(opp, "uiLink", o.UILink)
+	if o.Active == 1 {
+		opp["active"] = "Yes"
+	} else {
+		opp["active"] = "No"
+	}
+	if o.ResourceLinks != nil {
+		var links []any
+		if err := json.Unmarshal([]byte(*o.ResourceLinks), &links); err != nil {
+			return nil, fmt.Errorf("decode resource_links %s: %w", o.ID, err)
+		}
+		opp["resourceLinks"] = links
+	}
+
+	awardee := map[string]any{}
+	set(awardee, "name", o.AwardeeName)
+	set(awardee, "duns", o.AwardeeDUNS)
+	set(awardee, "ueiSAM", o.AwardeeUEI)
+	award := map[string]any{}
+	set(award, "amount", o.AwardAmount)
+	set(award, "date", o.AwardDate)
+	set(award, "number", o.AwardNumber)
+	if len(awardee) > 0 {
+		award["awardee"] = awardee
+	}
+	if len(award) > 0 {
+		opp["award"] = award
+	}
+
+	pop := map[string]any{}
+	for key, pair := range map[string][2]*string{
+		"state":   {o.PopStateCode, o.PopStateName},
+		"city":    {o.PopCityCode, o.PopCityName},
+		"country": {o.PopCountryCode, o.PopCountryName},
+	} {
+		m := map[string]any{}
+		set(m, "code", pair[0])
+		set(m, "name", pair[1])
+		if len(m) > 0 {
+			pop[key] = m
+		}
+	}
+	set(pop, "zip", o.PopZip)
+	if len(pop) > 0 {
+		opp["placeOfPerformance"] = pop
+	}
+
+	pocs := make([]any, 0, len(detail.Contacts))
+	for _, c := range detail.Contacts {
+		poc := map[string]any{}
+		set(poc, "type", c.ContactType)
+		set(poc, "fullName", c.FullName)
+		set(poc, "email", c.Email)
+		set(poc, "phone", c.Phone)
+		set(poc, "title", c.Title)
+		pocs = append(pocs, poc)
+	}
+	opp["pointOfContact"] = pocs
+
+	return opp, nil
+}
+
+// WriteNDJSON writes one GetOpportunityFull record per line for the given
+// notice IDs, so the contact list comes from the contacts table rather than
+// whatever the original API page carried. The output is readable by
+// ImportOpportunities. IDs that are no longer stored are skipped.
+func WriteNDJSON(w io.Writer, database *sql.DB, ids []string) (int, error) {
+	enc := json.NewEncoder(w)
+	n := 0
+	for _, id := range ids {
+		opp, err := GetOpportunityFull(database, id)
+		if err != nil {
+			return n, fmt.Errorf("%s: %w", id, err)
+		}
+		if opp == nil {
+			continue
+		}
+		if err := enc.Encode(opp); err != nil {
+			return n, err
+		}
+		n++
+	}
+	return n, nil
+}
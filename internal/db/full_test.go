@@ -0,0 +1,133 @@
+package db
+
+import (
+	"bytes"
+	"encoding/json"
+	"reflect"
+	"testing"
+)
+
+func TestGetOpportunityFull_RoundTrip(t *testing.T) {
+	d := openTestDB(t)
+
+	// Decode from JSON so nested values have the same types the API client
+	// produces (map[string]any / []any).
+	var in map[string]any
+	if err := json.Unmarshal([]byte(`{
+		"noticeId": "abc123",
+		"title": "Network Modernization",
+		"solicitationNumber": "W91-26-R-0001",
+		"fullParentPathName": "DEPT OF DEFENSE.ARMY",
+		"type": "Solicitation",
+		"postedDate": "2026-01-15",
+		"responseDeadline": "2026-02-15T17:00:00-05:00",
+		"naicsCode": "541512",
+		"typeOfSetAside": "SBA",
+		"typeOfSetAsideDescription": "Total Small Business Set-Aside (FAR 19.5)",
+		"uiLink": "https://sam.gov/opp/abc123/view",
+		"active": "Yes",
+		"resourceLinks": ["https://sam.gov/a.pdf"],
+		"award": {"amount": "125000", "number": "W91-AWD", "awardee": {"name": "Acme LLC", "ueiSAM": "UEI123"}},
+		"placeOfPerformance": {"state": {"code": "VA", "name": "Virginia"}, "zip": "22201"},
+		"pointOfContact": [{"type": "primary", "fullName": "Jo Smith", "email": "jo@example.gov"}]
+	}`), &in); err != nil {
+		t.Fatal(err)
+	}
+	if _, err := UpsertOpportunityFromAPI(d, in); err != nil {
+		t.Fatal(err)
+	}
+
+	got, err := GetOpportunityFull(d, "abc123")
+	if err != nil {
+		t.Fatalf("GetOpportunityFull: %v", err)
+	}
+	for _, key := range []string{
+		"noticeId", "title", "solicitationNumber", "fullParentPathName", "type", "postedDate",
+		"responseDeadline", "naicsCode", "typeOfSetAside", "typeOfSetAsideDescription",
+		"uiLink", "active", "resourceLinks", "award", "placeOfPerformance", "pointOfContact",
+	} {
+		if !reflect.DeepEqual(got[key], in[key]) {
+			t.Errorf("%s = %#v, want %#v", key, got[key], in[key])
+		}
+	}
+	// Department is derived from fullParentPathName on upsert.
+	if got["department"] != "DEPT OF DEFENSE" {
+		t.Errorf("department = %v, want DEPT OF DEFENSE", got["department"])
+	}
+
+	// Feeding the reassembled record back in must not change anything.
+	if outcome, err := UpsertOpportunityFromAPI(d, got); err != nil || outcome != UpsertUpdated {
+		t.Fatalf("re-upsert = %v, %v", outcome, err)
+	}
+	again, err := GetOpportunityFull(d, "abc123")
+	if err != nil {
+		t.Fatal(err)
+	}
+	if !reflect.DeepEqual(again, got) {
+		t.Errorf("re-upsert changed the record:\n got %#v\nwant %#v", again, got)
+	}
+}
+
+func TestGetOpportunityFull_Missing(t *testing.T) {
+	d := openTestDB(t)
+	got, err := GetOpportunityFull(d, "nope")
+	if err != nil || got != nil {
+		t.Errorf("GetOpportunityFull(missing) = %v, %v; want nil, nil", got, err)
+	}
+}
+
+func TestWriteNDJSON_CarriesStoredContacts(t *testing.T) {
+	d := openTestDB(t)
+	for _, rec := range []map[string]any{
+		{"noticeId": "with-poc", "title": "A", "pointOfContact": []any{
+			map[string]any{"type": "primary", "fullName": "Jo Smith", "email": "jo@example.gov"},
+			map[string]any{"type": "secondary", "fullName": "Al Jones"},
+		}},
+		{"noticeId": "no-poc", "title": "B"},
+	} {
+		if _, err := UpsertOpportunityFromAPI(d, rec); err != nil {
+			t.Fatal(err)
+		}
+	}
+
+	var buf bytes.Buffer
+	n, err := WriteNDJSON(&buf, d, []string{"with-poc", "gone", "no-poc"})
+	if err != nil {
+		t.Fatal(err)
+	}
+	if n != 2 {
+		t.Fatalf("wrote %d records, want 2 (missing IDs skipped)", n)
+	}
+
+	dec := json.NewDecoder(&buf)
+	var first, second map[string]any
+	if err := dec.Decode(&first); err != nil {
+		t.Fatal(err)
+	}
+	if err := dec.Decode(&second); err != nil {
+		t.Fatal(err)
+	}
+	if pocs, _ := first["pointOfContact"].([]any); len(pocs) != 2 {
+		t.Errorf("with-poc pointOfContact = %v, want 2 contacts", first["pointOfContact"])
+	}
+	if pocs, ok := second["pointOfContact"].([]any); !ok || len(pocs) != 0 {
+		t.Errorf("no-poc pointOfContact = %v, want empty list", second["pointOfContact"])
+	}
+
+	// The output is import-ready: re-importing into a fresh DB keeps contacts.
+	fresh := openTestDB(t)
+	var again bytes.Buffer
+	if _, err := WriteNDJSON(&again, d, []string{"with-poc"}); err != nil {
+		t.Fatal(err)
+	}
+	if _, err := ImportOpportunities(fresh, &again); err != nil {
+		t.Fatal(err)
+	}
+	got, err := GetOpportunity(fresh, "with-poc")
+	if err != nil || got == nil {
+		t.Fatalf("GetOpportunity after import = %v, %v", got, err)
+	}
+	if len(got.Contacts) != 2 {
+		t.Errorf("imported %d contacts, want 2", len(got.Contacts))
+	}
+}
@@ -0,0 +1,114 @@
+package db
+
+import (
+	"database/sql"
+	"fmt"
+	"sort"
+	"strings"
+	"unicode"
+)
+
+const (
+	// fuzzyCandidateCap bounds how many prefiltered rows are scored in Go.
+	fuzzyCandidateCap = 2000
+	// fuzzyMinScore drops candidates that share too few trigrams with the query.
+	fuzzyMinScore = 0.5
+)
+
+// ScoredOpportunity is a fuzzy title match with its similarity score (0–1).
+type ScoredOpportunity struct {
+	OpportunityListItem
+	Score float64 `json:"score"`
+}
+
+// FuzzySearchOpportunities ranks opportunities by trigram similarity between
+// query and title, tolerating spacing, punctuation, and small typos
+// ("cybersecurity" finds "Cyber-Security Svcs"). A broad SQL prefilter (any
+// title containing one of the query's trigrams, so a typo anywhere in a word
+// still leaves the rest to match) plus the other filters narrows the
+// candidates to at most fuzzyCandidateCap newest rows before scoring.
+// f.Search is ignored; f.Limit/f.Offset page the ranked list.
+func FuzzySearchOpportunities(db *sql.DB, f ListFilters, query string) ([]ScoredOpportunity, int, error) {
+	queryGrams := trigrams(query)
+	if len(queryGrams) == 0 {
+		return nil, 0, nil
+	}
+
+	f.Search = ""
+	qb := filterQuery(f)
+	grams := make([]string, 0, len(queryGrams))
+	for g := range queryGrams {
+		grams = append(grams, g)
+	}
+	sort.Strings(grams)
+	likes := make([]string, len(grams))
+	args := make([]any, len(grams))
+	for i, g := range grams {
+		// Trigrams are letters and digits only, so need no LIKE escaping.
+		likes[i] = "title LIKE ?"
+		args[i] = "%" + g + "%"
+	}
+	qb.and("("+strings.Join(likes, " OR ")+")", args...)
+
+	rows, err := db.Query(fmt.Sprintf(`SELECT %s FROM opportunities %s ORDER BY %s DESC LIMIT ?`,
+		selectListColumns(f), qb.whereSQL(), postedDateSortKey), append(qb.params, fuzzyCandidateCap)...)
+	if err != nil {
+		return nil, 0, fmt.Errorf("fuzzy query: %w", err)
+	}
+	candidates, err := scanListItems(rows, f.Freshness)
+	if err != nil {
+		return nil, 0, fmt.Errorf("fuzzy: %w", err)
+	}
+
+	var scored []ScoredOpportunity
+	for _, o := range candidates {
+		if o.Title == nil {
+			continue
+		}
+		score := trigramContainment(queryGrams, trigrams(*o.Title))
+		if score >= fuzzyMinScore {
+			scored = append(scored, ScoredOpportunity{OpportunityListItem: o, Score: score})
+		}
+	}
+	sort.SliceStable(scored, func(i, j int) bool { return scored[i].Score > scored[j].Score })
+
+	total := len(scored)
+	start := min(max(f.Offset, 0), total)
+	end := total
+	if f.Limit > 0 {
+		end = min(start+f.Limit, total)
+	}
+	return scored[start:end], total, nil
+}
+
+// trigrams returns the set of 3-letter shingles of s after lowercasing and
+// dropping everything but letters and digits, so spacing and punctuation
+// differences don't matter.
+func trigrams(s string) map[string]struct{} {
+	var norm []rune
+	for _, r := range strings.ToLower(s) {
+		if unicode.IsLetter(r) || unicode.IsDigit(r) {
+			norm = append(norm, r)
+		}
+	}
+	grams := make(map[string]struct{})
+	for i := 0; i+3 <= len(norm); i++ {
+		grams[string(norm[i:i+3])] = struct{}{}
+	}
+	return grams
+}
+
+// trigramContainment is the fraction of the query's trigrams found in the
+// title. Unlike Jaccard similarity it doesn't penalize long titles.
+func trigramContainment(query, title map[string]struct{}) float64 {
+	if len(query) == 0 {
+		return 0
+	}
+	hits := 0
+	for g := range query {
+		if _, ok := title[g]; ok {
+			hits++
+		}
+	}
+	return float64(hits) / float64(len(query))
+}
@@ -0,0 +1,69 @@
+package db
+
+import "testing"
+
+func TestFuzzySearchOpportunities_ToleratesSpacingAndPunctuation(t *testing.T) {
+	d := openTestDB(t)
+	if _, err := d.Exec(`INSERT INTO opportunities (id, title, posted_date) VALUES
+		('a', 'Cyber-Security Svcs', '01/10/2026'),
+		('b', 'Enterprise cyber security assessment', '01/11/2026'),
+		('c', 'Cybersecurity Operations Support', '01/12/2026'),
+		('d', 'Janitorial Services', '01/13/2026'),
+		('e', 'Cyber range hardware', '01/14/2026')`); err != nil {
+		t.Fatal(err)
+	}
+
+	got, total, err := FuzzySearchOpportunities(d, ListFilters{}, "cybersecurity")
+	if err != nil {
+		t.Fatalf("FuzzySearchOpportunities: %v", err)
+	}
+	if total != 3 {
+		t.Fatalf("total = %d, want 3 (got %+v)", total, got)
+	}
+	for _, o := range got {
+		if o.ID == "d" || o.ID == "e" {
+			t.Errorf("unexpected match %q (score %.2f)", o.ID, o.Score)
+		}
+		if o.Score != 1 {
+			t.Errorf("%s score = %.2f, want 1 for a full trigram match", o.ID, o.Score)
+		}
+	}
+}
+
+func TestFuzzySearchOpportunities_RanksTyposBelowExact(t *testing.T) {
+	d := openTestDB(t)
+	if _, err := d.Exec(`INSERT INTO opportunities (id, title, posted_date) VALUES
+		('typo', 'Cybersecurty Assessment', '01/12/2026'),
+		('exact', 'Cybersecurity Assessment', '01/10/2026')`); err != nil {
+		t.Fatal(err)
+	}
+
+	got, _, err := FuzzySearchOpportunities(d, ListFilters{Limit: 10}, "cybersecurity assessment")
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(got) != 2 {
+		t.Fatalf("got %d results, want 2", len(got))
+	}
+	if got[0].ID != "exact" || got[0].Score <= got[1].Score {
+		t.Errorf("ranking = [%s %.2f, %s %.2f], want exact first", got[0].ID, got[0].Score, got[1].ID, got[1].Score)
+	}
+}
+
+func TestFuzzySearchOpportunities_LeadingTypo(t *testing.T) {
+	d := openTestDB(t)
+	if _, err := d.Exec(`INSERT INTO opportunities (id, title, posted_date) VALUES
+		('c', 'Cybersecurity Operations Support', '01/12/2026'),
+		('d', 'Janitorial Services', '01/13/2026')`); err != nil {
+		t.Fatal(err)
+	}
+
+	// The first letter is wrong, so a prefix prefilter would never see 'c'.
+	got, total, err := FuzzySearchOpportunities(d, ListFilters{Limit: 10}, "xybersecurity")
+	if err != nil {
+		t.Fatal(err)
+	}
+	if total != 1 || got[0].ID != "c" {
+		t.Errorf("got %d results %+v, want only 'c'", total, got)
+	}
+}
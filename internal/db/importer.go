@@ -0,0 +1,130 @@
+package db
+
+import (
+	"bufio"
+	"database/sql"
+	"encoding/json"
+	"fmt"
+	"io"
+	"log"
+)
+
+// ImportStats counts what ImportOpportunities did with each record.
+type ImportStats struct {
+	Inserted  int
+	Updated   int
+	Unchanged int // already stored exactly as given
+	Skipped   int
+}
+
+// ImportOpportunities upserts SAM.gov-shaped records (as written by
+// `govscout get` or returned by the API) from r. The input may be a JSON
+// array of records or a stream of records (NDJSON); an API response object
+// is expanded into its opportunitiesData. Records without a noticeId are
+// skipped with a warning.
+func ImportOpportunities(database *sql.DB, r io.Reader) (ImportStats, error) {
+	var stats ImportStats
+	br := bufio.NewReader(r)
+	dec := json.NewDecoder(br)
+
+	n := 0
+	store := func(rec map[string]any) error {
+		data, isResponse := rec["opportunitiesData"].([]any)
+		if !isResponse || rec["noticeId"] != nil {
+			n++
+			return storeRecord(database, rec, n, &stats)
+		}
+		for _, item := range data {
+			n++
+			m, ok := item.(map[string]any)
+			if !ok {
+				log.Printf("import: record %d is not an object, skipping", n)
+				stats.Skipped++
+				continue
+			}
+			if err := storeRecord(database, m, n, &stats); err != nil {
+				return err
+			}
+		}
+		return nil
+	}
+
+	first, err := peekNonSpace(br)
+	if err == io.EOF {
+		return stats, nil
+	}
+	if err != nil {
+		return stats, err
+	}
+
+	if first == '[' {
+		if _, err := dec.Token(); err != nil {
+			return stats, fmt.Errorf("import: %w", err)
+		}
+		for dec.More() {
+			var rec map[string]any
+			if err := dec.Decode(&rec); err != nil {
+				return stats, fmt.Errorf("import: record %d: %w", n+1, err)
+			}
+			if err := store(rec); err != nil {
+				return stats, err
+			}
+		}
+		if _, err := dec.Token(); err != nil {
+			return stats, fmt.Errorf("import: %w", err)
+		}
+		return stats, nil
+	}
+
+	for {
+		var rec map[string]any
+		err := dec.Decode(&rec)
+		if err == io.EOF {
+			return stats, nil
+		}
+		if err != nil {
+			return stats, fmt.Errorf("import: record %d: %w", n+1, err)
+		}
+		if err := store(rec); err != nil {
+			return stats, err
+		}
+	}
+}
+
+func storeRecord(database *sql.DB, rec map[string]any, n int, stats *ImportStats) error {
+	if id, _ := rec["noticeId"].(string); id == "" {
+		log.Printf("import: record %d has no noticeId, skipping", n)
+		stats.Skipped++
+		return nil
+	}
+	outcome, err := UpsertOpportunityFromAPI(database, rec)
+	if err != nil {
+		return fmt.Errorf("import: record %d: %w", n, err)
+	}
+	switch outcome {
+	case UpsertInserted:
+		stats.Inserted++
+	case UpsertUpdated:
+		stats.Updated++
+	case UpsertUnchanged:
+		stats.Unchanged++
+	default:
+		stats.Skipped++
+	}
+	return nil
+}
+
+// peekNonSpace returns the first non-whitespace byte without consuming it.
+func peekNonSpace(br *bufio.Reader) (byte, error) {
+	for {
+		b, err := br.ReadByte()
+		if err != nil {
+			return 0, err
+		}
+		switch b {
+		case ' ', '\t', '\r', '\n':
+			continue
+		}
+		return b, br.UnreadByte()
+	}
+}
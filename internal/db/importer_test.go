@@ -0,0 +1,47 @@
+package db
+
+import (
+	"strings"
+	"testing"
+)
+
+func TestImportOpportunities_ArrayAndNDJSON(t *testing.T) {
+	tests := []struct {
+		name  string
+		input string
+	}{
+		{"array", `[{"noticeId":"a","title":"A"}, {"title":"no id"}, {"noticeId":"b","title":"B"}]`},
+		{"ndjson", "{\"noticeId\":\"a\",\"title\":\"A\"}\n{\"title\":\"no id\"}\n\n{\"noticeId\":\"b\",\"title\":\"B\"}\n"},
+		{"api response", `{"totalRecords":3,"opportunitiesData":[{"noticeId":"a"},{"title":"no id"},{"noticeId":"b"}]}`},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			d := openTestDB(t)
+			stats, err := ImportOpportunities(d, strings.NewReader(tt.input))
+			if err != nil {
+				t.Fatal(err)
+			}
+			if stats != (ImportStats{Inserted: 2, Skipped: 1}) {
+				t.Errorf("first import = %+v, want 2 inserted, 1 skipped", stats)
+			}
+
+			stats, err = ImportOpportunities(d, strings.NewReader(tt.input))
+			if err != nil {
+				t.Fatal(err)
+			}
+			if stats != (ImportStats{Unchanged: 2, Skipped: 1}) {
+				t.Errorf("re-import = %+v, want 2 unchanged, 1 skipped", stats)
+			}
+		})
+	}
+}
+
+func TestImportOpportunities_MalformedInput(t *testing.T) {
+	d := openTestDB(t)
+	if _, err := ImportOpportunities(d, strings.NewReader(`[{"noticeId":"a"}, {`)); err == nil {
+		t.Error("expected an error for truncated JSON")
+	}
+	if stats, err := ImportOpportunities(d, strings.NewReader("  \n")); err != nil || stats != (ImportStats{}) {
+		t.Errorf("empty input = %+v, %v; want zero stats", stats, err)
+	}
+}
@@ -0,0 +1,96 @@
+package db
+
+import (
+	"database/sql"
+	"fmt"
+)
+
+// ForeignKeyViolation is one row reported by PRAGMA foreign_key_check.
+type ForeignKeyViolation struct {
+	Table  string
+	RowID  *int64
+	Parent string
+}
+
+// IntegrityReport summarizes the health of the database file and its
+// referential consistency.
+type IntegrityReport struct {
+	IntegrityErrors      []string
+	ForeignKeyViolations []ForeignKeyViolation
+	OrphanedContacts     int64
+}
+
+// OK reports whether no problems were found.
+func (r *IntegrityReport) OK() bool {
+	return len(r.IntegrityErrors) == 0 && len(r.ForeignKeyViolations) == 0 && r.OrphanedContacts == 0
+}
+
+// CheckIntegrity runs SQLite's integrity and foreign key checks and counts
+// contacts whose opportunity no longer exists. Foreign keys should prevent
+// orphans, but databases created before enforcement (or edited by external
+// tooling) can still contain them.
+func CheckIntegrity(database *sql.DB) (*IntegrityReport, error) {
+	var report IntegrityReport
+
+	rows, err := database.Query("PRAGMA integrity_check")
+	if err != nil {
+		return nil, fmt.Errorf("integrity_check: %w", err)
+	}
+	for rows.Next() {
+		var msg string
+		if err := rows.Scan(&msg); err != nil {
+			rows.Close()
+			return nil, fmt.Errorf("scan integrity_check: %w", err)
+		}
+		if msg != "ok" {
+			report.IntegrityErrors = append(report.IntegrityErrors, msg)
+		}
+	}
+	if err := rows.Err(); err != nil {
+		rows.Close()
+		return nil, fmt.Errorf("integrity_check rows: %w", err)
+	}
+	rows.Close()
+
+	rows, err = database.Query("PRAGMA foreign_key_check")
+	if err != nil {
+		return nil, fmt.Errorf("foreign_key_check: %w", err)
+	}
+	for rows.Next() {
+		var v ForeignKeyViolation
+		var rowID sql.NullInt64
+		var fkID int64
+		if err := rows.Scan(&v.Table, &rowID, &v.Parent, &fkID); err != nil {
+			rows.Close()
+			return nil, fmt.Errorf("scan foreign_key_check: %w", err)
+		}
+		if rowID.Valid {
+			id := rowID.Int64
+			v.RowID = &id
+		}
+		report.ForeignKeyViolations = append(report.ForeignKeyViolations, v)
+	}
+	if err := rows.Err(); err != nil {
+		rows.Close()
+		return nil, fmt.Errorf("foreign_key_check rows: %w", err)
+	}
+	rows.Close()
+
+	if err := database.QueryRow(`SELECT COUNT(*) FROM contacts
+		WHERE notice_id NOT IN (SELECT id FROM opportunities)`).Scan(&report.OrphanedContacts); err != nil {
+		return nil, fmt.Errorf("count orphaned contacts: %w", err)
+	}
+
+	return &report, nil
+}
+
+// DeleteOrphanedContacts removes contacts whose opportunity no longer exists
+// and returns how many rows were deleted.
+func DeleteOrphanedContacts(database *sql.DB) (int64, error) {
+	res, err := database.Exec(`DELETE FROM contacts
+		WHERE notice_id NOT IN (SELECT id FROM opportunities)`)
+	if err != nil {
+		return 0, err
+	}
+	return res.RowsAffected()
+}
@@ -0,0 +1,77 @@
+package db
+
+import (
+	"database/sql"
+	"path/filepath"
+	"testing"
+)
+
+func openTestDB(t *testing.T) *sql.DB {
+	t.Helper()
+	d, err := Open(filepath.Join(t.TempDir(), "test.db"))
+	if err != nil {
+		t.Fatalf("open test db: %v", err)
+	}
+	t.Cleanup(func() { d.Close() })
+	return d
+}
+
+func TestCheckIntegrity_CleanDatabase(t *testing.T) {
+	d := openTestDB(t)
+	if _, err := d.Exec(`INSERT INTO opportunities (id, title) VALUES ('opp-1', 'x')`); err != nil {
+		t.Fatal(err)
+	}
+	if _, err := d.Exec(`INSERT INTO contacts (notice_id, full_name) VALUES ('opp-1', 'Jo')`); err != nil {
+		t.Fatal(err)
+	}
+
+	report, err := CheckIntegrity(d)
+	if err != nil {
+		t.Fatalf("CheckIntegrity: %v", err)
+	}
+	if !report.OK() {
+		t.Errorf("expected clean report, got %+v", report)
+	}
+}
+
+func TestCheckIntegrity_DetectsAndRepairsOrphanedContacts(t *testing.T) {
+	d := openTestDB(t)
+	// Simulate a database written before FK enforcement: the single pooled
+	// connection keeps this pragma for the rest of the test.
+	if _, err := d.Exec("PRAGMA foreign_keys = OFF"); err != nil {
+		t.Fatal(err)
+	}
+	if _, err := d.Exec(`INSERT INTO contacts (notice_id, full_name) VALUES ('missing', 'Ghost')`); err != nil {
+		t.Fatal(err)
+	}
+
+	report, err := CheckIntegrity(d)
+	if err != nil {
+		t.Fatalf("CheckIntegrity: %v", err)
+	}
+	if report.OK() {
+		t.Fatal("expected report to flag the orphaned contact")
+	}
+	if report.OrphanedContacts != 1 {
+		t.Errorf("OrphanedContacts = %d, want 1", report.OrphanedContacts)
+	}
+	if len(report.ForeignKeyViolations) != 1 || report.ForeignKeyViolations[0].Table != "contacts" {
+		t.Errorf("ForeignKeyViolations = %+v, want one contacts violation", report.ForeignKeyViolations)
+	}
+
+	n, err := DeleteOrphanedContacts(d)
+	if err != nil {
+		t.Fatalf("DeleteOrphanedContacts: %v", err)
+	}
+	if n != 1 {
+		t.Errorf("deleted %d, want 1", n)
+	}
+
+	report, err = CheckIntegrity(d)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if !report.OK() {
+		t.Errorf("expected clean report after repair, got %+v", report)
+	}
+}
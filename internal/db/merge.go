@@ -0,0 +1,127 @@
+package db
+
+import (
+	"context"
+	"database/sql"
+	"fmt"
+	"os"
+	"strings"
+)
+
+// mergeColumns are the opportunity columns copied by MergeDatabase, apart
+// from id and created_at which are handled separately.
+var mergeColumns = []string{
+	"title", "solicitation_number", "department", "sub_tier", "office",
+	"full_parent_path_name", "organization_type", "opp_type", "base_type",
+	"posted_date", "response_deadline", "response_deadline_utc", "archive_date", "naics_code", "classification_code",
+	"set_aside", "set_aside_description", "description", "description_blob", "description_text", "description_word_count", "ui_link", "active", "resource_links",
+	"award_amount", "award_date", "award_number", "awardee_name", "awardee_duns", "awardee_uei_sam",
+	"pop_state_code", "pop_state_name", "pop_city_code", "pop_city_name",
+	"pop_country_code", "pop_country_name", "pop_zip",
+	"raw_json", "modified_at",
+}
+
+// MergeStats counts what MergeDatabase took from the other database.
+type MergeStats struct {
+	Inserted int64 // opportunities this database didn't have
+	Updated  int64 // opportunities replaced by the other copy's newer modified_at
+	Skipped  int64 // opportunities whose local copy is as new or newer
+	Contacts int64 // contacts copied along with inserted and updated opportunities
+}
+
+// MergeDatabase folds the opportunities and contacts of the SQLite file at
+// otherPath into database. When both have a notice, the copy with the newer
+// modified_at wins and brings its contacts with it; created_at keeps the
+// earlier of the two. The other file is migrated to the current schema first
+// so column sets always line up. Everything is applied in one transaction.
+func MergeDatabase(database *sql.DB, otherPath string) (*MergeStats, error) {
+	if _, err := os.Stat(otherPath); err != nil {
+		return nil, fmt.Errorf("merge source: %w", err)
+	}
+	other, err := Open(otherPath)
+	if err != nil {
+		return nil, fmt.Errorf("migrate merge source: %w", err)
+	}
+	if err := other.Close(); err != nil {
+		return nil, fmt.Errorf("close merge source: %w", err)
+	}
+
+	// ATTACH is per connection, so pin one for the whole merge.
+	ctx := context.Background()
+	conn, err := database.Conn(ctx)
+	if err != nil {
+		return nil, err
+	}
+	defer conn.Close()
+
+	if _, err := conn.ExecContext(ctx, "ATTACH DATABASE ? AS other", otherPath); err != nil {
+		return nil, fmt.Errorf("attach: %w", err)
+	}
+	defer conn.ExecContext(ctx, "DETACH DATABASE other")
+
+	tx, err := conn.BeginTx(ctx, nil)
+	if err != nil {
+		return nil, err
+	}
+	defer tx.Rollback()
+
+	// merge_ids holds the notices the other copy wins.
+	if _, err := tx.Exec(`CREATE TEMP TABLE merge_ids (id TEXT PRIMARY KEY)`); err != nil {
+		return nil, fmt.Errorf("create merge_ids: %w", err)
+	}
+	if _, err := tx.Exec(`INSERT INTO temp.merge_ids
+		SELECT o.id FROM other.opportunities o
+		LEFT JOIN main.opportunities m ON m.id = o.id
+		WHERE m.id IS NULL OR o.modified_at > m.modified_at`); err != nil {
+		return nil, fmt.Errorf("select merge_ids: %w", err)
+	}
+
+	var stats MergeStats
+	var total, winners int64
+	if err := tx.QueryRow(`SELECT
+		(SELECT COUNT(*) FROM other.opportunities),
+		(SELECT COUNT(*) FROM temp.merge_ids),
+		(SELECT COUNT(*) FROM temp.merge_ids WHERE id NOT IN (SELECT id FROM main.opportunities))`,
+	).Scan(&total, &winners, &stats.Inserted); err != nil {
+		return nil, fmt.Errorf("count merge: %w", err)
+	}
+	stats.Updated = winners - stats.Inserted
+	stats.Skipped = total - winners
+
+	cols := strings.Join(mergeColumns, ", ")
+	sets := make([]string, len(mergeColumns))
+	for i, c := range mergeColumns {
+		sets[i] = c + " = excluded." + c
+	}
+	// The SELECT must have a WHERE clause for SQLite to read ON CONFLICT as
+	// an upsert rather than part of the join.
+	if _, err := tx.Exec(fmt.Sprintf(`INSERT INTO main.opportunities (id, created_at, %[1]s)
+		SELECT id, created_at, %[1]s FROM other.opportunities
+		WHERE id IN (SELECT id FROM temp.merge_ids)
+		ON CONFLICT(id) DO UPDATE SET
+			created_at = min(created_at, excluded.created_at),
+			%[2]s`, cols, strings.Join(sets, ",\n\t\t\t")),
+	); err != nil {
+		return nil, fmt.Errorf("merge opportunities: %w", err)
+	}
+
+	if _, err := tx.Exec(`DELETE FROM main.contacts WHERE notice_id IN (SELECT id FROM temp.merge_ids)`); err != nil {
+		return nil, fmt.Errorf("clear contacts: %w", err)
+	}
+	res, err := tx.Exec(`INSERT INTO main.contacts (notice_id, contact_type, full_name, email, phone, title, created_at)
+		SELECT notice_id, contact_type, full_name, email, phone, title, created_at
+		FROM other.contacts WHERE notice_id IN (SELECT id FROM temp.merge_ids)
+		ORDER BY id`)
+	if err != nil {
+		return nil, fmt.Errorf("merge contacts: %w", err)
+	}
+	stats.Contacts, _ = res.RowsAffected()
+
+	if _, err := tx.Exec(`DROP TABLE temp.merge_ids`); err != nil {
+		return nil, err
+	}
+	if err := tx.Commit(); err != nil {
+		return nil, err
+	}
+	return &stats, nil
+}
@@ -0,0 +1,119 @@
+package db
+
+import (
+	"path/filepath"
+	"testing"
+)
+
+func TestMergeDatabase(t *testing.T) {
+	d := openTestDB(t)
+	otherPath := filepath.Join(t.TempDir(), "other.db")
+	other, err := Open(otherPath)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	if _, err := d.Exec(`INSERT INTO opportunities (id, title, created_at, modified_at) VALUES
+		('shared-old', 'local stale', '2024-01-01 00:00:00', '2024-01-01 00:00:00'),
+		('shared-new', 'local fresh', '2024-03-01 00:00:00', '2024-03-01 00:00:00'),
+		('local-only', 'mine', '2024-01-01 00:00:00', '2024-01-01 00:00:00')`); err != nil {
+		t.Fatal(err)
+	}
+	if _, err := d.Exec(`INSERT INTO contacts (notice_id, full_name) VALUES
+		('shared-old', 'Old Contact'), ('shared-new', 'Kept Contact')`); err != nil {
+		t.Fatal(err)
+	}
+	if _, err := other.Exec(`INSERT INTO opportunities (id, title, created_at, modified_at) VALUES
+		('shared-old', 'remote fresh', '2024-02-01 00:00:00', '2024-02-01 00:00:00'),
+		('shared-new', 'remote stale', '2024-02-01 00:00:00', '2024-02-01 00:00:00'),
+		('remote-only', 'theirs', '2024-02-01 00:00:00', '2024-02-01 00:00:00')`); err != nil {
+		t.Fatal(err)
+	}
+	if _, err := other.Exec(`INSERT INTO contacts (notice_id, full_name) VALUES
+		('shared-old', 'New Contact'), ('shared-new', 'Ignored Contact'), ('remote-only', 'Their Contact')`); err != nil {
+		t.Fatal(err)
+	}
+	other.Close()
+
+	stats, err := MergeDatabase(d, otherPath)
+	if err != nil {
+		t.Fatal(err)
+	}
+	want := MergeStats{Inserted: 1, Updated: 1, Skipped: 1, Contacts: 2}
+	if *stats != want {
+		t.Errorf("stats = %+v, want %+v", *stats, want)
+	}
+
+	titles := map[string]string{}
+	rows, err := d.Query(`SELECT id, title FROM opportunities`)
+	if err != nil {
+		t.Fatal(err)
+	}
+	for rows.Next() {
+		var id, title string
+		if err := rows.Scan(&id, &title); err != nil {
+			t.Fatal(err)
+		}
+		titles[id] = title
+	}
+	rows.Close()
+	wantTitles := map[string]string{
+		"shared-old":  "remote fresh",
+		"shared-new":  "local fresh",
+		"local-only":  "mine",
+		"remote-only": "theirs",
+	}
+	for id, title := range wantTitles {
+		if titles[id] != title {
+			t.Errorf("%s title = %q, want %q", id, titles[id], title)
+		}
+	}
+
+	var createdAt string
+	if err := d.QueryRow(`SELECT created_at FROM opportunities WHERE id = 'shared-old'`).Scan(&createdAt); err != nil {
+		t.Fatal(err)
+	}
+	if createdAt != "2024-01-01 00:00:00" {
+		t.Errorf("created_at = %q, want the earlier local value", createdAt)
+	}
+
+	var contacts []string
+	rows, err = d.Query(`SELECT notice_id || ':' || full_name FROM contacts ORDER BY notice_id, full_name`)
+	if err != nil {
+		t.Fatal(err)
+	}
+	for rows.Next() {
+		var c string
+		if err := rows.Scan(&c); err != nil {
+			t.Fatal(err)
+		}
+		contacts = append(contacts, c)
+	}
+	rows.Close()
+	wantContacts := []string{"remote-only:Their Contact", "shared-new:Kept Contact", "shared-old:New Contact"}
+	if len(contacts) != len(wantContacts) {
+		t.Fatalf("contacts = %v, want %v", contacts, wantContacts)
+	}
+	for i := range wantContacts {
+		if contacts[i] != wantContacts[i] {
+			t.Errorf("contacts = %v, want %v", contacts, wantContacts)
+			break
+		}
+	}
+
+	// The attachment is released, so merging again is a no-op.
+	stats, err = MergeDatabase(d, otherPath)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if stats.Inserted != 0 || stats.Updated != 0 || stats.Skipped != 3 {
+		t.Errorf("second merge = %+v, want everything skipped", *stats)
+	}
+}
+
+func TestMergeDatabase_MissingFile(t *testing.T) {
+	d := openTestDB(t)
+	if _, err := MergeDatabase(d, filepath.Join(t.TempDir(), "nope.db")); err == nil {
+		t.Fatal("expected an error for a missing merge source")
+	}
+}
@@ -0,0 +1 @@
+CREATE INDEX IF NOT EXISTS idx_opp_created_at ON opportunities(created_at);
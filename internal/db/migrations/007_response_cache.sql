@@ -0,0 +1,6 @@
+-- Cached SAM.gov search responses, keyed by a hash of the search params (no API key)
+CREATE TABLE IF NOT EXISTS response_cache (
+    key TEXT NOT NULL PRIMARY KEY,
+    body BLOB NOT NULL,
+    fetched_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
@@ -0,0 +1 @@
+CREATE INDEX IF NOT EXISTS idx_opp_classification_code ON opportunities(classification_code);
@@ -0,0 +1,2 @@
+-- When `govscout refresh` last re-checked a row against SAM.gov (modified_at only moves on real changes)
+ALTER TABLE opportunities ADD COLUMN refreshed_at TEXT;
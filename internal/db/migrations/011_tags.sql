@@ -0,0 +1,11 @@
+-- Local triage annotations. Keyed by notice_id with no foreign key, so they
+-- survive the opportunity row being re-synced, replaced, or not stored yet.
+CREATE TABLE IF NOT EXISTS tags (
+    notice_id TEXT NOT NULL,
+    tag TEXT NOT NULL,
+    note TEXT NOT NULL DEFAULT '',
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    PRIMARY KEY (notice_id, tag)
+);
+
+CREATE INDEX IF NOT EXISTS idx_tags_tag ON tags(tag);
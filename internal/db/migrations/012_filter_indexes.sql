@@ -0,0 +1,4 @@
+-- Exact solicitation number lookups (matched case-insensitively). An
+-- award_amount index created here once is dropped by 019: the column is
+-- text like "$1,250,000.00", so no filter or sort could use it.
+CREATE INDEX IF NOT EXISTS idx_opp_solicitation_number ON opportunities(solicitation_number COLLATE NOCASE);
@@ -0,0 +1,2 @@
+-- Plain-text copy of description (tags stripped, entities decoded), filled at upsert time
+ALTER TABLE opportunities ADD COLUMN description_text TEXT;
@@ -0,0 +1,3 @@
+-- response_deadline normalized to UTC RFC 3339 (any SAM.gov form), filled at upsert time
+ALTER TABLE opportunities ADD COLUMN response_deadline_utc TEXT;
+CREATE INDEX IF NOT EXISTS idx_opp_response_deadline_utc ON opportunities(response_deadline_utc);
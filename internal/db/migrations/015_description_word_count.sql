@@ -0,0 +1,2 @@
+-- Word count of description_text, filled at upsert time for "~N words, M min read" in list views
+ALTER TABLE opportunities ADD COLUMN description_word_count INTEGER;
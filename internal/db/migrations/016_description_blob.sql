@@ -0,0 +1,2 @@
+-- gzip-compressed description, set by `sync --compress-descriptions` in place of the plain column (which is then NULL)
+ALTER TABLE opportunities ADD COLUMN description_blob BLOB;
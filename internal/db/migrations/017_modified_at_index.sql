@@ -0,0 +1,2 @@
+-- Serves the changes feed (GET /api/opportunities/changes), which polls modified_at > cursor
+CREATE INDEX IF NOT EXISTS idx_opp_modified_at ON opportunities(modified_at, id);
@@ -0,0 +1,4 @@
+-- Serves the default newest-first order and keyset paging, which sort on the
+-- postedDateSortKey expression rather than the raw MM/DD/YYYY column. The
+-- expression must match postedDateSortKey exactly for SQLite to use it.
+CREATE INDEX IF NOT EXISTS idx_opp_posted_sort ON opportunities(substr(posted_date,7,4)||substr(posted_date,1,2)||substr(posted_date,4,2), id);
@@ -0,0 +1,3 @@
+-- No query filters or sorts on award_amount (stored as display text such as
+-- "$1,250,000.00"), so its index only cost writes and space.
+DROP INDEX IF EXISTS idx_opp_award_amount;
@@ -4,9 +4,17 @@ import (
 	"database/sql"
 	"encoding/csv"
 	"encoding/json"
+	"errors"
 	"fmt"
 	"io"
+	"log"
+	"os"
+	"sort"
+	"strconv"
 	"strings"
+	"time"
+
+	"github.com/theognis1002/govscout/internal/codes"
 )
 
 type OpportunityRow struct {
@@ -28,6 +36,7 @@ type OpportunityRow struct {
 	SetAside            *string
 	SetAsideDescription *string
 	Description         *string
+	DescriptionText     *string
 	UILink              *string
 	Active              int
 	ResourceLinks       *string
@@ -60,24 +69,41 @@ type ContactRow struct {
 }
 
 type OpportunityListItem struct {
-	ID                  string
-	Title               *string
-	SolicitationNumber  *string
-	Department          *string
-	SubTier             *string
-	Office              *string
-	OppType             *string
-	BaseType            *string
-	PostedDate          *string
-	ResponseDeadline    *string
-	NAICSCode           *string
-	SetAside            *string
-	SetAsideDescription *string
-	Description         *string
-	Active              int
-	UILink              *string
-	PopStateCode        *string
-	PopStateName        *string
+	ID                  string  `json:"id"`
+	Title               *string `json:"title"`
+	SolicitationNumber  *string `json:"solicitation_number"`
+	Department          *string `json:"department"`
+	SubTier             *string `json:"sub_tier"`
+	Office              *string `json:"office"`
+	OppType             *string `json:"opp_type"`
+	BaseType            *string `json:"base_type"`
+	PostedDate          *string `json:"posted_date"`
+	ResponseDeadline    *string `json:"response_deadline"`
+	ResponseDeadlineUTC *string `json:"response_deadline_utc"` // RFC 3339 UTC; nil when unparseable
+	NAICSCode           *string `json:"naics_code"`
+	SetAside            *string `json:"set_aside"`
+	SetAsideDescription *string `json:"set_aside_description"`
+	Description         *string `json:"description,omitempty"`
+	DescriptionText     *string `json:"description_text"`
+	Active              int     `json:"active"`
+	UILink              *string `json:"ui_link"`
+	PopStateCode        *string `json:"pop_state_code"`
+	PopStateName        *string `json:"pop_state_name"`
+	AwardAmount         *string `json:"award_amount"` // as stored; SAM.gov formatting varies
+	// IsLatestAmendment is false when another stored notice with the same
+	// solicitation number was posted later (see latestAmendmentExpr).
+	IsLatestAmendment bool `json:"is_latest_amendment"`
+	// DaysToDeadline is computed at query time from ResponseDeadline against
+	// the server's date; negative once passed, nil without a usable deadline.
+	DaysToDeadline *int64 `json:"days_to_deadline"`
+	HasDocuments   bool   `json:"has_documents"` // at least one resource link
+	// FreshnessScore blends recency, an open deadline, and attachments
+	// (see freshnessScore); computed after the query like DaysToDeadline.
+	FreshnessScore float64 `json:"freshness_score"`
+	// DescriptionWordCount counts DescriptionText's words (stored at upsert);
+	// ReadingMinutes is derived from it at scan time.
+	DescriptionWordCount *int64 `json:"description_word_count"`
+	ReadingMinutes       int64  `json:"reading_minutes"`
 }
 
 type ListResult struct {
@@ -86,8 +112,9 @@ type ListResult struct {
 }
 
 type FilterStat struct {
-	Value string
-	Count int64
+	Value string `json:"value"`
+	Count int64  `json:"count"`
+	Label string `json:"label,omitempty"` // NAICS title, filled by the stats API
 }
 
 type Stats struct {
@@ -97,48 +124,120 @@ type Stats struct {
 	SetAsides   []FilterStat
 	States      []FilterStat
 	Departments []FilterStat
+	Distinct    map[string]int64
 }
 
 type OpportunityDetail struct {
-	Opp      OpportunityRow
-	Contacts []ContactRow
+	Opp               OpportunityRow
+	Contacts          []ContactRow
+	IsLatestAmendment bool // see OpportunityListItem.IsLatestAmendment
 }
 
 type ListFilters struct {
 	Search               string
+	SolicitationNumber   string // exact, case-insensitive; comma-separated
 	NAICSCode            string
+	ClassificationCode   string // PSC
 	OppType              string
 	SetAside             string
 	State                string
 	Department           string
 	DateFrom             string
 	DateTo               string
+	PostedRanges         []DateRange // posted_date within any of these (OR); see ParseDateRange
 	ResponseDeadline     string
 	ResponseDeadlineFrom string
 	ResponseDeadlineTo   string
+	MinDaysToRespond     int // deadline at least this many days out; excludes rows without one
+	AddedSince           string
+	CreatedAfter         string // SQLite datetime (UTC), see ParseSince
+	SortBy               string // see sortColumns; default posted_date
+	SortDir              string // asc or desc; default desc
 	ActiveOnly           bool
+	InactiveOnly         bool   // active = 0: archived or cancelled notices
+	LatestOnly           bool   // only the newest notice per solicitation number (see latestAmendmentExpr)
+	HasDocuments         bool   // resource_links holds at least one link
+	Tag                  string // comma-separated local tags (see SetTag); any match
+	OmitDescription      bool   // leave OpportunityListItem.Description nil to keep list payloads small
+	MatchAny             bool   // OR the filters together instead of ANDing them (match=any)
 	Limit                int
 	Offset               int
+	// Freshness weights freshness_score (see freshnessScore); the zero value
+	// means DefaultFreshnessWeights.
+	Freshness FreshnessWeights
+	// AfterID switches ListOpportunities to keyset paging: rows strictly
+	// after (AfterPostedDate, AfterID) in posted_date order, ignoring Offset.
+	// Take both from the last row of the previous page (see NextCursor);
+	// an empty AfterPostedDate means that row had no posted date.
+	AfterPostedDate string
+	AfterID         string
+}
+
+// MaxDateRanges caps ListFilters.PostedRanges so a request can't build an
+// arbitrarily large OR group.
+const MaxDateRanges = 12
+
+// DateRange is an inclusive posted-date window, both ends MM/DD/YYYY.
+type DateRange struct {
+	From string
+	To   string
+}
+
+// ParseDateRange reads "FROM..TO" with both ends YYYY-MM-DD or MM/DD/YYYY,
+// e.g. "2025-01-01..2025-03-31". FROM must not be after TO.
+func ParseDateRange(s string) (DateRange, error) {
+	fromStr, toStr, ok := strings.Cut(s, "..")
+	if !ok {
+		return DateRange{}, fmt.Errorf("invalid range %q (want FROM..TO, e.g. 2025-01-01..2025-03-31)", s)
+	}
+	var ends [2]time.Time
+	for i, v := range []string{fromStr, toStr} {
+		t, err := time.Parse("2006-01-02", strings.TrimSpace(v))
+		if err != nil {
+			if t, err = time.Parse("01/02/2006", strings.TrimSpace(v)); err != nil {
+				return DateRange{}, fmt.Errorf("invalid range %q: bad date %q", s, v)
+			}
+		}
+		ends[i] = t
+	}
+	if ends[0].After(ends[1]) {
+		return DateRange{}, fmt.Errorf("invalid range %q: start is after end", s)
+	}
+	return DateRange{From: ends[0].Format("01/02/2006"), To: ends[1].Format("01/02/2006")}, nil
 }
 
+// ErrCursorSort is returned for a keyset cursor combined with a sort other
+// than posted_date, which the cursor can't describe.
+var ErrCursorSort = errors.New("cursor paging requires posted_date ordering")
+
 type QueryBuilder struct {
 	clauses []string
 	params  []any
+	// matchAny ORs the clauses together instead of ANDing them (match=any).
+	// Each clause is self-contained, so the OR inside one (the search
+	// columns, an IN list, date ranges) is unaffected.
+	matchAny bool
 }
 
 func (qb *QueryBuilder) addLikeSearch(search string) {
 	if search == "" {
 		return
 	}
-	escaped := strings.ReplaceAll(search, `\`, `\\`)
-	escaped = strings.ReplaceAll(escaped, "%", `\%`)
-	escaped = strings.ReplaceAll(escaped, "_", `\_`)
-	pattern := "%" + escaped + "%"
+	pattern := likeContains(search)
 	qb.clauses = append(qb.clauses,
 		`(title LIKE ? ESCAPE '\' OR solicitation_number LIKE ? ESCAPE '\' OR department LIKE ? ESCAPE '\')`)
 	qb.params = append(qb.params, pattern, pattern, pattern)
 }
 
+// likeContains turns s into a substring LIKE pattern for use with
+// ESCAPE '\', so %, _ and \ in user input match literally.
+func likeContains(s string) string {
+	escaped := strings.ReplaceAll(s, `\`, `\\`)
+	escaped = strings.ReplaceAll(escaped, "%", `\%`)
+	escaped = strings.ReplaceAll(escaped, "_", `\_`)
+	return "%" + escaped + "%"
+}
+
 func (qb *QueryBuilder) addIn(column string, csv string) {
 	vals := splitCSV(csv)
 	if len(vals) == 0 {
@@ -172,15 +271,99 @@ func (qb *QueryBuilder) addDateLte(column, value string) {
 	qb.params = append(qb.params, sortable)
 }
 
+// addDateBetween bounds an MM/DD/YYYY column by from and/or to. With both
+// set the range is one clause, so match=any ORs the range as a whole
+// rather than each end of it.
+func (qb *QueryBuilder) addDateBetween(column, from, to string) {
+	if from == "" || to == "" {
+		qb.addDateGte(column, from)
+		qb.addDateLte(column, to)
+		return
+	}
+	key := fmt.Sprintf("substr(%s,7,4)||substr(%s,1,2)||substr(%s,4,2)", column, column, column)
+	qb.clauses = append(qb.clauses, fmt.Sprintf("(%s >= ? AND %s <= ?)", key, key))
+	qb.params = append(qb.params, mmddyyyyToYyyymmdd(from), mmddyyyyToYyyymmdd(to))
+}
+
+// addGte compares a column directly (no date reshaping). Use it for columns
+// stored in a sortable format, such as the SQLite datetime created_at.
+func (qb *QueryBuilder) addGte(column, value string) {
+	if value == "" {
+		return
+	}
+	qb.clauses = append(qb.clauses, column+" >= ?")
+	qb.params = append(qb.params, value)
+}
+
+// addLte is addGte's upper bound.
+func (qb *QueryBuilder) addLte(column, value string) {
+	if value == "" {
+		return
+	}
+	qb.clauses = append(qb.clauses, column+" <= ?")
+	qb.params = append(qb.params, value)
+}
+
+// addBetween is addDateBetween for directly comparable columns.
+func (qb *QueryBuilder) addBetween(column, lo, hi string) {
+	if lo == "" || hi == "" {
+		qb.addGte(column, lo)
+		qb.addLte(column, hi)
+		return
+	}
+	qb.clauses = append(qb.clauses, fmt.Sprintf("(%s >= ? AND %s <= ?)", column, column))
+	qb.params = append(qb.params, lo, hi)
+}
+
+// addDateRanges matches rows whose MM/DD/YYYY column falls in any of ranges,
+// as one parenthesized OR group.
+func (qb *QueryBuilder) addDateRanges(column string, ranges []DateRange) {
+	if len(ranges) == 0 {
+		return
+	}
+	key := fmt.Sprintf("substr(%s,7,4)||substr(%s,1,2)||substr(%s,4,2)", column, column, column)
+	preds := make([]string, len(ranges))
+	for i, dr := range ranges {
+		preds[i] = fmt.Sprintf("(%s >= ? AND %s <= ?)", key, key)
+		qb.params = append(qb.params, mmddyyyyToYyyymmdd(dr.From), mmddyyyyToYyyymmdd(dr.To))
+	}
+	qb.clauses = append(qb.clauses, "("+strings.Join(preds, " OR ")+")")
+}
+
 func (qb *QueryBuilder) addLiteral(clause string) {
 	qb.clauses = append(qb.clauses, clause)
 }
 
+// whereSQL joins the clauses with AND, or with OR when matchAny is set.
 func (qb *QueryBuilder) whereSQL() string {
 	if len(qb.clauses) == 0 {
 		return ""
 	}
-	return "WHERE " + strings.Join(qb.clauses, " AND ")
+	if !qb.matchAny {
+		return "WHERE " + strings.Join(qb.clauses, " AND ")
+	}
+	return "WHERE " + qb.anyGroup()
+}
+
+// anyGroup is the clauses as one parenthesized OR group.
+func (qb *QueryBuilder) anyGroup() string {
+	grouped := make([]string, len(qb.clauses))
+	for i, c := range qb.clauses {
+		grouped[i] = "(" + c + ")"
+	}
+	return "(" + strings.Join(grouped, " OR ") + ")"
+}
+
+// and narrows the query by clause whatever matchAny says, for conditions
+// that aren't user filters (paging cursors, fuzzy prefilters): any OR group
+// collapses into one clause that clause is ANDed with.
+func (qb *QueryBuilder) and(clause string, args ...any) {
+	if qb.matchAny && len(qb.clauses) > 0 {
+		qb.clauses = []string{qb.anyGroup()}
+	}
+	qb.matchAny = false
+	qb.clauses = append(qb.clauses, clause)
+	qb.params = append(qb.params, args...)
 }
 
 func mmddyyyyToYyyymmdd(date string) string {
@@ -206,23 +389,251 @@ func splitCSV(s string) []string {
 	return result
 }
 
-func ListOpportunities(db *sql.DB, f ListFilters) (*ListResult, error) {
-	var qb QueryBuilder
+// ParseSince converts a "since" expression into a SQLite datetime string
+// (UTC, "YYYY-MM-DD HH:MM:SS") comparable with created_at/modified_at.
+// Accepts a Go duration ("24h"), a day count ("7d"), a date (YYYY-MM-DD or
+// MM/DD/YYYY), or an RFC 3339 timestamp.
+func ParseSince(s string, now time.Time) (string, error) {
+	s = strings.TrimSpace(s)
+	if s == "" {
+		return "", nil
+	}
+	const sqliteFmt = "2006-01-02 15:04:05"
+	if days, ok := strings.CutSuffix(s, "d"); ok {
+		if n, err := strconv.Atoi(days); err == nil && n >= 0 {
+			return now.UTC().AddDate(0, 0, -n).Format(sqliteFmt), nil
+		}
+	}
+	if d, err := time.ParseDuration(s); err == nil && d >= 0 {
+		return now.UTC().Add(-d).Format(sqliteFmt), nil
+	}
+	if t, err := time.Parse(time.RFC3339, s); err == nil {
+		return t.UTC().Format(sqliteFmt), nil
+	}
+	for _, layout := range []string{"2006-01-02", "01/02/2006"} {
+		if t, err := time.Parse(layout, s); err == nil {
+			return t.Format(sqliteFmt), nil
+		}
+	}
+	return "", fmt.Errorf("invalid since value %q (want e.g. 24h, 7d, 2026-01-31)", s)
+}
+
+// filterQuery builds the WHERE clause shared by list and export queries.
+func filterQuery(f ListFilters) QueryBuilder {
+	qb := QueryBuilder{matchAny: f.MatchAny}
 
 	qb.addLikeSearch(f.Search)
+	qb.addIn("solicitation_number COLLATE NOCASE", f.SolicitationNumber)
 	qb.addIn("naics_code", f.NAICSCode)
+	qb.addIn("classification_code", f.ClassificationCode)
 	qb.addIn("opp_type", f.OppType)
 	qb.addIn("set_aside", f.SetAside)
 	qb.addIn("pop_state_code", f.State)
 	qb.addIn("department", f.Department)
-	qb.addDateGte("posted_date", f.DateFrom)
-	qb.addDateLte("posted_date", f.DateTo)
-	qb.addDateGte("response_deadline", f.ResponseDeadlineFrom)
-	qb.addDateLte("response_deadline", f.ResponseDeadlineTo)
+	qb.addDateBetween("posted_date", f.DateFrom, f.DateTo)
+	qb.addDateRanges("posted_date", f.PostedRanges)
+	// Deadlines compare on the UTC column, since SAM.gov mixes offsets,
+	// naive times, and bare dates in response_deadline. The deadline window
+	// and the min-days floor are one range, the later lower bound winning
+	// (both are RFC 3339 UTC, so they compare as strings).
+	deadlineFrom := deadlineBound(f.ResponseDeadlineFrom, false)
+	if f.MinDaysToRespond > 0 {
+		deadlineFrom = max(deadlineFrom, deadlineBound(time.Now().AddDate(0, 0, f.MinDaysToRespond).Format("01/02/2006"), false))
+	}
+	qb.addBetween("response_deadline_utc", deadlineFrom, deadlineBound(f.ResponseDeadlineTo, true))
+	qb.addGte("created_at", f.CreatedAfter)
 	if f.ActiveOnly {
 		qb.addLiteral("active = 1")
 	}
+	if f.InactiveOnly {
+		qb.addLiteral("active = 0")
+	}
+	if f.LatestOnly {
+		qb.addLiteral(latestAmendmentExpr)
+	}
+	if f.HasDocuments {
+		qb.addLiteral(hasDocumentsExpr)
+	}
+	var tq QueryBuilder
+	tq.addIn("tag", f.Tag)
+	if len(tq.clauses) > 0 {
+		qb.clauses = append(qb.clauses, "id IN (SELECT notice_id FROM tags "+tq.whereSQL()+")")
+		qb.params = append(qb.params, tq.params...)
+	}
+	return qb
+}
+
+// latestAmendmentExpr is true unless another notice sharing this row's
+// solicitation number (case-insensitive) was posted later; same-day ties go
+// to the higher notice ID, and rows without a posted date count as oldest.
+// Amendments keep the solicitation number but get their own notice ID, so
+// this marks superseded versions. Rows without a solicitation number are
+// always latest. It refers to the outer row as "opportunities", so the
+// outer query must not alias the table.
+const latestAmendmentExpr = `NOT EXISTS (SELECT 1 FROM opportunities newer
+		WHERE opportunities.solicitation_number <> ''
+		AND newer.solicitation_number = opportunities.solicitation_number COLLATE NOCASE
+		AND newer.id <> opportunities.id
+		AND (COALESCE(substr(newer.posted_date,7,4)||substr(newer.posted_date,1,2)||substr(newer.posted_date,4,2), '') >
+				COALESCE(substr(opportunities.posted_date,7,4)||substr(opportunities.posted_date,1,2)||substr(opportunities.posted_date,4,2), '')
+			OR (COALESCE(newer.posted_date, '') = COALESCE(opportunities.posted_date, '') AND newer.id > opportunities.id)))`
+
+// hasDocumentsExpr is true when resource_links holds at least one link.
+// resource_links is JSON text; an empty list is stored as "[]".
+const hasDocumentsExpr = `(resource_links IS NOT NULL AND resource_links NOT IN ('', '[]', 'null'))`
+
+// listColumns are the columns scanned into OpportunityListItem, in
+// scanListItems order.
+const listColumns = `id, title, solicitation_number, department, sub_tier, office,
+		opp_type, base_type, posted_date, response_deadline, response_deadline_utc, naics_code,
+		set_aside, set_aside_description, description, description_blob, description_text, description_word_count, active, ui_link,
+		pop_state_code, pop_state_name, award_amount, ` + latestAmendmentExpr + ` AS is_latest_amendment,
+		` + hasDocumentsExpr + ` AS has_documents`
+
+// selectListColumns is listColumns with description swapped for NULL when f
+// doesn't need it, so SQLite never reads the (often large) text.
+func selectListColumns(f ListFilters) string {
+	if f.OmitDescription {
+		return strings.Replace(listColumns, ", description, description_blob,", ", NULL AS description, NULL AS description_blob,", 1)
+	}
+	return listColumns
+}
 
+// postedDateSortKey turns the stored MM/DD/YYYY posted_date into a sortable
+// YYYYMMDD expression. Migration 018 indexes it verbatim with id; keep the
+// two in step or the planner falls back to a full sort.
+const postedDateSortKey = "substr(posted_date,7,4)||substr(posted_date,1,2)||substr(posted_date,4,2)"
+
+// sortColumns is the ORDER BY allowlist. User input only ever selects a key;
+// the SQL expression comes from here, never from the request.
+var sortColumns = map[string]string{
+	"posted_date":       postedDateSortKey,
+	"response_deadline": "response_deadline_utc",
+	"title":             "title COLLATE NOCASE",
+	"department":        "department COLLATE NOCASE",
+}
+
+// SortKeys are the accepted ListFilters.SortBy values, sorted: the
+// sortColumns keys plus freshness, which is ordered in Go.
+func SortKeys() []string {
+	keys := []string{"freshness"}
+	for k := range sortColumns {
+		keys = append(keys, k)
+	}
+	sort.Strings(keys)
+	return keys
+}
+
+// orderBy builds the ORDER BY clause for f, falling back to newest posted
+// first for unknown columns or directions. id breaks ties so paging is stable.
+func orderBy(f ListFilters) string {
+	expr, ok := sortColumns[f.SortBy]
+	if !ok {
+		expr = postedDateSortKey
+	}
+	dir := "DESC"
+	if strings.EqualFold(f.SortDir, "asc") {
+		dir = "ASC"
+	}
+	return fmt.Sprintf("ORDER BY %s %s, id %s", expr, dir, dir)
+}
+
+// keysetClause is the WHERE condition selecting rows after f's cursor in
+// orderBy order. Rows without a posted date sort last descending and first
+// ascending, and a row-value comparison against NULL matches nothing, so
+// they get their own arms.
+func keysetClause(f ListFilters) (string, []any, error) {
+	if f.SortBy != "" && f.SortBy != "posted_date" {
+		return "", nil, ErrCursorSort
+	}
+	key := postedDateSortKey
+	// The cursor date goes through the same substr reshaping as the column,
+	// so it compares equal to the row it came from whatever its format.
+	cursorKey := strings.ReplaceAll(postedDateSortKey, "posted_date", "?")
+	date, id := f.AfterPostedDate, f.AfterID
+	asc := strings.EqualFold(f.SortDir, "asc")
+	switch {
+	case date == "" && asc:
+		return fmt.Sprintf("(%s IS NOT NULL OR id > ?)", key), []any{id}, nil
+	case date == "":
+		return fmt.Sprintf("(%s IS NULL AND id < ?)", key), []any{id}, nil
+	case asc:
+		return fmt.Sprintf("(%s, id) > (%s, ?)", key, cursorKey), []any{date, date, date, id}, nil
+	default:
+		return fmt.Sprintf("((%s, id) < (%s, ?) OR %s IS NULL)", key, cursorKey, key), []any{date, date, date, id}, nil
+	}
+}
+
+// NextCursor returns the keyset cursor for the page after items, or ok=false
+// when a short page shows there is nothing more.
+func NextCursor(items []OpportunityListItem, limit int) (postedDate, id string, ok bool) {
+	if len(items) == 0 || len(items) < limit {
+		return "", "", false
+	}
+	last := items[len(items)-1]
+	if last.PostedDate != nil {
+		postedDate = *last.PostedDate
+	}
+	return postedDate, last.ID, true
+}
+
+func scanListItems(rows *sql.Rows, w FreshnessWeights) ([]OpportunityListItem, error) {
+	defer rows.Close()
+
+	now := time.Now()
+	var items []OpportunityListItem
+	for rows.Next() {
+		var o OpportunityListItem
+		var descBlob []byte
+		if err := rows.Scan(
+			&o.ID, &o.Title, &o.SolicitationNumber, &o.Department, &o.SubTier, &o.Office,
+			&o.OppType, &o.BaseType, &o.PostedDate, &o.ResponseDeadline, &o.ResponseDeadlineUTC, &o.NAICSCode,
+			&o.SetAside, &o.SetAsideDescription, &o.Description, &descBlob, &o.DescriptionText, &o.DescriptionWordCount, &o.Active, &o.UILink,
+			&o.PopStateCode, &o.PopStateName, &o.AwardAmount, &o.IsLatestAmendment, &o.HasDocuments,
+		); err != nil {
+			return nil, fmt.Errorf("scan: %w", err)
+		}
+		desc, err := inflateDescription(o.Description, descBlob)
+		if err != nil {
+			return nil, fmt.Errorf("%s: %w", o.ID, err)
+		}
+		o.Description = desc
+		o.DaysToDeadline = daysToDeadline(o.ResponseDeadline, now)
+		o.ReadingMinutes = ReadingMinutes(o.DescriptionWordCount)
+		o.FreshnessScore = freshnessScore(&o, w.orDefault(), now)
+		items = append(items, o)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, fmt.Errorf("rows: %w", err)
+	}
+	return items, nil
+}
+
+// MaxPageSizeEnv raises the largest page ListOpportunities (and the web
+// list and API) will serve, for trusted internal deployments. Unset keeps
+// DefaultMaxPageSize; values are capped at MaxPageSizeCeiling.
+const (
+	MaxPageSizeEnv     = "GOVSCOUT_MAX_PAGE_SIZE"
+	DefaultMaxPageSize = 100
+	MaxPageSizeCeiling = 1000
+)
+
+// MaxPageSize reads MaxPageSizeEnv; invalid values fall back to the default.
+func MaxPageSize() int {
+	v := os.Getenv(MaxPageSizeEnv)
+	if v == "" {
+		return DefaultMaxPageSize
+	}
+	n, err := strconv.Atoi(v)
+	if err != nil || n <= 0 {
+		log.Printf("ignoring invalid %s=%q (want a positive page size)", MaxPageSizeEnv, v)
+		return DefaultMaxPageSize
+	}
+	return min(n, MaxPageSizeCeiling)
+}
+
+func ListOpportunities(db *sql.DB, f ListFilters) (*ListResult, error) {
+	qb := filterQuery(f)
 	where := qb.whereSQL()
 
 	// Count
@@ -233,19 +644,29 @@ func ListOpportunities(db *sql.DB, f ListFilters) (*ListResult, error) {
 	}
 
 	limit := f.Limit
-	if limit <= 0 || limit > 100 {
+	if limit <= 0 || limit > MaxPageSize() {
 		limit = 25
 	}
 	offset := f.Offset
 	if offset < 0 {
 		offset = 0
 	}
+	// The cursor narrows the page query only; Total still counts every match.
+	if f.AfterID != "" {
+		clause, args, err := keysetClause(f)
+		if err != nil {
+			return nil, err
+		}
+		qb.and(clause, args...)
+		where = qb.whereSQL()
+		offset = 0
+	}
+	if f.SortBy == "freshness" {
+		return listByFreshness(db, f, qb, total, limit, offset)
+	}
 
-	query := fmt.Sprintf(`SELECT id, title, solicitation_number, department, sub_tier, office,
-		opp_type, base_type, posted_date, response_deadline, naics_code,
-		set_aside, set_aside_description, description, active, ui_link,
-		pop_state_code, pop_state_name
-		FROM opportunities %s ORDER BY substr(posted_date,7,4)||substr(posted_date,1,2)||substr(posted_date,4,2) DESC LIMIT ? OFFSET ?`, where)
+	query := fmt.Sprintf(`SELECT %s FROM opportunities %s %s LIMIT ? OFFSET ?`,
+		selectListColumns(f), where, orderBy(f))
 
 	params := make([]any, len(qb.params)+2)
 	copy(params, qb.params)
@@ -255,74 +676,31 @@ func ListOpportunities(db *sql.DB, f ListFilters) (*ListResult, error) {
 	if err != nil {
 		return nil, fmt.Errorf("query: %w", err)
 	}
-	defer rows.Close()
-
-	var items []OpportunityListItem
-	for rows.Next() {
-		var o OpportunityListItem
-		if err := rows.Scan(
-			&o.ID, &o.Title, &o.SolicitationNumber, &o.Department, &o.SubTier, &o.Office,
-			&o.OppType, &o.BaseType, &o.PostedDate, &o.ResponseDeadline, &o.NAICSCode,
-			&o.SetAside, &o.SetAsideDescription, &o.Description, &o.Active, &o.UILink,
-			&o.PopStateCode, &o.PopStateName,
-		); err != nil {
-			return nil, fmt.Errorf("scan: %w", err)
-		}
-		items = append(items, o)
-	}
-	if err := rows.Err(); err != nil {
-		return nil, fmt.Errorf("rows: %w", err)
+	items, err := scanListItems(rows, f.Freshness)
+	if err != nil {
+		return nil, err
 	}
 
 	return &ListResult{Total: total, Opportunities: items}, nil
 }
 
 func ExportOpportunities(database *sql.DB, f ListFilters) ([]OpportunityListItem, error) {
-	var qb QueryBuilder
-
-	qb.addLikeSearch(f.Search)
-	qb.addIn("naics_code", f.NAICSCode)
-	qb.addIn("opp_type", f.OppType)
-	qb.addIn("set_aside", f.SetAside)
-	qb.addIn("pop_state_code", f.State)
-	qb.addIn("department", f.Department)
-	qb.addDateGte("posted_date", f.DateFrom)
-	qb.addDateLte("posted_date", f.DateTo)
-	qb.addDateGte("response_deadline", f.ResponseDeadlineFrom)
-	qb.addDateLte("response_deadline", f.ResponseDeadlineTo)
-	if f.ActiveOnly {
-		qb.addLiteral("active = 1")
-	}
-
+	qb := filterQuery(f)
 	where := qb.whereSQL()
 
-	query := fmt.Sprintf(`SELECT id, title, solicitation_number, department, sub_tier, office,
-		opp_type, base_type, posted_date, response_deadline, naics_code,
-		set_aside, set_aside_description, description, active, ui_link,
-		pop_state_code, pop_state_name
-		FROM opportunities %s ORDER BY substr(posted_date,7,4)||substr(posted_date,1,2)||substr(posted_date,4,2) DESC`, where)
+	query := fmt.Sprintf(`SELECT %s FROM opportunities %s %s`,
+		selectListColumns(f), where, orderBy(f))
 
 	rows, err := database.Query(query, qb.params...)
 	if err != nil {
 		return nil, fmt.Errorf("export query: %w", err)
 	}
-	defer rows.Close()
-
-	var items []OpportunityListItem
-	for rows.Next() {
-		var o OpportunityListItem
-		if err := rows.Scan(
-			&o.ID, &o.Title, &o.SolicitationNumber, &o.Department, &o.SubTier, &o.Office,
-			&o.OppType, &o.BaseType, &o.PostedDate, &o.ResponseDeadline, &o.NAICSCode,
-			&o.SetAside, &o.SetAsideDescription, &o.Description, &o.Active, &o.UILink,
-			&o.PopStateCode, &o.PopStateName,
-		); err != nil {
-			return nil, fmt.Errorf("export scan: %w", err)
-		}
-		items = append(items, o)
+	items, err := scanListItems(rows, f.Freshness)
+	if err != nil {
+		return nil, fmt.Errorf("export: %w", err)
 	}
-	if err := rows.Err(); err != nil {
-		return nil, fmt.Errorf("export rows: %w", err)
+	if f.SortBy == "freshness" {
+		sortByFreshness(items, f.SortDir)
 	}
 	return items, nil
 }
@@ -367,23 +745,25 @@ func GetOpportunity(database *sql.DB, id string) (*OpportunityDetail, error) {
 	row := database.QueryRow(`SELECT id, title, solicitation_number, department, sub_tier, office,
 		full_parent_path_name, organization_type, opp_type, base_type,
 		posted_date, response_deadline, archive_date, naics_code, classification_code,
-		set_aside, set_aside_description, description, ui_link, active, resource_links,
+		set_aside, set_aside_description, description, description_blob, description_text, ui_link, active, resource_links,
 		award_amount, award_date, award_number, awardee_name, awardee_duns, awardee_uei_sam,
 		pop_state_code, pop_state_name, pop_city_code, pop_city_name,
 		pop_country_code, pop_country_name, pop_zip, raw_json,
-		created_at, modified_at
+		created_at, modified_at, `+latestAmendmentExpr+`
 		FROM opportunities WHERE id = ?`, id)
 
 	var o OpportunityRow
+	var isLatest bool
+	var descBlob []byte
 	err := row.Scan(
 		&o.ID, &o.Title, &o.SolicitationNumber, &o.Department, &o.SubTier, &o.Office,
 		&o.FullParentPathName, &o.OrganizationType, &o.OppType, &o.BaseType,
 		&o.PostedDate, &o.ResponseDeadline, &o.ArchiveDate, &o.NAICSCode, &o.ClassificationCode,
-		&o.SetAside, &o.SetAsideDescription, &o.Description, &o.UILink, &o.Active, &o.ResourceLinks,
+		&o.SetAside, &o.SetAsideDescription, &o.Description, &descBlob, &o.DescriptionText, &o.UILink, &o.Active, &o.ResourceLinks,
 		&o.AwardAmount, &o.AwardDate, &o.AwardNumber, &o.AwardeeName, &o.AwardeeDUNS, &o.AwardeeUEI,
 		&o.PopStateCode, &o.PopStateName, &o.PopCityCode, &o.PopCityName,
 		&o.PopCountryCode, &o.PopCountryName, &o.PopZip, &o.RawJSON,
-		&o.CreatedAt, &o.ModifiedAt,
+		&o.CreatedAt, &o.ModifiedAt, &isLatest,
 	)
 	if err == sql.ErrNoRows {
 		return nil, nil
@@ -391,6 +771,9 @@ func GetOpportunity(database *sql.DB, id string) (*OpportunityDetail, error) {
 	if err != nil {
 		return nil, fmt.Errorf("scan opportunity: %w", err)
 	}
+	if o.Description, err = inflateDescription(o.Description, descBlob); err != nil {
+		return nil, fmt.Errorf("opportunity %s: %w", id, err)
+	}
 
 	contactRows, err := database.Query(
 		`SELECT id, notice_id, contact_type, full_name, email, phone, title
@@ -412,48 +795,99 @@ func GetOpportunity(database *sql.DB, id string) (*OpportunityDetail, error) {
 		return nil, fmt.Errorf("contact rows: %w", err)
 	}
 
-	return &OpportunityDetail{Opp: o, Contacts: contacts}, nil
+	return &OpportunityDetail{Opp: o, Contacts: contacts, IsLatestAmendment: isLatest}, nil
+}
+
+// CountOpportunities returns how many opportunities are stored.
+func CountOpportunities(database *sql.DB) (int64, error) {
+	var n int64
+	err := database.QueryRow("SELECT COUNT(*) FROM opportunities").Scan(&n)
+	return n, err
 }
 
 func GetFilterStats(database *sql.DB) (*Stats, error) {
-	var s Stats
+	return GetFilterStatsPage(database, 0, 0)
+}
+
+// GetFilterStatsPage is GetFilterStats with each distinct-value list capped
+// at limit entries starting at offset (ordered by count, descending, then
+// value). A limit
+// of zero returns every value. Distinct reports the uncapped number of
+// distinct values per dimension, keyed by column name.
+func GetFilterStatsPage(database *sql.DB, limit, offset int) (*Stats, error) {
+	s := Stats{Distinct: map[string]int64{}}
 	if err := database.QueryRow("SELECT COUNT(*) FROM opportunities").Scan(&s.Total); err != nil {
 		return nil, err
 	}
 
 	statQueries := []struct {
-		query string
-		dest  *[]FilterStat
+		column string
+		dest   *[]FilterStat
 	}{
-		{"SELECT naics_code, COUNT(*) FROM opportunities WHERE naics_code IS NOT NULL AND naics_code != '' GROUP BY naics_code ORDER BY COUNT(*) DESC", &s.NAICSCodes},
-		{"SELECT opp_type, COUNT(*) FROM opportunities WHERE opp_type IS NOT NULL AND opp_type != '' GROUP BY opp_type ORDER BY COUNT(*) DESC", &s.OppTypes},
-		{"SELECT set_aside, COUNT(*) FROM opportunities WHERE set_aside IS NOT NULL AND set_aside != '' GROUP BY set_aside ORDER BY COUNT(*) DESC", &s.SetAsides},
-		{"SELECT pop_state_code, COUNT(*) FROM opportunities WHERE pop_state_code IS NOT NULL AND pop_state_code != '' GROUP BY pop_state_code ORDER BY COUNT(*) DESC", &s.States},
-		{"SELECT department, COUNT(*) FROM opportunities WHERE department IS NOT NULL AND department != '' GROUP BY department ORDER BY COUNT(*) DESC", &s.Departments},
+		{"naics_code", &s.NAICSCodes},
+		{"opp_type", &s.OppTypes},
+		{"set_aside", &s.SetAsides},
+		{"pop_state_code", &s.States},
+		{"department", &s.Departments},
 	}
 
 	for _, sq := range statQueries {
-		rows, err := database.Query(sq.query)
+		values, err := queryDistinct(database, sq.column, limit, offset)
 		if err != nil {
 			return nil, err
 		}
-		for rows.Next() {
-			var fs FilterStat
-			if err := rows.Scan(&fs.Value, &fs.Count); err != nil {
-				rows.Close()
-				return nil, err
-			}
-			*sq.dest = append(*sq.dest, fs)
+		*sq.dest = values
+
+		if limit <= 0 {
+			s.Distinct[sq.column] = int64(len(*sq.dest))
+			continue
 		}
-		if err := rows.Err(); err != nil {
+		var distinct int64
+		if err := database.QueryRow(fmt.Sprintf(
+			"SELECT COUNT(DISTINCT %[1]s) FROM opportunities WHERE %[1]s IS NOT NULL AND %[1]s != ''", sq.column,
+		)).Scan(&distinct); err != nil {
 			return nil, err
 		}
-		rows.Close()
+		s.Distinct[sq.column] = distinct
 	}
 
 	return &s, nil
 }
 
+// GetStateCounts returns how many opportunities name each place-of-performance
+// state code, most first.
+func GetStateCounts(database *sql.DB) ([]FilterStat, error) {
+	return queryDistinct(database, "pop_state_code", 0, 0)
+}
+
+// queryDistinct counts opportunities per non-empty value of column, ordered
+// by count descending with ties broken by value so filter dropdowns and
+// pages stay stable between requests, capped at limit values from offset
+// when limit > 0.
+func queryDistinct(database *sql.DB, column string, limit, offset int) ([]FilterStat, error) {
+	query := fmt.Sprintf("SELECT %[1]s, COUNT(*) FROM opportunities WHERE %[1]s IS NOT NULL AND %[1]s != '' GROUP BY %[1]s ORDER BY COUNT(*) DESC, %[1]s ASC", column)
+	var args []any
+	if limit > 0 {
+		query += " LIMIT ? OFFSET ?"
+		args = append(args, limit, max(offset, 0))
+	}
+	rows, err := database.Query(query, args...)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+
+	var values []FilterStat
+	for rows.Next() {
+		var fs FilterStat
+		if err := rows.Scan(&fs.Value, &fs.Count); err != nil {
+			return nil, err
+		}
+		values = append(values, fs)
+	}
+	return values, rows.Err()
+}
+
 func UpsertOpportunity(tx *sql.Tx, id string, title, solNum, dept, subTier, office,
 	fullParent, orgType, oppType, baseType, postedDate, responseDeadline, archiveDate,
 	naicsCode, classCode, setAside, setAsideDesc, description, uiLink *string,
@@ -462,25 +896,29 @@ func UpsertOpportunity(tx *sql.Tx, id string, title, solNum, dept, subTier, offi
 	popStateCode, popStateName, popCityCode, popCityName,
 	popCountryCode, popCountryName, popZip, rawJSON *string) error {
 
+	descText := DescriptionText(description)
 	_, err := tx.Exec(`INSERT INTO opportunities (
 		id, title, solicitation_number, department, sub_tier, office,
 		full_parent_path_name, organization_type, opp_type, base_type,
-		posted_date, response_deadline, archive_date, naics_code, classification_code,
-		set_aside, set_aside_description, description, ui_link, active, resource_links,
+		posted_date, response_deadline, response_deadline_utc, archive_date, naics_code, classification_code,
+		set_aside, set_aside_description, description, description_text, description_word_count, ui_link, active, resource_links,
 		award_amount, award_date, award_number, awardee_name, awardee_duns, awardee_uei_sam,
 		pop_state_code, pop_state_name, pop_city_code, pop_city_name,
 		pop_country_code, pop_country_name, pop_zip, raw_json
-	) VALUES (?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?)
+	) VALUES (?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?)
 	ON CONFLICT(id) DO UPDATE SET
 		title=excluded.title, solicitation_number=excluded.solicitation_number,
 		department=excluded.department, sub_tier=excluded.sub_tier, office=excluded.office,
 		full_parent_path_name=excluded.full_parent_path_name,
 		organization_type=excluded.organization_type, opp_type=excluded.opp_type,
 		base_type=excluded.base_type, posted_date=excluded.posted_date,
-		response_deadline=excluded.response_deadline, archive_date=excluded.archive_date,
+		response_deadline=excluded.response_deadline, response_deadline_utc=excluded.response_deadline_utc,
+		archive_date=excluded.archive_date,
 		naics_code=excluded.naics_code, classification_code=excluded.classification_code,
 		set_aside=excluded.set_aside, set_aside_description=excluded.set_aside_description,
-		description=excluded.description, ui_link=excluded.ui_link, active=excluded.active,
+		description=excluded.description, description_blob=NULL, description_text=excluded.description_text,
+		description_word_count=excluded.description_word_count,
+		ui_link=excluded.ui_link, active=excluded.active,
 		resource_links=excluded.resource_links,
 		award_amount=excluded.award_amount, award_date=excluded.award_date,
 		award_number=excluded.award_number, awardee_name=excluded.awardee_name,
@@ -492,8 +930,8 @@ func UpsertOpportunity(tx *sql.Tx, id string, title, solNum, dept, subTier, offi
 		modified_at=datetime('now')`,
 		id, title, solNum, dept, subTier, office,
 		fullParent, orgType, oppType, baseType,
-		postedDate, responseDeadline, archiveDate, naicsCode, classCode,
-		setAside, setAsideDesc, description, uiLink, active, resourceLinks,
+		postedDate, responseDeadline, deadlineUTC(responseDeadline), archiveDate, naicsCode, classCode,
+		setAside, setAsideDesc, description, descText, WordCount(descText), uiLink, active, resourceLinks,
 		awardAmount, awardDate, awardNumber, awardeeName, awardeeDUNS, awardeeUEI,
 		popStateCode, popStateName, popCityCode, popCityName,
 		popCountryCode, popCountryName, popZip, rawJSON,
@@ -516,10 +954,50 @@ func ReplaceContacts(tx *sql.Tx, noticeID string, contacts []ContactRow) error {
 	return nil
 }
 
-func UpsertOpportunityFromAPI(db *sql.DB, opp map[string]any) error {
+// UpsertOutcome reports what UpsertOpportunityFromAPI did with a record.
+type UpsertOutcome int
+
+const (
+	UpsertSkipped UpsertOutcome = iota // no noticeId, nothing written
+	UpsertInserted
+	UpsertUpdated
+	UpsertUnchanged // identical to the stored record, nothing written
+)
+
+// UpsertOpportunityFromAPI stores one SAM.gov record in its own
+// transaction, with the description stored plain.
+func UpsertOpportunityFromAPI(db *sql.DB, opp map[string]any) (UpsertOutcome, error) {
+	return upsertOne(db, opp, false)
+}
+
+// upsertOne is UpsertOpportunityFromAPI, compressing the description when
+// compress is set (see storeCompressedDescription).
+func upsertOne(db *sql.DB, opp map[string]any, compress bool) (UpsertOutcome, error) {
+	if id, _ := opp["noticeId"].(string); id == "" {
+		return UpsertSkipped, nil
+	}
+
+	tx, err := db.Begin()
+	if err != nil {
+		return UpsertSkipped, err
+	}
+	defer tx.Rollback()
+
+	outcome, err := upsertFromAPI(tx, opp, compress)
+	if err != nil {
+		return UpsertSkipped, err
+	}
+	if err := tx.Commit(); err != nil {
+		return UpsertSkipped, err
+	}
+	return outcome, nil
+}
+
+// upsertFromAPI is upsertOne inside a caller's transaction.
+func upsertFromAPI(tx *sql.Tx, opp map[string]any, compress bool) (UpsertOutcome, error) {
 	noticeID, _ := opp["noticeId"].(string)
 	if noticeID == "" {
-		return nil
+		return UpsertSkipped, nil
 	}
 
 	str := func(key string) *string {
@@ -599,11 +1077,19 @@ func UpsertOpportunityFromAPI(db *sql.DB, opp map[string]any) error {
 	rawBytes, _ := json.Marshal(opp)
 	rawStr := string(rawBytes)
 
-	tx, err := db.Begin()
-	if err != nil {
-		return err
+	// Every stored column and contact derives from the record, so an
+	// identical raw_json means nothing would change: skip the write and keep
+	// modified_at as the time of the last real change.
+	outcome := UpsertUpdated
+	var storedRaw sql.NullString
+	err := tx.QueryRow("SELECT raw_json FROM opportunities WHERE id = ?", noticeID).Scan(&storedRaw)
+	if err == sql.ErrNoRows {
+		outcome = UpsertInserted
+	} else if err != nil {
+		return UpsertSkipped, fmt.Errorf("lookup opportunity %s: %w", noticeID, err)
+	} else if storedRaw.Valid && storedRaw.String == rawStr {
+		return UpsertUnchanged, nil
 	}
-	defer tx.Rollback()
 
 	// SAM.gov v2: department is deprecated; extract from fullParentPathName
 	dept := str("department")
@@ -627,18 +1113,29 @@ func UpsertOpportunityFromAPI(db *sql.DB, opp map[string]any) error {
 	if setAsideDesc == nil {
 		setAsideDesc = str("setAsideDescription")
 	}
+	// Fill a missing description from the code; API-provided text always wins.
+	if setAside != nil && (setAsideDesc == nil || *setAsideDesc == "") {
+		if label := codes.SetAsideLabel(*setAside); label != "" {
+			setAsideDesc = &label
+		}
+	}
 
 	if err := UpsertOpportunity(tx, noticeID,
 		str("title"), str("solicitationNumber"), dept, str("subTier"), str("office"),
 		str("fullParentPathName"), str("organizationType"), str("type"), str("baseType"),
-		str("postedDate"), str("responseDeadline"), str("archiveDate"),
+		normalizePostedDate(str("postedDate")), str("responseDeadline"), str("archiveDate"),
 		str("naicsCode"), str("classificationCode"), setAside, setAsideDesc,
 		str("description"), str("uiLink"), activeInt, resourceLinksJSON,
 		awardAmount, awardDate, awardNumber, awardeeName, awardeeDUNS, awardeeUEI,
 		popStateCode, popStateName, popCityCode, popCityName,
 		popCountryCode, popCountryName, popZip, &rawStr,
 	); err != nil {
-		return fmt.Errorf("upsert opportunity %s: %w", noticeID, err)
+		return UpsertSkipped, fmt.Errorf("upsert opportunity %s: %w", noticeID, err)
+	}
+	if compress {
+		if err := storeCompressedDescription(tx, noticeID, str("description")); err != nil {
+			return UpsertSkipped, err
+		}
 	}
 
 	// Replace contacts
@@ -668,8 +1165,7 @@ func UpsertOpportunityFromAPI(db *sql.DB, opp map[string]any) error {
 		}
 	}
 	if err := ReplaceContacts(tx, noticeID, contacts); err != nil {
-		return fmt.Errorf("replace contacts %s: %w", noticeID, err)
+		return UpsertSkipped, fmt.Errorf("replace contacts %s: %w", noticeID, err)
 	}
-
-	return tx.Commit()
+	return outcome, nil
 }
@@ -1,9 +1,14 @@
 package db
 
 import (
+	"errors"
+	"fmt"
 	"reflect"
+	"slices"
+	"sort"
 	"strings"
 	"testing"
+	"time"
 )
 
 func TestAddLikeSearch(t *testing.T) {
@@ -189,6 +194,53 @@ func TestMmddyyyyToYyyymmdd(t *testing.T) {
 	}
 }
 
+func TestAddGte(t *testing.T) {
+	var qb QueryBuilder
+	qb.addGte("created_at", "")
+	if len(qb.clauses) != 0 {
+		t.Fatalf("expected no-op for empty value, got %v", qb.clauses)
+	}
+	qb.addGte("created_at", "2026-01-31 00:00:00")
+	if len(qb.clauses) != 1 || qb.clauses[0] != "created_at >= ?" {
+		t.Errorf("clauses = %v, want [created_at >= ?]", qb.clauses)
+	}
+	if !reflect.DeepEqual(qb.params, []any{"2026-01-31 00:00:00"}) {
+		t.Errorf("params = %v", qb.params)
+	}
+}
+
+func TestParseSince(t *testing.T) {
+	now := time.Date(2026, 2, 10, 12, 30, 0, 0, time.UTC)
+	tests := []struct {
+		in, want string
+	}{
+		{"", ""},
+		{"7d", "2026-02-03 12:30:00"},
+		{"24h", "2026-02-09 12:30:00"},
+		{"90m", "2026-02-10 11:00:00"},
+		{"2026-01-31", "2026-01-31 00:00:00"},
+		{"01/31/2026", "2026-01-31 00:00:00"},
+		{"2026-01-31T08:00:00-05:00", "2026-01-31 13:00:00"},
+	}
+	for _, tc := range tests {
+		t.Run(tc.in, func(t *testing.T) {
+			got, err := ParseSince(tc.in, now)
+			if err != nil {
+				t.Fatalf("ParseSince(%q): %v", tc.in, err)
+			}
+			if got != tc.want {
+				t.Errorf("ParseSince(%q) = %q, want %q", tc.in, got, tc.want)
+			}
+		})
+	}
+
+	for _, bad := range []string{"yesterday", "-3d", "13/45/2026"} {
+		if _, err := ParseSince(bad, now); err == nil {
+			t.Errorf("ParseSince(%q) expected error", bad)
+		}
+	}
+}
+
 func TestSplitCSV(t *testing.T) {
 	tests := []struct {
 		in   string
@@ -236,9 +288,788 @@ func TestWhereSQL_Composition(t *testing.T) {
 	}
 }
 
+func TestWhereSQL_MatchAny(t *testing.T) {
+	qb := QueryBuilder{matchAny: true}
+	qb.addLikeSearch("cyber")
+	qb.addIn("naics_code", "541512")
+	qb.addLiteral("resource_links IS NOT NULL AND resource_links <> '[]'")
+
+	search := `(title LIKE ? ESCAPE '\' OR solicitation_number LIKE ? ESCAPE '\' OR department LIKE ? ESCAPE '\')`
+	group := "((" + search + ") OR (naics_code IN (?)) OR (resource_links IS NOT NULL AND resource_links <> '[]'))"
+	if got := qb.whereSQL(); got != "WHERE "+group {
+		t.Errorf("whereSQL = %q, want %q", got, "WHERE "+group)
+	}
+
+	// Paging conditions still narrow the whole OR group.
+	qb.and("id > ?", "x")
+	if got, want := qb.whereSQL(), "WHERE "+group+" AND id > ?"; got != want {
+		t.Errorf("whereSQL after and = %q, want %q", got, want)
+	}
+	if strings.Count(qb.whereSQL(), "?") != len(qb.params) {
+		t.Errorf("placeholder count %d != param count %d", strings.Count(qb.whereSQL(), "?"), len(qb.params))
+	}
+}
+
+func TestListOpportunities_MatchAny(t *testing.T) {
+	d := openTestDB(t)
+	if _, err := d.Exec(`INSERT INTO opportunities (id, naics_code, department) VALUES
+		('naics', '541512', 'GSA'), ('dept', '336411', 'HOMELAND SECURITY'),
+		('both', '541512', 'HOMELAND SECURITY'), ('neither', '336411', 'GSA')`); err != nil {
+		t.Fatal(err)
+	}
+
+	count := func(f ListFilters) int64 {
+		t.Helper()
+		f.Limit = 10
+		res, err := ListOpportunities(d, f)
+		if err != nil {
+			t.Fatal(err)
+		}
+		return res.Total
+	}
+	f := ListFilters{NAICSCode: "541512", Department: "HOMELAND SECURITY"}
+	if got := count(f); got != 1 {
+		t.Errorf("match=all total = %d, want 1", got)
+	}
+	f.MatchAny = true
+	if got := count(f); got != 3 {
+		t.Errorf("match=any total = %d, want 3", got)
+	}
+}
+
+func TestListOpportunities_MatchAnyClosedRanges(t *testing.T) {
+	d := openTestDB(t)
+	if _, err := d.Exec(`INSERT INTO opportunities (id, naics_code, posted_date, response_deadline_utc) VALUES
+		('naics', '541512', '06/01/2025', NULL),
+		('posted-in', '336411', '02/10/2026', NULL),
+		('posted-before', '336411', '01/10/2026', NULL),
+		('posted-after', '336411', '03/10/2026', NULL),
+		('due-in', '336411', NULL, '2026-05-15T17:00:00Z'),
+		('due-later', '336411', NULL, '2027-05-15T17:00:00Z'),
+		('due-past', '336411', NULL, '2025-05-15T17:00:00Z')`); err != nil {
+		t.Fatal(err)
+	}
+	ids := func(f ListFilters) []string {
+		t.Helper()
+		f.Limit, f.MatchAny = 20, true
+		res, err := ListOpportunities(d, f)
+		if err != nil {
+			t.Fatal(err)
+		}
+		var out []string
+		for _, o := range res.Opportunities {
+			out = append(out, o.ID)
+		}
+		slices.Sort(out)
+		return out
+	}
+
+	// Each range is one OR term: rows outside it match only via the other
+	// filter.
+	got := ids(ListFilters{NAICSCode: "541512", DateFrom: "02/01/2026", DateTo: "02/28/2026"})
+	if want := []string{"naics", "posted-in"}; !slices.Equal(got, want) {
+		t.Errorf("posted range = %v, want %v", got, want)
+	}
+	got = ids(ListFilters{NAICSCode: "541512", ResponseDeadlineFrom: "05/01/2026", ResponseDeadlineTo: "05/31/2026"})
+	if want := []string{"due-in", "naics"}; !slices.Equal(got, want) {
+		t.Errorf("deadline range = %v, want %v", got, want)
+	}
+}
+
 func TestWhereSQL_Empty(t *testing.T) {
 	var qb QueryBuilder
 	if got := qb.whereSQL(); got != "" {
 		t.Errorf("whereSQL on empty builder = %q, want empty", got)
 	}
 }
+
+func TestUpsertOpportunityFromAPI_Outcome(t *testing.T) {
+	d := openTestDB(t)
+
+	tests := []struct {
+		name string
+		opp  map[string]any
+		want UpsertOutcome
+	}{
+		{"missing noticeId is skipped", map[string]any{"title": "x"}, UpsertSkipped},
+		{"first sighting inserts", map[string]any{"noticeId": "opp-1", "title": "Widgets"}, UpsertInserted},
+		{"second sighting updates", map[string]any{"noticeId": "opp-1", "title": "Widgets v2"}, UpsertUpdated},
+	}
+	for _, tc := range tests {
+		t.Run(tc.name, func(t *testing.T) {
+			got, err := UpsertOpportunityFromAPI(d, tc.opp)
+			if err != nil {
+				t.Fatalf("UpsertOpportunityFromAPI: %v", err)
+			}
+			if got != tc.want {
+				t.Errorf("outcome = %v, want %v", got, tc.want)
+			}
+		})
+	}
+}
+
+func TestUpsertOpportunityFromAPI_UnchangedKeepsModifiedAt(t *testing.T) {
+	d := openTestDB(t)
+	rec := map[string]any{
+		"noticeId":       "opp-1",
+		"title":          "Widgets",
+		"pointOfContact": []any{map[string]any{"fullName": "Jo Smith"}},
+	}
+	if _, err := UpsertOpportunityFromAPI(d, rec); err != nil {
+		t.Fatal(err)
+	}
+	const old = "2024-01-01 00:00:00"
+	if _, err := d.Exec(`UPDATE opportunities SET modified_at = ? WHERE id = 'opp-1'`, old); err != nil {
+		t.Fatal(err)
+	}
+	modifiedAt := func() string {
+		var m string
+		if err := d.QueryRow(`SELECT modified_at FROM opportunities WHERE id = 'opp-1'`).Scan(&m); err != nil {
+			t.Fatal(err)
+		}
+		return m
+	}
+
+	got, err := UpsertOpportunityFromAPI(d, rec)
+	if err != nil || got != UpsertUnchanged {
+		t.Fatalf("identical re-upsert = %v, %v; want UpsertUnchanged", got, err)
+	}
+	if m := modifiedAt(); m != old {
+		t.Errorf("modified_at = %s after identical re-upsert, want %s", m, old)
+	}
+	var contacts int
+	d.QueryRow(`SELECT COUNT(*) FROM contacts WHERE notice_id = 'opp-1'`).Scan(&contacts)
+	if contacts != 1 {
+		t.Errorf("contacts = %d, want 1", contacts)
+	}
+
+	rec["title"] = "Widgets v2"
+	if got, err := UpsertOpportunityFromAPI(d, rec); err != nil || got != UpsertUpdated {
+		t.Fatalf("changed re-upsert = %v, %v; want UpsertUpdated", got, err)
+	}
+	if m := modifiedAt(); m == old {
+		t.Error("modified_at not bumped by a real change")
+	}
+}
+
+func strPtr(s string) *string { return &s }
+
+func TestUpsertOpportunityFromAPI_FillsSetAsideDescription(t *testing.T) {
+	d := openTestDB(t)
+
+	if _, err := UpsertOpportunityFromAPI(d, map[string]any{
+		"noticeId": "no-desc", "typeOfSetAside": "SBA",
+	}); err != nil {
+		t.Fatal(err)
+	}
+	if _, err := UpsertOpportunityFromAPI(d, map[string]any{
+		"noticeId": "api-desc", "typeOfSetAside": "SBA", "typeOfSetAsideDescription": "From the API",
+	}); err != nil {
+		t.Fatal(err)
+	}
+
+	for id, want := range map[string]string{
+		"no-desc":  "Total Small Business Set-Aside (FAR 19.5)",
+		"api-desc": "From the API",
+	} {
+		var got string
+		if err := d.QueryRow("SELECT set_aside_description FROM opportunities WHERE id = ?", id).Scan(&got); err != nil {
+			t.Fatal(err)
+		}
+		if got != want {
+			t.Errorf("%s: set_aside_description = %q, want %q", id, got, want)
+		}
+	}
+}
+
+func TestUpsertOpportunityFromAPI_DescriptionText(t *testing.T) {
+	d := openTestDB(t)
+	if _, err := UpsertOpportunityFromAPI(d, map[string]any{
+		"noticeId": "html-desc", "description": "<p>Build <b>widgets</b></p>",
+	}); err != nil {
+		t.Fatal(err)
+	}
+	var desc, text string
+	if err := d.QueryRow("SELECT description, description_text FROM opportunities WHERE id = 'html-desc'").Scan(&desc, &text); err != nil {
+		t.Fatal(err)
+	}
+	if desc != "<p>Build <b>widgets</b></p>" || text != "Build widgets" {
+		t.Errorf("description = %q, description_text = %q; want markup kept and plain text alongside", desc, text)
+	}
+}
+
+func TestBackfillDescriptionText(t *testing.T) {
+	d := openTestDB(t)
+	if _, err := d.Exec(`INSERT INTO opportunities (id, description, description_text) VALUES
+		('a', '<p>Old &amp; plain</p>', NULL), ('b', NULL, NULL), ('c', '<p>x</p>', 'Keep me')`); err != nil {
+		t.Fatal(err)
+	}
+	if _, err := d.Exec("PRAGMA user_version = 0"); err != nil {
+		t.Fatal(err)
+	}
+	if err := runDataMigrations(d); err != nil {
+		t.Fatalf("runDataMigrations: %v", err)
+	}
+
+	want := map[string]*string{"a": strPtr("Old & plain"), "b": nil, "c": strPtr("Keep me")}
+	for id, w := range want {
+		var got *string
+		if err := d.QueryRow("SELECT description_text FROM opportunities WHERE id = ?", id).Scan(&got); err != nil {
+			t.Fatal(err)
+		}
+		if (got == nil) != (w == nil) || (got != nil && *got != *w) {
+			t.Errorf("%s: description_text = %v, want %v", id, got, w)
+		}
+	}
+}
+
+func TestDescriptionWordCount(t *testing.T) {
+	d := openTestDB(t)
+	if _, err := UpsertOpportunityFromAPI(d, map[string]any{
+		"noticeId": "counted", "description": "<p>Build <b>widgets</b> for&nbsp;the Army</p>",
+	}); err != nil {
+		t.Fatal(err)
+	}
+	if _, err := d.Exec(`INSERT INTO opportunities (id, description_text) VALUES
+		('old', 'one two three'), ('empty', ''), ('none', NULL)`); err != nil {
+		t.Fatal(err)
+	}
+	if _, err := d.Exec("PRAGMA user_version = 0"); err != nil {
+		t.Fatal(err)
+	}
+	if err := runDataMigrations(d); err != nil {
+		t.Fatalf("runDataMigrations: %v", err)
+	}
+
+	res, err := ListOpportunities(d, ListFilters{Limit: 10})
+	if err != nil {
+		t.Fatal(err)
+	}
+	want := map[string]int64{"counted": 5, "old": 3, "empty": 0}
+	for _, o := range res.Opportunities {
+		w, ok := want[o.ID]
+		switch {
+		case !ok && o.DescriptionWordCount != nil:
+			t.Errorf("%s: word count = %d, want nil", o.ID, *o.DescriptionWordCount)
+		case ok && (o.DescriptionWordCount == nil || *o.DescriptionWordCount != w):
+			t.Errorf("%s: word count = %v, want %d", o.ID, o.DescriptionWordCount, w)
+		}
+		if o.ID == "counted" && o.ReadingMinutes != 1 {
+			t.Errorf("reading_minutes = %d, want 1", o.ReadingMinutes)
+		}
+	}
+}
+
+func TestBackfillSetAsideDescriptions_OnlyFillsMissing(t *testing.T) {
+	d := openTestDB(t)
+	if _, err := d.Exec(`INSERT INTO opportunities (id, set_aside, set_aside_description) VALUES
+		('a', '8A', NULL), ('b', '8A', ''), ('c', '8A', 'Keep me'), ('d', 'UNKNOWN', NULL)`); err != nil {
+		t.Fatal(err)
+	}
+	if _, err := d.Exec("PRAGMA user_version = 0"); err != nil {
+		t.Fatal(err)
+	}
+	if err := runDataMigrations(d); err != nil {
+		t.Fatalf("runDataMigrations: %v", err)
+	}
+
+	want := map[string]*string{
+		"a": strPtr("8(a) Set-Aside (FAR 19.8)"),
+		"b": strPtr("8(a) Set-Aside (FAR 19.8)"),
+		"c": strPtr("Keep me"),
+		"d": nil,
+	}
+	for id, w := range want {
+		var got *string
+		if err := d.QueryRow("SELECT set_aside_description FROM opportunities WHERE id = ?", id).Scan(&got); err != nil {
+			t.Fatal(err)
+		}
+		if (got == nil) != (w == nil) || (got != nil && *got != *w) {
+			t.Errorf("%s: set_aside_description = %v, want %v", id, got, w)
+		}
+	}
+
+	var version int
+	if err := d.QueryRow("PRAGMA user_version").Scan(&version); err != nil {
+		t.Fatal(err)
+	}
+	if version != len(dataMigrations) {
+		t.Errorf("user_version = %d, want %d", version, len(dataMigrations))
+	}
+}
+
+func TestOrderBy_AllowlistAndDefaults(t *testing.T) {
+	tests := []struct {
+		name string
+		f    ListFilters
+		want string
+	}{
+		{"default is newest posted first", ListFilters{}, "ORDER BY " + postedDateSortKey + " DESC, id DESC"},
+		{"title ascending", ListFilters{SortBy: "title", SortDir: "asc"}, "ORDER BY title COLLATE NOCASE ASC, id ASC"},
+		{"direction is case-insensitive", ListFilters{SortBy: "department", SortDir: "ASC"}, "ORDER BY department COLLATE NOCASE ASC, id ASC"},
+		{"injection attempt falls back", ListFilters{SortBy: "title; DROP TABLE opportunities", SortDir: "asc"}, "ORDER BY " + postedDateSortKey + " ASC, id ASC"},
+		{"bad direction falls back to desc", ListFilters{SortBy: "title", SortDir: "sideways"}, "ORDER BY title COLLATE NOCASE DESC, id DESC"},
+	}
+	for _, tc := range tests {
+		t.Run(tc.name, func(t *testing.T) {
+			if got := orderBy(tc.f); got != tc.want {
+				t.Errorf("orderBy = %q, want %q", got, tc.want)
+			}
+		})
+	}
+}
+
+func TestSortKeys(t *testing.T) {
+	want := []string{"department", "freshness", "posted_date", "response_deadline", "title"}
+	if got := SortKeys(); !slices.Equal(got, want) {
+		t.Errorf("SortKeys() = %v, want %v", got, want)
+	}
+}
+
+func TestListOpportunities_SortByTitleWithStableTies(t *testing.T) {
+	d := openTestDB(t)
+	if _, err := d.Exec(`INSERT INTO opportunities (id, title, posted_date) VALUES
+		('c', 'bravo', '01/01/2026'), ('a', 'Alpha', '01/02/2026'), ('b', 'bravo', '01/03/2026')`); err != nil {
+		t.Fatal(err)
+	}
+
+	res, err := ListOpportunities(d, ListFilters{SortBy: "title", SortDir: "asc", Limit: 10})
+	if err != nil {
+		t.Fatal(err)
+	}
+	var got []string
+	for _, o := range res.Opportunities {
+		got = append(got, o.ID)
+	}
+	if want := []string{"a", "b", "c"}; !reflect.DeepEqual(got, want) {
+		t.Errorf("order = %v, want %v", got, want)
+	}
+}
+
+func TestListOpportunities_MinDaysToRespond(t *testing.T) {
+	d := openTestDB(t)
+	day := func(n int) string { return time.Now().AddDate(0, 0, n).Format("01/02/2006") }
+	if _, err := d.Exec(`INSERT INTO opportunities (id, response_deadline) VALUES
+		('soon', ?), ('later', ?), ('none', NULL), ('blank', '')`, day(2), day(10)); err != nil {
+		t.Fatal(err)
+	}
+	if err := backfillResponseDeadlineUTC(d); err != nil {
+		t.Fatal(err)
+	}
+
+	res, err := ListOpportunities(d, ListFilters{MinDaysToRespond: 5, Limit: 10})
+	if err != nil {
+		t.Fatal(err)
+	}
+	if res.Total != 1 || res.Opportunities[0].ID != "later" {
+		t.Errorf("got %d results %+v, want only 'later'", res.Total, res.Opportunities)
+	}
+}
+
+func TestListOpportunities_DeadlineFiltersUseUTC(t *testing.T) {
+	d := openTestDB(t)
+	for id, deadline := range map[string]string{
+		"offset":    "2026-02-15T23:30:00-05:00", // 04:30 UTC on the 16th
+		"naive":     "2026-02-15T17:00:00",
+		"date-only": "2026-02-14",
+		"legacy":    "02/17/2026",
+		"garbage":   "TBD",
+	} {
+		if _, err := UpsertOpportunityFromAPI(d, map[string]any{"noticeId": id, "responseDeadline": deadline}); err != nil {
+			t.Fatal(err)
+		}
+	}
+
+	ids := func(f ListFilters) []string {
+		t.Helper()
+		f.Limit, f.SortBy, f.SortDir = 10, "response_deadline", "asc"
+		res, err := ListOpportunities(d, f)
+		if err != nil {
+			t.Fatal(err)
+		}
+		var got []string
+		for _, o := range res.Opportunities {
+			got = append(got, o.ID)
+		}
+		return got
+	}
+	if got := ids(ListFilters{ResponseDeadlineFrom: "02/15/2026", ResponseDeadlineTo: "02/15/2026"}); !reflect.DeepEqual(got, []string{"naive"}) {
+		t.Errorf("deadline on 02/15 = %v, want [naive] (offset falls on the 16th in UTC)", got)
+	}
+	if got := ids(ListFilters{ResponseDeadlineFrom: "02/14/2026"}); !reflect.DeepEqual(got, []string{"date-only", "naive", "offset", "legacy"}) {
+		t.Errorf("deadline from 02/14 = %v, want every parseable deadline in UTC order", got)
+	}
+}
+
+func TestListOpportunities_OmitDescription(t *testing.T) {
+	d := openTestDB(t)
+	if _, err := d.Exec(`INSERT INTO opportunities (id, set_aside_description, description)
+		VALUES ('a', 'Total Small Business', 'long text')`); err != nil {
+		t.Fatal(err)
+	}
+
+	res, err := ListOpportunities(d, ListFilters{Limit: 10})
+	if err != nil {
+		t.Fatal(err)
+	}
+	if got := res.Opportunities[0].Description; got == nil || *got != "long text" {
+		t.Errorf("default Description = %v, want long text", got)
+	}
+
+	res, err = ListOpportunities(d, ListFilters{Limit: 10, OmitDescription: true})
+	if err != nil {
+		t.Fatal(err)
+	}
+	o := res.Opportunities[0]
+	if o.Description != nil {
+		t.Errorf("OmitDescription: Description = %q, want nil", *o.Description)
+	}
+	if o.SetAsideDescription == nil || *o.SetAsideDescription != "Total Small Business" {
+		t.Errorf("OmitDescription dropped set_aside_description: %v", o.SetAsideDescription)
+	}
+}
+
+func TestListOpportunities_HasDocuments(t *testing.T) {
+	d := openTestDB(t)
+	if _, err := d.Exec(`INSERT INTO opportunities (id, resource_links) VALUES
+		('docs', '["https://sam.gov/a.pdf"]'), ('empty', '[]'), ('blank', ''), ('none', NULL)`); err != nil {
+		t.Fatal(err)
+	}
+
+	res, err := ListOpportunities(d, ListFilters{HasDocuments: true, Limit: 10})
+	if err != nil {
+		t.Fatal(err)
+	}
+	if res.Total != 1 || res.Opportunities[0].ID != "docs" {
+		t.Errorf("got %d results %+v, want only 'docs'", res.Total, res.Opportunities)
+	}
+}
+
+func TestGetFilterStatsPage_TiesSortByValue(t *testing.T) {
+	d := openTestDB(t)
+	// Inserted out of order so row order can't produce the expected result.
+	if _, err := d.Exec(`INSERT INTO opportunities (id, pop_state_code) VALUES
+		('1', 'VA'), ('2', 'TX'), ('3', 'CA'), ('4', 'MD'), ('5', 'MD'), ('6', 'VA'), ('7', 'AK')`); err != nil {
+		t.Fatal(err)
+	}
+
+	s, err := GetFilterStatsPage(d, 0, 0)
+	if err != nil {
+		t.Fatal(err)
+	}
+	want := []FilterStat{
+		{Value: "MD", Count: 2}, {Value: "VA", Count: 2},
+		{Value: "AK", Count: 1}, {Value: "CA", Count: 1}, {Value: "TX", Count: 1},
+	}
+	if !reflect.DeepEqual(s.States, want) {
+		t.Errorf("states = %v, want %v", s.States, want)
+	}
+
+	// Paging through ties is stable too.
+	page, err := GetFilterStatsPage(d, 2, 2)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if !reflect.DeepEqual(page.States, want[2:4]) {
+		t.Errorf("page 2 = %v, want %v", page.States, want[2:4])
+	}
+}
+
+func TestGetFilterStatsPage_PagesKeepDistinctTotals(t *testing.T) {
+	d := openTestDB(t)
+	if _, err := d.Exec(`INSERT INTO opportunities (id, naics_code, department) VALUES
+		('1', '541512', 'DOD'), ('2', '541512', 'DOD'), ('3', '541512', 'GSA'),
+		('4', '541511', NULL), ('5', '541511', NULL),
+		('6', '561720', NULL), ('7', '236220', NULL), ('8', '334111', '')`); err != nil {
+		t.Fatal(err)
+	}
+
+	all := []FilterStat{
+		{Value: "541512", Count: 3}, {Value: "541511", Count: 2},
+		{Value: "236220", Count: 1}, {Value: "334111", Count: 1}, {Value: "561720", Count: 1},
+	}
+	for _, tc := range []struct {
+		offset int
+		want   []FilterStat
+	}{
+		{0, all[0:2]},
+		{2, all[2:4]}, // the page boundary falls inside the tied counts
+		{4, all[4:5]},
+		{6, nil},
+	} {
+		s, err := GetFilterStatsPage(d, 2, tc.offset)
+		if err != nil {
+			t.Fatal(err)
+		}
+		if !reflect.DeepEqual(s.NAICSCodes, tc.want) {
+			t.Errorf("offset %d: naics = %v, want %v", tc.offset, s.NAICSCodes, tc.want)
+		}
+		// Totals describe the whole table, not the page.
+		if s.Total != 8 || s.Distinct["naics_code"] != 5 || s.Distinct["department"] != 2 {
+			t.Errorf("offset %d: total = %d, distinct = %v; want 8, naics 5, department 2", tc.offset, s.Total, s.Distinct)
+		}
+	}
+}
+
+func TestListOpportunities_ActiveAndInactiveOnly(t *testing.T) {
+	d := openTestDB(t)
+	if _, err := d.Exec(`INSERT INTO opportunities (id, active) VALUES ('open', 1), ('archived', 0)`); err != nil {
+		t.Fatal(err)
+	}
+
+	for _, tc := range []struct {
+		name string
+		f    ListFilters
+		want []string
+	}{
+		{"default includes all", ListFilters{}, []string{"archived", "open"}},
+		{"active only", ListFilters{ActiveOnly: true}, []string{"open"}},
+		{"inactive only", ListFilters{InactiveOnly: true}, []string{"archived"}},
+	} {
+		t.Run(tc.name, func(t *testing.T) {
+			tc.f.Limit = 10
+			res, err := ListOpportunities(d, tc.f)
+			if err != nil {
+				t.Fatal(err)
+			}
+			var got []string
+			for _, o := range res.Opportunities {
+				got = append(got, o.ID)
+			}
+			sort.Strings(got)
+			if !reflect.DeepEqual(got, tc.want) {
+				t.Errorf("got %v, want %v", got, tc.want)
+			}
+		})
+	}
+}
+
+func TestListOpportunities_KeysetMatchesOffsetPaging(t *testing.T) {
+	d := openTestDB(t)
+	// Shared posted dates exercise the id tiebreak; NULLs sort last descending.
+	if _, err := d.Exec(`INSERT INTO opportunities (id, posted_date) VALUES
+		('a', '01/02/2026'), ('b', '01/02/2026'), ('c', '12/31/2025'), ('d', '03/01/2026'),
+		('e', NULL), ('f', '01/02/2026'), ('g', NULL)`); err != nil {
+		t.Fatal(err)
+	}
+
+	for _, dir := range []string{"desc", "asc"} {
+		t.Run(dir, func(t *testing.T) {
+			all, err := ListOpportunities(d, ListFilters{SortDir: dir, Limit: 100})
+			if err != nil {
+				t.Fatal(err)
+			}
+			var want []string
+			for _, o := range all.Opportunities {
+				want = append(want, o.ID)
+			}
+
+			var got []string
+			f := ListFilters{SortDir: dir, Limit: 2}
+			for range 10 {
+				res, err := ListOpportunities(d, f)
+				if err != nil {
+					t.Fatal(err)
+				}
+				if res.Total != 7 {
+					t.Errorf("Total = %d, want 7 on every page", res.Total)
+				}
+				for _, o := range res.Opportunities {
+					got = append(got, o.ID)
+				}
+				date, id, ok := NextCursor(res.Opportunities, f.Limit)
+				if !ok {
+					break
+				}
+				f.AfterPostedDate, f.AfterID = date, id
+			}
+			if !reflect.DeepEqual(got, want) {
+				t.Errorf("keyset pages = %v, want %v", got, want)
+			}
+		})
+	}
+
+	if _, err := ListOpportunities(d, ListFilters{SortBy: "title", AfterID: "a"}); !errors.Is(err, ErrCursorSort) {
+		t.Errorf("cursor with title sort: err = %v, want ErrCursorSort", err)
+	}
+}
+
+func TestKeysetPaging_UsesPostedSortIndex(t *testing.T) {
+	d := openTestDB(t)
+	f := ListFilters{SortDir: "asc", AfterPostedDate: "01/02/2026", AfterID: "b"}
+	clause, args, err := keysetClause(f)
+	if err != nil {
+		t.Fatal(err)
+	}
+	rows, err := d.Query("EXPLAIN QUERY PLAN SELECT id FROM opportunities WHERE "+clause+" "+orderBy(f)+" LIMIT 25", args...)
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer rows.Close()
+	var plan []string
+	for rows.Next() {
+		var id, parent, notUsed int
+		var detail string
+		if err := rows.Scan(&id, &parent, &notUsed, &detail); err != nil {
+			t.Fatal(err)
+		}
+		plan = append(plan, detail)
+	}
+	got := strings.Join(plan, "; ")
+	if !strings.Contains(got, "idx_opp_posted_sort") || strings.Contains(got, "TEMP B-TREE") {
+		t.Errorf("plan = %q, want a walk of idx_opp_posted_sort with no sort step", got)
+	}
+}
+
+func TestListOpportunities_SolicitationNumber(t *testing.T) {
+	d := openTestDB(t)
+	if _, err := d.Exec(`INSERT INTO opportunities (id, solicitation_number) VALUES
+		('a', 'W91-26-R-0001'), ('b', 'W91-26-R-00012'), ('c', NULL)`); err != nil {
+		t.Fatal(err)
+	}
+
+	res, err := ListOpportunities(d, ListFilters{SolicitationNumber: "w91-26-r-0001", Limit: 10})
+	if err != nil {
+		t.Fatal(err)
+	}
+	if res.Total != 1 || res.Opportunities[0].ID != "a" {
+		t.Errorf("got %d results %+v, want only 'a' (exact match)", res.Total, res.Opportunities)
+	}
+}
+
+func TestListOpportunities_MaxPageSizeEnv(t *testing.T) {
+	d := openTestDB(t)
+	for i := 0; i < 150; i++ {
+		if _, err := d.Exec(`INSERT INTO opportunities (id, title) VALUES (?, 'x')`, fmt.Sprintf("opp-%03d", i)); err != nil {
+			t.Fatal(err)
+		}
+	}
+
+	for _, tt := range []struct {
+		env  string
+		want int
+	}{
+		{"", 25},      // default cap of 100: oversized limits fall back to 25
+		{"bogus", 25}, // invalid values keep the default
+		{"200", 150},  // raised cap serves the whole page
+	} {
+		t.Setenv(MaxPageSizeEnv, tt.env)
+		res, err := ListOpportunities(d, ListFilters{Limit: 150})
+		if err != nil {
+			t.Fatal(err)
+		}
+		if len(res.Opportunities) != tt.want {
+			t.Errorf("%s=%q: got %d rows, want %d", MaxPageSizeEnv, tt.env, len(res.Opportunities), tt.want)
+		}
+	}
+
+	t.Setenv(MaxPageSizeEnv, "50000")
+	if got := MaxPageSize(); got != MaxPageSizeCeiling {
+		t.Errorf("MaxPageSize() = %d, want ceiling %d", got, MaxPageSizeCeiling)
+	}
+}
+
+func TestParseDateRange(t *testing.T) {
+	tests := []struct {
+		in      string
+		want    DateRange
+		wantErr bool
+	}{
+		{"2025-01-01..2025-03-31", DateRange{"01/01/2025", "03/31/2025"}, false},
+		{"07/01/2025..09/30/2025", DateRange{"07/01/2025", "09/30/2025"}, false},
+		{"2025-03-01..2025-03-01", DateRange{"03/01/2025", "03/01/2025"}, false},
+		{"2025-03-31..2025-01-01", DateRange{}, true},
+		{"2025-01-01", DateRange{}, true},
+		{"2025-01-01..soon", DateRange{}, true},
+	}
+	for _, tt := range tests {
+		got, err := ParseDateRange(tt.in)
+		if (err != nil) != tt.wantErr || got != tt.want {
+			t.Errorf("ParseDateRange(%q) = %+v, %v; want %+v (err %v)", tt.in, got, err, tt.want, tt.wantErr)
+		}
+	}
+}
+
+func TestListOpportunities_PostedRangesOR(t *testing.T) {
+	d := openTestDB(t)
+	if _, err := d.Exec(`INSERT INTO opportunities (id, title, posted_date) VALUES
+		('q1', 'x', '02/15/2025'), ('q2', 'x', '05/15/2025'), ('q3', 'x', '08/15/2025'), ('q3-end', 'x', '09/30/2025')`); err != nil {
+		t.Fatal(err)
+	}
+
+	res, err := ListOpportunities(d, ListFilters{
+		PostedRanges: []DateRange{{"01/01/2025", "03/31/2025"}, {"07/01/2025", "09/30/2025"}},
+		SortDir:      "asc",
+		Limit:        10,
+	})
+	if err != nil {
+		t.Fatal(err)
+	}
+	var got []string
+	for _, o := range res.Opportunities {
+		got = append(got, o.ID)
+	}
+	if want := []string{"q1", "q3", "q3-end"}; !reflect.DeepEqual(got, want) || res.Total != 3 {
+		t.Errorf("got %v (total %d), want %v", got, res.Total, want)
+	}
+
+	// Other filters still AND with the group.
+	res, err = ListOpportunities(d, ListFilters{
+		PostedRanges: []DateRange{{"01/01/2025", "03/31/2025"}, {"07/01/2025", "09/30/2025"}},
+		DateFrom:     "08/01/2025",
+		Limit:        10,
+	})
+	if err != nil {
+		t.Fatal(err)
+	}
+	if res.Total != 2 {
+		t.Errorf("with DateFrom: total = %d, want 2", res.Total)
+	}
+}
+
+func TestListOpportunities_LatestAmendment(t *testing.T) {
+	d := openTestDB(t)
+	if _, err := d.Exec(`INSERT INTO opportunities (id, title, solicitation_number, posted_date) VALUES
+		('orig', 'x', 'W91-26-R-0001', '01/05/2026'),
+		('amend-1', 'x', 'w91-26-r-0001', '01/20/2026'),
+		('other', 'x', 'FA-26-0002', '01/10/2026'),
+		('same-day-a', 'x', 'SD-1', '02/01/2026'),
+		('same-day-b', 'x', 'SD-1', '02/01/2026'),
+		('no-sol-1', 'x', '', '01/01/2026'),
+		('no-sol-2', 'x', NULL, '01/02/2026')`); err != nil {
+		t.Fatal(err)
+	}
+
+	res, err := ListOpportunities(d, ListFilters{Limit: 20})
+	if err != nil {
+		t.Fatal(err)
+	}
+	latest := map[string]bool{}
+	for _, o := range res.Opportunities {
+		latest[o.ID] = o.IsLatestAmendment
+	}
+	want := map[string]bool{
+		"orig": false, "amend-1": true, "other": true,
+		"same-day-a": false, "same-day-b": true,
+		"no-sol-1": true, "no-sol-2": true,
+	}
+	if !reflect.DeepEqual(latest, want) {
+		t.Errorf("is_latest_amendment = %v, want %v", latest, want)
+	}
+
+	res, err = ListOpportunities(d, ListFilters{LatestOnly: true, Limit: 20})
+	if err != nil {
+		t.Fatal(err)
+	}
+	if res.Total != 5 {
+		t.Errorf("LatestOnly total = %d, want 5", res.Total)
+	}
+
+	detail, err := GetOpportunity(d, "orig")
+	if err != nil || detail == nil {
+		t.Fatalf("GetOpportunity = %v, %v", detail, err)
+	}
+	if detail.IsLatestAmendment {
+		t.Error("detail for the superseded original reports IsLatestAmendment")
+	}
+}
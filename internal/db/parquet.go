@@ -0,0 +1,88 @@
+//go:build parquet
+
+package db
+
+import (
+	"io"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/parquet-go/parquet-go"
+)
+
+// ParquetSupported reports whether this binary was built with -tags parquet.
+const ParquetSupported = true
+
+type parquetRow struct {
+	ID                  string     `parquet:"id"`
+	Title               *string    `parquet:"title"`
+	SolicitationNumber  *string    `parquet:"solicitation_number"`
+	Department          *string    `parquet:"department"`
+	SubTier             *string    `parquet:"sub_tier"`
+	Office              *string    `parquet:"office"`
+	OppType             *string    `parquet:"opp_type"`
+	BaseType            *string    `parquet:"base_type"`
+	PostedDate          *time.Time `parquet:"posted_date,timestamp(millisecond)"`
+	ResponseDeadline    *time.Time `parquet:"response_deadline,timestamp(millisecond)"`
+	NAICSCode           *string    `parquet:"naics_code"`
+	SetAside            *string    `parquet:"set_aside"`
+	SetAsideDescription *string    `parquet:"set_aside_description"`
+	Description         *string    `parquet:"description"`
+	Active              bool       `parquet:"active"`
+	UILink              *string    `parquet:"ui_link"`
+	PopStateCode        *string    `parquet:"pop_state_code"`
+	PopStateName        *string    `parquet:"pop_state_name"`
+	AwardAmount         *float64   `parquet:"award_amount"`
+}
+
+// WriteParquet writes items as a Parquet file with typed columns: dates
+// become UTC timestamps (null when unparseable), active becomes a bool, and
+// award_amount a double (null when unparseable, see parseAwardAmount).
+func WriteParquet(w io.Writer, items []OpportunityListItem) error {
+	rows := make([]parquetRow, len(items))
+	for i, o := range items {
+		rows[i] = parquetRow{
+			ID:                  o.ID,
+			Title:               o.Title,
+			SolicitationNumber:  o.SolicitationNumber,
+			Department:          o.Department,
+			SubTier:             o.SubTier,
+			Office:              o.Office,
+			OppType:             o.OppType,
+			BaseType:            o.BaseType,
+			PostedDate:          parseSAMTime(o.PostedDate),
+			ResponseDeadline:    parseSAMTime(o.ResponseDeadline),
+			NAICSCode:           o.NAICSCode,
+			SetAside:            o.SetAside,
+			SetAsideDescription: o.SetAsideDescription,
+			Description:         o.Description,
+			Active:              o.Active == 1,
+			UILink:              o.UILink,
+			PopStateCode:        o.PopStateCode,
+			PopStateName:        o.PopStateName,
+			AwardAmount:         parseAwardAmount(o.AwardAmount),
+		}
+	}
+
+	pw := parquet.NewGenericWriter[parquetRow](w)
+	if _, err := pw.Write(rows); err != nil {
+		pw.Close()
+		return err
+	}
+	return pw.Close()
+}
+
+// parseAwardAmount is awardAmountExpr in Go: the stored award_amount with
+// "$" and thousands separators dropped, or nil when it isn't a number.
+func parseAwardAmount(s *string) *float64 {
+	if s == nil {
+		return nil
+	}
+	clean := strings.NewReplacer(",", "", "$", "").Replace(strings.TrimSpace(*s))
+	v, err := strconv.ParseFloat(clean, 64)
+	if err != nil {
+		return nil
+	}
+	return &v
+}
@@ -0,0 +1,17 @@
+//go:build !parquet
+
+package db
+
+import (
+	"errors"
+	"io"
+)
+
+// ParquetSupported reports whether this binary was built with -tags parquet.
+const ParquetSupported = false
+
+// WriteParquet is unavailable in the default build, which stays free of the
+// Arrow/Parquet dependency.
+func WriteParquet(io.Writer, []OpportunityListItem) error {
+	return errors.New("parquet export not compiled in (rebuild with -tags parquet)")
+}
@@ -0,0 +1,36 @@
+//go:build parquet
+
+package db
+
+import (
+	"bytes"
+	"testing"
+
+	"github.com/parquet-go/parquet-go"
+)
+
+func TestWriteParquet_AwardAmount(t *testing.T) {
+	d := openTestDB(t)
+	if _, err := d.Exec(`INSERT INTO opportunities (id, title, posted_date, award_amount) VALUES
+		('a', 'Awarded', '03/01/2026', '$1,250,000.00'), ('b', 'Odd', '03/02/2026', 'TBD'), ('c', 'Open', '03/03/2026', NULL)`); err != nil {
+		t.Fatal(err)
+	}
+	items, err := ExportOpportunities(d, ListFilters{SortBy: "posted_date", SortDir: "asc"})
+	if err != nil {
+		t.Fatal(err)
+	}
+	var buf bytes.Buffer
+	if err := WriteParquet(&buf, items); err != nil {
+		t.Fatal(err)
+	}
+	rows, err := parquet.Read[parquetRow](bytes.NewReader(buf.Bytes()), int64(buf.Len()))
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(rows) != 3 || rows[0].AwardAmount == nil || *rows[0].AwardAmount != 1250000 {
+		t.Fatalf("rows = %+v, want award_amount 1250000 first", rows)
+	}
+	if rows[1].AwardAmount != nil || rows[2].AwardAmount != nil {
+		t.Errorf("unparseable and missing amounts = %v, %v; want null", rows[1].AwardAmount, rows[2].AwardAmount)
+	}
+}
@@ -0,0 +1,42 @@
+package db
+
+import (
+	"fmt"
+	"log"
+	"os"
+	"strconv"
+)
+
+// Page cache and memory-map sizes, in MiB, applied to every connection Open
+// makes (CLI and server alike). Larger values help stats and filter queries
+// on big databases, especially on slow disks; GOVSCOUT_MMAP_MB=0 turns
+// memory-mapped I/O off.
+const (
+	CacheMBEnv     = "GOVSCOUT_CACHE_MB"
+	MmapMBEnv      = "GOVSCOUT_MMAP_MB"
+	DefaultCacheMB = 64
+	DefaultMmapMB  = 256
+)
+
+// tuningPragmas returns the DSN suffix setting cache_size and mmap_size from
+// CacheMBEnv and MmapMBEnv.
+func tuningPragmas() string {
+	cacheMB := envMB(CacheMBEnv, DefaultCacheMB, 1)
+	mmapMB := envMB(MmapMBEnv, DefaultMmapMB, 0)
+	// A negative cache_size is in KiB rather than pages.
+	return fmt.Sprintf("&_pragma=cache_size(%d)&_pragma=mmap_size(%d)", -cacheMB*1024, int64(mmapMB)<<20)
+}
+
+// envMB reads a size in MiB, falling back to def when unset or below floor.
+func envMB(name string, def, floor int) int {
+	v := os.Getenv(name)
+	if v == "" {
+		return def
+	}
+	n, err := strconv.Atoi(v)
+	if err != nil || n < floor {
+		log.Printf("ignoring invalid %s=%q (want a size in MiB, at least %d)", name, v, floor)
+		return def
+	}
+	return n
+}
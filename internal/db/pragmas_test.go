@@ -0,0 +1,82 @@
+package db
+
+import (
+	"fmt"
+	"path/filepath"
+	"testing"
+)
+
+func TestOpen_TuningPragmas(t *testing.T) {
+	for _, tt := range []struct {
+		cacheEnv, mmapEnv string
+		wantCache         int64 // PRAGMA cache_size (negative: KiB)
+		wantMmap          int64 // PRAGMA mmap_size, bytes
+	}{
+		{"", "", -DefaultCacheMB * 1024, DefaultMmapMB << 20},
+		{"16", "0", -16 * 1024, 0},
+		{"0", "-1", -DefaultCacheMB * 1024, DefaultMmapMB << 20}, // invalid: defaults
+	} {
+		t.Setenv(CacheMBEnv, tt.cacheEnv)
+		t.Setenv(MmapMBEnv, tt.mmapEnv)
+		d := openTestDB(t)
+
+		var cache, mmap int64
+		if err := d.QueryRow("PRAGMA cache_size").Scan(&cache); err != nil {
+			t.Fatal(err)
+		}
+		if err := d.QueryRow("PRAGMA mmap_size").Scan(&mmap); err != nil {
+			t.Fatal(err)
+		}
+		if cache != tt.wantCache {
+			t.Errorf("cache=%q: cache_size = %d, want %d", tt.cacheEnv, cache, tt.wantCache)
+		}
+		// SQLite clamps mmap_size to its compile-time maximum, so only check
+		// that it was switched off or on as asked.
+		if (mmap == 0) != (tt.wantMmap == 0) {
+			t.Errorf("mmap=%q: mmap_size = %d, want %d", tt.mmapEnv, mmap, tt.wantMmap)
+		}
+	}
+}
+
+// BenchmarkGetFilterStats_100k times the stats query on a 100k-row
+// database. Compare settings with e.g.
+//
+//	GOVSCOUT_CACHE_MB=2 GOVSCOUT_MMAP_MB=0 go test ./internal/db -run '^$' -bench FilterStats
+//	go test ./internal/db -run '^$' -bench FilterStats
+func BenchmarkGetFilterStats_100k(b *testing.B) {
+	d, err := Open(filepath.Join(b.TempDir(), "bench.db"))
+	if err != nil {
+		b.Fatal(err)
+	}
+	defer d.Close()
+
+	tx, err := d.Begin()
+	if err != nil {
+		b.Fatal(err)
+	}
+	stmt, err := tx.Prepare(`INSERT INTO opportunities
+		(id, title, naics_code, opp_type, set_aside, pop_state_code, department, posted_date)
+		VALUES (?, ?, ?, ?, ?, ?, ?, ?)`)
+	if err != nil {
+		b.Fatal(err)
+	}
+	for i := 0; i < 100_000; i++ {
+		if _, err := stmt.Exec(fmt.Sprintf("opp-%06d", i), fmt.Sprintf("Opportunity %d", i),
+			fmt.Sprintf("5415%02d", i%100), []string{"Solicitation", "Presolicitation", "Award Notice"}[i%3],
+			[]string{"SBA", "8A", "WOSB", ""}[i%4], fmt.Sprintf("S%02d", i%50), fmt.Sprintf("DEPT %d", i%40),
+			fmt.Sprintf("%02d/%02d/2025", i%12+1, i%28+1)); err != nil {
+			b.Fatal(err)
+		}
+	}
+	stmt.Close()
+	if err := tx.Commit(); err != nil {
+		b.Fatal(err)
+	}
+
+	b.ResetTimer()
+	for i := 0; i < b.N; i++ {
+		if _, err := GetFilterStats(d); err != nil {
+			b.Fatal(err)
+		}
+	}
+}
@@ -0,0 +1,60 @@
+package db
+
+import (
+	"database/sql"
+	"time"
+)
+
+// ListStaleOpportunityIDs returns up to limit notice IDs last changed or
+// re-checked before cutoff (a SQLite datetime, see ParseSince), active ones
+// first, oldest first within each group.
+func ListStaleOpportunityIDs(db *sql.DB, cutoff string, limit int) ([]string, error) {
+	rows, err := db.Query(`SELECT id FROM opportunities
+		WHERE max(modified_at, COALESCE(refreshed_at, '')) < ?
+		ORDER BY active DESC, max(modified_at, COALESCE(refreshed_at, '')), id
+		LIMIT ?`, cutoff, limit)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+
+	var ids []string
+	for rows.Next() {
+		var id string
+		if err := rows.Scan(&id); err != nil {
+			return nil, err
+		}
+		ids = append(ids, id)
+	}
+	return ids, rows.Err()
+}
+
+// MarkRefreshed records that id was just re-checked against SAM.gov, so it
+// isn't picked as stale again until the cutoff passes, changed or not.
+func MarkRefreshed(db *sql.DB, id string) error {
+	_, err := db.Exec(`UPDATE opportunities SET refreshed_at = datetime('now') WHERE id = ?`, id)
+	return err
+}
+
+// ListSoonestClosingIDs returns up to limit active notice IDs whose response
+// deadline is still ahead of now, soonest first.
+func ListSoonestClosingIDs(db *sql.DB, now time.Time, limit int) ([]string, error) {
+	rows, err := db.Query(`SELECT id FROM opportunities
+		WHERE active = 1 AND response_deadline_utc >= ?
+		ORDER BY response_deadline_utc, id
+		LIMIT ?`, now.UTC().Format(deadlineUTCLayout), limit)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+
+	var ids []string
+	for rows.Next() {
+		var id string
+		if err := rows.Scan(&id); err != nil {
+			return nil, err
+		}
+		ids = append(ids, id)
+	}
+	return ids, rows.Err()
+}
@@ -0,0 +1,38 @@
+package db
+
+import (
+	"reflect"
+	"testing"
+)
+
+func TestListStaleOpportunityIDs(t *testing.T) {
+	d := openTestDB(t)
+	if _, err := d.Exec(`INSERT INTO opportunities (id, active, modified_at, refreshed_at) VALUES
+		('inactive-old', 0, '2024-01-01 00:00:00', NULL),
+		('active-older', 1, '2024-01-01 00:00:00', NULL),
+		('active-old', 1, '2024-02-01 00:00:00', NULL),
+		('rechecked', 1, '2024-01-01 00:00:00', '2024-06-01 00:00:00'),
+		('fresh', 1, '2024-06-01 00:00:00', NULL)`); err != nil {
+		t.Fatal(err)
+	}
+
+	ids, err := ListStaleOpportunityIDs(d, "2024-03-01 00:00:00", 10)
+	if err != nil {
+		t.Fatal(err)
+	}
+	want := []string{"active-older", "active-old", "inactive-old"}
+	if !reflect.DeepEqual(ids, want) {
+		t.Errorf("stale ids = %v, want %v", ids, want)
+	}
+
+	if err := MarkRefreshed(d, "active-older"); err != nil {
+		t.Fatal(err)
+	}
+	ids, err = ListStaleOpportunityIDs(d, "2024-03-01 00:00:00", 1)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if !reflect.DeepEqual(ids, []string{"active-old"}) {
+		t.Errorf("after MarkRefreshed with limit 1 = %v, want [active-old]", ids)
+	}
+}
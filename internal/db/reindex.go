@@ -0,0 +1,67 @@
+package db
+
+import (
+	"database/sql"
+	"fmt"
+	"time"
+)
+
+// IndexTiming is how long one index took to rebuild.
+type IndexTiming struct {
+	Name     string
+	Duration time.Duration
+}
+
+// Reindex drops and recreates every idx_opp_* index from its stored
+// definition, then runs ANALYZE so the planner sees fresh statistics. Meant
+// for after bulk loads; the rebuild runs in one transaction, so a failure
+// leaves the original indexes in place.
+func Reindex(db *sql.DB) ([]IndexTiming, time.Duration, error) {
+	rows, err := db.Query(`SELECT name, sql FROM sqlite_master
+		WHERE type = 'index' AND name LIKE 'idx\_opp\_%' ESCAPE '\' AND sql IS NOT NULL
+		ORDER BY name`)
+	if err != nil {
+		return nil, 0, err
+	}
+	type index struct{ name, sql string }
+	var indexes []index
+	for rows.Next() {
+		var ix index
+		if err := rows.Scan(&ix.name, &ix.sql); err != nil {
+			rows.Close()
+			return nil, 0, err
+		}
+		indexes = append(indexes, ix)
+	}
+	rows.Close()
+	if err := rows.Err(); err != nil {
+		return nil, 0, err
+	}
+
+	tx, err := db.Begin()
+	if err != nil {
+		return nil, 0, err
+	}
+	defer tx.Rollback()
+
+	timings := make([]IndexTiming, 0, len(indexes))
+	for _, ix := range indexes {
+		start := time.Now()
+		if _, err := tx.Exec(`DROP INDEX "` + ix.name + `"`); err != nil {
+			return nil, 0, fmt.Errorf("drop %s: %w", ix.name, err)
+		}
+		if _, err := tx.Exec(ix.sql); err != nil {
+			return nil, 0, fmt.Errorf("create %s: %w", ix.name, err)
+		}
+		timings = append(timings, IndexTiming{Name: ix.name, Duration: time.Since(start)})
+	}
+	if err := tx.Commit(); err != nil {
+		return nil, 0, err
+	}
+
+	start := time.Now()
+	if _, err := db.Exec("ANALYZE"); err != nil {
+		return timings, 0, fmt.Errorf("analyze: %w", err)
+	}
+	return timings, time.Since(start), nil
+}
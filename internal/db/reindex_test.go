@@ -0,0 +1,37 @@
+package db
+
+import "testing"
+
+func TestReindex(t *testing.T) {
+	d := openTestDB(t)
+	if _, err := d.Exec(`INSERT INTO opportunities (id, solicitation_number) VALUES ('a', 'W91-1')`); err != nil {
+		t.Fatal(err)
+	}
+
+	timings, _, err := Reindex(d)
+	if err != nil {
+		t.Fatal(err)
+	}
+	names := map[string]bool{}
+	for _, ti := range timings {
+		names[ti.Name] = true
+	}
+	for _, want := range []string{"idx_opp_posted_date", "idx_opp_solicitation_number", "idx_opp_posted_sort"} {
+		if !names[want] {
+			t.Errorf("Reindex did not rebuild %s (got %v)", want, names)
+		}
+	}
+
+	if names["idx_opp_award_amount"] {
+		t.Error("idx_opp_award_amount should have been dropped by migration 019")
+	}
+
+	// Every index is back afterwards.
+	var n int
+	if err := d.QueryRow(`SELECT COUNT(*) FROM sqlite_master WHERE type = 'index' AND name LIKE 'idx\_opp\_%' ESCAPE '\'`).Scan(&n); err != nil {
+		t.Fatal(err)
+	}
+	if n != len(timings) {
+		t.Errorf("%d idx_opp_* indexes after Reindex, want %d", n, len(timings))
+	}
+}
@@ -1,6 +1,18 @@
 package db
 
-import "database/sql"
+import (
+	"database/sql"
+	"log"
+	"os"
+	"strconv"
+	"strings"
+
+	"github.com/theognis1002/govscout/internal/samgov"
+)
+
+// LogRetentionEnv caps how many sync_runs rows are kept, newest first.
+// Unset or 0 keeps every run.
+const LogRetentionEnv = "GOVSCOUT_LOG_RETENTION"
 
 type SyncRunRow struct {
 	ID             int64
@@ -23,18 +35,87 @@ func InsertSyncRun(db *sql.DB, ctx, from, to string, apiCalls, records int, rate
 	if err != nil {
 		return 0, err
 	}
+	if keep := syncRunRetention(); keep > 0 {
+		if _, err := PruneSyncRuns(db, keep); err != nil {
+			log.Printf("prune sync_runs: %v", err)
+		}
+	}
 	return result.LastInsertId()
 }
 
+// syncRunRetention reads LogRetentionEnv; invalid values keep everything.
+func syncRunRetention() int {
+	v := os.Getenv(LogRetentionEnv)
+	if v == "" {
+		return 0
+	}
+	n, err := strconv.Atoi(v)
+	if err != nil || n < 0 {
+		log.Printf("ignoring invalid %s=%q (want a row count, 0 for unbounded)", LogRetentionEnv, v)
+		return 0
+	}
+	return n
+}
+
+// PruneSyncRuns deletes all but the newest keep sync runs, returning how
+// many were removed.
+func PruneSyncRuns(db *sql.DB, keep int) (int64, error) {
+	res, err := db.Exec(`DELETE FROM sync_runs WHERE id NOT IN
+		(SELECT id FROM sync_runs ORDER BY id DESC LIMIT ?)`, keep)
+	if err != nil {
+		return 0, err
+	}
+	return res.RowsAffected()
+}
+
 func ListSyncRuns(db *sql.DB, limit int) ([]SyncRunRow, error) {
-	if limit <= 0 {
-		limit = 50
+	runs, _, err := QuerySyncRuns(db, SyncRunQuery{Limit: limit})
+	return runs, err
+}
+
+// ListFailedSyncRuns returns recent runs that errored or hit a rate limit.
+func ListFailedSyncRuns(db *sql.DB, limit int) ([]SyncRunRow, error) {
+	runs, _, err := QuerySyncRuns(db, SyncRunQuery{Limit: limit, FailedOnly: true})
+	return runs, err
+}
+
+// SyncRunQuery selects a page of sync runs, newest first.
+type SyncRunQuery struct {
+	Limit      int    // zero means 50
+	Offset     int
+	Context    string // incremental, backfill, ...; empty for all
+	FailedOnly bool   // only runs that errored or hit a rate limit
+}
+
+// QuerySyncRuns returns one page of sync runs and how many match in total,
+// so callers can page through history older than the first screen.
+func QuerySyncRuns(db *sql.DB, q SyncRunQuery) ([]SyncRunRow, int64, error) {
+	if q.Limit <= 0 {
+		q.Limit = 50
+	}
+	var conds []string
+	var args []any
+	if q.Context != "" {
+		conds = append(conds, "context = ?")
+		args = append(args, q.Context)
+	}
+	if q.FailedOnly {
+		conds = append(conds, "(error_message IS NOT NULL OR rate_limited = 1)")
+	}
+	where := ""
+	if len(conds) > 0 {
+		where = "WHERE " + strings.Join(conds, " AND ")
+	}
+
+	var total int64
+	if err := db.QueryRow("SELECT COUNT(*) FROM sync_runs "+where, args...).Scan(&total); err != nil {
+		return nil, 0, err
 	}
 	rows, err := db.Query(`SELECT id, started_at, finished_at, context, posted_from, posted_to,
 		api_calls, records_fetched, rate_limited, error_message
-		FROM sync_runs ORDER BY id DESC LIMIT ?`, limit)
+		FROM sync_runs `+where+` ORDER BY id DESC LIMIT ? OFFSET ?`, append(args, q.Limit, max(q.Offset, 0))...)
 	if err != nil {
-		return nil, err
+		return nil, 0, err
 	}
 	defer rows.Close()
 
@@ -44,15 +125,15 @@ func ListSyncRuns(db *sql.DB, limit int) ([]SyncRunRow, error) {
 		var rl int
 		if err := rows.Scan(&r.ID, &r.StartedAt, &r.FinishedAt, &r.Context, &r.PostedFrom, &r.PostedTo,
 			&r.APICalls, &r.RecordsFetched, &rl, &r.ErrorMessage); err != nil {
-			return nil, err
+			return nil, 0, err
 		}
 		r.RateLimited = rl == 1
 		runs = append(runs, r)
 	}
 	if err := rows.Err(); err != nil {
-		return nil, err
+		return nil, 0, err
 	}
-	return runs, nil
+	return runs, total, nil
 }
 
 func GetSyncState(db *sql.DB, key string) (string, error) {
@@ -70,20 +151,70 @@ func SetSyncState(db *sql.DB, key, value string) error {
 	return err
 }
 
-func GetEarliestPostedDate(db *sql.DB) (string, error) {
-	var val sql.NullString
+// DeleteSyncState removes key, so readers see it as never set.
+func DeleteSyncState(db *sql.DB, key string) error {
+	_, err := db.Exec("DELETE FROM sync_state WHERE key = ?", key)
+	return err
+}
+
+// GetEarliestPostedDate is the oldest stored posted date, or the zero Date
+// when no row has one. Rows whose posted_date doesn't parse are skipped.
+func GetEarliestPostedDate(db *sql.DB) (samgov.Date, error) {
+	var val string
 	err := db.QueryRow(`SELECT posted_date FROM opportunities
-		WHERE posted_date IS NOT NULL AND posted_date != ''
+		WHERE posted_date GLOB '[0-9][0-9]/[0-9][0-9]/[0-9][0-9][0-9][0-9]'
 		ORDER BY substr(posted_date,7,4)||substr(posted_date,1,2)||substr(posted_date,4,2) ASC
 		LIMIT 1`).Scan(&val)
 	if err == sql.ErrNoRows {
-		return "", nil
+		return samgov.Date{}, nil
 	}
 	if err != nil {
-		return "", err
+		return samgov.Date{}, err
 	}
-	if val.Valid {
-		return val.String, nil
+	return samgov.ParseDate(val)
+}
+
+// BackfillCallSample averages api_calls over the most recent limit clean
+// backfill windows (no error, not rate limited), so callers can project how
+// many pages a window usually takes. runs is zero when there is no history.
+func BackfillCallSample(db *sql.DB, limit int) (runs int, avgCalls float64, err error) {
+	var avg sql.NullFloat64
+	err = db.QueryRow(`SELECT COUNT(*), AVG(api_calls) FROM (
+		SELECT api_calls FROM sync_runs
+		WHERE context = 'backfill' AND error_message IS NULL AND rate_limited = 0 AND api_calls > 0
+		ORDER BY id DESC LIMIT ?)`, limit).Scan(&runs, &avg)
+	return runs, avg.Float64, err
+}
+
+// SyncWindow is the posted-date range one sync run fetched, both ends
+// MM/DD/YYYY.
+type SyncWindow struct {
+	From string
+	To   string
+}
+
+// CompletedSyncWindows returns the posted-date ranges of every recorded
+// unfiltered run (incremental or backfill) that finished cleanly: no error,
+// not rate limited. Runs with upsert errors or cut short carry an
+// error_message, so they don't count as covered; neither do filtered syncs,
+// whose context carries their scope.
+func CompletedSyncWindows(db *sql.DB) ([]SyncWindow, error) {
+	rows, err := db.Query(`SELECT posted_from, posted_to FROM sync_runs
+		WHERE context IN ('incremental', 'backfill')
+		AND error_message IS NULL AND rate_limited = 0
+		AND posted_from IS NOT NULL AND posted_to IS NOT NULL`)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+
+	var windows []SyncWindow
+	for rows.Next() {
+		var w SyncWindow
+		if err := rows.Scan(&w.From, &w.To); err != nil {
+			return nil, err
+		}
+		windows = append(windows, w)
 	}
-	return "", nil
+	return windows, rows.Err()
 }
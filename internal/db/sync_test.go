@@ -0,0 +1,146 @@
+package db
+
+import "testing"
+
+func TestListFailedSyncRuns(t *testing.T) {
+	d := openTestDB(t)
+	msg := "api error 500"
+	InsertSyncRun(d, "incremental", "01/01/2026", "01/03/2026", 1, 10, false, nil)
+	InsertSyncRun(d, "backfill", "10/01/2025", "12/31/2025", 2, 0, true, nil)
+	InsertSyncRun(d, "backfill", "07/01/2025", "09/30/2025", 1, 0, false, &msg)
+
+	all, err := ListSyncRuns(d, 10)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(all) != 3 {
+		t.Fatalf("ListSyncRuns returned %d runs, want 3", len(all))
+	}
+
+	failed, err := ListFailedSyncRuns(d, 10)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(failed) != 2 {
+		t.Fatalf("ListFailedSyncRuns returned %d runs, want 2", len(failed))
+	}
+	if failed[0].ErrorMessage == nil || *failed[0].ErrorMessage != msg {
+		t.Errorf("newest failed run = %+v, want the errored one", failed[0])
+	}
+	if !failed[1].RateLimited {
+		t.Errorf("second failed run = %+v, want the rate-limited one", failed[1])
+	}
+}
+
+func TestDeleteSyncState(t *testing.T) {
+	d := openTestDB(t)
+	if err := SetSyncState(d, "backfill_cursor", "01/01/2026"); err != nil {
+		t.Fatal(err)
+	}
+	if err := DeleteSyncState(d, "backfill_cursor"); err != nil {
+		t.Fatal(err)
+	}
+	if v, err := GetSyncState(d, "backfill_cursor"); err != nil || v != "" {
+		t.Errorf("after delete got %q, %v; want empty", v, err)
+	}
+	// Deleting a missing key is not an error.
+	if err := DeleteSyncState(d, "backfill_cursor"); err != nil {
+		t.Errorf("second delete: %v", err)
+	}
+}
+
+func TestInsertSyncRun_Retention(t *testing.T) {
+	d := openTestDB(t)
+	for range 3 {
+		InsertSyncRun(d, "incremental", "", "", 1, 0, false, nil)
+	}
+
+	t.Setenv(LogRetentionEnv, "2")
+	InsertSyncRun(d, "backfill", "", "", 1, 0, false, nil)
+	runs, err := ListSyncRuns(d, 10)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(runs) != 2 || runs[0].Context != "backfill" {
+		t.Errorf("kept %d runs (newest %+v), want the 2 newest", len(runs), runs[0])
+	}
+
+	t.Setenv(LogRetentionEnv, "0")
+	for range 3 {
+		InsertSyncRun(d, "incremental", "", "", 1, 0, false, nil)
+	}
+	if runs, _ := ListSyncRuns(d, 10); len(runs) != 5 {
+		t.Errorf("unbounded retention kept %d runs, want 5", len(runs))
+	}
+}
+
+func TestQuerySyncRuns_OffsetAndContext(t *testing.T) {
+	d := openTestDB(t)
+	for i := 0; i < 5; i++ {
+		InsertSyncRun(d, "incremental", "", "", 1, i, false, nil)
+		InsertSyncRun(d, "backfill", "", "", 2, i, i == 4, nil)
+	}
+
+	runs, total, err := QuerySyncRuns(d, SyncRunQuery{Limit: 2, Offset: 2, Context: "backfill"})
+	if err != nil {
+		t.Fatal(err)
+	}
+	if total != 5 || len(runs) != 2 {
+		t.Fatalf("got %d runs of %d, want 2 of 5", len(runs), total)
+	}
+	if runs[0].Context != "backfill" || runs[0].RecordsFetched != 2 || runs[1].RecordsFetched != 1 {
+		t.Errorf("page = %+v, want the third and fourth newest backfill runs", runs)
+	}
+
+	if runs, total, _ := QuerySyncRuns(d, SyncRunQuery{Context: "backfill", FailedOnly: true}); total != 1 || len(runs) != 1 || !runs[0].RateLimited {
+		t.Errorf("failed backfill runs = %+v (total %d), want the one rate-limited run", runs, total)
+	}
+	if runs, _, _ := QuerySyncRuns(d, SyncRunQuery{Offset: 10}); len(runs) != 0 {
+		t.Errorf("offset past the end returned %d runs", len(runs))
+	}
+}
+
+func TestGetEarliestPostedDate_MixedFormats(t *testing.T) {
+	d := openTestDB(t)
+	earliest, err := GetEarliestPostedDate(d)
+	if err != nil || !earliest.IsZero() {
+		t.Fatalf("empty table: got %v, %v; want the zero date", earliest, err)
+	}
+
+	// Both shapes SAM.gov sends for postedDate are stored as MM/DD/YYYY, so
+	// the ISO datetime from 2025 sorts before the 2026 bare date.
+	for id, posted := range map[string]string{"bare": "01/10/2026", "iso": "2025-12-31T08:00:00-05:00"} {
+		if _, err := UpsertOpportunityFromAPI(d, map[string]any{"noticeId": id, "postedDate": posted}); err != nil {
+			t.Fatal(err)
+		}
+	}
+	var stored string
+	if err := d.QueryRow("SELECT posted_date FROM opportunities WHERE id = 'iso'").Scan(&stored); err != nil {
+		t.Fatal(err)
+	}
+	if stored != "12/31/2025" {
+		t.Errorf("stored posted_date = %q, want 12/31/2025", stored)
+	}
+	if earliest, err = GetEarliestPostedDate(d); err != nil || earliest.String() != "12/31/2025" {
+		t.Errorf("earliest = %v, %v; want 12/31/2025", earliest, err)
+	}
+
+	// Rows stored raw before normalization are rewritten by the data
+	// migration; values that don't parse are left alone and ignored.
+	if _, err := d.Exec(`INSERT INTO opportunities (id, posted_date) VALUES
+		('legacy', '2025-06-01T12:00:00-04:00'), ('junk', 'n/a')`); err != nil {
+		t.Fatal(err)
+	}
+	if _, err := d.Exec("PRAGMA user_version = 0"); err != nil {
+		t.Fatal(err)
+	}
+	if err := runDataMigrations(d); err != nil {
+		t.Fatalf("runDataMigrations: %v", err)
+	}
+	if earliest, err = GetEarliestPostedDate(d); err != nil || earliest.String() != "06/01/2025" {
+		t.Errorf("earliest after migration = %v, %v; want 06/01/2025", earliest, err)
+	}
+	if err := d.QueryRow("SELECT posted_date FROM opportunities WHERE id = 'junk'").Scan(&stored); err != nil || stored != "n/a" {
+		t.Errorf("unparseable posted_date = %q, %v; want it kept", stored, err)
+	}
+}
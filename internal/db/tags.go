@@ -0,0 +1,60 @@
+package db
+
+import (
+	"database/sql"
+	"fmt"
+	"slices"
+	"strings"
+)
+
+// TagStatuses are the triage statuses `govscout tag --status` accepts.
+var TagStatuses = []string{"interested", "pursuing", "passed"}
+
+// Tag is a local annotation on a notice.
+type Tag struct {
+	Tag       string `json:"tag"`
+	Note      string `json:"note"`
+	CreatedAt string `json:"created_at"`
+}
+
+// SetTag tags noticeID, replacing the note (and timestamp) if the tag is
+// already there. The opportunity doesn't need to be stored.
+func SetTag(db *sql.DB, noticeID, tag, note string) error {
+	if !slices.Contains(TagStatuses, tag) {
+		return fmt.Errorf("unknown tag %q (want one of %s)", tag, strings.Join(TagStatuses, ", "))
+	}
+	_, err := db.Exec(`INSERT INTO tags (notice_id, tag, note) VALUES (?, ?, ?)
+		ON CONFLICT(notice_id, tag) DO UPDATE SET note = excluded.note, created_at = datetime('now')`,
+		noticeID, tag, note)
+	return err
+}
+
+// DeleteTag removes tag from noticeID, reporting whether it was there.
+func DeleteTag(db *sql.DB, noticeID, tag string) (bool, error) {
+	res, err := db.Exec(`DELETE FROM tags WHERE notice_id = ? AND tag = ?`, noticeID, tag)
+	if err != nil {
+		return false, err
+	}
+	n, err := res.RowsAffected()
+	return n > 0, err
+}
+
+// ListTags returns noticeID's tags, newest first.
+func ListTags(db *sql.DB, noticeID string) ([]Tag, error) {
+	rows, err := db.Query(`SELECT tag, note, created_at FROM tags
+		WHERE notice_id = ? ORDER BY created_at DESC, tag`, noticeID)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+
+	var tags []Tag
+	for rows.Next() {
+		var t Tag
+		if err := rows.Scan(&t.Tag, &t.Note, &t.CreatedAt); err != nil {
+			return nil, err
+		}
+		tags = append(tags, t)
+	}
+	return tags, rows.Err()
+}
@@ -0,0 +1,57 @@
+package db
+
+import "testing"
+
+func TestTags(t *testing.T) {
+	d := openTestDB(t)
+	if _, err := d.Exec(`INSERT INTO opportunities (id, title) VALUES ('a', 'A'), ('b', 'B')`); err != nil {
+		t.Fatal(err)
+	}
+
+	if err := SetTag(d, "a", "pursuing", "call the CO"); err != nil {
+		t.Fatal(err)
+	}
+	if err := SetTag(d, "a", "pursuing", "bid due Friday"); err != nil {
+		t.Fatal(err)
+	}
+	if err := SetTag(d, "b", "passed", ""); err != nil {
+		t.Fatal(err)
+	}
+	if err := SetTag(d, "a", "maybe", ""); err == nil {
+		t.Error("expected an error for an unknown tag")
+	}
+
+	tags, err := ListTags(d, "a")
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(tags) != 1 || tags[0].Tag != "pursuing" || tags[0].Note != "bid due Friday" {
+		t.Errorf("ListTags(a) = %+v, want one pursuing tag with the latest note", tags)
+	}
+
+	res, err := ListOpportunities(d, ListFilters{Tag: "pursuing,interested", Limit: 10})
+	if err != nil {
+		t.Fatal(err)
+	}
+	if res.Total != 1 || res.Opportunities[0].ID != "a" {
+		t.Errorf("Tag filter = %d results %+v, want only 'a'", res.Total, res.Opportunities)
+	}
+
+	// Tags belong to the notice ID, not the row, so a re-created row keeps them.
+	if _, err := d.Exec(`DELETE FROM opportunities WHERE id = 'a'`); err != nil {
+		t.Fatal(err)
+	}
+	if _, err := d.Exec(`INSERT INTO opportunities (id, title) VALUES ('a', 'A again')`); err != nil {
+		t.Fatal(err)
+	}
+	if res, err := ListOpportunities(d, ListFilters{Tag: "pursuing", Limit: 10}); err != nil || res.Total != 1 {
+		t.Errorf("after re-create: %v, %v; want 1 result", res, err)
+	}
+
+	if ok, err := DeleteTag(d, "a", "pursuing"); err != nil || !ok {
+		t.Errorf("DeleteTag = %v, %v; want true", ok, err)
+	}
+	if ok, _ := DeleteTag(d, "a", "pursuing"); ok {
+		t.Error("DeleteTag of a missing tag reported true")
+	}
+}
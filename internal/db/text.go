@@ -0,0 +1,60 @@
+package db
+
+import (
+	"html"
+	"strings"
+)
+
+// StripHTML drops tags from SAM.gov description HTML, leaving a space where
+// each tag was so adjacent words don't run together.
+func StripHTML(s string) string {
+	var buf strings.Builder
+	inTag := false
+	for _, r := range s {
+		if r == '<' {
+			inTag = true
+			continue
+		}
+		if r == '>' {
+			inTag = false
+			buf.WriteRune(' ')
+			continue
+		}
+		if !inTag {
+			buf.WriteRune(r)
+		}
+	}
+	return buf.String()
+}
+
+// DescriptionText is the description_text stored alongside description: the
+// markup stripped, entities decoded, and whitespace collapsed. Nil stays nil.
+func DescriptionText(description *string) *string {
+	if description == nil {
+		return nil
+	}
+	text := strings.Join(strings.Fields(html.UnescapeString(StripHTML(*description))), " ")
+	return &text
+}
+
+// wordsPerMinute is the reading speed ReadingMinutes assumes.
+const wordsPerMinute = 200
+
+// WordCount is the description_word_count stored alongside
+// description_text: its whitespace-separated words. Nil stays nil.
+func WordCount(text *string) *int64 {
+	if text == nil {
+		return nil
+	}
+	n := int64(len(strings.Fields(*text)))
+	return &n
+}
+
+// ReadingMinutes estimates reading time for words at wordsPerMinute,
+// rounded up so any text reads as at least a minute; 0 without words.
+func ReadingMinutes(words *int64) int64 {
+	if words == nil || *words <= 0 {
+		return 0
+	}
+	return (*words + wordsPerMinute - 1) / wordsPerMinute
+}
@@ -0,0 +1,53 @@
+package db
+
+import (
+	"strings"
+	"testing"
+)
+
+func TestDescriptionText(t *testing.T) {
+	tests := []struct {
+		in   string
+		want string
+	}{
+		{in: "<p>Build&nbsp;widgets</p><ul><li>Fast</li><li>Cheap</li></ul>", want: "Build widgets Fast Cheap"},
+		{in: "Plain   text\n\nalready", want: "Plain text already"},
+		{in: "AT&amp;T <b>only</b>", want: "AT&T only"},
+		{in: "", want: ""},
+	}
+	for _, tt := range tests {
+		if got := DescriptionText(&tt.in); got == nil || *got != tt.want {
+			t.Errorf("DescriptionText(%q) = %v, want %q", tt.in, got, tt.want)
+		}
+	}
+	if got := DescriptionText(nil); got != nil {
+		t.Errorf("DescriptionText(nil) = %q, want nil", *got)
+	}
+}
+
+func TestWordCountAndReadingMinutes(t *testing.T) {
+	n := func(v int64) *int64 { return &v }
+	tests := []struct {
+		text    string
+		words   int64
+		minutes int64
+	}{
+		{text: "", words: 0, minutes: 0},
+		{text: "Build widgets fast", words: 3, minutes: 1},
+		{text: strings.Repeat("word ", 200), words: 200, minutes: 1},
+		{text: strings.Repeat("word ", 1201), words: 1201, minutes: 7},
+	}
+	for _, tt := range tests {
+		got := WordCount(&tt.text)
+		if got == nil || *got != tt.words {
+			t.Errorf("WordCount(%.20q) = %v, want %d", tt.text, got, tt.words)
+			continue
+		}
+		if m := ReadingMinutes(got); m != tt.minutes {
+			t.Errorf("ReadingMinutes(%d) = %d, want %d", tt.words, m, tt.minutes)
+		}
+	}
+	if WordCount(nil) != nil || ReadingMinutes(nil) != 0 || ReadingMinutes(n(0)) != 0 {
+		t.Error("nil text should have no count and no reading time")
+	}
+}
@@ -0,0 +1,60 @@
+package db
+
+import (
+	"database/sql"
+	"fmt"
+)
+
+// postedDateISO reshapes the stored MM/DD/YYYY posted_date into YYYY-MM-DD
+// so SQLite's date functions can read it. Malformed or missing dates come
+// out as strings date() and strftime() turn into NULL.
+const postedDateISO = "substr(posted_date,7,4)||'-'||substr(posted_date,1,2)||'-'||substr(posted_date,4,2)"
+
+// timelineBuckets maps each TimelineBucket name to the SQL expression that
+// labels a row's period. Weeks run Monday to Sunday and are labeled by
+// their Monday.
+var timelineBuckets = map[string]string{
+	"day":   "date(" + postedDateISO + ")",
+	"week":  "date(" + postedDateISO + ", 'weekday 0', '-6 days')",
+	"month": "strftime('%Y-%m', " + postedDateISO + ")",
+}
+
+// TimelineBuckets lists the accepted bucket names, for error messages.
+var TimelineBuckets = []string{"day", "week", "month"}
+
+// TimelinePoint is the number of opportunities posted in one period:
+// YYYY-MM for months, YYYY-MM-DD for days and for the Monday starting a week.
+type TimelinePoint struct {
+	Period string `json:"period"`
+	Count  int64  `json:"count"`
+}
+
+// GetPostedTimeline counts the opportunities matching f per bucket of
+// posted_date, oldest period first. Periods with no opportunities are
+// omitted, as are rows without a readable posted date. Paging and sort
+// fields of f are ignored.
+func GetPostedTimeline(database *sql.DB, f ListFilters, bucket string) ([]TimelinePoint, error) {
+	expr, ok := timelineBuckets[bucket]
+	if !ok {
+		return nil, fmt.Errorf("unknown timeline bucket %q", bucket)
+	}
+	qb := filterQuery(f)
+	query := fmt.Sprintf(`SELECT period, COUNT(*) FROM (
+		SELECT %s AS period FROM opportunities %s
+	) WHERE period IS NOT NULL GROUP BY period ORDER BY period`, expr, qb.whereSQL())
+	rows, err := database.Query(query, qb.params...)
+	if err != nil {
+		return nil, fmt.Errorf("timeline: %w", err)
+	}
+	defer rows.Close()
+
+	points := []TimelinePoint{}
+	for rows.Next() {
+		var p TimelinePoint
+		if err := rows.Scan(&p.Period, &p.Count); err != nil {
+			return nil, fmt.Errorf("timeline: %w", err)
+		}
+		points = append(points, p)
+	}
+	return points, rows.Err()
+}
@@ -0,0 +1,42 @@
+package db
+
+import (
+	"reflect"
+	"testing"
+)
+
+func TestGetPostedTimeline(t *testing.T) {
+	d := openTestDB(t)
+	if _, err := d.Exec(`INSERT INTO opportunities (id, title, posted_date, naics_code) VALUES
+		('a', 'x', '01/05/2025', '541511'), ('b', 'x', '01/06/2025', '541511'),
+		('c', 'x', '01/12/2025', '336411'), ('d', 'x', '03/01/2025', '541511'),
+		('e', 'x', NULL, '541511'), ('f', 'x', 'garbage', '541511')`); err != nil {
+		t.Fatal(err)
+	}
+
+	tests := []struct {
+		bucket string
+		f      ListFilters
+		want   []TimelinePoint
+	}{
+		{"month", ListFilters{}, []TimelinePoint{{"2025-01", 3}, {"2025-03", 1}}},
+		// 01/05/2025 is a Sunday, so it closes the week starting 12/30/2024.
+		{"week", ListFilters{}, []TimelinePoint{{"2024-12-30", 1}, {"2025-01-06", 2}, {"2025-02-24", 1}}},
+		{"day", ListFilters{DateFrom: "01/06/2025", DateTo: "01/31/2025"}, []TimelinePoint{{"2025-01-06", 1}, {"2025-01-12", 1}}},
+		{"month", ListFilters{NAICSCode: "541511"}, []TimelinePoint{{"2025-01", 2}, {"2025-03", 1}}},
+		{"month", ListFilters{NAICSCode: "999999"}, []TimelinePoint{}},
+	}
+	for _, tt := range tests {
+		got, err := GetPostedTimeline(d, tt.f, tt.bucket)
+		if err != nil {
+			t.Fatalf("%s %+v: %v", tt.bucket, tt.f, err)
+		}
+		if !reflect.DeepEqual(got, tt.want) {
+			t.Errorf("%s %+v = %v, want %v", tt.bucket, tt.f, got, tt.want)
+		}
+	}
+
+	if _, err := GetPostedTimeline(d, ListFilters{}, "year"); err == nil {
+		t.Error("unknown bucket: want an error")
+	}
+}
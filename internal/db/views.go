@@ -0,0 +1,86 @@
+package db
+
+import (
+	"database/sql"
+	"encoding/json"
+	"errors"
+	"strings"
+)
+
+// ErrViewExists is returned by CreateSearchView when the user already has a
+// view with that name.
+var ErrViewExists = errors.New("view already exists")
+
+// SearchView is a named filter set. Filters is stored and returned verbatim;
+// it's whatever JSON object the client saved.
+type SearchView struct {
+	Name      string          `json:"name"`
+	Filters   json.RawMessage `json:"filters"`
+	CreatedAt string          `json:"created_at"`
+}
+
+// CreateSearchView stores a view for userID, returning ErrViewExists if the
+// name is taken.
+func CreateSearchView(db *sql.DB, userID int64, name string, filters json.RawMessage) (*SearchView, error) {
+	var v SearchView
+	var raw string
+	err := db.QueryRow(`INSERT INTO search_views (user_id, name, filters) VALUES (?, ?, ?)
+		RETURNING name, filters, created_at`, userID, name, string(filters)).Scan(&v.Name, &raw, &v.CreatedAt)
+	if err != nil {
+		if strings.Contains(err.Error(), "UNIQUE constraint failed") {
+			return nil, ErrViewExists
+		}
+		return nil, err
+	}
+	v.Filters = json.RawMessage(raw)
+	return &v, nil
+}
+
+// ListSearchViews returns userID's views ordered by name.
+func ListSearchViews(db *sql.DB, userID int64) ([]SearchView, error) {
+	rows, err := db.Query(`SELECT name, filters, created_at FROM search_views
+		WHERE user_id = ? ORDER BY name`, userID)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+
+	var views []SearchView
+	for rows.Next() {
+		var v SearchView
+		var raw string
+		if err := rows.Scan(&v.Name, &raw, &v.CreatedAt); err != nil {
+			return nil, err
+		}
+		v.Filters = json.RawMessage(raw)
+		views = append(views, v)
+	}
+	return views, rows.Err()
+}
+
+// GetSearchView returns userID's view called name, or nil if there isn't one.
+func GetSearchView(db *sql.DB, userID int64, name string) (*SearchView, error) {
+	var v SearchView
+	var raw string
+	err := db.QueryRow(`SELECT name, filters, created_at FROM search_views
+		WHERE user_id = ? AND name = ?`, userID, name).Scan(&v.Name, &raw, &v.CreatedAt)
+	if err == sql.ErrNoRows {
+		return nil, nil
+	}
+	if err != nil {
+		return nil, err
+	}
+	v.Filters = json.RawMessage(raw)
+	return &v, nil
+}
+
+// DeleteSearchView removes userID's view called name, reporting whether it
+// existed.
+func DeleteSearchView(db *sql.DB, userID int64, name string) (bool, error) {
+	res, err := db.Exec(`DELETE FROM search_views WHERE user_id = ? AND name = ?`, userID, name)
+	if err != nil {
+		return false, err
+	}
+	n, err := res.RowsAffected()
+	return n > 0, err
+}
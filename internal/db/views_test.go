@@ -0,0 +1,61 @@
+package db
+
+import (
+	"errors"
+	"testing"
+)
+
+func TestSearchViews(t *testing.T) {
+	d := openTestDB(t)
+	for _, name := range []string{"alice", "bob"} {
+		if err := CreateUser(d, name, "x", false); err != nil {
+			t.Fatal(err)
+		}
+	}
+	alice, _ := GetUserByUsername(d, "alice")
+	bob, _ := GetUserByUsername(d, "bob")
+
+	v, err := CreateSearchView(d, alice.ID, "cyber", []byte(`{"search":"cyber"}`))
+	if err != nil {
+		t.Fatal(err)
+	}
+	if v.Name != "cyber" || string(v.Filters) != `{"search":"cyber"}` || v.CreatedAt == "" {
+		t.Errorf("created view = %+v", v)
+	}
+	if _, err := CreateSearchView(d, alice.ID, "cyber", []byte(`{}`)); !errors.Is(err, ErrViewExists) {
+		t.Errorf("duplicate create err = %v, want ErrViewExists", err)
+	}
+	// Names are per user.
+	if _, err := CreateSearchView(d, bob.ID, "cyber", []byte(`{}`)); err != nil {
+		t.Errorf("other user's view with the same name: %v", err)
+	}
+	if _, err := CreateSearchView(d, alice.ID, "aerospace", []byte(`{"naics":"336411"}`)); err != nil {
+		t.Fatal(err)
+	}
+
+	views, err := ListSearchViews(d, alice.ID)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(views) != 2 || views[0].Name != "aerospace" || views[1].Name != "cyber" {
+		t.Errorf("ListSearchViews = %+v, want aerospace, cyber", views)
+	}
+
+	got, err := GetSearchView(d, alice.ID, "aerospace")
+	if err != nil || got == nil || string(got.Filters) != `{"naics":"336411"}` {
+		t.Errorf("GetSearchView = %+v, %v", got, err)
+	}
+	if got, err := GetSearchView(d, bob.ID, "aerospace"); err != nil || got != nil {
+		t.Errorf("GetSearchView for another user = %+v, %v; want nil", got, err)
+	}
+
+	if ok, err := DeleteSearchView(d, alice.ID, "cyber"); err != nil || !ok {
+		t.Errorf("DeleteSearchView = %v, %v; want true", ok, err)
+	}
+	if ok, err := DeleteSearchView(d, alice.ID, "cyber"); err != nil || ok {
+		t.Errorf("second DeleteSearchView = %v, %v; want false", ok, err)
+	}
+	if got, _ := GetSearchView(d, bob.ID, "cyber"); got == nil {
+		t.Error("deleting alice's view removed bob's")
+	}
+}
@@ -0,0 +1,57 @@
+package samgov
+
+import (
+	"crypto/sha256"
+	"encoding/hex"
+	"fmt"
+	"net/url"
+	"time"
+)
+
+// ResponseCache stores raw search responses by key. Implementations decide
+// where entries live (see db.ResponseCache for the SQLite-backed one).
+type ResponseCache interface {
+	// Get returns the body stored under key if it is younger than maxAge.
+	Get(key string, maxAge time.Duration) ([]byte, bool)
+	Put(key string, body []byte) error
+}
+
+// WithResponseCache serves repeated searches from cache while entries are
+// younger than ttl. A nil cache or non-positive ttl disables caching.
+func WithResponseCache(cache ResponseCache, ttl time.Duration) ClientOption {
+	return func(c *Client) {
+		c.cache = cache
+		c.cacheTTL = ttl
+	}
+}
+
+// CacheKey hashes the normalized search parameters. The API key is never part
+// of the input, so rotating or changing keys doesn't split the cache and no
+// secret ends up in the database.
+func CacheKey(params SearchParams) string {
+	q := url.Values{}
+	q.Set("limit", fmt.Sprintf("%d", params.Limit))
+	q.Set("offset", fmt.Sprintf("%d", params.Offset))
+	set := func(k, v string) {
+		if v != "" {
+			q.Set(k, v)
+		}
+	}
+	if params.NoticeID != "" {
+		set("noticeid", string(params.NoticeID))
+	} else {
+		set("postedFrom", params.PostedFrom.String())
+		set("postedTo", params.PostedTo.String())
+	}
+	set("title", params.Title)
+	set("ptype", params.Type)
+	set("ncode", params.NAICS)
+	set("ccode", params.ClassificationCode)
+	set("state", params.State)
+	set("typeOfSetAside", params.SetAside)
+	set("solnum", params.SolicitationNumber)
+
+	// Encode sorts by key, giving a stable representation.
+	sum := sha256.Sum256([]byte(q.Encode()))
+	return hex.EncodeToString(sum[:])
+}
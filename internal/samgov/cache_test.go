@@ -0,0 +1,132 @@
+package samgov
+
+import (
+	"context"
+	"fmt"
+	"net/http"
+	"net/http/httptest"
+	"strings"
+	"sync/atomic"
+	"testing"
+	"time"
+)
+
+type memCache map[string][]byte
+
+func (m memCache) Get(key string, _ time.Duration) ([]byte, bool) {
+	b, ok := m[key]
+	return b, ok
+}
+
+func (m memCache) Put(key string, body []byte) error {
+	m[key] = body
+	return nil
+}
+
+func TestClient_Search_ServesRepeatsFromCache(t *testing.T) {
+	var calls atomic.Int64
+	srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		calls.Add(1)
+		fmt.Fprint(w, `{"totalRecords":1,"opportunitiesData":[{"noticeId":"a"}]}`)
+	}))
+	defer srv.Close()
+
+	cache := memCache{}
+	c, err := NewClient("secret-key", WithResponseCache(cache, time.Minute))
+	if err != nil {
+		t.Fatal(err)
+	}
+	c.baseURL = srv.URL
+
+	params := SearchParams{Limit: 10, PostedFrom: mustParseDate("01/01/2026"), PostedTo: mustParseDate("01/31/2026")}
+	for i := 0; i < 2; i++ {
+		resp, err := c.Search(params)
+		if err != nil {
+			t.Fatalf("Search #%d: %v", i+1, err)
+		}
+		if len(resp.OpportunitiesData) != 1 {
+			t.Fatalf("Search #%d: got %d opps, want 1", i+1, len(resp.OpportunitiesData))
+		}
+	}
+	if calls.Load() != 1 {
+		t.Errorf("expected 1 HTTP call with warm cache, got %d", calls.Load())
+	}
+
+	// A different window is a different key.
+	if _, err := c.Search(SearchParams{Limit: 10, PostedFrom: mustParseDate("02/01/2026"), PostedTo: mustParseDate("02/28/2026")}); err != nil {
+		t.Fatal(err)
+	}
+	if calls.Load() != 2 {
+		t.Errorf("expected cache miss for new params, calls = %d", calls.Load())
+	}
+
+	for k, body := range cache {
+		if strings.Contains(k, "secret-key") || strings.Contains(string(body), "secret-key") {
+			t.Error("api key leaked into cache")
+		}
+	}
+}
+
+func TestCacheKey_IgnoresDateWindowForNoticeID(t *testing.T) {
+	a := CacheKey(SearchParams{Limit: 1, NoticeID: "abc", PostedFrom: mustParseDate("01/01/2026")})
+	b := CacheKey(SearchParams{Limit: 1, NoticeID: "abc"})
+	if a != b {
+		t.Error("posted dates are not sent with noticeid and must not change the key")
+	}
+	if a == CacheKey(SearchParams{Limit: 1, NoticeID: "abd"}) {
+		t.Error("different notice IDs produced the same key")
+	}
+}
+
+func TestCacheKey_ClassificationCode(t *testing.T) {
+	base := SearchParams{Limit: 1000, NAICS: "541512"}
+	withPSC := base
+	withPSC.ClassificationCode = "D302"
+	if CacheKey(base) == CacheKey(withPSC) {
+		t.Error("a PSC filter must change the cache key")
+	}
+}
+
+func TestClient_Search_NoCacheBypassesCache(t *testing.T) {
+	var calls atomic.Int64
+	srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		calls.Add(1)
+		fmt.Fprint(w, `{"totalRecords":0,"opportunitiesData":[]}`)
+	}))
+	defer srv.Close()
+
+	cache := memCache{}
+	c, err := NewClient("k1", WithResponseCache(cache, time.Minute))
+	if err != nil {
+		t.Fatal(err)
+	}
+	c.baseURL = srv.URL
+
+	params := SearchParams{Limit: 10, PostedFrom: mustParseDate("01/01/2026"), PostedTo: mustParseDate("01/31/2026"), NoCache: true}
+	for i := 0; i < 2; i++ {
+		resp, err := c.Search(params)
+		if err != nil {
+			t.Fatal(err)
+		}
+		if resp.Cached {
+			t.Errorf("Search #%d came from cache despite NoCache", i+1)
+		}
+	}
+	if calls.Load() != 2 || len(cache) != 0 {
+		t.Errorf("calls = %d, cache entries = %d; want 2 and 0", calls.Load(), len(cache))
+	}
+}
+
+func TestSearchWindow_CacheHitsAreNotAPICalls(t *testing.T) {
+	total := int64(1)
+	search := func(ctx context.Context, p SearchParams) (*APIResponse, error) {
+		return &APIResponse{TotalRecords: &total, OpportunitiesData: []map[string]any{{"noticeId": "a"}}, Cached: true}, nil
+	}
+	res, err := searchWindow(context.Background(), search, NewDate(time.Now()), NewDate(time.Now()), SearchParams{}, func([]map[string]any) error { return nil })
+	if err != nil {
+		t.Fatal(err)
+	}
+	if res.APICalls != 0 || res.CacheHits != 1 || res.TotalFetched != 1 {
+		t.Errorf("result = %+v, want 0 API calls, 1 cache hit, 1 record", *res)
+	}
+}
@@ -6,8 +6,11 @@ import (
 	"errors"
 	"fmt"
 	"io"
+	"log"
+	"net"
 	"net/http"
 	"net/url"
+	"os"
 	"strconv"
 	"strings"
 	"sync/atomic"
@@ -16,12 +19,51 @@ import (
 
 var ErrRateLimited = errors.New("rate limited: all API keys exhausted")
 
+// ErrMaintenance means SAM.gov answered with its scheduled-downtime page
+// rather than an API response. It is not retried: planned outages last far
+// longer than any backoff.
+var ErrMaintenance = errors.New("SAM.gov is down for maintenance")
+
+// Timeouts used when neither WithTimeouts nor the GOVSCOUT_CONNECT_TIMEOUT /
+// GOVSCOUT_REQUEST_TIMEOUT environment variables say otherwise. The connect
+// timeout covers dialing and the TLS handshake; the request timeout covers the
+// whole exchange, body included.
+const (
+	DefaultConnectTimeout = 10 * time.Second
+	DefaultRequestTimeout = 30 * time.Second
+)
+
+// Version is stamped at build time with -ldflags "-X .../samgov.Version=...".
+var Version = "dev"
+
+// DefaultUserAgent returns GOVSCOUT_USER_AGENT when set, otherwise
+// govscout/<version>.
+func DefaultUserAgent() string {
+	if ua := os.Getenv("GOVSCOUT_USER_AGENT"); ua != "" {
+		return ua
+	}
+	return "govscout/" + Version
+}
+
+// Source is where sync gets opportunities from: the live Client, or a
+// MockClient serving fixtures (see NewSourceFromEnv).
+type Source interface {
+	SearchCtx(ctx context.Context, params SearchParams) (*APIResponse, error)
+	SearchWindowCtx(ctx context.Context, from, to Date, filter SearchParams, onPage func([]map[string]any) error) (*WindowResult, error)
+}
+
 type Client struct {
 	keys        []string
 	current     atomic.Int64
 	http        *http.Client
 	baseURL     string
 	retryPolicy RetryPolicy
+	cache       ResponseCache
+	cacheTTL    time.Duration
+	userAgent   string
+
+	connectTimeout time.Duration
+	requestTimeout time.Duration
 }
 
 type ClientOption func(*Client)
@@ -30,36 +72,119 @@ func WithRetryPolicy(p RetryPolicy) ClientOption {
 	return func(c *Client) { c.retryPolicy = p }
 }
 
+// WithHTTPClient replaces the HTTP client outright; WithTimeouts then has no
+// effect.
 func WithHTTPClient(h *http.Client) ClientOption {
 	return func(c *Client) { c.http = h }
 }
 
+// WithTimeouts overrides the connect and overall request timeouts. A zero
+// value keeps the environment or default setting for that timeout.
+func WithTimeouts(connect, request time.Duration) ClientOption {
+	return func(c *Client) {
+		if connect > 0 {
+			c.connectTimeout = connect
+		}
+		if request > 0 {
+			c.requestTimeout = request
+		}
+	}
+}
+
+// envDuration reads a positive duration such as "15s" from the environment,
+// returning fallback when the variable is unset.
+func envDuration(name string, fallback time.Duration) (time.Duration, error) {
+	v := strings.TrimSpace(os.Getenv(name))
+	if v == "" {
+		return fallback, nil
+	}
+	d, err := time.ParseDuration(v)
+	if err != nil || d <= 0 {
+		return 0, fmt.Errorf("%s: invalid duration %q", name, v)
+	}
+	return d, nil
+}
+
+// WithUserAgent overrides the User-Agent header sent to SAM.gov.
+func WithUserAgent(ua string) ClientOption {
+	return func(c *Client) { c.userAgent = ua }
+}
+
+// apiKeyHelp points users at where SAM.gov issues public API keys.
+const apiKeyHelp = "get a public API key from https://sam.gov (sign in, then Account Details > Public API Key)"
+
+// maxAPIKeyLen bounds a single key; SAM.gov keys are 40 characters, so
+// anything far longer is a pasted value gone wrong.
+const maxAPIKeyLen = 128
+
 func NewClient(apiKeyEnv string, opts ...ClientOption) (*Client, error) {
-	if apiKeyEnv == "" {
-		return nil, errors.New("SAMGOV_API_KEY is required")
+	if strings.TrimSpace(apiKeyEnv) == "" {
+		return nil, errors.New("SAMGOV_API_KEY is required; " + apiKeyHelp)
 	}
 	var keys []string
 	for k := range strings.SplitSeq(apiKeyEnv, ",") {
 		k = strings.TrimSpace(k)
-		if k != "" {
-			keys = append(keys, k)
+		if k == "" {
+			continue
+		}
+		if err := validateAPIKey(k); err != nil {
+			return nil, fmt.Errorf("SAMGOV_API_KEY key %d: %w; %s", len(keys)+1, err, apiKeyHelp)
 		}
+		keys = append(keys, k)
 	}
 	if len(keys) == 0 {
-		return nil, errors.New("no valid API keys found")
+		return nil, errors.New("no valid API keys found in SAMGOV_API_KEY; " + apiKeyHelp)
+	}
+	connectTimeout, err := envDuration("GOVSCOUT_CONNECT_TIMEOUT", DefaultConnectTimeout)
+	if err != nil {
+		return nil, err
+	}
+	requestTimeout, err := envDuration("GOVSCOUT_REQUEST_TIMEOUT", DefaultRequestTimeout)
+	if err != nil {
+		return nil, err
 	}
 	c := &Client{
-		keys:        keys,
-		http:        &http.Client{Timeout: 30 * time.Second},
-		baseURL:     "https://api.sam.gov/opportunities/v2/search",
-		retryPolicy: DefaultRetryPolicy,
+		keys:           keys,
+		baseURL:        "https://api.sam.gov/opportunities/v2/search",
+		retryPolicy:    DefaultRetryPolicy,
+		userAgent:      DefaultUserAgent(),
+		connectTimeout: connectTimeout,
+		requestTimeout: requestTimeout,
 	}
 	for _, opt := range opts {
 		opt(c)
 	}
+	if c.http == nil {
+		transport := http.DefaultTransport.(*http.Transport).Clone()
+		transport.DialContext = (&net.Dialer{Timeout: c.connectTimeout, KeepAlive: 30 * time.Second}).DialContext
+		transport.TLSHandshakeTimeout = c.connectTimeout
+		c.http = &http.Client{Timeout: c.requestTimeout, Transport: transport}
+	}
+	c.userAgent = strings.TrimSpace(c.userAgent)
+	if c.userAgent == "" {
+		return nil, errors.New("user agent must not be empty")
+	}
 	return c, nil
 }
 
+// validateAPIKey catches keys that can only fail with a confusing 401/403:
+// the .env.example placeholder, copied quotes or whitespace, and overlong
+// values. The key itself is never echoed back.
+func validateAPIKey(k string) error {
+	if strings.EqualFold(k, "your-api-key-here") {
+		return errors.New("is still the .env.example placeholder")
+	}
+	if len(k) > maxAPIKeyLen {
+		return fmt.Errorf("is %d characters, longer than any SAM.gov key", len(k))
+	}
+	for _, r := range k {
+		if !(r >= 'a' && r <= 'z' || r >= 'A' && r <= 'Z' || r >= '0' && r <= '9' || r == '-' || r == '_') {
+			return fmt.Errorf("contains %q; SAM.gov keys are letters, digits, '-' and '_' only", r)
+		}
+	}
+	return nil
+}
+
 func (c *Client) currentKey() string {
 	idx := c.current.Load() % int64(len(c.keys))
 	return c.keys[idx]
@@ -75,7 +200,25 @@ func (c *Client) Search(params SearchParams) (*APIResponse, error) {
 }
 
 // SearchCtx performs a single search call with retries, backoff, and key rotation.
+// When a response cache is configured, fresh cached responses are returned
+// without touching the network (marked Cached) unless params.NoCache is set.
 func (c *Client) SearchCtx(ctx context.Context, params SearchParams) (*APIResponse, error) {
+	if err := params.Validate(); err != nil {
+		return nil, err
+	}
+	useCache := c.cache != nil && c.cacheTTL > 0 && !params.NoCache
+	var key string
+	if useCache {
+		key = CacheKey(params)
+		if body, ok := c.cache.Get(key, c.cacheTTL); ok {
+			var cached APIResponse
+			if err := json.Unmarshal(body, &cached); err == nil {
+				cached.Cached = true
+				return &cached, nil
+			}
+		}
+	}
+
 	var resp *APIResponse
 	err := Do(ctx, c.retryPolicy, func(ctx context.Context) error {
 		r, err := c.searchOnce(ctx, params)
@@ -85,13 +228,22 @@ func (c *Client) SearchCtx(ctx context.Context, params SearchParams) (*APIRespon
 		resp = r
 		return nil
 	})
+	if err == nil && useCache {
+		if body, merr := json.Marshal(resp); merr == nil {
+			if perr := c.cache.Put(key, body); perr != nil {
+				log.Printf("response cache put: %v", perr)
+			}
+		}
+	}
 	return resp, err
 }
 
 // searchOnce executes a single logical search, cycling through keys on 401/403/429
 // until either success, a non-retryable error, or all keys fail. If all keys fail
 // within this cycle, it returns a Retryable ErrRateLimited so the outer Do loop
-// can back off and try again (honoring Retry-After when seen).
+// can back off and try again (honoring Retry-After when seen). A maintenance
+// page ends in ErrMaintenance. Other failures are *APIError; network and
+// server errors are retryable.
 func (c *Client) searchOnce(ctx context.Context, params SearchParams) (*APIResponse, error) {
 	startIdx := c.current.Load()
 	var retryAfter time.Duration
@@ -108,13 +260,13 @@ func (c *Client) searchOnce(ctx context.Context, params SearchParams) (*APIRespo
 		q.Set("offset", fmt.Sprintf("%d", params.Offset))
 
 		if params.NoticeID != "" {
-			q.Set("noticeid", params.NoticeID)
+			q.Set("noticeid", string(params.NoticeID))
 		} else {
-			if params.PostedFrom != "" {
-				q.Set("postedFrom", params.PostedFrom)
+			if !params.PostedFrom.IsZero() {
+				q.Set("postedFrom", params.PostedFrom.String())
 			}
-			if params.PostedTo != "" {
-				q.Set("postedTo", params.PostedTo)
+			if !params.PostedTo.IsZero() {
+				q.Set("postedTo", params.PostedTo.String())
 			}
 		}
 		if params.Title != "" {
@@ -126,29 +278,36 @@ func (c *Client) searchOnce(ctx context.Context, params SearchParams) (*APIRespo
 		if params.NAICS != "" {
 			q.Set("ncode", params.NAICS)
 		}
+		if params.ClassificationCode != "" {
+			q.Set("ccode", params.ClassificationCode)
+		}
 		if params.State != "" {
 			q.Set("state", params.State)
 		}
 		if params.SetAside != "" {
 			q.Set("typeOfSetAside", params.SetAside)
 		}
+		if params.SolicitationNumber != "" {
+			q.Set("solnum", params.SolicitationNumber)
+		}
 		u.RawQuery = q.Encode()
 
 		req, err := http.NewRequestWithContext(ctx, http.MethodGet, u.String(), nil)
 		if err != nil {
 			return nil, err
 		}
+		req.Header.Set("User-Agent", c.userAgent)
 		resp, err := c.http.Do(req)
 		if err != nil {
 			if ctx.Err() != nil {
 				return nil, ctx.Err()
 			}
-			return nil, Retryable(fmt.Errorf("http get: %w", err))
+			return nil, Retryable(&APIError{Kind: KindNetwork, Err: c.transportError("http get", err)})
 		}
 		body, err := io.ReadAll(resp.Body)
 		resp.Body.Close()
 		if err != nil {
-			return nil, Retryable(fmt.Errorf("read body: %w", err))
+			return nil, Retryable(&APIError{Kind: KindNetwork, Err: c.transportError("read body", err)})
 		}
 
 		if resp.StatusCode == 429 || resp.StatusCode == 401 || resp.StatusCode == 403 {
@@ -165,22 +324,65 @@ func (c *Client) searchOnce(ctx context.Context, params SearchParams) (*APIRespo
 			continue
 		}
 
+		if isMaintenance(resp, body) {
+			return nil, ErrMaintenance
+		}
+
 		if resp.StatusCode >= 500 && resp.StatusCode <= 599 {
-			return nil, Retryable(fmt.Errorf("api error %d: %s", resp.StatusCode, truncate(string(body), 200)))
+			return nil, Retryable(&APIError{Kind: KindServer, Status: resp.StatusCode, Body: truncate(string(body), 200)})
 		}
 
 		if resp.StatusCode != 200 {
-			return nil, fmt.Errorf("api error %d: %s", resp.StatusCode, string(body))
+			return nil, &APIError{Kind: KindBadRequest, Status: resp.StatusCode, Body: string(body)}
 		}
 
 		var apiResp APIResponse
 		if err := json.Unmarshal(body, &apiResp); err != nil {
-			return nil, fmt.Errorf("decode: %w", err)
+			return nil, &APIError{Kind: KindParse, Status: resp.StatusCode, Err: err}
+		}
+		if n, err := strconv.Atoi(strings.TrimSpace(resp.Header.Get("X-RateLimit-Remaining"))); err == nil {
+			apiResp.RateLimitRemaining = &n
 		}
 		return &apiResp, nil
 	}
 }
 
+// transportError tells a host that never answered apart from one that
+// answered too slowly, and drops the request URL (which carries the API key).
+func (c *Client) transportError(op string, err error) error {
+	var ue *url.Error
+	if errors.As(err, &ue) {
+		err = ue.Err
+	}
+	var opErr *net.OpError
+	if errors.As(err, &opErr) && opErr.Op == "dial" {
+		return fmt.Errorf("could not connect to SAM.gov: %w", err)
+	}
+	var netErr net.Error
+	if errors.As(err, &netErr) && netErr.Timeout() {
+		return fmt.Errorf("request timed out after %s: %w", c.requestTimeout, err)
+	}
+	return fmt.Errorf("%s: %w", op, err)
+}
+
+// isMaintenance reports whether a 5xx is SAM.gov's maintenance page: a 503
+// serving HTML instead of JSON, or any 5xx whose body says "maintenance".
+// Plain 5xx errors stay retryable *APIErrors.
+func isMaintenance(resp *http.Response, body []byte) bool {
+	if resp.StatusCode < 500 || resp.StatusCode > 599 {
+		return false
+	}
+	text := strings.ToLower(string(body))
+	if strings.Contains(text, "maintenance") {
+		return true
+	}
+	if resp.StatusCode != http.StatusServiceUnavailable {
+		return false
+	}
+	return strings.Contains(resp.Header.Get("Content-Type"), "text/html") ||
+		strings.HasPrefix(strings.TrimSpace(text), "<")
+}
+
 func parseRetryAfter(h string) time.Duration {
 	if h == "" {
 		return 0
@@ -205,56 +407,130 @@ func truncate(s string, n int) string {
 	return s[:n] + "..."
 }
 
+// LowRateLimitRemaining is the X-RateLimit-Remaining value at or below which
+// searchWindow stops early, before SAM.gov starts answering 429.
+const LowRateLimitRemaining = 2
+
 type WindowResult struct {
-	TotalFetched int
-	APICalls     int
-	RateLimited  bool
+	TotalFetched      int // distinct notices handed to onPage
+	APICalls          int // searches that reached SAM.gov
+	CacheHits         int // pages served from the response cache instead
+	RateLimited       bool
+	Maintenance       bool // SAM.gov served its downtime page; stopped like a rate limit
+	DuplicatesSkipped int  // rows repeating a noticeId already seen in this window
+	StatusSkipped     int  // rows dropped because their active flag didn't match filter.Active
+	// ApproachingLimit: stopped with pages left because X-RateLimit-Remaining
+	// fell to LowRateLimitRemaining.
+	ApproachingLimit bool
+	// RateLimitRemaining is the last X-RateLimit-Remaining seen, or -1 when
+	// SAM.gov didn't send one (cached and mock responses never do).
+	RateLimitRemaining int
 }
 
-func (c *Client) SearchWindow(from, to string, onPage func([]map[string]any) error) (*WindowResult, error) {
-	return c.SearchWindowCtx(context.Background(), from, to, onPage)
+// LowOnCalls reports whether the key budget is nearly spent, so callers
+// should not start another window.
+func (r *WindowResult) LowOnCalls() bool {
+	return r.RateLimitRemaining >= 0 && r.RateLimitRemaining <= LowRateLimitRemaining
 }
 
-func (c *Client) SearchWindowCtx(ctx context.Context, from, to string, onPage func([]map[string]any) error) (*WindowResult, error) {
+func (c *Client) SearchWindow(from, to Date, onPage func([]map[string]any) error) (*WindowResult, error) {
+	return c.SearchWindowCtx(context.Background(), from, to, SearchParams{}, onPage)
+}
+
+func (c *Client) SearchWindowCtx(ctx context.Context, from, to Date, filter SearchParams, onPage func([]map[string]any) error) (*WindowResult, error) {
+	return searchWindow(ctx, c.SearchCtx, from, to, filter, onPage)
+}
+
+// searchWindow pages through every result posted between from and to,
+// handing each page to onPage. Shared by Client and MockClient so both page
+// identically. filter narrows the search (NAICS, set-aside, state, ...); its
+// paging, posted dates and notice ID are ignored, and the zero value
+// searches everything. SAM.gov occasionally repeats a notice across pages of
+// one result set; repeats are dropped before onPage and counted in
+// DuplicatesSkipped so TotalFetched counts distinct notices. filter.Active
+// is applied here too, after de-duplication; those rows count in
+// StatusSkipped.
+func searchWindow(ctx context.Context, search func(context.Context, SearchParams) (*APIResponse, error),
+	from, to Date, filter SearchParams, onPage func([]map[string]any) error) (*WindowResult, error) {
+	res := &WindowResult{RateLimitRemaining: -1}
 	offset := 0
-	totalFetched := 0
-	apiCalls := 0
+	rowsSeen := 0
+	seen := make(map[string]struct{})
 
 	for {
 		if err := ctx.Err(); err != nil {
-			return &WindowResult{TotalFetched: totalFetched, APICalls: apiCalls}, err
-		}
-		apiCalls++
-		resp, err := c.SearchCtx(ctx, SearchParams{
-			Limit:      1000,
-			Offset:     offset,
-			PostedFrom: from,
-			PostedTo:   to,
-		})
+			return res, err
+		}
+		if res.APICalls > 0 && res.LowOnCalls() {
+			log.Printf("window %s to %s: X-RateLimit-Remaining=%d, stopping before the limit", from, to, res.RateLimitRemaining)
+			res.ApproachingLimit = true
+			break
+		}
+		params := filter
+		params.Limit = 1000
+		params.Offset = offset
+		params.PostedFrom = from
+		params.PostedTo = to
+		params.NoticeID = ""
+		resp, err := search(ctx, params)
+		if err == nil && resp.Cached {
+			res.CacheHits++
+		} else {
+			res.APICalls++
+		}
 		if errors.Is(err, ErrRateLimited) {
-			return &WindowResult{TotalFetched: totalFetched, APICalls: apiCalls, RateLimited: true}, nil
+			res.RateLimited = true
+			break
+		}
+		if errors.Is(err, ErrMaintenance) {
+			res.Maintenance = true
+			break
 		}
 		if err != nil {
 			return nil, err
 		}
+		if resp.RateLimitRemaining != nil {
+			res.RateLimitRemaining = *resp.RateLimitRemaining
+		}
 
 		pageCount := len(resp.OpportunitiesData)
-		if pageCount > 0 {
-			if err := onPage(resp.OpportunitiesData); err != nil {
+		page := make([]map[string]any, 0, pageCount)
+		for _, rec := range resp.OpportunitiesData {
+			if id, _ := rec["noticeId"].(string); id != "" {
+				if _, dup := seen[id]; dup {
+					res.DuplicatesSkipped++
+					continue
+				}
+				seen[id] = struct{}{}
+			}
+			if filter.Active != "" {
+				if active, _ := rec["active"].(string); !strings.EqualFold(active, filter.Active) {
+					res.StatusSkipped++
+					continue
+				}
+			}
+			page = append(page, rec)
+		}
+		if len(page) > 0 {
+			if err := onPage(page); err != nil {
 				return nil, fmt.Errorf("onPage: %w", err)
 			}
 		}
-		totalFetched += pageCount
+		res.TotalFetched += len(page)
+		rowsSeen += pageCount
 
 		totalRecords := int64(0)
 		if resp.TotalRecords != nil {
 			totalRecords = *resp.TotalRecords
 		}
-		if int64(totalFetched) >= totalRecords || pageCount < 1000 {
+		if int64(rowsSeen) >= totalRecords || pageCount < 1000 {
 			break
 		}
 		offset += 1000
 	}
 
-	return &WindowResult{TotalFetched: totalFetched, APICalls: apiCalls}, nil
+	if res.DuplicatesSkipped > 0 {
+		log.Printf("window %s to %s: skipped %d duplicate notice IDs", from, to, res.DuplicatesSkipped)
+	}
+	return res, nil
 }
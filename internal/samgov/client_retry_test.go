@@ -6,6 +6,7 @@ import (
 	"fmt"
 	"net/http"
 	"net/http/httptest"
+	"strings"
 	"sync/atomic"
 	"testing"
 	"time"
@@ -140,3 +141,71 @@ func TestSearch_NetworkErrorRetries(t *testing.T) {
 		t.Errorf("unexpected ctx error: %v", err)
 	}
 }
+
+func TestSearch_ConnectErrorMessage(t *testing.T) {
+	srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {}))
+	url := srv.URL
+	srv.Close()
+
+	c, err := NewClient("secret-key", WithRetryPolicy(RetryPolicy{MaxAttempts: 1}))
+	if err != nil {
+		t.Fatal(err)
+	}
+	c.baseURL = url
+
+	_, err = c.Search(SearchParams{Limit: 1})
+	if err == nil || !strings.Contains(err.Error(), "could not connect") {
+		t.Fatalf("err = %v, want a could-not-connect error", err)
+	}
+	if strings.Contains(err.Error(), "secret-key") {
+		t.Errorf("error leaks the API key: %v", err)
+	}
+}
+
+func TestSearch_RequestTimeoutMessage(t *testing.T) {
+	srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		w.WriteHeader(http.StatusOK)
+		w.(http.Flusher).Flush()
+		select {
+		case <-r.Context().Done():
+		case <-time.After(time.Second):
+		}
+	}))
+	defer srv.Close()
+
+	c, err := NewClient("k", WithRetryPolicy(RetryPolicy{MaxAttempts: 1}), WithTimeouts(time.Second, 50*time.Millisecond))
+	if err != nil {
+		t.Fatal(err)
+	}
+	c.baseURL = srv.URL
+
+	_, err = c.Search(SearchParams{Limit: 1})
+	if err == nil || !strings.Contains(err.Error(), "request timed out") {
+		t.Fatalf("err = %v, want a request-timed-out error", err)
+	}
+}
+
+func TestNewClient_TimeoutEnv(t *testing.T) {
+	t.Setenv("GOVSCOUT_CONNECT_TIMEOUT", "3s")
+	t.Setenv("GOVSCOUT_REQUEST_TIMEOUT", "45s")
+	c, err := NewClient("k")
+	if err != nil {
+		t.Fatal(err)
+	}
+	if c.connectTimeout != 3*time.Second || c.http.Timeout != 45*time.Second {
+		t.Errorf("timeouts = %s / %s, want 3s / 45s", c.connectTimeout, c.http.Timeout)
+	}
+
+	c, err = NewClient("k", WithTimeouts(5*time.Second, 0))
+	if err != nil {
+		t.Fatal(err)
+	}
+	if c.connectTimeout != 5*time.Second || c.requestTimeout != 45*time.Second {
+		t.Errorf("WithTimeouts(5s, 0) = %s / %s, want 5s / 45s", c.connectTimeout, c.requestTimeout)
+	}
+
+	t.Setenv("GOVSCOUT_REQUEST_TIMEOUT", "soon")
+	if _, err := NewClient("k"); err == nil {
+		t.Error("expected an error for an invalid GOVSCOUT_REQUEST_TIMEOUT")
+	}
+}
@@ -1,13 +1,17 @@
 package samgov
 
 import (
+	"context"
 	"errors"
 	"fmt"
 	"net/http"
 	"net/http/httptest"
 	"net/url"
+	"reflect"
+	"strings"
 	"sync/atomic"
 	"testing"
+	"time"
 )
 
 func TestNewClient_EmptyKey(t *testing.T) {
@@ -16,6 +20,29 @@ func TestNewClient_EmptyKey(t *testing.T) {
 	}
 }
 
+func TestNewClient_MalformedKey(t *testing.T) {
+	tests := []struct {
+		name, keys, want string
+	}{
+		{"blank", "   ", "SAMGOV_API_KEY is required"},
+		{"placeholder", "your-api-key-here", "placeholder"},
+		{"quoted", `"abc123"`, `contains '"'`},
+		{"inner space", "good,abc 123", "key 2: contains ' '"},
+		{"too long", strings.Repeat("a", maxAPIKeyLen+1), "longer than any SAM.gov key"},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			_, err := NewClient(tt.keys)
+			if err == nil {
+				t.Fatal("expected an error, got nil")
+			}
+			if !strings.Contains(err.Error(), tt.want) || !strings.Contains(err.Error(), "https://sam.gov") {
+				t.Errorf("error = %q, want it to mention %q and where to get a key", err, tt.want)
+			}
+		})
+	}
+}
+
 func TestNewClient_OnlyWhitespaceAndCommas(t *testing.T) {
 	// Guards against the subtle case where `" , , "` passes the empty-string
 	// check but produces zero valid keys after trimming.
@@ -143,15 +170,17 @@ func TestClient_Search_WiresQueryParams(t *testing.T) {
 	c.baseURL = srv.URL
 
 	resp, err := c.Search(SearchParams{
-		Limit:      50,
-		Offset:     100,
-		PostedFrom: "01/01/2026",
-		PostedTo:   "01/31/2026",
-		Title:      "cyber",
-		Type:       "k",
-		NAICS:      "541511",
-		State:      "VA",
-		SetAside:   "SBA",
+		Limit:              50,
+		Offset:             100,
+		PostedFrom:         mustParseDate("01/01/2026"),
+		PostedTo:           mustParseDate("01/31/2026"),
+		Title:              "cyber",
+		Type:               "k",
+		NAICS:              "541511",
+		State:              "VA",
+		SetAside:           "SBA",
+		SolicitationNumber: "W91-26-R-0001",
+		ClassificationCode: "D302",
 	})
 	if err != nil {
 		t.Fatalf("Search error: %v", err)
@@ -168,6 +197,8 @@ func TestClient_Search_WiresQueryParams(t *testing.T) {
 		"ncode":          "541511",
 		"state":          "VA",
 		"typeOfSetAside": "SBA",
+		"solnum":         "W91-26-R-0001",
+		"ccode":          "D302",
 	}
 	for k, v := range want {
 		if gotQuery[k] != v {
@@ -200,8 +231,8 @@ func TestClient_Search_NoticeIDSuppressesDateWindow(t *testing.T) {
 	_, err := c.Search(SearchParams{
 		Limit:      1,
 		NoticeID:   "abc123",
-		PostedFrom: "01/01/2026",
-		PostedTo:   "01/31/2026",
+		PostedFrom: mustParseDate("01/01/2026"),
+		PostedTo:   mustParseDate("01/31/2026"),
 	})
 	if err != nil {
 		t.Fatal(err)
@@ -242,3 +273,186 @@ func TestClient_Search_NonRateLimitErrorReturnsAPIError(t *testing.T) {
 		t.Errorf("500 should not trigger rotation; got %d calls", calls.Load())
 	}
 }
+
+func TestClient_Search_SendsUserAgent(t *testing.T) {
+	var gotUA string
+	srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		gotUA = r.Header.Get("User-Agent")
+		fmt.Fprint(w, `{"totalRecords":0,"opportunitiesData":[]}`)
+	}))
+	defer srv.Close()
+
+	t.Setenv("GOVSCOUT_USER_AGENT", "")
+	c, err := NewClient("k")
+	if err != nil {
+		t.Fatal(err)
+	}
+	c.baseURL = srv.URL
+	if _, err := c.Search(SearchParams{Limit: 1}); err != nil {
+		t.Fatal(err)
+	}
+	if gotUA != "govscout/"+Version {
+		t.Errorf("default User-Agent = %q, want govscout/%s", gotUA, Version)
+	}
+
+	t.Setenv("GOVSCOUT_USER_AGENT", "acme-bids/2.0 (ops@example.com)")
+	c, err = NewClient("k")
+	if err != nil {
+		t.Fatal(err)
+	}
+	c.baseURL = srv.URL
+	if _, err := c.Search(SearchParams{Limit: 1}); err != nil {
+		t.Fatal(err)
+	}
+	if gotUA != "acme-bids/2.0 (ops@example.com)" {
+		t.Errorf("env User-Agent = %q", gotUA)
+	}
+
+	c, err = NewClient("k", WithUserAgent("flag-agent/1"))
+	if err != nil {
+		t.Fatal(err)
+	}
+	c.baseURL = srv.URL
+	if _, err := c.Search(SearchParams{Limit: 1}); err != nil {
+		t.Fatal(err)
+	}
+	if gotUA != "flag-agent/1" {
+		t.Errorf("WithUserAgent User-Agent = %q, want flag-agent/1", gotUA)
+	}
+}
+
+func TestNewClient_BlankUserAgentRejected(t *testing.T) {
+	if _, err := NewClient("k", WithUserAgent("   ")); err == nil {
+		t.Fatal("expected error for blank user agent, got nil")
+	}
+}
+
+func TestClient_Search_InvalidRangeSkipsRequest(t *testing.T) {
+	var hits atomic.Int64
+	srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		hits.Add(1)
+		fmt.Fprint(w, `{"totalRecords":0,"opportunitiesData":[]}`)
+	}))
+	defer srv.Close()
+
+	c, _ := NewClient("k")
+	c.baseURL = srv.URL
+
+	_, err := c.Search(SearchParams{
+		Limit:      1,
+		PostedFrom: mustParseDate("03/01/2026"),
+		PostedTo:   mustParseDate("01/01/2026"),
+	})
+	if err == nil {
+		t.Fatal("expected an error for a transposed range")
+	}
+	if hits.Load() != 0 {
+		t.Errorf("made %d requests, want 0", hits.Load())
+	}
+}
+
+func TestSearchWindow_SkipsDuplicateNoticeIDs(t *testing.T) {
+	// Two full pages where the second repeats two notices from the first.
+	page := func(ids ...string) []map[string]any {
+		recs := make([]map[string]any, len(ids))
+		for i, id := range ids {
+			recs[i] = map[string]any{"noticeId": id}
+		}
+		return recs
+	}
+	first := make([]string, 1000)
+	for i := range first {
+		first[i] = fmt.Sprintf("n%04d", i)
+	}
+	total := int64(1003)
+	pages := [][]map[string]any{page(first...), page("n0000", "n0999", "n1000")}
+	search := func(ctx context.Context, p SearchParams) (*APIResponse, error) {
+		return &APIResponse{TotalRecords: &total, OpportunitiesData: pages[p.Offset/1000]}, nil
+	}
+
+	var got []string
+	res, err := searchWindow(context.Background(), search, NewDate(time.Now()), NewDate(time.Now()), SearchParams{}, func(recs []map[string]any) error {
+		for _, r := range recs {
+			got = append(got, r["noticeId"].(string))
+		}
+		return nil
+	})
+	if err != nil {
+		t.Fatal(err)
+	}
+	if res.APICalls != 2 {
+		t.Errorf("APICalls = %d, want 2 (paging still follows the raw row count)", res.APICalls)
+	}
+	if res.DuplicatesSkipped != 2 || res.TotalFetched != 1001 || len(got) != 1001 {
+		t.Errorf("result = %+v with %d rows handed on, want 1001 distinct and 2 duplicates skipped", *res, len(got))
+	}
+	if got[len(got)-1] != "n1000" {
+		t.Errorf("last row = %s, want n1000", got[len(got)-1])
+	}
+}
+
+func TestSearchWindow_FiltersActiveClientSide(t *testing.T) {
+	total := int64(3)
+	recs := []map[string]any{
+		{"noticeId": "open", "active": "Yes"},
+		{"noticeId": "archived", "active": "No"},
+		{"noticeId": "unknown"},
+	}
+	search := func(ctx context.Context, p SearchParams) (*APIResponse, error) {
+		return &APIResponse{TotalRecords: &total, OpportunitiesData: recs}, nil
+	}
+
+	for _, tt := range []struct {
+		active string
+		want   []string
+	}{
+		{"", []string{"open", "archived", "unknown"}},
+		{"Yes", []string{"open"}},
+		{"No", []string{"archived"}},
+	} {
+		var got []string
+		res, err := searchWindow(context.Background(), search, NewDate(time.Now()), NewDate(time.Now()), SearchParams{Active: tt.active}, func(page []map[string]any) error {
+			for _, r := range page {
+				got = append(got, r["noticeId"].(string))
+			}
+			return nil
+		})
+		if err != nil {
+			t.Fatal(err)
+		}
+		if !reflect.DeepEqual(got, tt.want) || res.StatusSkipped != 3-len(tt.want) {
+			t.Errorf("Active=%q: got %v (%d skipped), want %v", tt.active, got, res.StatusSkipped, tt.want)
+		}
+	}
+}
+
+func TestSearchWindow_StopsWhenRateLimitRemainingRunsLow(t *testing.T) {
+	var calls atomic.Int64
+	srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		calls.Add(1)
+		w.Header().Set("X-RateLimit-Remaining", "2")
+		recs := make([]string, 1000)
+		for i := range recs {
+			recs[i] = fmt.Sprintf(`{"noticeId":"n%04d"}`, i)
+		}
+		fmt.Fprintf(w, `{"totalRecords":5000,"opportunitiesData":[%s]}`, strings.Join(recs, ","))
+	}))
+	defer srv.Close()
+
+	c, err := NewClient("k", WithRetryPolicy(RetryPolicy{MaxAttempts: 1}))
+	if err != nil {
+		t.Fatal(err)
+	}
+	c.baseURL = srv.URL
+
+	res, err := c.SearchWindow(NewDate(time.Now()), NewDate(time.Now()), func([]map[string]any) error { return nil })
+	if err != nil {
+		t.Fatal(err)
+	}
+	if !res.ApproachingLimit || res.RateLimited || res.RateLimitRemaining != 2 {
+		t.Errorf("result = %+v, want approaching limit with 2 remaining", *res)
+	}
+	if calls.Load() != 1 || res.TotalFetched != 1000 {
+		t.Errorf("calls = %d, fetched = %d; want the first page only", calls.Load(), res.TotalFetched)
+	}
+}
@@ -0,0 +1,76 @@
+package samgov
+
+import (
+	"fmt"
+	"time"
+)
+
+// DateLayout is the MM/DD/YYYY format SAM.gov expects for postedFrom and
+// postedTo.
+const DateLayout = "01/02/2006"
+
+// NoticeID identifies a single opportunity (SAM.gov's noticeId).
+type NoticeID string
+
+// Date is a calendar day (no time of day) used in SAM.gov queries. The zero
+// value means "unset" and renders as "".
+type Date struct {
+	t time.Time
+}
+
+// NewDate truncates t to its calendar day in t's location.
+func NewDate(t time.Time) Date {
+	y, m, d := t.Date()
+	return Date{t: time.Date(y, m, d, 0, 0, 0, 0, time.UTC)}
+}
+
+// ParseDate accepts MM/DD/YYYY (the API format) or YYYY-MM-DD.
+func ParseDate(s string) (Date, error) {
+	for _, layout := range []string{DateLayout, "2006-01-02"} {
+		if t, err := time.Parse(layout, s); err == nil {
+			return Date{t: t}, nil
+		}
+	}
+	return Date{}, fmt.Errorf("invalid date %q (want MM/DD/YYYY or YYYY-MM-DD)", s)
+}
+
+// String formats the date as MM/DD/YYYY, or "" for the zero Date.
+func (d Date) String() string {
+	if d.t.IsZero() {
+		return ""
+	}
+	return d.t.Format(DateLayout)
+}
+
+// IsZero reports whether the date is unset.
+func (d Date) IsZero() bool {
+	return d.t.IsZero()
+}
+
+// Time returns midnight UTC of the date.
+func (d Date) Time() time.Time {
+	return d.t
+}
+
+// AddDays returns the date n days later (earlier when n is negative).
+func (d Date) AddDays(n int) Date {
+	return Date{t: d.t.AddDate(0, 0, n)}
+}
+
+// AddYears returns the date n years later (earlier when n is negative).
+func (d Date) AddYears(n int) Date {
+	return Date{t: d.t.AddDate(n, 0, 0)}
+}
+
+func (d Date) Before(other Date) bool {
+	return d.t.Before(other.t)
+}
+
+func (d Date) After(other Date) bool {
+	return d.t.After(other.t)
+}
+
+// DaysSince returns the whole days from other to d.
+func (d Date) DaysSince(other Date) int {
+	return int(d.t.Sub(other.t).Hours() / 24)
+}
@@ -0,0 +1,111 @@
+package samgov
+
+import (
+	"testing"
+	"time"
+)
+
+func mustParseDate(s string) Date {
+	d, err := ParseDate(s)
+	if err != nil {
+		panic(err)
+	}
+	return d
+}
+
+func TestParseDate(t *testing.T) {
+	tests := []struct {
+		name    string
+		input   string
+		want    time.Time
+		wantErr bool
+	}{
+		{
+			name:  "MM/DD/YYYY format",
+			input: "01/27/2026",
+			want:  time.Date(2026, 1, 27, 0, 0, 0, 0, time.UTC),
+		},
+		{
+			name:  "YYYY-MM-DD format",
+			input: "2026-01-27",
+			want:  time.Date(2026, 1, 27, 0, 0, 0, 0, time.UTC),
+		},
+		{
+			name:  "MM/DD/YYYY leap day",
+			input: "02/29/2024",
+			want:  time.Date(2024, 2, 29, 0, 0, 0, 0, time.UTC),
+		},
+		{
+			name:  "YYYY-MM-DD leap day",
+			input: "2024-02-29",
+			want:  time.Date(2024, 2, 29, 0, 0, 0, 0, time.UTC),
+		},
+		{
+			name:    "invalid format",
+			input:   "27-01-2026",
+			wantErr: true,
+		},
+		{
+			name:    "empty string",
+			input:   "",
+			wantErr: true,
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			got, err := ParseDate(tt.input)
+			if tt.wantErr {
+				if err == nil {
+					t.Fatalf("expected error, got %v", got)
+				}
+				return
+			}
+			if err != nil {
+				t.Fatalf("unexpected error: %v", err)
+			}
+			if !got.Time().Equal(tt.want) {
+				t.Errorf("got %v, want %v", got.Time(), tt.want)
+			}
+		})
+	}
+}
+
+func TestDate_StringAndArithmetic(t *testing.T) {
+	d := NewDate(time.Date(2026, 3, 1, 23, 30, 0, 0, time.FixedZone("EST", -5*3600)))
+	if got := d.String(); got != "03/01/2026" {
+		t.Errorf("String() = %q, want 03/01/2026 (calendar day in the input's zone)", got)
+	}
+	if got := d.AddDays(-1).String(); got != "02/28/2026" {
+		t.Errorf("AddDays(-1) = %q, want 02/28/2026", got)
+	}
+	if got := d.DaysSince(mustParseDate("02/20/2026")); got != 9 {
+		t.Errorf("DaysSince = %d, want 9", got)
+	}
+	if (Date{}).String() != "" {
+		t.Error("zero Date should render empty")
+	}
+}
+
+func TestSearchParamsValidate(t *testing.T) {
+	tests := []struct {
+		name    string
+		params  SearchParams
+		wantErr bool
+	}{
+		{"ordered range", SearchParams{PostedFrom: mustParseDate("01/01/2026"), PostedTo: mustParseDate("03/01/2026")}, false},
+		{"single day", SearchParams{PostedFrom: mustParseDate("01/01/2026"), PostedTo: mustParseDate("01/01/2026")}, false},
+		{"exactly one year", SearchParams{PostedFrom: mustParseDate("01/01/2025"), PostedTo: mustParseDate("01/01/2026")}, false},
+		{"transposed", SearchParams{PostedFrom: mustParseDate("03/01/2026"), PostedTo: mustParseDate("01/01/2026")}, true},
+		{"over one year", SearchParams{PostedFrom: mustParseDate("01/01/2025"), PostedTo: mustParseDate("01/02/2026")}, true},
+		{"open-ended", SearchParams{PostedFrom: mustParseDate("01/01/2020")}, false},
+		{"notice id ignores dates", SearchParams{NoticeID: "abc", PostedFrom: mustParseDate("03/01/2026"), PostedTo: mustParseDate("01/01/2026")}, false},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if err := tt.params.Validate(); (err != nil) != tt.wantErr {
+				t.Errorf("Validate() = %v, wantErr %v", err, tt.wantErr)
+			}
+		})
+	}
+}
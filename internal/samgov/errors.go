@@ -0,0 +1,57 @@
+package samgov
+
+import "fmt"
+
+// ErrorKind classifies a failed SAM.gov search.
+type ErrorKind int
+
+const (
+	// KindNetwork: no usable response (could not connect, timed out, or the
+	// body was cut short).
+	KindNetwork ErrorKind = iota + 1
+	// KindBadRequest: a 4xx other than the rate-limit and auth statuses,
+	// which rotate keys and end in ErrRateLimited instead.
+	KindBadRequest
+	// KindServer: a 5xx from SAM.gov other than its maintenance page
+	// (ErrMaintenance).
+	KindServer
+	// KindParse: a 200 whose body wasn't the expected JSON.
+	KindParse
+)
+
+func (k ErrorKind) String() string {
+	switch k {
+	case KindNetwork:
+		return "network"
+	case KindBadRequest:
+		return "bad request"
+	case KindServer:
+		return "server"
+	case KindParse:
+		return "parse"
+	}
+	return fmt.Sprintf("ErrorKind(%d)", int(k))
+}
+
+// APIError is how Search reports every failure other than rate limiting
+// (ErrRateLimited), the maintenance page (ErrMaintenance), context
+// cancellation, and params rejected by SearchParams.Validate before any
+// request. Branch on Kind with errors.As.
+type APIError struct {
+	Kind   ErrorKind
+	Status int    // HTTP status; 0 for network errors
+	Body   string // response body (truncated for 5xx)
+	Err    error  // underlying cause for network and parse errors
+}
+
+func (e *APIError) Error() string {
+	switch e.Kind {
+	case KindBadRequest, KindServer:
+		return fmt.Sprintf("api error %d: %s", e.Status, e.Body)
+	case KindParse:
+		return fmt.Sprintf("decode: %v", e.Err)
+	}
+	return e.Err.Error()
+}
+
+func (e *APIError) Unwrap() error { return e.Err }
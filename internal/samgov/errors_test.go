@@ -0,0 +1,134 @@
+package samgov
+
+import (
+	"errors"
+	"net/http"
+	"net/http/httptest"
+	"testing"
+)
+
+func TestSearch_ErrorKinds(t *testing.T) {
+	tests := []struct {
+		name   string
+		status int
+		body   string
+		want   ErrorKind
+	}{
+		{"400", http.StatusBadRequest, `{"error":"Invalid Date Entered"}`, KindBadRequest},
+		{"404", http.StatusNotFound, "not found", KindBadRequest},
+		{"500", http.StatusInternalServerError, "boom", KindServer},
+		{"503", http.StatusServiceUnavailable, "down", KindServer},
+		{"bad json", http.StatusOK, "{not json", KindParse},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+				w.WriteHeader(tt.status)
+				w.Write([]byte(tt.body))
+			}))
+			defer srv.Close()
+
+			c, err := NewClient("k", WithRetryPolicy(RetryPolicy{MaxAttempts: 1}))
+			if err != nil {
+				t.Fatal(err)
+			}
+			c.baseURL = srv.URL
+
+			_, err = c.Search(SearchParams{Limit: 1})
+			var apiErr *APIError
+			if !errors.As(err, &apiErr) {
+				t.Fatalf("err = %v (%T), want *APIError", err, err)
+			}
+			if apiErr.Kind != tt.want {
+				t.Errorf("Kind = %s, want %s", apiErr.Kind, tt.want)
+			}
+			if tt.want != KindParse && apiErr.Status != tt.status {
+				t.Errorf("Status = %d, want %d", apiErr.Status, tt.status)
+			}
+		})
+	}
+}
+
+func TestSearch_NetworkErrorKind(t *testing.T) {
+	srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {}))
+	url := srv.URL
+	srv.Close()
+
+	c, err := NewClient("k", WithRetryPolicy(RetryPolicy{MaxAttempts: 1}))
+	if err != nil {
+		t.Fatal(err)
+	}
+	c.baseURL = url
+
+	_, err = c.Search(SearchParams{Limit: 1})
+	var apiErr *APIError
+	if !errors.As(err, &apiErr) || apiErr.Kind != KindNetwork {
+		t.Fatalf("err = %v, want a KindNetwork *APIError", err)
+	}
+	if !IsRetryable(err) {
+		t.Error("network errors should be retryable")
+	}
+}
+
+func TestSearch_RateLimitIsNotAPIError(t *testing.T) {
+	srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		w.WriteHeader(http.StatusTooManyRequests)
+	}))
+	defer srv.Close()
+
+	c, err := NewClient("k", WithRetryPolicy(RetryPolicy{MaxAttempts: 1}))
+	if err != nil {
+		t.Fatal(err)
+	}
+	c.baseURL = srv.URL
+
+	_, err = c.Search(SearchParams{Limit: 1})
+	if !errors.Is(err, ErrRateLimited) {
+		t.Fatalf("err = %v, want ErrRateLimited", err)
+	}
+	var apiErr *APIError
+	if errors.As(err, &apiErr) {
+		t.Errorf("rate limit reported as *APIError kind %s", apiErr.Kind)
+	}
+}
+
+func TestSearch_MaintenancePage(t *testing.T) {
+	tests := []struct {
+		name        string
+		status      int
+		contentType string
+		body        string
+	}{
+		{"503 html", http.StatusServiceUnavailable, "text/html; charset=utf-8", "<html><body>Be right back</body></html>"},
+		{"503 untyped html", http.StatusServiceUnavailable, "", "\n<!DOCTYPE html><html></html>"},
+		{"502 maintenance text", http.StatusBadGateway, "text/plain", "Scheduled Maintenance in progress"},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			calls := 0
+			srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+				calls++
+				if tt.contentType != "" {
+					w.Header().Set("Content-Type", tt.contentType)
+				}
+				w.WriteHeader(tt.status)
+				w.Write([]byte(tt.body))
+			}))
+			defer srv.Close()
+
+			c, err := NewClient("k", WithRetryPolicy(RetryPolicy{MaxAttempts: 3}))
+			if err != nil {
+				t.Fatal(err)
+			}
+			c.baseURL = srv.URL
+
+			_, err = c.Search(SearchParams{Limit: 1})
+			if !errors.Is(err, ErrMaintenance) {
+				t.Fatalf("err = %v, want ErrMaintenance", err)
+			}
+			if calls != 1 {
+				t.Errorf("calls = %d, want 1 (maintenance is not retried)", calls)
+			}
+		})
+	}
+}
@@ -0,0 +1,144 @@
+package samgov
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"log"
+	"os"
+	"path/filepath"
+	"sort"
+	"strings"
+	"sync"
+)
+
+// MockDirEnv names the fixtures directory that switches NewSourceFromEnv to
+// a MockClient.
+const MockDirEnv = "GOVSCOUT_MOCK_DIR"
+
+// MockClient serves canned API responses from a directory of *.json files,
+// each shaped like a SAM.gov search response. It needs no API key or
+// network, which makes sync runs deterministic in tests and demos.
+//
+// All records from all files form one data set. Searches filter it by
+// noticeId or by postedDate (records with an unparseable postedDate always
+// match), narrow it by solicitation number, NAICS code, set-aside and
+// place-of-performance state, and page through it with limit/offset like
+// the live API.
+type MockClient struct {
+	dir string
+
+	once    sync.Once
+	records []map[string]any
+	loadErr error
+}
+
+func NewMockClient(dir string) *MockClient {
+	return &MockClient{dir: dir}
+}
+
+// NewSourceFromEnv returns a MockClient when GOVSCOUT_MOCK_DIR is set and a
+// live Client otherwise.
+func NewSourceFromEnv(apiKeyEnv string, opts ...ClientOption) (Source, error) {
+	if dir := os.Getenv(MockDirEnv); dir != "" {
+		log.Printf("using mock SAM.gov fixtures from %s", dir)
+		return NewMockClient(dir), nil
+	}
+	return NewClient(apiKeyEnv, opts...)
+}
+
+func (m *MockClient) load() error {
+	m.once.Do(func() {
+		files, err := filepath.Glob(filepath.Join(m.dir, "*.json"))
+		if err != nil {
+			m.loadErr = err
+			return
+		}
+		if len(files) == 0 {
+			m.loadErr = fmt.Errorf("mock: no *.json fixtures in %s", m.dir)
+			return
+		}
+		sort.Strings(files)
+		for _, f := range files {
+			b, err := os.ReadFile(f)
+			if err != nil {
+				m.loadErr = err
+				return
+			}
+			var resp APIResponse
+			if err := json.Unmarshal(b, &resp); err != nil {
+				m.loadErr = fmt.Errorf("mock: decode %s: %w", f, err)
+				return
+			}
+			m.records = append(m.records, resp.OpportunitiesData...)
+		}
+	})
+	return m.loadErr
+}
+
+func (m *MockClient) SearchCtx(ctx context.Context, params SearchParams) (*APIResponse, error) {
+	if err := ctx.Err(); err != nil {
+		return nil, err
+	}
+	if err := params.Validate(); err != nil {
+		return nil, err
+	}
+	if err := m.load(); err != nil {
+		return nil, err
+	}
+
+	var matched []map[string]any
+	for _, rec := range m.records {
+		if params.NoticeID != "" {
+			if id, _ := rec["noticeId"].(string); id != string(params.NoticeID) {
+				continue
+			}
+		} else if posted, ok := rec["postedDate"].(string); ok {
+			if d, err := ParseDate(posted); err == nil {
+				if (!params.PostedFrom.IsZero() && d.Before(params.PostedFrom)) ||
+					(!params.PostedTo.IsZero() && d.After(params.PostedTo)) {
+					continue
+				}
+			}
+		}
+		if params.SolicitationNumber != "" {
+			if sol, _ := rec["solicitationNumber"].(string); !strings.EqualFold(sol, params.SolicitationNumber) {
+				continue
+			}
+		}
+		if params.NAICS != "" {
+			if code, _ := rec["naicsCode"].(string); code != params.NAICS {
+				continue
+			}
+		}
+		if params.SetAside != "" {
+			if code, _ := rec["typeOfSetAside"].(string); !strings.EqualFold(code, params.SetAside) {
+				continue
+			}
+		}
+		if params.State != "" && !strings.EqualFold(popStateCode(rec), params.State) {
+			continue
+		}
+		matched = append(matched, rec)
+	}
+
+	total := int64(len(matched))
+	start := min(max(params.Offset, 0), len(matched))
+	end := len(matched)
+	if params.Limit > 0 {
+		end = min(start+params.Limit, end)
+	}
+	return &APIResponse{TotalRecords: &total, OpportunitiesData: matched[start:end]}, nil
+}
+
+func (m *MockClient) SearchWindowCtx(ctx context.Context, from, to Date, filter SearchParams, onPage func([]map[string]any) error) (*WindowResult, error) {
+	return searchWindow(ctx, m.SearchCtx, from, to, filter, onPage)
+}
+
+// popStateCode is a record's placeOfPerformance.state.code, or "".
+func popStateCode(rec map[string]any) string {
+	pop, _ := rec["placeOfPerformance"].(map[string]any)
+	state, _ := pop["state"].(map[string]any)
+	code, _ := state["code"].(string)
+	return code
+}
@@ -0,0 +1,92 @@
+package samgov
+
+import (
+	"context"
+	"os"
+	"path/filepath"
+	"testing"
+)
+
+func writeFixture(t *testing.T, dir, name, body string) {
+	t.Helper()
+	if err := os.WriteFile(filepath.Join(dir, name), []byte(body), 0o644); err != nil {
+		t.Fatal(err)
+	}
+}
+
+func TestMockClient_FiltersAndPages(t *testing.T) {
+	dir := t.TempDir()
+	writeFixture(t, dir, "a.json", `{"totalRecords":2,"opportunitiesData":[
+		{"noticeId":"jan","postedDate":"2026-01-10"},
+		{"noticeId":"feb","postedDate":"2026-02-10"}]}`)
+	writeFixture(t, dir, "b.json", `{"opportunitiesData":[
+		{"noticeId":"undated"},
+		{"noticeId":"mar","postedDate":"03/10/2026"}]}`)
+	m := NewMockClient(dir)
+	ctx := context.Background()
+
+	resp, err := m.SearchCtx(ctx, SearchParams{
+		Limit:      10,
+		PostedFrom: mustParseDate("02/01/2026"),
+		PostedTo:   mustParseDate("03/31/2026"),
+	})
+	if err != nil {
+		t.Fatal(err)
+	}
+	var ids []string
+	for _, r := range resp.OpportunitiesData {
+		ids = append(ids, r["noticeId"].(string))
+	}
+	if len(ids) != 3 || ids[0] != "feb" || ids[1] != "undated" || ids[2] != "mar" {
+		t.Errorf("window ids = %v, want [feb undated mar]", ids)
+	}
+
+	resp, err = m.SearchCtx(ctx, SearchParams{Limit: 1, Offset: 1})
+	if err != nil {
+		t.Fatal(err)
+	}
+	if *resp.TotalRecords != 4 || len(resp.OpportunitiesData) != 1 || resp.OpportunitiesData[0]["noticeId"] != "feb" {
+		t.Errorf("page 2 = %v (total %d), want [feb] of 4", resp.OpportunitiesData, *resp.TotalRecords)
+	}
+
+	resp, err = m.SearchCtx(ctx, SearchParams{Limit: 10, NoticeID: "mar"})
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(resp.OpportunitiesData) != 1 {
+		t.Errorf("noticeId lookup returned %d records, want 1", len(resp.OpportunitiesData))
+	}
+}
+
+func TestMockClient_SearchWindowFilter(t *testing.T) {
+	dir := t.TempDir()
+	writeFixture(t, dir, "a.json", `{"opportunitiesData":[
+		{"noticeId":"it-sba","postedDate":"03/10/2026","naicsCode":"541511","typeOfSetAside":"SBA",
+			"placeOfPerformance":{"state":{"code":"VA","name":"Virginia"}}},
+		{"noticeId":"it-sba-md","postedDate":"03/10/2026","naicsCode":"541511","typeOfSetAside":"SBA",
+			"placeOfPerformance":{"state":{"code":"MD","name":"Maryland"}}},
+		{"noticeId":"it-open","postedDate":"03/11/2026","naicsCode":"541511"},
+		{"noticeId":"build-sba","postedDate":"03/12/2026","naicsCode":"236220","typeOfSetAside":"SBA"}]}`)
+	m := NewMockClient(dir)
+
+	var ids []string
+	_, err := m.SearchWindowCtx(context.Background(), mustParseDate("03/01/2026"), mustParseDate("03/31/2026"),
+		SearchParams{NAICS: "541511", SetAside: "sba", State: "va"}, func(recs []map[string]any) error {
+			for _, r := range recs {
+				ids = append(ids, r["noticeId"].(string))
+			}
+			return nil
+		})
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(ids) != 1 || ids[0] != "it-sba" {
+		t.Errorf("filtered window = %v, want [it-sba]", ids)
+	}
+}
+
+func TestMockClient_EmptyDirIsAnError(t *testing.T) {
+	if _, err := NewMockClient(t.TempDir()).SearchCtx(context.Background(), SearchParams{Limit: 1}); err == nil {
+		t.Error("expected an error for a directory without fixtures")
+	}
+}
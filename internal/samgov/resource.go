@@ -0,0 +1,65 @@
+package samgov
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"net"
+	"net/http"
+	"net/url"
+	"strings"
+	"time"
+)
+
+// ResourceHTTPClient returns an HTTP client for streaming resource
+// documents (pass it to NewClient with WithHTTPClient). Unlike the search
+// client it has no overall timeout, which would cut off a large download
+// mid-body; connect bounds dialing and the TLS handshake, header bounds the
+// wait for response headers, and the request context bounds the rest.
+func ResourceHTTPClient(connect, header time.Duration) *http.Client {
+	transport := http.DefaultTransport.(*http.Transport).Clone()
+	transport.DialContext = (&net.Dialer{Timeout: connect, KeepAlive: 30 * time.Second}).DialContext
+	transport.TLSHandshakeTimeout = connect
+	transport.ResponseHeaderTimeout = header
+	return &http.Client{Transport: transport}
+}
+
+// IsSAMResource reports whether u points at SAM.gov over HTTPS, i.e. is a
+// URL the API key may be sent to.
+func IsSAMResource(u *url.URL) bool {
+	host := strings.ToLower(u.Hostname())
+	return u.Scheme == "https" && (host == "sam.gov" || strings.HasSuffix(host, ".sam.gov"))
+}
+
+// OpenResource fetches a resource-link document with the current API key
+// appended. The caller must close the response body. Non-2xx responses are
+// returned as errors.
+func (c *Client) OpenResource(ctx context.Context, rawURL string) (*http.Response, error) {
+	u, err := url.Parse(rawURL)
+	if err != nil {
+		return nil, fmt.Errorf("parse resource url: %w", err)
+	}
+	q := u.Query()
+	q.Set("api_key", c.currentKey())
+	u.RawQuery = q.Encode()
+
+	req, err := http.NewRequestWithContext(ctx, http.MethodGet, u.String(), nil)
+	if err != nil {
+		return nil, err
+	}
+	req.Header.Set("User-Agent", c.userAgent)
+	resp, err := c.http.Do(req)
+	if err != nil {
+		// url.Error would echo the URL, and with it the key.
+		var ue *url.Error
+		if errors.As(err, &ue) {
+			err = ue.Err
+		}
+		return nil, fmt.Errorf("fetch resource: %w", err)
+	}
+	if resp.StatusCode < 200 || resp.StatusCode > 299 {
+		resp.Body.Close()
+		return nil, fmt.Errorf("fetch resource: status %d", resp.StatusCode)
+	}
+	return resp, nil
+}
@@ -0,0 +1,91 @@
+package samgov
+
+import (
+	"context"
+	"io"
+	"net/http"
+	"net/http/httptest"
+	"net/url"
+	"testing"
+	"time"
+)
+
+func TestIsSAMResource(t *testing.T) {
+	tests := []struct {
+		raw  string
+		want bool
+	}{
+		{"https://sam.gov/api/prod/opps/v3/opportunities/resources/files/abc/download", true},
+		{"https://api.sam.gov/x", true},
+		{"http://sam.gov/x", false},
+		{"https://evilsam.gov/x", false},
+		{"https://sam.gov.example.com", false},
+	}
+	for _, tt := range tests {
+		u, err := url.Parse(tt.raw)
+		if err != nil {
+			t.Fatal(err)
+		}
+		if got := IsSAMResource(u); got != tt.want {
+			t.Errorf("IsSAMResource(%q) = %v, want %v", tt.raw, got, tt.want)
+		}
+	}
+}
+
+func TestClient_OpenResource_AppendsKey(t *testing.T) {
+	srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		if r.URL.Query().Get("api_key") != "k1" || r.URL.Query().Get("v") != "1" {
+			w.WriteHeader(http.StatusForbidden)
+			return
+		}
+		w.Header().Set("Content-Type", "application/pdf")
+		io.WriteString(w, "%PDF")
+	}))
+	defer srv.Close()
+
+	c, err := NewClient("k1")
+	if err != nil {
+		t.Fatal(err)
+	}
+	resp, err := c.OpenResource(context.Background(), srv.URL+"/doc?v=1")
+	if err != nil {
+		t.Fatalf("OpenResource: %v", err)
+	}
+	defer resp.Body.Close()
+	body, _ := io.ReadAll(resp.Body)
+	if string(body) != "%PDF" || resp.Header.Get("Content-Type") != "application/pdf" {
+		t.Errorf("got %q (%s)", body, resp.Header.Get("Content-Type"))
+	}
+
+	if _, err := c.OpenResource(context.Background(), srv.URL+"/doc"); err == nil {
+		t.Error("expected an error for a non-2xx response")
+	}
+}
+
+func TestResourceHTTPClient_StreamsPastHeaderTimeout(t *testing.T) {
+	srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		io.WriteString(w, "part one,")
+		w.(http.Flusher).Flush()
+		time.Sleep(150 * time.Millisecond) // longer than the header timeout
+		io.WriteString(w, "part two")
+	}))
+	defer srv.Close()
+
+	h := ResourceHTTPClient(time.Second, 50*time.Millisecond)
+	if h.Timeout != 0 {
+		t.Fatalf("Timeout = %s, want none", h.Timeout)
+	}
+	c, err := NewClient("k1", WithHTTPClient(h))
+	if err != nil {
+		t.Fatal(err)
+	}
+	resp, err := c.OpenResource(context.Background(), srv.URL+"/doc")
+	if err != nil {
+		t.Fatalf("OpenResource: %v", err)
+	}
+	defer resp.Body.Close()
+	body, err := io.ReadAll(resp.Body)
+	if err != nil || string(body) != "part one,part two" {
+		t.Errorf("body = %q, %v; want the whole stream", body, err)
+	}
+}
@@ -1,19 +1,55 @@
 package samgov
 
+import "fmt"
+
 type APIResponse struct {
 	TotalRecords      *int64           `json:"totalRecords"`
 	OpportunitiesData []map[string]any `json:"opportunitiesData"`
+	// RateLimitRemaining is the X-RateLimit-Remaining header of the live
+	// response that produced this page; nil when absent. Not cached.
+	RateLimitRemaining *int `json:"-"`
+	// Cached is true when the response came from the response cache, so
+	// no API call was made.
+	Cached bool `json:"-"`
 }
 
 type SearchParams struct {
 	Limit      int
 	Offset     int
-	PostedFrom string
-	PostedTo   string
+	PostedFrom Date
+	PostedTo   Date
 	Title      string
 	Type       string
 	NAICS      string
 	State      string
 	SetAside   string
-	NoticeID   string
+	NoticeID   NoticeID
+	// SolicitationNumber is an exact solicitation number lookup (solnum).
+	SolicitationNumber string
+	// ClassificationCode is the Product Service Code (ccode); combined with
+	// NAICS, SAM.gov returns only notices matching both.
+	ClassificationCode string
+	// Active keeps only notices whose active flag is "Yes" or "No". SAM.gov's
+	// search has no such parameter, so searchWindow drops the rest of each
+	// page client-side; "" keeps both.
+	Active string
+	// NoCache skips the response cache, both reading and writing, for
+	// searches whose results are still changing (windows reaching today).
+	NoCache bool
+}
+
+// Validate catches date ranges SAM.gov would reject or answer with nothing:
+// PostedFrom after PostedTo, or a span over one year (the API's maximum).
+// Notice ID lookups ignore the dates and always pass.
+func (p SearchParams) Validate() error {
+	if p.NoticeID != "" || p.PostedFrom.IsZero() || p.PostedTo.IsZero() {
+		return nil
+	}
+	if p.PostedFrom.After(p.PostedTo) {
+		return fmt.Errorf("posted-from %s is after posted-to %s (dates transposed?)", p.PostedFrom, p.PostedTo)
+	}
+	if p.PostedTo.After(p.PostedFrom.AddYears(1)) {
+		return fmt.Errorf("posted range %s to %s is over one year; SAM.gov allows at most one year per search", p.PostedFrom, p.PostedTo)
+	}
+	return nil
 }
@@ -0,0 +1,105 @@
+package sync
+
+import (
+	"database/sql"
+	"errors"
+	"fmt"
+	"sort"
+	"time"
+
+	"github.com/theognis1002/govscout/internal/db"
+	"github.com/theognis1002/govscout/internal/samgov"
+)
+
+// Audit is the posted-date coverage map `sync --audit` reports: which days
+// between Start and End no cleanly finished sync window ever fetched.
+// Nothing is fetched or written to build it.
+type Audit struct {
+	Start       string `json:"start"` // earliest stored posted_date, or --from
+	End         string `json:"end"`   // today
+	Windows     int    `json:"windows"`
+	TotalDays   int    `json:"total_days"`
+	CoveredDays int    `json:"covered_days"`
+	Gaps        []Gap  `json:"gaps"`
+}
+
+// Gap is an inclusive run of days no completed window covered.
+type Gap struct {
+	From string `json:"from"`
+	To   string `json:"to"`
+	Days int    `json:"days"`
+}
+
+// AuditCoverage rebuilds the covered ranges from sync_runs (see
+// db.CompletedSyncWindows) and reports the gaps between from and today.
+// A zero from means the earliest stored posted date.
+func AuditCoverage(database *sql.DB, from samgov.Date) (*Audit, error) {
+	today := samgov.NewDate(time.Now())
+	if from.IsZero() {
+		earliest, err := db.GetEarliestPostedDate(database)
+		if err != nil {
+			return nil, fmt.Errorf("earliest posted date: %w", err)
+		}
+		if earliest.IsZero() {
+			return nil, errors.New("no stored opportunities to audit; pass --from to audit an empty database")
+		}
+		from = earliest
+	}
+
+	rows, err := db.CompletedSyncWindows(database)
+	if err != nil {
+		return nil, fmt.Errorf("sync windows: %w", err)
+	}
+	var windows [][2]samgov.Date
+	for _, w := range rows {
+		wf, err1 := samgov.ParseDate(w.From)
+		wt, err2 := samgov.ParseDate(w.To)
+		if err1 != nil || err2 != nil || wt.Before(wf) {
+			continue
+		}
+		windows = append(windows, [2]samgov.Date{wf, wt})
+	}
+
+	a := &Audit{Start: from.String(), End: today.String(), Windows: len(windows), Gaps: []Gap{}}
+	if today.Before(from) {
+		return a, nil
+	}
+	a.TotalDays = today.DaysSince(from) + 1
+	gaps := coverageGaps(windows, from, today)
+	missing := 0
+	for _, g := range gaps {
+		days := g[1].DaysSince(g[0]) + 1
+		missing += days
+		a.Gaps = append(a.Gaps, Gap{From: g[0].String(), To: g[1].String(), Days: days})
+	}
+	a.CoveredDays = a.TotalDays - missing
+	return a, nil
+}
+
+// coverageGaps merges windows (inclusive day ranges, in any order) and
+// returns the inclusive ranges within [start, end] none of them touch.
+func coverageGaps(windows [][2]samgov.Date, start, end samgov.Date) [][2]samgov.Date {
+	sort.Slice(windows, func(i, j int) bool { return windows[i][0].Before(windows[j][0]) })
+	var gaps [][2]samgov.Date
+	next := start // first day not yet known to be covered
+	for _, w := range windows {
+		if next.After(end) {
+			break
+		}
+		if w[1].Before(next) {
+			continue
+		}
+		if w[0].After(next) {
+			gapEnd := w[0].AddDays(-1)
+			if gapEnd.After(end) {
+				gapEnd = end
+			}
+			gaps = append(gaps, [2]samgov.Date{next, gapEnd})
+		}
+		next = w[1].AddDays(1)
+	}
+	if !next.After(end) {
+		gaps = append(gaps, [2]samgov.Date{next, end})
+	}
+	return gaps
+}
@@ -0,0 +1,71 @@
+package sync
+
+import (
+	"reflect"
+	"testing"
+	"time"
+
+	"github.com/theognis1002/govscout/internal/db"
+	"github.com/theognis1002/govscout/internal/samgov"
+)
+
+func TestCoverageGaps(t *testing.T) {
+	d := func(s string) samgov.Date {
+		t.Helper()
+		v, err := samgov.ParseDate(s)
+		if err != nil {
+			t.Fatal(err)
+		}
+		return v
+	}
+	windows := [][2]samgov.Date{
+		{d("2025-03-01"), d("2025-03-10")},
+		{d("2025-01-01"), d("2025-01-31")},
+		{d("2025-01-31"), d("2025-02-10")}, // shares an endpoint, like backfill windows
+		{d("2025-03-05"), d("2025-03-08")}, // inside the previous one
+	}
+	got := coverageGaps(windows, d("2024-12-30"), d("2025-03-20"))
+	want := [][2]samgov.Date{
+		{d("2024-12-30"), d("2024-12-31")},
+		{d("2025-02-11"), d("2025-02-28")},
+		{d("2025-03-11"), d("2025-03-20")},
+	}
+	if !reflect.DeepEqual(got, want) {
+		t.Errorf("gaps = %v, want %v", got, want)
+	}
+
+	if gaps := coverageGaps(nil, d("2025-01-01"), d("2025-01-05")); len(gaps) != 1 {
+		t.Errorf("no windows: gaps = %v, want the whole range", gaps)
+	}
+	if gaps := coverageGaps(windows[1:3], d("2025-01-05"), d("2025-02-01")); len(gaps) != 0 {
+		t.Errorf("fully covered: gaps = %v, want none", gaps)
+	}
+}
+
+func TestAuditCoverage(t *testing.T) {
+	database := openTestDB(t)
+	today := samgov.NewDate(time.Now())
+	msg := "api error 500"
+	// Covered: the last 10 days. The errored, rate-limited and filtered
+	// windows before that don't count, leaving days 11-30 back as a gap.
+	db.InsertSyncRun(database, "incremental", today.AddDays(-10).String(), today.String(), 1, 5, false, nil)
+	db.InsertSyncRun(database, "backfill", today.AddDays(-20).String(), today.AddDays(-10).String(), 1, 0, false, &msg)
+	db.InsertSyncRun(database, "backfill", today.AddDays(-30).String(), today.AddDays(-20).String(), 1, 0, true, nil)
+	db.InsertSyncRun(database, "backfill naics=541511", today.AddDays(-30).String(), today.AddDays(-11).String(), 1, 3, false, nil)
+
+	if _, err := AuditCoverage(database, samgov.Date{}); err == nil {
+		t.Error("audit of an empty database without --from should fail")
+	}
+
+	a, err := AuditCoverage(database, today.AddDays(-30))
+	if err != nil {
+		t.Fatal(err)
+	}
+	if a.Windows != 1 || a.TotalDays != 31 || a.CoveredDays != 11 {
+		t.Errorf("audit = %+v, want 1 window covering 11 of 31 days", a)
+	}
+	want := []Gap{{From: today.AddDays(-30).String(), To: today.AddDays(-11).String(), Days: 20}}
+	if !reflect.DeepEqual(a.Gaps, want) {
+		t.Errorf("gaps = %+v, want %+v", a.Gaps, want)
+	}
+}
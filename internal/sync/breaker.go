@@ -0,0 +1,86 @@
+package sync
+
+import (
+	"database/sql"
+	"log"
+	"strconv"
+	"time"
+
+	"github.com/theognis1002/govscout/internal/db"
+)
+
+// Circuit breaker for repeated rate limits: after BreakerThreshold
+// consecutive rate-limited runs, sync pauses until a cooldown expires. The
+// cooldown doubles with every further rate-limited run, up to
+// maxBreakerCooldown. A run that finishes without hitting a rate limit
+// resets the streak.
+const (
+	defaultBreakerThreshold = 3
+	defaultBreakerCooldown  = time.Hour
+	maxBreakerCooldown      = 24 * time.Hour
+
+	stateRateLimitStreak = "rate_limit_streak"
+	stateCooldownUntil   = "rate_limit_cooldown_until"
+)
+
+// cooldownRemaining returns how long the breaker still blocks syncing, or
+// zero when no cooldown is active.
+func cooldownRemaining(database *sql.DB, now time.Time) time.Duration {
+	v, err := db.GetSyncState(database, stateCooldownUntil)
+	if err != nil || v == "" {
+		return 0
+	}
+	until, err := time.Parse(time.RFC3339, v)
+	if err != nil {
+		log.Printf("ignoring unparseable %s %q: %v", stateCooldownUntil, v, err)
+		return 0
+	}
+	return max(until.Sub(now), 0)
+}
+
+// breakerCooldown is the pause after the streak-th consecutive rate-limited
+// run, or zero while the streak is below the threshold.
+func breakerCooldown(streak, threshold int, base time.Duration) time.Duration {
+	if streak < threshold {
+		return 0
+	}
+	d := base
+	for i := threshold; i < streak && d < maxBreakerCooldown; i++ {
+		d *= 2
+	}
+	return min(d, maxBreakerCooldown)
+}
+
+// recordRateLimit updates the streak after a completed run and arms the
+// cooldown once the threshold is reached. Dry runs and disabled breakers
+// leave the state alone.
+func recordRateLimit(database *sql.DB, opts Options, now time.Time, rateLimited bool) {
+	if opts.DryRun || opts.BreakerThreshold < 0 {
+		return
+	}
+	if !rateLimited {
+		if err := db.SetSyncState(database, stateRateLimitStreak, "0"); err != nil {
+			log.Printf("reset rate limit streak: %v", err)
+		}
+		return
+	}
+
+	streak := 1
+	if v, _ := db.GetSyncState(database, stateRateLimitStreak); v != "" {
+		if n, err := strconv.Atoi(v); err == nil {
+			streak = n + 1
+		}
+	}
+	if err := db.SetSyncState(database, stateRateLimitStreak, strconv.Itoa(streak)); err != nil {
+		log.Printf("save rate limit streak: %v", err)
+	}
+
+	if wait := breakerCooldown(streak, opts.BreakerThreshold, opts.BreakerCooldown); wait > 0 {
+		until := now.Add(wait).UTC()
+		if err := db.SetSyncState(database, stateCooldownUntil, until.Format(time.RFC3339)); err != nil {
+			log.Printf("save rate limit cooldown: %v", err)
+			return
+		}
+		log.Printf("rate limited %d runs in a row; pausing sync until %s", streak, until.Format(time.RFC3339))
+	}
+}
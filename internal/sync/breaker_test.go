@@ -0,0 +1,78 @@
+package sync
+
+import (
+	"context"
+	"net/http"
+	"net/http/httptest"
+	"sync/atomic"
+	"testing"
+	"time"
+
+	"github.com/theognis1002/govscout/internal/db"
+)
+
+func TestBreakerCooldown(t *testing.T) {
+	tests := []struct {
+		streak int
+		want   time.Duration
+	}{
+		{streak: 2, want: 0},
+		{streak: 3, want: time.Hour},
+		{streak: 4, want: 2 * time.Hour},
+		{streak: 6, want: 8 * time.Hour},
+		{streak: 40, want: maxBreakerCooldown},
+	}
+	for _, tt := range tests {
+		if got := breakerCooldown(tt.streak, 3, time.Hour); got != tt.want {
+			t.Errorf("breakerCooldown(%d) = %s, want %s", tt.streak, got, tt.want)
+		}
+	}
+}
+
+func TestRunCtx_BreakerSkipsAfterRepeatedRateLimits(t *testing.T) {
+	var hits atomic.Int32
+	srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		hits.Add(1)
+		w.WriteHeader(http.StatusTooManyRequests)
+	}))
+	defer srv.Close()
+
+	database := openTestDB(t)
+	client := newTestClient(t, srv.URL)
+	opts := Options{MaxCalls: 5, BreakerThreshold: 2}
+
+	for i := 0; i < 2; i++ {
+		if err := RunCtx(context.Background(), database, client, opts); err != nil {
+			t.Fatalf("RunCtx #%d: %v", i+1, err)
+		}
+	}
+	if until, _ := db.GetSyncState(database, stateCooldownUntil); until == "" {
+		t.Fatal("expected a cooldown after two rate-limited runs")
+	}
+
+	before := hits.Load()
+	if err := RunCtx(context.Background(), database, client, opts); err != nil {
+		t.Fatalf("RunCtx during cooldown: %v", err)
+	}
+	if hits.Load() != before {
+		t.Errorf("sync hit the API %d times during cooldown", hits.Load()-before)
+	}
+}
+
+func TestRecordRateLimit_CleanRunResetsStreak(t *testing.T) {
+	database := openTestDB(t)
+	opts := Options{BreakerThreshold: 3, BreakerCooldown: time.Hour}
+	now := time.Now()
+
+	recordRateLimit(database, opts, now, true)
+	recordRateLimit(database, opts, now, true)
+	recordRateLimit(database, opts, now, false)
+	recordRateLimit(database, opts, now, true)
+
+	if v, _ := db.GetSyncState(database, stateRateLimitStreak); v != "1" {
+		t.Errorf("streak = %q, want 1", v)
+	}
+	if wait := cooldownRemaining(database, now); wait != 0 {
+		t.Errorf("cooldown = %s, want none below the threshold", wait)
+	}
+}
@@ -0,0 +1,70 @@
+package sync
+
+import (
+	"context"
+	"time"
+)
+
+// Pacing between backfill windows: a fixed Options.WindowDelay, optionally
+// adapted to how SAM.gov is responding. In adaptive mode the delay doubles
+// after a window whose calls took noticeably longer than the previous
+// window's (an early sign of throttling) and halves after one that didn't,
+// never dropping below WindowDelay or rising above maxWindowDelay.
+const (
+	minAdaptiveStep = 500 * time.Millisecond
+	maxWindowDelay  = 30 * time.Second
+	// slowdownFactor is how much slower per call a window must be than the
+	// previous one before the adaptive delay grows.
+	slowdownFactor = 1.5
+)
+
+type windowPacer struct {
+	base     time.Duration
+	current  time.Duration
+	adaptive bool
+	lastCall time.Duration // mean time per API call in the previous window
+}
+
+func newWindowPacer(base time.Duration, adaptive bool) *windowPacer {
+	return &windowPacer{base: base, current: base, adaptive: adaptive}
+}
+
+// delay is the pause to take before the next window.
+func (p *windowPacer) delay() time.Duration {
+	return p.current
+}
+
+// observe records how long a window took across its API calls and, in
+// adaptive mode, adjusts the next delay.
+func (p *windowPacer) observe(elapsed time.Duration, calls int) {
+	if !p.adaptive || calls <= 0 {
+		return
+	}
+	perCall := elapsed / time.Duration(calls)
+	slower := p.lastCall > 0 && float64(perCall) > float64(p.lastCall)*slowdownFactor
+	p.lastCall = perCall
+	if slower {
+		p.current = min(max(p.current*2, minAdaptiveStep), maxWindowDelay)
+		return
+	}
+	p.current /= 2
+	if p.current < max(p.base, minAdaptiveStep) {
+		p.current = p.base
+	}
+}
+
+// sleepCtx waits for d or until ctx is done, returning ctx's error in the
+// latter case.
+func sleepCtx(ctx context.Context, d time.Duration) error {
+	if d <= 0 {
+		return nil
+	}
+	t := time.NewTimer(d)
+	defer t.Stop()
+	select {
+	case <-ctx.Done():
+		return ctx.Err()
+	case <-t.C:
+		return nil
+	}
+}
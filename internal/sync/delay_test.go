@@ -0,0 +1,56 @@
+package sync
+
+import (
+	"testing"
+	"time"
+)
+
+func TestWindowPacer_Fixed(t *testing.T) {
+	p := newWindowPacer(2*time.Second, false)
+	p.observe(time.Second, 1)
+	p.observe(10*time.Second, 1)
+	if got := p.delay(); got != 2*time.Second {
+		t.Errorf("fixed delay = %s, want 2s", got)
+	}
+}
+
+func TestWindowPacer_Adaptive(t *testing.T) {
+	p := newWindowPacer(0, true)
+	// Per-call time 1s, 2s, 4s (slowing), then 2s, 2s, 2s (recovered).
+	steps := []struct {
+		elapsed time.Duration
+		calls   int
+		want    time.Duration
+	}{
+		{time.Second, 1, 0},
+		{4 * time.Second, 2, minAdaptiveStep},
+		{8 * time.Second, 2, 2 * minAdaptiveStep},
+		{4 * time.Second, 2, minAdaptiveStep},
+		{2 * time.Second, 1, 0},
+		{2 * time.Second, 1, 0},
+	}
+	for i, s := range steps {
+		p.observe(s.elapsed, s.calls)
+		if got := p.delay(); got != s.want {
+			t.Errorf("step %d: delay = %s, want %s", i, got, s.want)
+		}
+	}
+}
+
+func TestWindowPacer_AdaptiveCapsAndFloors(t *testing.T) {
+	p := newWindowPacer(time.Second, true)
+	p.observe(time.Millisecond, 1)
+	for range 20 {
+		p.lastCall = time.Millisecond
+		p.observe(time.Second, 1)
+	}
+	if got := p.delay(); got != maxWindowDelay {
+		t.Errorf("delay after repeated slowdowns = %s, want cap %s", got, maxWindowDelay)
+	}
+	for range 20 {
+		p.observe(time.Millisecond, 1)
+	}
+	if got := p.delay(); got != time.Second {
+		t.Errorf("delay after recovery = %s, want base 1s", got)
+	}
+}
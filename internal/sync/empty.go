@@ -0,0 +1,48 @@
+package sync
+
+import (
+	"database/sql"
+	"log"
+	"time"
+
+	"github.com/theognis1002/govscout/internal/db"
+	"github.com/theognis1002/govscout/internal/samgov"
+)
+
+// Learned empty windows (Options.SkipEmptyWindows): a backfill window that
+// came back with no notices is remembered in sync_state, keyed by the search
+// scope (windowScope) and the window dates, and later backfills skip it
+// until the record is older than the TTL. Windows that end inside the incremental horizon are
+// never recorded, since late postings there are still likely.
+const emptyWindowPrefix = "empty_window:"
+
+func emptyWindowKey(scope string, from, to samgov.Date) string {
+	return emptyWindowPrefix + scope + ":" + from.String() + "-" + to.String()
+}
+
+// knownEmpty reports whether key was recorded empty within ttl. Expired or
+// unreadable records are dropped so the window is fetched again.
+func knownEmpty(database *sql.DB, key string, ttl time.Duration, now time.Time) bool {
+	v, err := db.GetSyncState(database, key)
+	if err != nil || v == "" {
+		return false
+	}
+	at, err := time.Parse(time.RFC3339, v)
+	if err != nil || now.Sub(at) >= ttl {
+		if err := db.DeleteSyncState(database, key); err != nil {
+			log.Printf("drop expired %s: %v", key, err)
+		}
+		return false
+	}
+	return true
+}
+
+// worthRecordingEmpty is true for a window SAM.gov fully answered with no
+// notices, old enough that new postings in it are unlikely. A window whose
+// notices were all dropped by an active/inactive filter isn't empty: they
+// can change status.
+func worthRecordingEmpty(result *samgov.WindowResult, windowTo, today samgov.Date) bool {
+	return result.TotalFetched == 0 && result.DuplicatesSkipped == 0 && result.StatusSkipped == 0 &&
+		!result.RateLimited && !result.Maintenance && !result.ApproachingLimit &&
+		windowTo.Before(today.AddDays(-maxIncrementalDays))
+}
@@ -0,0 +1,92 @@
+package sync
+
+import (
+	"context"
+	"fmt"
+	"net/http"
+	"net/http/httptest"
+	"sync"
+	"testing"
+	"time"
+
+	"github.com/theognis1002/govscout/internal/db"
+	"github.com/theognis1002/govscout/internal/samgov"
+)
+
+func TestRunWithSummary_SkipsLearnedEmptyWindows(t *testing.T) {
+	var mu sync.Mutex
+	var windows []string
+	srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		mu.Lock()
+		windows = append(windows, r.URL.Query().Get("postedFrom")+"-"+r.URL.Query().Get("postedTo"))
+		mu.Unlock()
+		fmt.Fprint(w, `{"totalRecords":0,"opportunitiesData":[]}`)
+	}))
+	defer srv.Close()
+	backfilled := func() []string {
+		mu.Lock()
+		defer mu.Unlock()
+		got := windows[1:] // drop the incremental window
+		windows = nil
+		return got
+	}
+
+	database := openTestDB(t)
+	client := newTestClient(t, srv.URL)
+	cursor, _ := samgov.ParseDate("01/01/2020")
+	floor, _ := samgov.ParseDate("01/01/2019")
+	first := cursor.AddDays(-backfillWindowDays).String() + "-" + cursor.String()
+	second := cursor.AddDays(-2*backfillWindowDays).String() + "-" + cursor.AddDays(-backfillWindowDays).String()
+	opts := Options{MaxCalls: 3, From: floor, SkipEmptyWindows: 30 * 24 * time.Hour}
+	run := func(opts Options) *Summary {
+		t.Helper()
+		if err := db.SetSyncState(database, "backfill_cursor", cursor.String()); err != nil {
+			t.Fatal(err)
+		}
+		sum, err := RunWithSummary(context.Background(), database, client, opts)
+		if err != nil {
+			t.Fatal(err)
+		}
+		return sum
+	}
+
+	if sum := run(opts); sum.EmptySkipped != 0 {
+		t.Fatalf("first run skipped %d windows, want 0", sum.EmptySkipped)
+	}
+	if got := backfilled(); len(got) != 1 || got[0] != first {
+		t.Fatalf("first run fetched %v, want %s", got, first)
+	}
+
+	// The empty window is skipped for free, so the budget reaches the next one.
+	if sum := run(opts); sum.EmptySkipped != 1 {
+		t.Errorf("second run skipped %d windows, want 1", sum.EmptySkipped)
+	}
+	if got := backfilled(); len(got) != 1 || got[0] != second {
+		t.Errorf("second run fetched %v, want %s", got, second)
+	}
+
+	// Opt-in: without a TTL every window is fetched again.
+	opts.SkipEmptyWindows = 0
+	run(opts)
+	if got := backfilled(); len(got) != 1 || got[0] != first {
+		t.Errorf("run without skipping fetched %v, want %s", got, first)
+	}
+}
+
+func TestKnownEmpty_Expires(t *testing.T) {
+	database := openTestDB(t)
+	now := time.Date(2026, 3, 1, 0, 0, 0, 0, time.UTC)
+	key := emptyWindowKey("all", samgov.NewDate(now.AddDate(-1, 0, 0)), samgov.NewDate(now.AddDate(-1, 3, 0)))
+	if err := db.SetSyncState(database, key, now.Add(-48*time.Hour).Format(time.RFC3339)); err != nil {
+		t.Fatal(err)
+	}
+	if !knownEmpty(database, key, 72*time.Hour, now) {
+		t.Error("record inside the TTL should count as empty")
+	}
+	if knownEmpty(database, key, 24*time.Hour, now) {
+		t.Error("record older than the TTL should not count")
+	}
+	if v, _ := db.GetSyncState(database, key); v != "" {
+		t.Errorf("expired record kept: %q", v)
+	}
+}
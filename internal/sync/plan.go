@@ -0,0 +1,89 @@
+package sync
+
+import (
+	"database/sql"
+	"errors"
+	"fmt"
+	"math"
+	"time"
+
+	"github.com/theognis1002/govscout/internal/db"
+	"github.com/theognis1002/govscout/internal/samgov"
+)
+
+// planSampleRuns is how many recent backfill windows PlanBackfill averages
+// to estimate pages per window.
+const planSampleRuns = 50
+
+// Plan projects what finishing a backfill would cost, for `sync --plan`.
+// Nothing is fetched or written to build it.
+type Plan struct {
+	Direction      string  `json:"direction"` // backward or forward
+	Cursor         string  `json:"cursor"`    // where the next backfill window starts
+	Target         string  `json:"target"`    // --from (backward) or today (forward)
+	WindowDays     int     `json:"window_days"`
+	Windows        int     `json:"windows"`
+	PagesPerWindow float64 `json:"pages_per_window"` // averaged from SampleRuns, else 1
+	SampleRuns     int     `json:"sample_runs"`      // recorded backfill windows the average came from
+	EstimatedCalls int     `json:"estimated_calls"`
+	MaxCalls       int     `json:"max_calls"`
+	Runs           int     `json:"runs"` // sync runs needed at MaxCalls, after each run's incremental call
+}
+
+// PlanBackfill counts the backfill windows between the resolved cursor and
+// the target date and estimates the API calls they need. Backward plans
+// need opts.From as the target; forward plans run up to today.
+func PlanBackfill(database *sql.DB, opts Options) (*Plan, error) {
+	if opts.MaxCalls <= 0 {
+		opts.MaxCalls = 18
+	}
+	today := samgov.NewDate(time.Now())
+	p := &Plan{Direction: "backward", WindowDays: backfillWindowDays, MaxCalls: opts.MaxCalls}
+
+	var cursor, target samgov.Date
+	var err error
+	if opts.Forward {
+		p.Direction = "forward"
+		if cursor, err = resolveForwardCursor(database, StateKey(forwardCursorKey, opts), opts.From); err != nil {
+			return nil, fmt.Errorf("resolve cursor: %w", err)
+		}
+		target = today
+	} else {
+		if opts.From.IsZero() {
+			return nil, errors.New("a backward plan needs a target date (--from)")
+		}
+		lookback := opts.IncrementalDays
+		if lookback <= 0 {
+			lookback = resolveIncrementalDays(database, StateKey("last_sync", opts), today)
+		}
+		if cursor, err = resolveBackfillCursor(database, StateKey("backfill_cursor", opts), today, lookback); err != nil {
+			return nil, fmt.Errorf("resolve cursor: %w", err)
+		}
+		target = opts.From
+	}
+	p.Cursor, p.Target = cursor.String(), target.String()
+
+	days := target.DaysSince(cursor)
+	if !opts.Forward {
+		days = -days
+	}
+	if days > 0 {
+		p.Windows = (days + backfillWindowDays - 1) / backfillWindowDays
+	}
+
+	runs, avg, err := db.BackfillCallSample(database, planSampleRuns)
+	if err != nil {
+		return nil, fmt.Errorf("sample backfill runs: %w", err)
+	}
+	p.SampleRuns, p.PagesPerWindow = runs, 1
+	if runs > 0 {
+		p.PagesPerWindow = avg
+	}
+	p.EstimatedCalls = int(math.Ceil(float64(p.Windows) * p.PagesPerWindow))
+
+	// Each run spends at least one call on its incremental window first.
+	if perRun := opts.MaxCalls - 1; p.EstimatedCalls > 0 && perRun > 0 {
+		p.Runs = (p.EstimatedCalls + perRun - 1) / perRun
+	}
+	return p, nil
+}
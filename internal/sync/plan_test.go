@@ -0,0 +1,57 @@
+package sync
+
+import (
+	"testing"
+	"time"
+
+	"github.com/theognis1002/govscout/internal/db"
+	"github.com/theognis1002/govscout/internal/samgov"
+)
+
+func TestPlanBackfill(t *testing.T) {
+	database := openTestDB(t)
+	if err := db.SetSyncState(database, "backfill_cursor", "01/01/2026"); err != nil {
+		t.Fatal(err)
+	}
+	msg := "api error 500"
+	db.InsertSyncRun(database, "backfill", "10/03/2025", "01/01/2026", 2, 40, false, nil)
+	db.InsertSyncRun(database, "backfill", "07/05/2025", "10/03/2025", 1, 10, false, nil)
+	db.InsertSyncRun(database, "backfill", "04/06/2025", "07/05/2025", 9, 0, false, &msg)
+	db.InsertSyncRun(database, "incremental", "12/29/2025", "01/01/2026", 7, 5, false, nil)
+
+	from, _ := samgov.ParseDate("01/01/2025")
+	plan, err := PlanBackfill(database, Options{From: from, MaxCalls: 5})
+	if err != nil {
+		t.Fatal(err)
+	}
+	// 365 days back at 90 days per window; errored and incremental runs are
+	// left out of the 1.5 pages-per-window sample.
+	if plan.Windows != 5 || plan.SampleRuns != 2 || plan.PagesPerWindow != 1.5 {
+		t.Errorf("plan = %+v, want 5 windows at 1.5 pages from 2 samples", plan)
+	}
+	if plan.EstimatedCalls != 8 || plan.Runs != 2 {
+		t.Errorf("plan = %+v, want 8 calls over 2 runs of 4 backfill calls", plan)
+	}
+	if runs, _ := db.ListSyncRuns(database, 10); len(runs) != 4 {
+		t.Errorf("plan wrote sync runs: got %d, want 4", len(runs))
+	}
+
+	if _, err := PlanBackfill(database, Options{}); err == nil {
+		t.Error("backward plan without --from should fail")
+	}
+	if _, err := PlanBackfill(database, Options{Forward: true}); err == nil {
+		t.Error("first forward plan without --from should fail")
+	}
+}
+
+func TestPlanBackfill_ForwardWithoutHistory(t *testing.T) {
+	database := openTestDB(t)
+	from := samgov.NewDate(time.Now()).AddDays(-100)
+	plan, err := PlanBackfill(database, Options{From: from, Forward: true})
+	if err != nil {
+		t.Fatal(err)
+	}
+	if plan.Direction != "forward" || plan.Windows != 2 || plan.PagesPerWindow != 1 || plan.EstimatedCalls != 2 || plan.Runs != 1 {
+		t.Errorf("plan = %+v, want 2 forward windows at 1 page each in 1 run", plan)
+	}
+}
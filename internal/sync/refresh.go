@@ -0,0 +1,106 @@
+package sync
+
+import (
+	"context"
+	"database/sql"
+	"errors"
+	"fmt"
+	"log"
+	"time"
+
+	"github.com/theognis1002/govscout/internal/db"
+	"github.com/theognis1002/govscout/internal/samgov"
+)
+
+// RefreshResult summarizes a Refresh run.
+type RefreshResult struct {
+	Checked     int // notices looked up
+	Changed     int // stored row updated with newer data
+	Unchanged   int // SAM.gov returned exactly what was stored
+	Missing     int // SAM.gov no longer returns the notice
+	Failed      int // lookups that errored (logged, left stale)
+	APICalls    int
+	RateLimited bool
+	Maintenance bool
+}
+
+// Refresh re-fetches up to limit opportunities not changed or re-checked
+// since cutoff (see db.ListStaleOpportunityIDs), one API call per notice and
+// never more than maxCalls. Every notice looked up is marked refreshed, so
+// the next run moves on to the next-oldest rows. Stops early on rate limits
+// and SAM.gov maintenance.
+func Refresh(ctx context.Context, database *sql.DB, client samgov.Source, cutoff string, limit, maxCalls int) (*RefreshResult, error) {
+	if maxCalls <= 0 {
+		maxCalls = 18
+	}
+	ids, err := db.ListStaleOpportunityIDs(database, cutoff, min(limit, maxCalls))
+	if err != nil {
+		return nil, fmt.Errorf("list stale: %w", err)
+	}
+	return refreshIDs(ctx, database, client, ids, "refresh", 0)
+}
+
+// refreshIDs re-fetches each notice in ids, pausing between lookups, and
+// records the pass as one sync_runs row under runContext.
+func refreshIDs(ctx context.Context, database *sql.DB, client samgov.Source, ids []string, runContext string, pause time.Duration) (*RefreshResult, error) {
+	var res RefreshResult
+	for i, id := range ids {
+		if err := ctx.Err(); err != nil {
+			return &res, err
+		}
+		if i > 0 {
+			if err := sleepCtx(ctx, pause); err != nil {
+				return &res, err
+			}
+		}
+		res.APICalls++
+		resp, err := client.SearchCtx(ctx, samgov.SearchParams{NoticeID: samgov.NoticeID(id), Limit: 1})
+		if errors.Is(err, samgov.ErrRateLimited) {
+			log.Printf("rate limited during %s, stopping", runContext)
+			res.RateLimited = true
+			break
+		}
+		if errors.Is(err, samgov.ErrMaintenance) {
+			log.Printf("SAM.gov is down for maintenance during %s, stopping", runContext)
+			res.Maintenance = true
+			break
+		}
+		if err != nil {
+			if ctx.Err() != nil {
+				return &res, ctx.Err()
+			}
+			log.Printf("refresh %s: %v", id, err)
+			res.Failed++
+			continue
+		}
+		res.Checked++
+
+		if len(resp.OpportunitiesData) == 0 {
+			res.Missing++
+		} else {
+			outcome, err := db.UpsertOpportunityFromAPI(database, resp.OpportunitiesData[0])
+			if err != nil {
+				log.Printf("refresh %s: upsert: %v", id, err)
+				res.Failed++
+				continue
+			}
+			switch outcome {
+			case db.UpsertUnchanged:
+				res.Unchanged++
+			default:
+				res.Changed++
+			}
+		}
+		if err := db.MarkRefreshed(database, id); err != nil {
+			return &res, fmt.Errorf("mark refreshed %s: %w", id, err)
+		}
+	}
+
+	var note *string
+	if res.Maintenance {
+		msg := samgov.ErrMaintenance.Error()
+		note = &msg
+	}
+	db.InsertSyncRun(database, runContext, "", "", res.APICalls, res.Changed, res.RateLimited, note)
+	return &res, nil
+}
@@ -0,0 +1,93 @@
+package sync
+
+import (
+	"context"
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/theognis1002/govscout/internal/samgov"
+)
+
+func TestRefresh(t *testing.T) {
+	dir := t.TempDir()
+	fixture := `{"totalRecords":2,"opportunitiesData":[
+		{"noticeId":"stale-changed","title":"Widgets (awarded)","active":"No"},
+		{"noticeId":"stale-same","title":"Gadgets"}]}`
+	if err := os.WriteFile(filepath.Join(dir, "page.json"), []byte(fixture), 0o644); err != nil {
+		t.Fatal(err)
+	}
+	database := openTestDB(t)
+	client := samgov.NewMockClient(dir)
+
+	// stale-same is stored exactly as SAM.gov will return it.
+	resp, err := client.SearchCtx(context.Background(), samgov.SearchParams{NoticeID: "stale-same"})
+	if err != nil {
+		t.Fatal(err)
+	}
+	if err := (&upsertCounts{}).store(database)(resp.OpportunitiesData); err != nil {
+		t.Fatal(err)
+	}
+	if _, err := database.Exec(`INSERT INTO opportunities (id, title, active) VALUES
+		('stale-changed', 'Widgets', 1), ('stale-gone', 'Old', 1)`); err != nil {
+		t.Fatal(err)
+	}
+	if _, err := database.Exec(`UPDATE opportunities SET modified_at = '2024-01-01 00:00:00'`); err != nil {
+		t.Fatal(err)
+	}
+
+	res, err := Refresh(context.Background(), database, client, "2024-06-01 00:00:00", 10, 18)
+	if err != nil {
+		t.Fatal(err)
+	}
+	want := RefreshResult{Checked: 3, Changed: 1, Unchanged: 1, Missing: 1, APICalls: 3}
+	if *res != want {
+		t.Errorf("Refresh = %+v, want %+v", *res, want)
+	}
+
+	var title string
+	var active int
+	if err := database.QueryRow(`SELECT title, active FROM opportunities WHERE id = 'stale-changed'`).Scan(&title, &active); err != nil {
+		t.Fatal(err)
+	}
+	if title != "Widgets (awarded)" || active != 0 {
+		t.Errorf("stale-changed = %q active=%d, want refreshed values", title, active)
+	}
+
+	// Everything was just re-checked, so nothing is stale any more.
+	res, err = Refresh(context.Background(), database, client, "2024-06-01 00:00:00", 10, 18)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if res.Checked != 0 || res.APICalls != 0 {
+		t.Errorf("second Refresh = %+v, want nothing to do", *res)
+	}
+}
+
+func TestRefresh_RespectsMaxCalls(t *testing.T) {
+	database := openTestDB(t)
+	if _, err := database.Exec(`INSERT INTO opportunities (id, modified_at) VALUES
+		('a', '2024-01-01 00:00:00'), ('b', '2024-01-01 00:00:00'), ('c', '2024-01-01 00:00:00')`); err != nil {
+		t.Fatal(err)
+	}
+	res, err := Refresh(context.Background(), database, unrelatedMock(t), "2024-06-01 00:00:00", 10, 2)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if res.APICalls != 2 || res.Missing != 2 {
+		t.Errorf("Refresh = %+v, want 2 calls", *res)
+	}
+}
+
+// unrelatedMock is a MockClient whose only fixture is a notice no test
+// stores, so lookups succeed with no results. (An empty fixtures directory
+// is a load error, which fails every lookup instead.)
+func unrelatedMock(t *testing.T) *samgov.MockClient {
+	t.Helper()
+	dir := t.TempDir()
+	fixture := `{"totalRecords":1,"opportunitiesData":[{"noticeId":"unrelated","title":"Elsewhere"}]}`
+	if err := os.WriteFile(filepath.Join(dir, "page.json"), []byte(fixture), 0o644); err != nil {
+		t.Fatal(err)
+	}
+	return samgov.NewMockClient(dir)
+}
@@ -6,7 +6,10 @@ import (
 	"fmt"
 	"net/http"
 	"net/http/httptest"
+	"os"
 	"path/filepath"
+	"sync"
+	"sync/atomic"
 	"testing"
 	"time"
 
@@ -92,3 +95,254 @@ func TestRunCtx_RateLimitedIncrementalStopsGracefully(t *testing.T) {
 		t.Fatalf("expected nil on rate limit (graceful), got %v", err)
 	}
 }
+
+func TestRunCtx_OnlyNewCountsInsertsOnly(t *testing.T) {
+	srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		fmt.Fprint(w, `{"totalRecords":1,"opportunitiesData":[{"noticeId":"opp-1","title":"Widgets"}]}`)
+	}))
+	defer srv.Close()
+
+	database := openTestDB(t)
+	client := newTestClient(t, srv.URL)
+	// MaxCalls 1 leaves no budget for backfill, so only the incremental window runs.
+	opts := Options{MaxCalls: 1, OnlyNew: true}
+
+	for i := 0; i < 2; i++ {
+		if err := RunCtx(context.Background(), database, client, opts); err != nil {
+			t.Fatalf("RunCtx #%d: %v", i+1, err)
+		}
+	}
+
+	runs, err := db.ListSyncRuns(database, 10)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(runs) != 2 {
+		t.Fatalf("got %d sync runs, want 2", len(runs))
+	}
+	// Newest first: the second run re-fetched the same record.
+	if runs[0].RecordsFetched != 0 || runs[1].RecordsFetched != 1 {
+		t.Errorf("records_fetched = [%d, %d], want [0, 1]", runs[0].RecordsFetched, runs[1].RecordsFetched)
+	}
+}
+
+func TestRunCtx_MockSourceEndToEnd(t *testing.T) {
+	dir := t.TempDir()
+	posted := time.Now().Format("2006-01-02")
+	fixture := fmt.Sprintf(`{"totalRecords":2,"opportunitiesData":[
+		{"noticeId":"mock-1","title":"Widgets","postedDate":%q},
+		{"noticeId":"mock-2","title":"Gadgets","postedDate":%q}]}`, posted, posted)
+	if err := os.WriteFile(filepath.Join(dir, "page.json"), []byte(fixture), 0o644); err != nil {
+		t.Fatal(err)
+	}
+
+	database := openTestDB(t)
+	if err := RunCtx(context.Background(), database, samgov.NewMockClient(dir), Options{MaxCalls: 1}); err != nil {
+		t.Fatalf("RunCtx: %v", err)
+	}
+
+	var n int
+	if err := database.QueryRow("SELECT COUNT(*) FROM opportunities").Scan(&n); err != nil {
+		t.Fatal(err)
+	}
+	if n != 2 {
+		t.Errorf("stored %d opportunities, want 2", n)
+	}
+}
+
+func TestRunCtx_ForwardBackfill(t *testing.T) {
+	var mu sync.Mutex
+	var windows []string
+	srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		mu.Lock()
+		defer mu.Unlock()
+		windows = append(windows, r.URL.Query().Get("postedFrom")+"-"+r.URL.Query().Get("postedTo"))
+		fmt.Fprint(w, `{"totalRecords":0,"opportunitiesData":[]}`)
+	}))
+	defer srv.Close()
+	takeWindows := func() []string {
+		mu.Lock()
+		defer mu.Unlock()
+		got := windows
+		windows = nil
+		return got
+	}
+
+	database := openTestDB(t)
+	client := newTestClient(t, srv.URL)
+	today := samgov.NewDate(time.Now())
+	start := today.AddDays(-100)
+
+	if err := RunCtx(context.Background(), database, client, Options{MaxCalls: 1, Forward: true}); err == nil {
+		t.Fatal("expected an error for forward backfill without a start date")
+	}
+	if got := takeWindows(); len(got) != 0 {
+		t.Fatalf("made %d calls before failing, want 0", len(got))
+	}
+
+	// Budget for the incremental window plus one backfill window.
+	if err := RunCtx(context.Background(), database, client, Options{MaxCalls: 3, Forward: true, From: start}); err != nil {
+		t.Fatalf("RunCtx: %v", err)
+	}
+	want := start.String() + "-" + start.AddDays(backfillWindowDays).String()
+	if got := takeWindows(); len(got) != 2 || got[1] != want {
+		t.Fatalf("windows = %v, want backfill %s", got, want)
+	}
+	cursor, err := db.GetSyncState(database, forwardCursorKey)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if cursor != start.AddDays(backfillWindowDays).String() {
+		t.Errorf("forward cursor = %q, want %s", cursor, start.AddDays(backfillWindowDays))
+	}
+	if backward, _ := db.GetSyncState(database, "backfill_cursor"); backward != "" {
+		t.Errorf("backfill_cursor = %q, want untouched", backward)
+	}
+
+	// The next run resumes from the cursor and stops at today.
+	if err := RunCtx(context.Background(), database, client, Options{MaxCalls: 10, Forward: true}); err != nil {
+		t.Fatalf("RunCtx: %v", err)
+	}
+	want = start.AddDays(backfillWindowDays).String() + "-" + today.String()
+	if got := takeWindows(); len(got) != 2 || got[1] != want {
+		t.Errorf("windows = %v, want backfill %s then stop", got, want)
+	}
+}
+
+func TestRunWithSummary(t *testing.T) {
+	srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		fmt.Fprint(w, `{"totalRecords":1,"opportunitiesData":[{"noticeId":"opp-1","title":"Widgets"}]}`)
+	}))
+	defer srv.Close()
+
+	database := openTestDB(t)
+	client := newTestClient(t, srv.URL)
+
+	// Budget for the incremental window plus one backfill window, both of
+	// which return the same record.
+	sum, err := RunWithSummary(context.Background(), database, client, Options{MaxCalls: 3})
+	if err != nil {
+		t.Fatalf("RunWithSummary: %v", err)
+	}
+	if len(sum.Windows) != 2 || sum.Windows[0].Phase != "incremental" || sum.Windows[1].Phase != "backfill" {
+		t.Fatalf("windows = %+v, want incremental then backfill", sum.Windows)
+	}
+	if sum.APICalls != 2 || sum.Records != 2 || sum.Inserted != 1 || sum.Unchanged != 1 || sum.RateLimited {
+		t.Errorf("summary = %+v, want 2 calls, 2 records, 1 inserted, 1 unchanged", *sum)
+	}
+}
+
+func TestRunWithSummary_MaintenanceStopsBackfillWithoutAdvancing(t *testing.T) {
+	var calls atomic.Int64
+	srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		if calls.Add(1) == 1 {
+			fmt.Fprint(w, `{"totalRecords":0,"opportunitiesData":[]}`)
+			return
+		}
+		w.Header().Set("Content-Type", "text/html")
+		w.WriteHeader(http.StatusServiceUnavailable)
+		fmt.Fprint(w, `<html><body>SAM.gov is currently undergoing scheduled maintenance.</body></html>`)
+	}))
+	defer srv.Close()
+
+	database := openTestDB(t)
+	client := newTestClient(t, srv.URL)
+
+	sum, err := RunWithSummary(context.Background(), database, client, Options{MaxCalls: 10})
+	if err != nil {
+		t.Fatalf("expected nil on maintenance (graceful), got %v", err)
+	}
+	if !sum.Maintenance || sum.RateLimited {
+		t.Errorf("summary = %+v, want maintenance and not rate limited", *sum)
+	}
+	if len(sum.Windows) != 2 {
+		t.Fatalf("windows = %+v, want incremental then one backfill window", sum.Windows)
+	}
+	if n := calls.Load(); n != 2 {
+		t.Errorf("calls = %d, want 2 (maintenance is not retried)", n)
+	}
+
+	cursor, err := db.GetSyncState(database, "backfill_cursor")
+	if err != nil {
+		t.Fatal(err)
+	}
+	if cursor != "" {
+		t.Errorf("backfill_cursor = %q, want it left unset so the window is refetched", cursor)
+	}
+
+	runs, err := db.ListSyncRuns(database, 1)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(runs) != 1 || runs[0].ErrorMessage == nil || *runs[0].ErrorMessage != samgov.ErrMaintenance.Error() {
+		t.Errorf("latest sync run = %+v, want the maintenance note", runs)
+	}
+}
+
+func TestRunWithSummary_LowRateLimitRemainingSkipsBackfill(t *testing.T) {
+	var calls atomic.Int64
+	srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		calls.Add(1)
+		w.Header().Set("X-RateLimit-Remaining", "1")
+		fmt.Fprint(w, `{"totalRecords":0,"opportunitiesData":[]}`)
+	}))
+	defer srv.Close()
+
+	database := openTestDB(t)
+	client := newTestClient(t, srv.URL)
+
+	sum, err := RunWithSummary(context.Background(), database, client, Options{MaxCalls: 10})
+	if err != nil {
+		t.Fatalf("RunWithSummary: %v", err)
+	}
+	if calls.Load() != 1 || len(sum.Windows) != 1 {
+		t.Errorf("calls = %d, windows = %+v; want the incremental window only", calls.Load(), sum.Windows)
+	}
+	if !sum.ApproachingLimit || sum.RateLimited || sum.RateLimitRemaining == nil || *sum.RateLimitRemaining != 1 {
+		t.Errorf("summary = %+v, want approaching limit with 1 remaining and no rate limit", *sum)
+	}
+}
+
+func TestRunWithSummary_FilteredSyncKeepsItsOwnState(t *testing.T) {
+	var mu sync.Mutex
+	var queries []string
+	srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		mu.Lock()
+		queries = append(queries, r.URL.Query().Get("ncode")+"|"+r.URL.Query().Get("typeOfSetAside"))
+		mu.Unlock()
+		fmt.Fprint(w, `{"totalRecords":0,"opportunitiesData":[]}`)
+	}))
+	defer srv.Close()
+
+	database := openTestDB(t)
+	opts := Options{MaxCalls: 3, Filter: samgov.SearchParams{NAICS: "541511", SetAside: "SBA"}}
+	sum, err := RunWithSummary(context.Background(), database, newTestClient(t, srv.URL), opts)
+	if err != nil {
+		t.Fatalf("RunWithSummary: %v", err)
+	}
+	if len(sum.Windows) != 2 || len(queries) != 2 {
+		t.Fatalf("windows = %+v, want the incremental window and one backfill window", sum.Windows)
+	}
+	for _, q := range queries {
+		if q != "541511|SBA" {
+			t.Errorf("search sent ncode|typeOfSetAside = %q, want 541511|SBA in both phases", q)
+		}
+	}
+
+	const scope = "naics=541511,set-aside=SBA"
+	for _, key := range []string{"backfill_cursor", "last_sync"} {
+		if v, _ := db.GetSyncState(database, key+":"+scope); v == "" {
+			t.Errorf("%s:%s unset, want the filtered sync's own state", key, scope)
+		}
+		if v, _ := db.GetSyncState(database, key); v != "" {
+			t.Errorf("%s = %q, want the full sync's state left alone", key, v)
+		}
+	}
+	runs, err := db.ListSyncRuns(database, 2)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(runs) != 2 || runs[0].Context != "backfill "+scope || runs[1].Context != "incremental "+scope {
+		t.Errorf("sync runs = %+v, want contexts carrying the scope", runs)
+	}
+}
@@ -6,6 +6,7 @@ import (
 	"errors"
 	"fmt"
 	"log"
+	"strings"
 	"time"
 
 	"github.com/theognis1002/govscout/internal/db"
@@ -15,21 +16,151 @@ import (
 const (
 	backfillWindowDays = 90
 	incrementalDays    = 3
-	dateFmt            = "01/02/2006"
+	// maxIncrementalDays caps the lookback derived from last_sync so a long
+	// outage doesn't turn the incremental phase into one huge window; the
+	// backfill phase picks up anything older.
+	maxIncrementalDays = 30
+
+	// forwardCursorKey tracks forward (oldest-first) backfill separately so
+	// switching direction never clobbers the backward cursor.
+	forwardCursorKey = "backfill_cursor_forward"
 )
 
 type Options struct {
 	MaxCalls int
 	DryRun   bool
-	From     string
+	From     samgov.Date // backfill floor; zero means no floor. Forward: the start date
+	// Forward backfills oldest-first from From (or the saved
+	// backfill_cursor_forward) toward today, so partial progress is a
+	// contiguous stretch of history. The default walks back from today.
+	Forward bool
+
+	// IncrementalDays overrides the incremental lookback. Zero derives it
+	// from last_sync (gap since the previous run plus one day of overlap).
+	IncrementalDays int
+	// OnlyNew records only genuinely new inserts as records_synced, so
+	// re-fetching already stored windows doesn't inflate the sync history.
+	OnlyNew bool
+	// BreakerThreshold is how many consecutive rate-limited runs trip the
+	// circuit breaker (zero means 3, negative disables it).
+	BreakerThreshold int
+	// BreakerCooldown is the first pause once the breaker trips (zero means
+	// one hour); it doubles per further rate-limited run, up to 24h.
+	BreakerCooldown time.Duration
+	// WindowDelay is a pause before each backfill window to spread load;
+	// zero means none. Skipped in dry runs.
+	WindowDelay time.Duration
+	// AdaptiveDelay grows the pause when SAM.gov responses slow down and
+	// shrinks it back toward WindowDelay when they recover (see windowPacer).
+	AdaptiveDelay bool
+	// SkipEmptyWindows, when positive, remembers backfill windows that
+	// returned nothing and skips them on later backfills of the same scope
+	// for this long (see empty.go). Zero always fetches every window.
+	SkipEmptyWindows time.Duration
+	// Strict aborts the run on the first upsert error instead of logging
+	// it and skipping the record.
+	Strict bool
+	// BatchSize commits upserts in transactions of up to this many writes,
+	// flushed at least once per page, instead of one transaction per
+	// record. Zero or one keeps per-record transactions.
+	BatchSize int
+	// CompressDescriptions stores the descriptions of records this run
+	// writes gzip-compressed (see db.CompressDescriptions).
+	CompressDescriptions bool
+	// Filter narrows both phases' searches (NAICS, set-aside, state, ...)
+	// so targeted users spend calls only on notices they want. A filtered
+	// sync keeps its own cursors and last_sync (see StateKey). The zero
+	// value syncs everything.
+	Filter samgov.SearchParams
 }
 
 // Run is a backwards-compatible wrapper for RunCtx.
-func Run(database *sql.DB, client *samgov.Client, opts Options) error {
+func Run(database *sql.DB, client samgov.Source, opts Options) error {
 	return RunCtx(context.Background(), database, client, opts)
 }
 
-func RunCtx(ctx context.Context, database *sql.DB, client *samgov.Client, opts Options) (retErr error) {
+// Summary is what a sync run did, for `sync --json` and other automation.
+type Summary struct {
+	DryRun             bool            `json:"dry_run"`
+	Skipped            string          `json:"skipped,omitempty"` // why nothing ran, e.g. the rate-limit cooldown
+	APICalls           int             `json:"api_calls"`
+	CacheHits          int             `json:"cache_hits"` // pages served from the response cache; not in APICalls
+	Records            int             `json:"records_fetched"`
+	Inserted           int             `json:"inserted"`
+	Updated            int             `json:"updated"`
+	Unchanged          int             `json:"unchanged"`
+	UpsertErrors       int             `json:"upsert_errors"` // records that failed to store and were skipped
+	RateLimited        bool            `json:"rate_limited"`
+	Maintenance        bool            `json:"maintenance"` // SAM.gov served its downtime page
+	Duplicates         int             `json:"duplicates_skipped"`
+	EmptySkipped       int             `json:"empty_windows_skipped"`       // learned-empty backfill windows not re-queried
+	ApproachingLimit   bool            `json:"approaching_limit"`              // stopped early on a low X-RateLimit-Remaining
+	RateLimitRemaining *int            `json:"rate_limit_remaining,omitempty"` // last X-RateLimit-Remaining SAM.gov sent
+	Windows            []WindowSummary `json:"windows"`
+	Error              string          `json:"error,omitempty"`
+}
+
+// WindowSummary is one fetched window: the incremental window or one
+// backfill window.
+type WindowSummary struct {
+	Phase        string `json:"phase"` // incremental or backfill
+	From         string `json:"posted_from"`
+	To           string `json:"posted_to"`
+	APICalls     int    `json:"api_calls"`
+	Records      int    `json:"records_fetched"`
+	Inserted     int    `json:"inserted"`
+	Updated      int    `json:"updated"`
+	Unchanged    int    `json:"unchanged"`
+	UpsertErrors int    `json:"upsert_errors"`
+	RateLimited  bool   `json:"rate_limited"`
+	Maintenance  bool   `json:"maintenance"`
+	Duplicates   int    `json:"duplicates_skipped"`
+}
+
+// add records a fetched window and folds it into the totals.
+func (s *Summary) add(phase string, from, to samgov.Date, result *samgov.WindowResult, counts upsertCounts) {
+	s.Windows = append(s.Windows, WindowSummary{
+		Phase:        phase,
+		From:         from.String(),
+		To:           to.String(),
+		APICalls:     result.APICalls,
+		Records:      result.TotalFetched,
+		Inserted:     counts.inserted,
+		Updated:      counts.updated,
+		Unchanged:    counts.unchanged,
+		UpsertErrors: counts.failed,
+		RateLimited:  result.RateLimited,
+		Maintenance:  result.Maintenance,
+		Duplicates:   result.DuplicatesSkipped,
+	})
+	s.APICalls += result.APICalls
+	s.CacheHits += result.CacheHits
+	s.Records += result.TotalFetched
+	s.Inserted += counts.inserted
+	s.Updated += counts.updated
+	s.Unchanged += counts.unchanged
+	s.UpsertErrors += counts.failed
+	s.RateLimited = s.RateLimited || result.RateLimited
+	s.Maintenance = s.Maintenance || result.Maintenance
+	s.Duplicates += result.DuplicatesSkipped
+	s.ApproachingLimit = s.ApproachingLimit || result.ApproachingLimit || result.LowOnCalls()
+	if result.RateLimitRemaining >= 0 {
+		remaining := result.RateLimitRemaining
+		s.RateLimitRemaining = &remaining
+	}
+}
+
+// RunCtx syncs from client, which is the live SAM.gov client or a
+// samgov.MockClient.
+func RunCtx(ctx context.Context, database *sql.DB, client samgov.Source, opts Options) error {
+	_, err := RunWithSummary(ctx, database, client, opts)
+	return err
+}
+
+// RunWithSummary is RunCtx that also reports what the run did. The summary
+// is non-nil even when err is, covering the windows completed before it.
+func RunWithSummary(ctx context.Context, database *sql.DB, client samgov.Source, opts Options) (sum *Summary, retErr error) {
+	sum = &Summary{DryRun: opts.DryRun, Windows: []WindowSummary{}}
 	defer func() {
 		if r := recover(); r != nil {
 			err := fmt.Errorf("sync panic: %v", r)
@@ -43,42 +174,81 @@ func RunCtx(ctx context.Context, database *sql.DB, client *samgov.Client, opts O
 	if opts.MaxCalls <= 0 {
 		opts.MaxCalls = 18
 	}
+	if opts.BreakerThreshold == 0 {
+		opts.BreakerThreshold = defaultBreakerThreshold
+	}
+	if opts.BreakerCooldown <= 0 {
+		opts.BreakerCooldown = defaultBreakerCooldown
+	}
 	apiCallsUsed := 0
-	today := time.Now()
+	now := time.Now()
+	today := samgov.NewDate(now)
+
+	if !opts.DryRun && opts.BreakerThreshold > 0 {
+		if wait := cooldownRemaining(database, now); wait > 0 {
+			log.Printf("rate limit cooldown active for another %s; skipping sync", wait.Round(time.Second))
+			sum.Skipped = "rate limit cooldown until " + time.Now().Add(wait).UTC().Format(time.RFC3339)
+			return sum, nil
+		}
+	}
+
+	// Resolved up front so a forward run missing its start date fails before
+	// spending any calls.
+	var forwardCursor samgov.Date
+	if opts.Forward {
+		var err error
+		if forwardCursor, err = resolveForwardCursor(database, StateKey(forwardCursorKey, opts), opts.From); err != nil {
+			return sum, fmt.Errorf("resolve cursor: %w", err)
+		}
+	}
+	incrContext, backfillContext := runContext("incremental", opts), runContext("backfill", opts)
+	if scope := windowScope(opts); scope != "all" {
+		log.Printf("sync filter: %s", scope)
+	}
 
-	// Phase 1: Incremental (last 3 days)
-	incrFrom := today.AddDate(0, 0, -incrementalDays).Format(dateFmt)
-	incrTo := today.Format(dateFmt)
+	// Phase 1: Incremental
+	lookback := opts.IncrementalDays
+	if lookback <= 0 {
+		lookback = resolveIncrementalDays(database, StateKey("last_sync", opts), today)
+	}
+	incrFrom := today.AddDays(-lookback)
+	incrTo := today
 
-	log.Printf("incremental sync: %s to %s", incrFrom, incrTo)
+	log.Printf("incremental sync: %s to %s (%d days)", incrFrom, incrTo, lookback)
 	if opts.DryRun {
 		log.Printf("[dry-run] would fetch %s to %s", incrFrom, incrTo)
 	} else {
-		result, err := client.SearchWindowCtx(ctx, incrFrom, incrTo, func(opps []map[string]any) error {
-			for _, opp := range opps {
-				if err := db.UpsertOpportunityFromAPI(database, opp); err != nil {
-					log.Printf("upsert error: %v", err)
-				}
-			}
-			return nil
-		})
+		counts := upsertCounts{strict: opts.Strict, batchSize: opts.BatchSize, compress: opts.CompressDescriptions}
+		result, err := client.SearchWindowCtx(ctx, incrFrom, incrTo, windowFilter(opts, incrTo, today), counts.store(database))
 		if err != nil {
 			if errors.Is(err, context.Canceled) || errors.Is(err, context.DeadlineExceeded) {
 				errMsg := "cancelled: " + err.Error()
-				db.InsertSyncRun(database, "incremental", incrFrom, incrTo, 0, 0, false, &errMsg)
-				return err
+				db.InsertSyncRun(database, incrContext, incrFrom.String(), incrTo.String(), 0, 0, false, &errMsg)
+				return sum, err
 			}
+			sum.UpsertErrors += counts.failed
 			errMsg := err.Error()
-			db.InsertSyncRun(database, "incremental", incrFrom, incrTo, 0, 0, false, &errMsg)
-			return fmt.Errorf("incremental sync: %w", err)
+			db.InsertSyncRun(database, incrContext, incrFrom.String(), incrTo.String(), 0, 0, false, &errMsg)
+			return sum, fmt.Errorf("incremental sync: %w", err)
 		}
 		apiCallsUsed += result.APICalls
-		db.InsertSyncRun(database, "incremental", incrFrom, incrTo, result.APICalls, result.TotalFetched, result.RateLimited, nil)
-		log.Printf("incremental: %d records, %d api calls, rate_limited=%v", result.TotalFetched, result.APICalls, result.RateLimited)
+		sum.add("incremental", incrFrom, incrTo, result, counts)
+		db.InsertSyncRun(database, incrContext, incrFrom.String(), incrTo.String(), result.APICalls, counts.recorded(result, opts.OnlyNew), result.RateLimited, windowNote(result, counts))
+		log.Printf("incremental: %d records (%d new, %d updated, %d unchanged, %d upsert errors), %d api calls, rate_limited=%v",
+			result.TotalFetched, counts.inserted, counts.updated, counts.unchanged, counts.failed, result.APICalls, result.RateLimited)
 
 		if result.RateLimited {
 			log.Println("rate limited during incremental, stopping")
-			return nil
+			recordRateLimit(database, opts, time.Now(), true)
+			return sum, nil
+		}
+		if result.Maintenance {
+			log.Println("SAM.gov is down for maintenance during incremental, stopping; the next run resumes")
+			return sum, nil
+		}
+		if result.ApproachingLimit || result.LowOnCalls() {
+			log.Printf("approaching rate limit (X-RateLimit-Remaining=%d) after incremental, skipping backfill", result.RateLimitRemaining)
+			return sum, nil
 		}
 	}
 
@@ -86,83 +256,222 @@ func RunCtx(ctx context.Context, database *sql.DB, client *samgov.Client, opts O
 	remaining := opts.MaxCalls - apiCallsUsed
 	if remaining < 2 {
 		log.Println("no budget remaining for backfill")
+		recordRateLimit(database, opts, time.Now(), false)
 		checkpointLog(database)
-		return nil
-	}
-
-	cursor, err := resolveBackfillCursor(database, today)
-	if err != nil {
-		return fmt.Errorf("resolve cursor: %w", err)
+		return sum, nil
 	}
 
-	var backfillFloor *time.Time
-	if opts.From != "" {
-		t, err := time.Parse(dateFmt, opts.From)
-		if err != nil {
-			return fmt.Errorf("parse --from: %w", err)
+	cursorKey := StateKey("backfill_cursor", opts)
+	cursor := forwardCursor
+	if opts.Forward {
+		cursorKey = StateKey(forwardCursorKey, opts)
+	} else {
+		var err error
+		if cursor, err = resolveBackfillCursor(database, cursorKey, today, lookback); err != nil {
+			return sum, fmt.Errorf("resolve cursor: %w", err)
 		}
-		backfillFloor = &t
 	}
 
+	pacer := newWindowPacer(opts.WindowDelay, opts.AdaptiveDelay)
+	rateLimited := false
 	for apiCallsUsed+2 <= opts.MaxCalls {
 		if err := ctx.Err(); err != nil {
 			log.Printf("sync cancelled: %v", err)
-			return err
+			return sum, err
 		}
-		if backfillFloor != nil && !cursor.After(*backfillFloor) {
-			log.Printf("reached backfill floor %s", backfillFloor.Format(dateFmt))
-			break
+		var windowFrom, windowTo, next samgov.Date
+		if opts.Forward {
+			if !cursor.Before(today) {
+				log.Printf("forward backfill caught up to %s", today)
+				break
+			}
+			windowFrom = cursor
+			windowTo = cursor.AddDays(backfillWindowDays)
+			if windowTo.After(today) {
+				windowTo = today
+			}
+			next = windowTo
+		} else {
+			if !opts.From.IsZero() && !cursor.After(opts.From) {
+				log.Printf("reached backfill floor %s", opts.From)
+				break
+			}
+			windowTo = cursor
+			windowFrom = cursor.AddDays(-backfillWindowDays)
+			next = windowFrom
 		}
 
-		windowTo := cursor
-		windowFrom := cursor.AddDate(0, 0, -backfillWindowDays)
-
-		fromStr := windowFrom.Format(dateFmt)
-		toStr := windowTo.Format(dateFmt)
+		fromStr := windowFrom.String()
+		toStr := windowTo.String()
 		log.Printf("backfill window: %s to %s", fromStr, toStr)
 
+		emptyKey := emptyWindowKey(windowScope(opts), windowFrom, windowTo)
+		if opts.SkipEmptyWindows > 0 && knownEmpty(database, emptyKey, opts.SkipEmptyWindows, time.Now()) {
+			log.Printf("skipping %s to %s: returned no notices within the last %s", fromStr, toStr, opts.SkipEmptyWindows)
+			sum.EmptySkipped++
+			cursor = next
+			if !opts.DryRun {
+				db.SetSyncState(database, cursorKey, cursor.String())
+			}
+			continue
+		}
+
 		if opts.DryRun {
 			log.Printf("[dry-run] would fetch %s to %s", fromStr, toStr)
-			cursor = windowFrom
+			cursor = next
 			apiCallsUsed += 2
 			continue
 		}
 
-		result, err := client.SearchWindowCtx(ctx, fromStr, toStr, func(opps []map[string]any) error {
-			for _, opp := range opps {
-				if err := db.UpsertOpportunityFromAPI(database, opp); err != nil {
-					log.Printf("upsert error: %v", err)
-				}
+		if d := pacer.delay(); d > 0 && apiCallsUsed > 0 {
+			log.Printf("waiting %s before window", d)
+			if err := sleepCtx(ctx, d); err != nil {
+				log.Printf("sync cancelled: %v", err)
+				return sum, err
 			}
-			return nil
-		})
+		}
+
+		counts := upsertCounts{strict: opts.Strict, batchSize: opts.BatchSize, compress: opts.CompressDescriptions}
+		started := time.Now()
+		result, err := client.SearchWindowCtx(ctx, windowFrom, windowTo, windowFilter(opts, windowTo, today), counts.store(database))
 		if err != nil {
 			if errors.Is(err, context.Canceled) || errors.Is(err, context.DeadlineExceeded) {
 				errMsg := "cancelled: " + err.Error()
-				db.InsertSyncRun(database, "backfill", fromStr, toStr, 0, 0, false, &errMsg)
-				return err
+				db.InsertSyncRun(database, backfillContext, fromStr, toStr, 0, 0, false, &errMsg)
+				return sum, err
 			}
+			sum.UpsertErrors += counts.failed
 			errMsg := err.Error()
-			db.InsertSyncRun(database, "backfill", fromStr, toStr, 0, 0, false, &errMsg)
-			return fmt.Errorf("backfill: %w", err)
+			db.InsertSyncRun(database, backfillContext, fromStr, toStr, 0, 0, false, &errMsg)
+			return sum, fmt.Errorf("backfill: %w", err)
 		}
 
+		pacer.observe(time.Since(started), result.APICalls)
 		apiCallsUsed += result.APICalls
-		db.InsertSyncRun(database, "backfill", fromStr, toStr, result.APICalls, result.TotalFetched, result.RateLimited, nil)
-		log.Printf("backfill: %d records, %d api calls, rate_limited=%v", result.TotalFetched, result.APICalls, result.RateLimited)
+		sum.add("backfill", windowFrom, windowTo, result, counts)
+		db.InsertSyncRun(database, backfillContext, fromStr, toStr, result.APICalls, counts.recorded(result, opts.OnlyNew), result.RateLimited, windowNote(result, counts))
+		log.Printf("backfill: %d records (%d new, %d updated, %d unchanged, %d upsert errors), %d api calls, rate_limited=%v",
+			result.TotalFetched, counts.inserted, counts.updated, counts.unchanged, counts.failed, result.APICalls, result.RateLimited)
 
-		cursor = windowFrom
-		db.SetSyncState(database, "backfill_cursor", cursor.Format(dateFmt))
+		if result.Maintenance {
+			// Leave the cursor so the next run refetches this window whole.
+			log.Println("SAM.gov is down for maintenance during backfill, stopping; the next run resumes")
+			break
+		}
+		if result.ApproachingLimit {
+			// Same: the window has pages left, so refetch it next run.
+			log.Printf("approaching rate limit (X-RateLimit-Remaining=%d) mid-window, stopping backfill", result.RateLimitRemaining)
+			break
+		}
+
+		cursor = next
+		db.SetSyncState(database, cursorKey, cursor.String())
+		if opts.SkipEmptyWindows > 0 && worthRecordingEmpty(result, windowTo, today) {
+			db.SetSyncState(database, emptyKey, time.Now().UTC().Format(time.RFC3339))
+		}
 
 		if result.RateLimited {
 			log.Println("rate limited during backfill, stopping")
+			rateLimited = true
+			break
+		}
+		if result.LowOnCalls() {
+			log.Printf("approaching rate limit (X-RateLimit-Remaining=%d), stopping backfill", result.RateLimitRemaining)
 			break
 		}
 	}
 
-	db.SetSyncState(database, "last_sync", today.Format(dateFmt))
+	recordRateLimit(database, opts, time.Now(), rateLimited)
+	// A dry run fetched nothing, so moving last_sync would shrink the next
+	// real run's lookback past days it never covered.
+	if !opts.DryRun {
+		db.SetSyncState(database, StateKey("last_sync", opts), today.String())
+	}
 	checkpointLog(database)
-	return nil
+	return sum, nil
+}
+
+// windowNote is the sync_runs error for a window cut short by SAM.gov
+// maintenance or rate-limit headroom, or one that skipped records on upsert
+// errors, so `govscout log` shows what went wrong and `sync --audit` doesn't
+// count the window as covered; nil otherwise.
+func windowNote(result *samgov.WindowResult, counts upsertCounts) *string {
+	var cut string
+	switch {
+	case result.Maintenance:
+		cut = samgov.ErrMaintenance.Error()
+	case result.ApproachingLimit:
+		cut = "stopped early: approaching rate limit"
+	}
+	var msg string
+	switch {
+	case cut != "" && counts.failed > 0:
+		msg = fmt.Sprintf("%s; %d upsert errors", cut, counts.failed)
+	case cut != "":
+		msg = cut
+	case counts.failed > 0:
+		msg = fmt.Sprintf("%d upsert errors (records skipped)", counts.failed)
+	default:
+		return nil
+	}
+	return &msg
+}
+
+// upsertCounts tallies what a window's upserts actually did.
+type upsertCounts struct {
+	inserted  int
+	updated   int
+	unchanged int
+	failed    int
+	strict    bool // fail the window on the first upsert error
+	batchSize int  // writes per transaction; see Options.BatchSize
+	compress  bool // see Options.CompressDescriptions
+}
+
+// store returns a page callback that upserts each record and tallies the
+// outcome. Upsert errors are counted, logged and skipped so one bad record
+// doesn't abort the window, unless strict is set. Batched writes are
+// flushed before the callback returns, so every finished page is durable
+// even if a later one fails.
+func (c *upsertCounts) store(database *sql.DB) func([]map[string]any) error {
+	batch := db.NewUpsertBatch(database, c.batchSize)
+	batch.CompressDescriptions = c.compress
+	return func(opps []map[string]any) error {
+		for _, opp := range opps {
+			outcome, err := batch.Upsert(opp)
+			if err != nil {
+				c.failed++
+				if c.strict {
+					return errors.Join(fmt.Errorf("upsert %v: %w", opp["noticeId"], err), batch.Flush())
+				}
+				log.Printf("upsert error: %v", err)
+				continue
+			}
+			switch outcome {
+			case db.UpsertInserted:
+				c.inserted++
+			case db.UpsertUpdated:
+				c.updated++
+			case db.UpsertUnchanged:
+				c.unchanged++
+			}
+			if batch.Full() {
+				if err := batch.Flush(); err != nil {
+					return err
+				}
+			}
+		}
+		return batch.Flush()
+	}
+}
+
+// recorded is the records_synced value for a window: everything fetched, or
+// only new inserts when onlyNew is set.
+func (c *upsertCounts) recorded(result *samgov.WindowResult, onlyNew bool) int {
+	if onlyNew {
+		return c.inserted
+	}
+	return result.TotalFetched
 }
 
 func checkpointLog(database *sql.DB) {
@@ -171,29 +480,113 @@ func checkpointLog(database *sql.DB) {
 	}
 }
 
-func parseFlexibleDate(s string) (time.Time, error) {
-	if t, err := time.Parse(dateFmt, s); err == nil {
-		return t, nil
+// resolveIncrementalDays covers the gap since the last completed sync plus a
+// day of overlap, never less than the default and never more than
+// maxIncrementalDays.
+func resolveIncrementalDays(database *sql.DB, key string, today samgov.Date) int {
+	last, err := db.GetSyncState(database, key)
+	if err != nil || last == "" {
+		return incrementalDays
+	}
+	lastDay, err := samgov.ParseDate(last)
+	if err != nil {
+		log.Printf("ignoring unparseable last_sync: %v", err)
+		return incrementalDays
 	}
-	return time.Parse("2006-01-02", s)
+	days := today.DaysSince(lastDay) + 1
+	return min(max(days, incrementalDays), maxIncrementalDays)
 }
 
-func resolveBackfillCursor(database *sql.DB, today time.Time) (time.Time, error) {
-	cursorStr, err := db.GetSyncState(database, "backfill_cursor")
+func resolveBackfillCursor(database *sql.DB, key string, today samgov.Date, lookback int) (samgov.Date, error) {
+	cursorStr, err := db.GetSyncState(database, key)
 	if err != nil {
-		return time.Time{}, err
+		return samgov.Date{}, err
 	}
 	if cursorStr != "" {
-		return parseFlexibleDate(cursorStr)
+		return samgov.ParseDate(cursorStr)
 	}
 
 	earliest, err := db.GetEarliestPostedDate(database)
 	if err != nil {
-		return time.Time{}, err
+		return samgov.Date{}, err
+	}
+	if !earliest.IsZero() {
+		return earliest, nil
+	}
+
+	return today.AddDays(-lookback), nil
+}
+
+// resolveForwardCursor resumes forward backfill where the last run stopped,
+// or starts it at from on the first run.
+func resolveForwardCursor(database *sql.DB, key string, from samgov.Date) (samgov.Date, error) {
+	cursorStr, err := db.GetSyncState(database, key)
+	if err != nil {
+		return samgov.Date{}, err
 	}
-	if earliest != "" {
-		return parseFlexibleDate(earliest)
+	if cursorStr != "" {
+		return samgov.ParseDate(cursorStr)
+	}
+	if from.IsZero() {
+		return samgov.Date{}, errors.New("forward backfill needs a start date (--from) on its first run")
 	}
+	return from, nil
+}
 
-	return today.AddDate(0, 0, -incrementalDays), nil
+// windowScope names the search filter a sync runs with: "all" when
+// unfiltered, otherwise its criteria in a fixed order, e.g.
+// "naics=541511,set-aside=SBA". Cursors, learned-empty windows and
+// sync_runs contexts are kept per scope.
+func windowScope(opts Options) string {
+	f := opts.Filter
+	var parts []string
+	for _, c := range []struct{ name, value string }{
+		{"naics", f.NAICS},
+		{"set-aside", f.SetAside},
+		{"state", f.State},
+		{"type", f.Type},
+		{"psc", f.ClassificationCode},
+		{"title", f.Title},
+		{"sol-number", f.SolicitationNumber},
+		{"active", f.Active},
+	} {
+		if c.value != "" {
+			parts = append(parts, c.name+"="+c.value)
+		}
+	}
+	if len(parts) == 0 {
+		return "all"
+	}
+	return strings.Join(parts, ",")
+}
+
+// windowFilter is the search filter for a window ending at windowTo. Windows
+// within maxIncrementalDays of today still gain notices, so they skip the
+// response cache: a cached page would hide new postings.
+func windowFilter(opts Options, windowTo, today samgov.Date) samgov.SearchParams {
+	f := opts.Filter
+	f.NoCache = !windowTo.Before(today.AddDays(-maxIncrementalDays))
+	return f
+}
+
+// StateKey is the sync_state key a sync with opts reads and writes for key
+// (backfill_cursor, backfill_cursor_forward, last_sync). Unfiltered syncs
+// use key itself; a filtered sync appends its scope, so it never advances
+// the full sync's cursors past windows it only searched in part.
+func StateKey(key string, opts Options) string {
+	if scope := windowScope(opts); scope != "all" {
+		return key + ":" + scope
+	}
+	return key
+}
+
+// runContext is the sync_runs context for a phase's windows. Filtered runs
+// carry their scope, so `sync --audit` and the backfill call projections
+// (which read the plain incremental/backfill contexts) only count full
+// searches.
+func runContext(phase string, opts Options) string {
+	if scope := windowScope(opts); scope != "all" {
+		return phase + " " + scope
+	}
+	return phase
 }
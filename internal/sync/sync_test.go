@@ -1,64 +1,135 @@
 package sync
 
 import (
+	"context"
+	"database/sql"
+	"strings"
 	"testing"
 	"time"
+
+	"github.com/theognis1002/govscout/internal/db"
+	"github.com/theognis1002/govscout/internal/samgov"
 )
 
-func TestParseFlexibleDate(t *testing.T) {
+func TestResolveIncrementalDays(t *testing.T) {
+	today := samgov.NewDate(time.Date(2026, 3, 20, 9, 0, 0, 0, time.UTC))
 	tests := []struct {
-		name    string
-		input   string
-		want    time.Time
-		wantErr bool
+		name     string
+		lastSync string
+		want     int
 	}{
-		{
-			name:  "MM/DD/YYYY format",
-			input: "01/27/2026",
-			want:  time.Date(2026, 1, 27, 0, 0, 0, 0, time.UTC),
-		},
-		{
-			name:  "YYYY-MM-DD format",
-			input: "2026-01-27",
-			want:  time.Date(2026, 1, 27, 0, 0, 0, 0, time.UTC),
-		},
-		{
-			name:  "MM/DD/YYYY leap day",
-			input: "02/29/2024",
-			want:  time.Date(2024, 2, 29, 0, 0, 0, 0, time.UTC),
-		},
-		{
-			name:  "YYYY-MM-DD leap day",
-			input: "2024-02-29",
-			want:  time.Date(2024, 2, 29, 0, 0, 0, 0, time.UTC),
-		},
-		{
-			name:    "invalid format",
-			input:   "27-01-2026",
-			wantErr: true,
-		},
-		{
-			name:    "empty string",
-			input:   "",
-			wantErr: true,
-		},
+		{name: "no previous sync", lastSync: "", want: incrementalDays},
+		{name: "synced yesterday keeps default", lastSync: "03/19/2026", want: incrementalDays},
+		{name: "week gap plus overlap", lastSync: "03/13/2026", want: 8},
+		{name: "long outage is capped", lastSync: "01/01/2026", want: maxIncrementalDays},
+		{name: "garbage falls back to default", lastSync: "yesterday", want: incrementalDays},
 	}
 
 	for _, tt := range tests {
 		t.Run(tt.name, func(t *testing.T) {
-			got, err := parseFlexibleDate(tt.input)
-			if tt.wantErr {
-				if err == nil {
-					t.Fatalf("expected error, got %v", got)
+			database := openTestDB(t)
+			if tt.lastSync != "" {
+				if err := db.SetSyncState(database, "last_sync", tt.lastSync); err != nil {
+					t.Fatal(err)
 				}
-				return
-			}
-			if err != nil {
-				t.Fatalf("unexpected error: %v", err)
 			}
-			if !got.Equal(tt.want) {
-				t.Errorf("got %v, want %v", got, tt.want)
+			if got := resolveIncrementalDays(database, "last_sync", today); got != tt.want {
+				t.Errorf("resolveIncrementalDays = %d, want %d", got, tt.want)
 			}
 		})
 	}
 }
+
+func TestRunWithSummary_DryRunKeepsLastSync(t *testing.T) {
+	database := openTestDB(t)
+	today := samgov.NewDate(time.Now())
+	lastSync := today.AddDays(-10).String()
+	if err := db.SetSyncState(database, "last_sync", lastSync); err != nil {
+		t.Fatal(err)
+	}
+
+	if _, err := RunWithSummary(context.Background(), database, samgov.NewMockClient(t.TempDir()), Options{DryRun: true}); err != nil {
+		t.Fatal(err)
+	}
+	if got, _ := db.GetSyncState(database, "last_sync"); got != lastSync {
+		t.Errorf("last_sync after dry run = %q, want %q", got, lastSync)
+	}
+	if got := resolveIncrementalDays(database, "last_sync", today); got != 11 {
+		t.Errorf("next lookback = %d days, want 11", got)
+	}
+}
+
+func TestStoreUpsertErrors(t *testing.T) {
+	opps := []map[string]any{
+		{"noticeId": "good-1", "title": "Widgets"},
+		{"noticeId": "bad", "title": "Rejected"},
+		{"noticeId": "good-2", "title": "Gadgets"},
+	}
+	rejectBad := func(t *testing.T) *sql.DB {
+		database := openTestDB(t)
+		if _, err := database.Exec(`CREATE TRIGGER reject_bad BEFORE INSERT ON opportunities
+			WHEN NEW.id = 'bad' BEGIN SELECT RAISE(ABORT, 'rejected'); END`); err != nil {
+			t.Fatal(err)
+		}
+		return database
+	}
+
+	t.Run("default counts and skips", func(t *testing.T) {
+		var counts upsertCounts
+		if err := counts.store(rejectBad(t))(opps); err != nil {
+			t.Fatalf("store = %v, want nil", err)
+		}
+		if counts.inserted != 2 || counts.failed != 1 {
+			t.Errorf("counts = %+v, want 2 inserted, 1 failed", counts)
+		}
+		note := windowNote(&samgov.WindowResult{}, counts)
+		if note == nil || !strings.Contains(*note, "1 upsert errors") {
+			t.Errorf("windowNote = %v, want the upsert error count", note)
+		}
+	})
+
+	t.Run("strict aborts on the first error", func(t *testing.T) {
+		counts := upsertCounts{strict: true}
+		err := counts.store(rejectBad(t))(opps)
+		if err == nil || !strings.Contains(err.Error(), "upsert bad") {
+			t.Fatalf("store = %v, want an error naming the bad notice", err)
+		}
+		if counts.inserted != 1 || counts.failed != 1 {
+			t.Errorf("counts = %+v, want 1 inserted, 1 failed", counts)
+		}
+	})
+
+	t.Run("batched writes commit by the end of the page", func(t *testing.T) {
+		database := rejectBad(t)
+		counts := upsertCounts{batchSize: 2}
+		if err := counts.store(database)(opps); err != nil {
+			t.Fatalf("store = %v, want nil", err)
+		}
+		if counts.inserted != 2 || counts.failed != 1 {
+			t.Errorf("counts = %+v, want 2 inserted, 1 failed", counts)
+		}
+		var n int
+		if err := database.QueryRow("SELECT COUNT(*) FROM opportunities").Scan(&n); err != nil || n != 2 {
+			t.Errorf("stored %d opportunities (%v), want 2", n, err)
+		}
+	})
+
+	t.Run("strict flushes the batch before failing", func(t *testing.T) {
+		database := rejectBad(t)
+		counts := upsertCounts{strict: true, batchSize: 10}
+		if err := counts.store(database)(opps); err == nil {
+			t.Fatal("store = nil, want the upsert error")
+		}
+		var n int
+		if err := database.QueryRow("SELECT COUNT(*) FROM opportunities").Scan(&n); err != nil || n != 1 {
+			t.Errorf("stored %d opportunities (%v), want the 1 written before the error", n, err)
+		}
+	})
+
+	if note := windowNote(&samgov.WindowResult{}, upsertCounts{inserted: 3}); note != nil {
+		t.Errorf("windowNote = %q for a clean window, want nil", *note)
+	}
+	if note := windowNote(&samgov.WindowResult{ApproachingLimit: true}, upsertCounts{}); note == nil || !strings.Contains(*note, "stopped early") {
+		t.Errorf("windowNote = %v for a window cut short, want a note so --audit skips it", note)
+	}
+}
@@ -0,0 +1,101 @@
+package sync
+
+import (
+	"context"
+	"database/sql"
+	"fmt"
+	"log"
+	"os"
+	"strconv"
+	"time"
+
+	"github.com/theognis1002/govscout/internal/db"
+	"github.com/theognis1002/govscout/internal/samgov"
+)
+
+// Warm-up for `govscout serve`: every WarmIntervalEnv the server re-fetches
+// the active opportunities closing soonest, so the detail pages people are
+// most likely to open show current SAM.gov data. Off unless the interval is
+// set.
+const (
+	WarmIntervalEnv = "GOVSCOUT_WARM_INTERVAL"
+	WarmCountEnv    = "GOVSCOUT_WARM_COUNT"
+
+	defaultWarmCount = 10
+	// defaultWarmPause spaces the lookups within one pass.
+	defaultWarmPause = 2 * time.Second
+)
+
+// WarmOptions configure the serve warm-up.
+type WarmOptions struct {
+	Interval time.Duration // between passes
+	Count    int           // notices per pass, one API call each
+	Pause    time.Duration // between lookups within a pass
+}
+
+// WarmOptionsFromEnv reads WarmIntervalEnv and WarmCountEnv. ok is false
+// when the interval is unset, meaning the warm-up is off.
+func WarmOptionsFromEnv() (opts WarmOptions, ok bool, err error) {
+	v := os.Getenv(WarmIntervalEnv)
+	if v == "" {
+		return opts, false, nil
+	}
+	opts = WarmOptions{Count: defaultWarmCount, Pause: defaultWarmPause}
+	if opts.Interval, err = time.ParseDuration(v); err != nil || opts.Interval <= 0 {
+		return opts, false, fmt.Errorf("%s: want a positive duration like 30m, got %q", WarmIntervalEnv, v)
+	}
+	if c := os.Getenv(WarmCountEnv); c != "" {
+		if opts.Count, err = strconv.Atoi(c); err != nil || opts.Count <= 0 {
+			return opts, false, fmt.Errorf("%s: want a positive count, got %q", WarmCountEnv, c)
+		}
+	}
+	return opts, true, nil
+}
+
+// Warm re-fetches the opts.Count active opportunities with the soonest
+// upcoming deadlines once (see db.ListSoonestClosingIDs), recording the pass
+// in sync_runs as "warm". It does nothing while the sync rate-limit breaker
+// is cooling down, since both draw on the same daily quota.
+func Warm(ctx context.Context, database *sql.DB, client samgov.Source, opts WarmOptions) (*RefreshResult, error) {
+	if wait := cooldownRemaining(database, time.Now()); wait > 0 {
+		log.Printf("warm: rate limit cooldown active for %s, skipping", wait.Round(time.Second))
+		return &RefreshResult{}, nil
+	}
+	ids, err := db.ListSoonestClosingIDs(database, time.Now(), opts.Count)
+	if err != nil {
+		return nil, fmt.Errorf("list soonest closing: %w", err)
+	}
+	if len(ids) == 0 {
+		return &RefreshResult{}, nil
+	}
+	return refreshIDs(ctx, database, client, ids, "warm", opts.Pause)
+}
+
+// RunWarmer runs Warm now and then every opts.Interval until ctx is done.
+// A pass that hits a rate limit or SAM.gov maintenance doubles the wait
+// before the next one, up to maxBreakerCooldown; a clean pass resets it.
+func RunWarmer(ctx context.Context, database *sql.DB, client samgov.Source, opts WarmOptions) {
+	log.Printf("warm: refreshing the %d soonest-closing opportunities every %s", opts.Count, opts.Interval)
+	wait := opts.Interval
+	for {
+		res, err := Warm(ctx, database, client, opts)
+		if ctx.Err() != nil {
+			return
+		}
+		switch {
+		case err != nil:
+			log.Printf("warm: %v", err)
+		case res.RateLimited || res.Maintenance:
+			wait = min(wait*2, maxBreakerCooldown)
+			log.Printf("warm: stopped early, next pass in %s", wait)
+		default:
+			wait = opts.Interval
+			if res.APICalls > 0 {
+				log.Printf("warm: %d checked (%d changed, %d missing, %d failed)", res.Checked, res.Changed, res.Missing, res.Failed)
+			}
+		}
+		if err := sleepCtx(ctx, wait); err != nil {
+			return
+		}
+	}
+}
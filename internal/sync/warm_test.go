@@ -0,0 +1,73 @@
+package sync
+
+import (
+	"context"
+	"testing"
+	"time"
+
+	"github.com/theognis1002/govscout/internal/db"
+)
+
+func TestWarm(t *testing.T) {
+	database := openTestDB(t)
+	soon := time.Now().UTC().Add(48 * time.Hour).Format("2006-01-02T15:04:05Z")
+	later := time.Now().UTC().Add(240 * time.Hour).Format("2006-01-02T15:04:05Z")
+	if _, err := database.Exec(`INSERT INTO opportunities (id, active, response_deadline_utc) VALUES
+		('later', 1, ?), ('soon', 1, ?), ('closed', 1, '2020-01-01T00:00:00Z'), ('inactive', 0, ?)`,
+		later, soon, soon); err != nil {
+		t.Fatal(err)
+	}
+
+	ids, err := db.ListSoonestClosingIDs(database, time.Now(), 10)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(ids) != 2 || ids[0] != "soon" || ids[1] != "later" {
+		t.Errorf("soonest closing = %v, want [soon later]", ids)
+	}
+
+	client := unrelatedMock(t)
+	res, err := Warm(context.Background(), database, client, WarmOptions{Count: 1})
+	if err != nil {
+		t.Fatal(err)
+	}
+	if res.APICalls != 1 || res.Missing != 1 {
+		t.Errorf("Warm = %+v, want one lookup of the soonest", *res)
+	}
+	runs, _, err := db.QuerySyncRuns(database, db.SyncRunQuery{Context: "warm"})
+	if err != nil || len(runs) != 1 {
+		t.Errorf("warm sync runs = %d (%v), want 1", len(runs), err)
+	}
+
+	// The sync breaker's cooldown pauses the warm-up too.
+	until := time.Now().Add(time.Hour).UTC().Format(time.RFC3339)
+	if err := db.SetSyncState(database, stateCooldownUntil, until); err != nil {
+		t.Fatal(err)
+	}
+	if res, err := Warm(context.Background(), database, client, WarmOptions{Count: 1}); err != nil || res.APICalls != 0 {
+		t.Errorf("Warm during cooldown = %+v, %v; want no calls", res, err)
+	}
+}
+
+func TestWarmOptionsFromEnv(t *testing.T) {
+	t.Setenv(WarmIntervalEnv, "")
+	if _, ok, err := WarmOptionsFromEnv(); ok || err != nil {
+		t.Errorf("unset interval: ok=%v err=%v, want off", ok, err)
+	}
+
+	t.Setenv(WarmIntervalEnv, "30m")
+	t.Setenv(WarmCountEnv, "")
+	opts, ok, err := WarmOptionsFromEnv()
+	if !ok || err != nil || opts.Interval != 30*time.Minute || opts.Count != defaultWarmCount {
+		t.Errorf("WarmOptionsFromEnv = %+v, %v, %v; want 30m with the default count", opts, ok, err)
+	}
+
+	t.Setenv(WarmCountEnv, "0")
+	if _, _, err := WarmOptionsFromEnv(); err == nil {
+		t.Error("zero count should be rejected")
+	}
+	t.Setenv(WarmIntervalEnv, "soon")
+	if _, _, err := WarmOptionsFromEnv(); err == nil {
+		t.Error("unparseable interval should be rejected")
+	}
+}
@@ -0,0 +1,466 @@
+package web
+
+import (
+	"encoding/json"
+	"errors"
+	"fmt"
+	"log"
+	"math"
+	"net/http"
+	"slices"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/go-chi/chi/v5"
+	"github.com/theognis1002/govscout/internal/codes"
+	"github.com/theognis1002/govscout/internal/db"
+	"github.com/theognis1002/govscout/internal/samgov"
+)
+
+const (
+	defaultStatsLimit    = 20
+	maxStatsLimit        = 1000
+	defaultContactsLimit = 50
+	maxContactsLimit     = 500
+	defaultAwardsLimit   = 50
+	maxAwardsLimit       = 500
+	syncRunsPageSize     = 50
+	maxSyncRunsPageSize  = 500
+)
+
+// requireAPIAuth is requireAuth for JSON routes: unauthenticated requests get
+// a 401 instead of a redirect to the login page.
+func (s *Server) requireAPIAuth(next http.Handler) http.Handler {
+	return http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		user := s.getSession(r)
+		if user == nil {
+			writeError(w, http.StatusUnauthorized, "authentication required")
+			return
+		}
+		next.ServeHTTP(w, r.WithContext(setUser(r.Context(), user)))
+	})
+}
+
+func writeJSON(w http.ResponseWriter, status int, v any) {
+	w.Header().Set("Content-Type", "application/json")
+	w.WriteHeader(status)
+	if err := json.NewEncoder(w).Encode(v); err != nil {
+		log.Printf("write json: %v", err)
+	}
+}
+
+type apiStatDimension struct {
+	Total  int64           `json:"total"`
+	Values []db.FilterStat `json:"values"`
+}
+
+type apiStats struct {
+	Total       int64            `json:"total"`
+	Limit       int              `json:"limit"`
+	Offset      int              `json:"offset"`
+	NAICSCodes  apiStatDimension `json:"naics_codes"`
+	OppTypes    apiStatDimension `json:"opp_types"`
+	SetAsides   apiStatDimension `json:"set_asides"`
+	States      apiStatDimension `json:"states"`
+	Departments apiStatDimension `json:"departments"`
+}
+
+func (s *Server) handleAPIStats(w http.ResponseWriter, r *http.Request) {
+	limit, err := queryIntIn(r, "limit", defaultStatsLimit, 1, maxStatsLimit)
+	if err != nil {
+		writeError(w, http.StatusBadRequest, err.Error())
+		return
+	}
+	offset, err := queryIntIn(r, "offset", 0, 0, math.MaxInt)
+	if err != nil {
+		writeError(w, http.StatusBadRequest, err.Error())
+		return
+	}
+
+	stats, err := db.GetFilterStatsPage(s.db, limit, offset)
+	if err != nil {
+		log.Printf("api stats: %v", err)
+		writeError(w, http.StatusInternalServerError, "internal server error")
+		return
+	}
+
+	for i, v := range stats.NAICSCodes {
+		stats.NAICSCodes[i].Label = codes.NAICSName(v.Value)
+	}
+	dim := func(column string, values []db.FilterStat) apiStatDimension {
+		if values == nil {
+			values = []db.FilterStat{}
+		}
+		return apiStatDimension{Total: stats.Distinct[column], Values: values}
+	}
+	writeJSON(w, http.StatusOK, apiStats{
+		Total:       stats.Total,
+		Limit:       limit,
+		Offset:      offset,
+		NAICSCodes:  dim("naics_code", stats.NAICSCodes),
+		OppTypes:    dim("opp_type", stats.OppTypes),
+		SetAsides:   dim("set_aside", stats.SetAsides),
+		States:      dim("pop_state_code", stats.States),
+		Departments: dim("department", stats.Departments),
+	})
+}
+
+// handleAPICompleteness reports how many opportunities have each key column
+// filled in, to spot when upstream data goes sparse.
+func (s *Server) handleAPICompleteness(w http.ResponseWriter, r *http.Request) {
+	c, err := db.GetCompleteness(s.db)
+	if err != nil {
+		log.Printf("api completeness: %v", err)
+		writeError(w, http.StatusInternalServerError, "internal server error")
+		return
+	}
+	writeJSON(w, http.StatusOK, c)
+}
+
+// handleAPITimeline returns an array of {period, count}: opportunities per
+// bucket of posted_date (month by default; week or day) for timeline
+// charts. It takes the /api/opportunities filters, range= params included,
+// plus from/to (YYYY-MM-DD or MM/DD/YYYY) bounding the posted date.
+func (s *Server) handleAPITimeline(w http.ResponseWriter, r *http.Request) {
+	bucket := r.URL.Query().Get("bucket")
+	if bucket == "" {
+		bucket = "month"
+	}
+	if !slices.Contains(db.TimelineBuckets, bucket) {
+		writeError(w, http.StatusBadRequest, "bucket must be one of "+strings.Join(db.TimelineBuckets, ", "))
+		return
+	}
+	filters, err := parseFilters(r)
+	if err != nil {
+		writeError(w, http.StatusBadRequest, err.Error())
+		return
+	}
+	if filters.PostedRanges, err = parsePostedRanges(r); err != nil {
+		writeError(w, http.StatusBadRequest, err.Error())
+		return
+	}
+	for key, dst := range map[string]*string{"from": &filters.DateFrom, "to": &filters.DateTo} {
+		v := r.URL.Query().Get(key)
+		if v == "" {
+			continue
+		}
+		d, err := samgov.ParseDate(v)
+		if err != nil {
+			writeError(w, http.StatusBadRequest, key+": "+err.Error())
+			return
+		}
+		*dst = d.String()
+	}
+
+	points, err := db.GetPostedTimeline(s.db, filters, bucket)
+	if err != nil {
+		log.Printf("api timeline: %v", err)
+		writeError(w, http.StatusInternalServerError, "internal server error")
+		return
+	}
+	writeJSON(w, http.StatusOK, points)
+}
+
+// queryInt parses an integer query parameter, returning fallback when the
+// parameter is missing or malformed.
+func queryInt(r *http.Request, key string, fallback int) int {
+	v := r.URL.Query().Get(key)
+	if v == "" {
+		return fallback
+	}
+	n, err := strconv.Atoi(v)
+	if err != nil {
+		return fallback
+	}
+	return n
+}
+
+// queryIntIn parses an integer query parameter that must lie in [lo, hi],
+// returning fallback when the parameter is missing.
+func queryIntIn(r *http.Request, key string, fallback, lo, hi int) (int, error) {
+	v := r.URL.Query().Get(key)
+	if v == "" {
+		return fallback, nil
+	}
+	n, err := strconv.Atoi(v)
+	if err == nil && n >= lo && n <= hi {
+		return n, nil
+	}
+	if hi == math.MaxInt {
+		return 0, fmt.Errorf("%s must be an integer of at least %d", key, lo)
+	}
+	return 0, fmt.Errorf("%s must be an integer from %d to %d", key, lo, hi)
+}
+
+type apiOpportunityList struct {
+	Total         int64      `json:"total"`
+	Limit         int        `json:"limit"`
+	Offset        int        `json:"offset"`
+	Opportunities any        `json:"opportunities"`
+	Next          *apiCursor `json:"next,omitempty"`
+}
+
+// apiCursor holds the query params that fetch the next keyset page.
+type apiCursor struct {
+	AfterPostedDate string `json:"after_posted_date"`
+	AfterNoticeID   string `json:"after_notice_id"`
+}
+
+// handleAPIOpportunities lists opportunities with the same filters as the
+// HTML list. With fuzzy=true the search term is matched against titles by
+// trigram similarity and each result carries a score. Descriptions are left
+// out unless include_description=true; the detail endpoint always has them.
+// columns=id,title,... trims each result to those fields (see columns.go).
+func (s *Server) handleAPIOpportunities(w http.ResponseWriter, r *http.Request) {
+	filters, err := parseFilters(r)
+	if err != nil {
+		writeError(w, http.StatusBadRequest, err.Error())
+		return
+	}
+	cols, err := parseColumns(r)
+	if err != nil {
+		writeError(w, http.StatusBadRequest, err.Error())
+		return
+	}
+	filters.OmitDescription = r.URL.Query().Get("include_description") != "true" && !cols["description"]
+
+	if filters.PostedRanges, err = parsePostedRanges(r); err != nil {
+		writeError(w, http.StatusBadRequest, err.Error())
+		return
+	}
+
+	if r.URL.Query().Get("fuzzy") == "true" && filters.Search != "" {
+		scored, total, err := db.FuzzySearchOpportunities(s.db, filters, filters.Search)
+		if err != nil {
+			log.Printf("api fuzzy search: %v", err)
+			writeError(w, http.StatusInternalServerError, "internal server error")
+			return
+		}
+		if scored == nil {
+			scored = []db.ScoredOpportunity{}
+		}
+		opps, err := selectColumns(scored, cols)
+		if err != nil {
+			log.Printf("api fuzzy search: select columns: %v", err)
+			writeError(w, http.StatusInternalServerError, "internal server error")
+			return
+		}
+		writeJSON(w, http.StatusOK, apiOpportunityList{
+			Total:         int64(total),
+			Limit:         filters.Limit,
+			Offset:        filters.Offset,
+			Opportunities: opps,
+		})
+		return
+	}
+
+	// Keyset paging stays fast at any depth, unlike large offsets. The HTML
+	// list keeps numbered pages, so only the API reads the cursor.
+	filters.AfterPostedDate = r.URL.Query().Get("after_posted_date")
+	filters.AfterID = r.URL.Query().Get("after_notice_id")
+	result, err := db.ListOpportunities(s.db, filters)
+	if errors.Is(err, db.ErrCursorSort) {
+		writeError(w, http.StatusBadRequest, "after_notice_id requires sort_by=posted_date")
+		return
+	}
+	if err != nil {
+		log.Printf("api list opportunities: %v", err)
+		writeError(w, http.StatusInternalServerError, "internal server error")
+		return
+	}
+	items := result.Opportunities
+	if items == nil {
+		items = []db.OpportunityListItem{}
+	}
+	opps, err := selectColumns(items, cols)
+	if err != nil {
+		log.Printf("api list opportunities: select columns: %v", err)
+		writeError(w, http.StatusInternalServerError, "internal server error")
+		return
+	}
+	resp := apiOpportunityList{
+		Total:         result.Total,
+		Limit:         filters.Limit,
+		Offset:        filters.Offset,
+		Opportunities: opps,
+	}
+	// next is set whenever a posted_date-ordered page came back full.
+	if filters.SortBy == "" || filters.SortBy == "posted_date" {
+		if date, id, ok := db.NextCursor(items, filters.Limit); ok {
+			resp.Next = &apiCursor{AfterPostedDate: date, AfterNoticeID: id}
+		}
+	}
+	if filters.AfterID != "" {
+		resp.Offset = 0
+	}
+	writeJSON(w, http.StatusOK, resp)
+}
+
+// apiChanges is the GET /api/opportunities/changes response. Next holds
+// the params for the following poll; it repeats the request's cursor when
+// nothing changed.
+type apiChanges struct {
+	Opportunities []db.ChangedOpportunity `json:"opportunities"`
+	Next          apiChangesCursor        `json:"next"`
+	HasMore       bool                    `json:"has_more"` // the page was full; poll again now
+}
+
+type apiChangesCursor struct {
+	Since   string `json:"since"` // RFC 3339 UTC
+	AfterID string `json:"after_id,omitempty"`
+}
+
+// handleAPIChanges serves the changes feed for client-side mirrors:
+// opportunities modified (or inserted) after since, oldest change first,
+// narrowed by the usual list filters. since takes the same forms as
+// created_after; pass next.since and next.after_id back to resume.
+func (s *Server) handleAPIChanges(w http.ResponseWriter, r *http.Request) {
+	q := r.URL.Query()
+	if q.Get("since") == "" {
+		writeError(w, http.StatusBadRequest, "since is required (e.g. 2026-01-31T00:00:00Z)")
+		return
+	}
+	since, err := db.ParseSince(q.Get("since"), time.Now())
+	if err != nil {
+		writeError(w, http.StatusBadRequest, err.Error())
+		return
+	}
+	filters, err := parseFilters(r)
+	if err != nil {
+		writeError(w, http.StatusBadRequest, err.Error())
+		return
+	}
+	filters.OmitDescription = q.Get("include_description") != "true"
+	if filters.PostedRanges, err = parsePostedRanges(r); err != nil {
+		writeError(w, http.StatusBadRequest, err.Error())
+		return
+	}
+
+	page, err := db.ListChanges(s.db, filters, db.ChangeCursor{ModifiedAt: since, AfterID: q.Get("after_id")})
+	if err != nil {
+		log.Printf("api changes: %v", err)
+		writeError(w, http.StatusInternalServerError, "internal server error")
+		return
+	}
+	next, err := time.Parse("2006-01-02 15:04:05", page.Next.ModifiedAt)
+	if err != nil {
+		log.Printf("api changes: cursor %q: %v", page.Next.ModifiedAt, err)
+		writeError(w, http.StatusInternalServerError, "internal server error")
+		return
+	}
+	writeJSON(w, http.StatusOK, apiChanges{
+		Opportunities: page.Opportunities,
+		Next:          apiChangesCursor{Since: next.Format(time.RFC3339), AfterID: page.Next.AfterID},
+		HasMore:       page.More,
+	})
+}
+
+// parsePostedRanges reads the repeated range=FROM..TO params, which OR
+// together (e.g. Q1 and Q3 only).
+func parsePostedRanges(r *http.Request) ([]db.DateRange, error) {
+	ranges := r.URL.Query()["range"]
+	if len(ranges) > db.MaxDateRanges {
+		return nil, fmt.Errorf("at most %d range params", db.MaxDateRanges)
+	}
+	var out []db.DateRange
+	for _, v := range ranges {
+		dr, err := db.ParseDateRange(v)
+		if err != nil {
+			return nil, err
+		}
+		out = append(out, dr)
+	}
+	return out, nil
+}
+
+// handleAPIOpportunity returns one opportunity in the SAM.gov response shape,
+// plus descriptionText: the description without its HTML.
+func (s *Server) handleAPIOpportunity(w http.ResponseWriter, r *http.Request) {
+	opp, err := db.GetOpportunityFull(s.db, chi.URLParam(r, "id"))
+	if err != nil {
+		log.Printf("api get opportunity: %v", err)
+		writeError(w, http.StatusInternalServerError, "internal server error")
+		return
+	}
+	if opp == nil {
+		writeError(w, http.StatusNotFound, "not found")
+		return
+	}
+	// Not part of GetOpportunityFull, which must round-trip through upsert.
+	if desc, ok := opp["description"].(string); ok {
+		opp["descriptionText"] = *db.DescriptionText(&desc)
+	}
+	writeJSON(w, http.StatusOK, opp)
+}
+
+// handleAPIOpportunityTags returns the local triage tags for a notice ID,
+// whether or not the opportunity itself is stored.
+func (s *Server) handleAPIOpportunityTags(w http.ResponseWriter, r *http.Request) {
+	tags, err := db.ListTags(s.db, chi.URLParam(r, "id"))
+	if err != nil {
+		log.Printf("api list tags: %v", err)
+		writeError(w, http.StatusInternalServerError, "internal server error")
+		return
+	}
+	if tags == nil {
+		tags = []db.Tag{}
+	}
+	writeJSON(w, http.StatusOK, tags)
+}
+
+type apiContactList struct {
+	Total    int64               `json:"total"`
+	Limit    int                 `json:"limit"`
+	Offset   int                 `json:"offset"`
+	Contacts []db.ContactSummary `json:"contacts"`
+}
+
+// handleAPIContacts lists distinct points of contact with how many
+// opportunities each is attached to.
+func (s *Server) handleAPIContacts(w http.ResponseWriter, r *http.Request) {
+	limit := queryInt(r, "limit", defaultContactsLimit)
+	if limit <= 0 || limit > maxContactsLimit {
+		limit = defaultContactsLimit
+	}
+	offset := max(queryInt(r, "offset", 0), 0)
+
+	contacts, total, err := db.ListContacts(s.db, limit, offset)
+	if err != nil {
+		log.Printf("api contacts: %v", err)
+		writeError(w, http.StatusInternalServerError, "internal server error")
+		return
+	}
+	if contacts == nil {
+		contacts = []db.ContactSummary{}
+	}
+	writeJSON(w, http.StatusOK, apiContactList{
+		Total:    total,
+		Limit:    limit,
+		Offset:   offset,
+		Contacts: contacts,
+	})
+}
+
+// handleAPIAwardee lists stored awards to one company, matched by awardee
+// name substring or exact UEI, with the award total across all matches.
+func (s *Server) handleAPIAwardee(w http.ResponseWriter, r *http.Request) {
+	limit := queryInt(r, "limit", defaultAwardsLimit)
+	if limit <= 0 || limit > maxAwardsLimit {
+		limit = defaultAwardsLimit
+	}
+	offset := max(queryInt(r, "offset", 0), 0)
+
+	name := strings.TrimSpace(chi.URLParam(r, "name"))
+	if name == "" {
+		writeError(w, http.StatusBadRequest, "awardee name or UEI is required")
+		return
+	}
+	rollup, err := db.SearchAwardees(s.db, name, limit, offset)
+	if err != nil {
+		log.Printf("api awardee: %v", err)
+		writeError(w, http.StatusInternalServerError, "internal server error")
+		return
+	}
+	writeJSON(w, http.StatusOK, rollup)
+}
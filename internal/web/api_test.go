@@ -0,0 +1,322 @@
+package web
+
+import (
+	"encoding/json"
+	"net/http"
+	"net/http/httptest"
+	"path/filepath"
+	"reflect"
+	"strings"
+	"testing"
+
+	"github.com/go-chi/chi/v5"
+	"github.com/theognis1002/govscout/internal/db"
+)
+
+func TestHandleAPIOpportunities_Ranges(t *testing.T) {
+	d, err := db.Open(filepath.Join(t.TempDir(), "test.db"))
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer d.Close()
+	if _, err := d.Exec(`INSERT INTO opportunities (id, title, posted_date) VALUES
+		('q1', 'x', '02/15/2025'), ('q2', 'x', '05/15/2025'), ('q3', 'x', '08/15/2025')`); err != nil {
+		t.Fatal(err)
+	}
+	s := &Server{db: d}
+
+	rec := httptest.NewRecorder()
+	s.handleAPIOpportunities(rec, httptest.NewRequest("GET", "/api/opportunities?range=2025-01-01..2025-03-31&range=2025-07-01..2025-09-30", nil))
+	if rec.Code != http.StatusOK {
+		t.Fatalf("status = %d: %s", rec.Code, rec.Body.String())
+	}
+	var resp struct {
+		Total int64 `json:"total"`
+	}
+	if err := json.Unmarshal(rec.Body.Bytes(), &resp); err != nil {
+		t.Fatal(err)
+	}
+	if resp.Total != 2 {
+		t.Errorf("total = %d, want 2 (Q1 and Q3 only)", resp.Total)
+	}
+
+	for _, query := range []string{
+		"range=2025-03-31..2025-01-01",
+		"range=2025-01-01",
+		strings.Repeat("range=2025-01-01..2025-01-02&", db.MaxDateRanges+1),
+	} {
+		rec := httptest.NewRecorder()
+		s.handleAPIOpportunities(rec, httptest.NewRequest("GET", "/api/opportunities?"+query, nil))
+		if rec.Code != http.StatusBadRequest {
+			t.Errorf("%s: status = %d, want 400", query, rec.Code)
+		}
+	}
+}
+
+func TestParseFilters_ActiveAndInactiveConflict(t *testing.T) {
+	d, err := db.Open(filepath.Join(t.TempDir(), "test.db"))
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer d.Close()
+	s := &Server{db: d}
+
+	rec := httptest.NewRecorder()
+	s.handleAPIOpportunities(rec, httptest.NewRequest("GET", "/api/opportunities?active_only=true&inactive_only=true", nil))
+	if rec.Code != http.StatusBadRequest {
+		t.Errorf("both flags: status = %d, want 400", rec.Code)
+	}
+	rec = httptest.NewRecorder()
+	s.handleAPIOpportunities(rec, httptest.NewRequest("GET", "/api/opportunities?inactive_only=true", nil))
+	if rec.Code != http.StatusOK {
+		t.Errorf("inactive_only alone: status = %d, want 200", rec.Code)
+	}
+}
+
+func TestHandleAPIOpportunities_Columns(t *testing.T) {
+	d, err := db.Open(filepath.Join(t.TempDir(), "test.db"))
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer d.Close()
+	if _, err := d.Exec(`INSERT INTO opportunities (id, title, posted_date, department, description) VALUES
+		('a', 'Widgets', '02/15/2025', 'DEPT OF DEFENSE', 'Long text')`); err != nil {
+		t.Fatal(err)
+	}
+	s := &Server{db: d}
+
+	rec := httptest.NewRecorder()
+	s.handleAPIOpportunities(rec, httptest.NewRequest("GET", "/api/opportunities?columns=id,title,%20description", nil))
+	if rec.Code != http.StatusOK {
+		t.Fatalf("status = %d: %s", rec.Code, rec.Body.String())
+	}
+	var resp struct {
+		Opportunities []map[string]any `json:"opportunities"`
+	}
+	if err := json.Unmarshal(rec.Body.Bytes(), &resp); err != nil {
+		t.Fatal(err)
+	}
+	if len(resp.Opportunities) != 1 {
+		t.Fatalf("got %d opportunities, want 1", len(resp.Opportunities))
+	}
+	got := resp.Opportunities[0]
+	want := map[string]any{"id": "a", "title": "Widgets", "description": "Long text"}
+	if len(got) != len(want) {
+		t.Errorf("keys = %v, want only %v", got, want)
+	}
+	for k, v := range want {
+		if got[k] != v {
+			t.Errorf("%s = %v, want %v", k, got[k], v)
+		}
+	}
+
+	for _, query := range []string{"columns=id,bogus", "columns=,"} {
+		rec := httptest.NewRecorder()
+		s.handleAPIOpportunities(rec, httptest.NewRequest("GET", "/api/opportunities?"+query, nil))
+		if rec.Code != http.StatusBadRequest {
+			t.Errorf("%s: status = %d, want 400", query, rec.Code)
+		}
+	}
+}
+
+func TestHandleAPIStats(t *testing.T) {
+	d, err := db.Open(filepath.Join(t.TempDir(), "test.db"))
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer d.Close()
+	if _, err := d.Exec(`INSERT INTO opportunities (id, naics_code) VALUES
+		('a', '541512'), ('b', '541512'), ('c', '541511'), ('d', '236220')`); err != nil {
+		t.Fatal(err)
+	}
+	s := &Server{db: d}
+
+	rec := httptest.NewRecorder()
+	s.routes().ServeHTTP(rec, httptest.NewRequest("GET", "/api/stats", nil))
+	if rec.Code != http.StatusUnauthorized {
+		t.Errorf("without a session: status = %d, want 401", rec.Code)
+	}
+
+	get := func(query string) *httptest.ResponseRecorder {
+		rec := httptest.NewRecorder()
+		s.handleAPIStats(rec, httptest.NewRequest("GET", "/api/stats?"+query, nil))
+		return rec
+	}
+
+	rec = get("limit=2&offset=1")
+	if rec.Code != http.StatusOK {
+		t.Fatalf("status = %d: %s", rec.Code, rec.Body)
+	}
+	var got apiStats
+	if err := json.Unmarshal(rec.Body.Bytes(), &got); err != nil {
+		t.Fatal(err)
+	}
+	var values []string
+	for _, v := range got.NAICSCodes.Values {
+		values = append(values, v.Value)
+	}
+	if got.Limit != 2 || got.Offset != 1 || strings.Join(values, ",") != "236220,541511" {
+		t.Errorf("limit %d offset %d naics %v; want 2, 1, [236220 541511]", got.Limit, got.Offset, values)
+	}
+	if got.Total != 4 || got.NAICSCodes.Total != 3 {
+		t.Errorf("total = %d, naics distinct = %d; want 4 and 3 regardless of the page", got.Total, got.NAICSCodes.Total)
+	}
+
+	for _, query := range []string{"limit=0", "limit=1001", "limit=ten", "offset=-1", "offset=x"} {
+		rec := get(query)
+		key, _, _ := strings.Cut(query, "=")
+		if rec.Code != http.StatusBadRequest || !strings.Contains(rec.Body.String(), key) {
+			t.Errorf("%s: status = %d, body %s; want 400 naming %s", query, rec.Code, rec.Body, key)
+		}
+	}
+}
+
+func TestHandleAPIStatesGeoJSON(t *testing.T) {
+	d, err := db.Open(filepath.Join(t.TempDir(), "test.db"))
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer d.Close()
+	if _, err := d.Exec(`INSERT INTO opportunities (id, title, pop_state_code) VALUES
+		('a', 'x', 'VA'), ('b', 'x', 'VA'), ('c', 'x', 'ZZ'), ('d', 'x', NULL)`); err != nil {
+		t.Fatal(err)
+	}
+	s := &Server{db: d}
+
+	rec := httptest.NewRecorder()
+	s.handleAPIStatesGeoJSON(rec, httptest.NewRequest("GET", "/api/stats/states.geojson", nil))
+	if rec.Code != http.StatusOK || rec.Header().Get("Content-Type") != "application/geo+json" {
+		t.Fatalf("status = %d, content type %q", rec.Code, rec.Header().Get("Content-Type"))
+	}
+	var fc geoFeatureCollection
+	if err := json.Unmarshal(rec.Body.Bytes(), &fc); err != nil {
+		t.Fatal(err)
+	}
+	if fc.Type != "FeatureCollection" || len(fc.Features) != 2 {
+		t.Fatalf("got %+v, want 2 features", fc)
+	}
+	va := fc.Features[0]
+	if va.Properties.StateCode != "VA" || va.Properties.Count != 2 || va.Properties.StateName != "Virginia" {
+		t.Errorf("first feature = %+v, want VA with 2", va.Properties)
+	}
+	if va.Geometry == nil || va.Geometry.Type != "Point" || va.Geometry.Coordinates[0] > -70 {
+		t.Errorf("VA geometry = %+v, want a [lon, lat] point", va.Geometry)
+	}
+	if fc.Features[1].Geometry != nil {
+		t.Errorf("unknown code should have a null geometry: %+v", fc.Features[1])
+	}
+}
+
+func TestHandleAPIAwardee(t *testing.T) {
+	d, err := db.Open(filepath.Join(t.TempDir(), "test.db"))
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer d.Close()
+	if _, err := d.Exec(`INSERT INTO opportunities (id, title, awardee_name, award_date, award_amount) VALUES
+		('a1', 'x', 'ACME Federal LLC', '2025-01-01', '1,000'), ('a2', 'x', 'Acme Federal', '2025-02-01', '500')`); err != nil {
+		t.Fatal(err)
+	}
+	s := &Server{db: d}
+
+	r := chi.NewRouter()
+	r.Get("/api/awardees/{name}", s.handleAPIAwardee)
+	rec := httptest.NewRecorder()
+	r.ServeHTTP(rec, httptest.NewRequest("GET", "/api/awardees/acme%20federal?limit=1", nil))
+	if rec.Code != http.StatusOK {
+		t.Fatalf("status = %d: %s", rec.Code, rec.Body.String())
+	}
+	var got db.AwardeeRollup
+	if err := json.Unmarshal(rec.Body.Bytes(), &got); err != nil {
+		t.Fatal(err)
+	}
+	if got.Total != 2 || got.TotalAmount != 1500 || len(got.Awards) != 1 || got.Awards[0].NoticeID != "a2" {
+		t.Errorf("rollup = %+v, want 2 awards totalling 1500 with only the newest on this page", got)
+	}
+}
+
+func TestHandleAPITimeline(t *testing.T) {
+	d, err := db.Open(filepath.Join(t.TempDir(), "test.db"))
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer d.Close()
+	if _, err := d.Exec(`INSERT INTO opportunities (id, title, posted_date, set_aside) VALUES
+		('a', 'x', '01/15/2025', 'SBA'), ('b', 'x', '01/20/2025', NULL), ('c', 'x', '03/02/2025', 'SBA')`); err != nil {
+		t.Fatal(err)
+	}
+	s := &Server{db: d}
+
+	for query, want := range map[string][]db.TimelinePoint{
+		"":                             {{Period: "2025-01", Count: 2}, {Period: "2025-03", Count: 1}},
+		"set_aside=SBA":                {{Period: "2025-01", Count: 1}, {Period: "2025-03", Count: 1}},
+		"bucket=day&from=2025-01-16":   {{Period: "2025-01-20", Count: 1}, {Period: "2025-03-02", Count: 1}},
+		"range=2025-03-01..2025-03-31": {{Period: "2025-03", Count: 1}},
+		"to=01/01/2025":                {},
+	} {
+		rec := httptest.NewRecorder()
+		s.handleAPITimeline(rec, httptest.NewRequest("GET", "/api/stats/timeline?"+query, nil))
+		if rec.Code != http.StatusOK {
+			t.Fatalf("%q: status = %d: %s", query, rec.Code, rec.Body.String())
+		}
+		var got []db.TimelinePoint
+		if err := json.Unmarshal(rec.Body.Bytes(), &got); err != nil {
+			t.Fatal(err)
+		}
+		if !reflect.DeepEqual(got, want) {
+			t.Errorf("%q = %v, want %v", query, got, want)
+		}
+	}
+
+	for _, query := range []string{"bucket=year", "from=yesterday", "range=2025-01-01"} {
+		rec := httptest.NewRecorder()
+		s.handleAPITimeline(rec, httptest.NewRequest("GET", "/api/stats/timeline?"+query, nil))
+		if rec.Code != http.StatusBadRequest {
+			t.Errorf("%s: status = %d, want 400", query, rec.Code)
+		}
+	}
+}
+
+func TestHandleAPIChanges(t *testing.T) {
+	d, err := db.Open(filepath.Join(t.TempDir(), "test.db"))
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer d.Close()
+	if _, err := d.Exec(`INSERT INTO opportunities (id, title, description, modified_at) VALUES
+		('a', 'A', '<p>long</p>', '2026-02-01 12:00:00'), ('b', 'B', NULL, '2026-03-01 08:30:00')`); err != nil {
+		t.Fatal(err)
+	}
+	s := &Server{db: d}
+
+	rec := httptest.NewRecorder()
+	s.handleAPIChanges(rec, httptest.NewRequest("GET", "/api/opportunities/changes?since=2026-02-01T12:00:00Z&limit=1", nil))
+	if rec.Code != http.StatusOK {
+		t.Fatalf("status = %d: %s", rec.Code, rec.Body.String())
+	}
+	var got struct {
+		Opportunities []map[string]any `json:"opportunities"`
+		Next          struct {
+			Since   string `json:"since"`
+			AfterID string `json:"after_id"`
+		} `json:"next"`
+		HasMore bool `json:"has_more"`
+	}
+	if err := json.Unmarshal(rec.Body.Bytes(), &got); err != nil {
+		t.Fatal(err)
+	}
+	if len(got.Opportunities) != 1 || got.Opportunities[0]["id"] != "b" || got.Opportunities[0]["modified_at"] != "2026-03-01T08:30:00Z" {
+		t.Errorf("opportunities = %v, want only b (a is not after since)", got.Opportunities)
+	}
+	if got.Next.Since != "2026-03-01T08:30:00Z" || got.Next.AfterID != "b" || !got.HasMore {
+		t.Errorf("next = %+v, has_more %v", got.Next, got.HasMore)
+	}
+
+	for _, query := range []string{"", "since=whenever"} {
+		rec := httptest.NewRecorder()
+		s.handleAPIChanges(rec, httptest.NewRequest("GET", "/api/opportunities/changes?"+query, nil))
+		if rec.Code != http.StatusBadRequest {
+			t.Errorf("%q: status = %d, want 400", query, rec.Code)
+		}
+	}
+}
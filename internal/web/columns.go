@@ -0,0 +1,94 @@
+package web
+
+import (
+	"encoding/json"
+	"errors"
+	"fmt"
+	"net/http"
+	"slices"
+	"strings"
+)
+
+// listColumns is the columns allowlist for /api/opportunities: the JSON keys
+// of db.OpportunityListItem a client may ask for.
+var listColumns = []string{
+	"id",
+	"title",
+	"solicitation_number",
+	"department",
+	"sub_tier",
+	"office",
+	"opp_type",
+	"base_type",
+	"posted_date",
+	"response_deadline",
+	"response_deadline_utc",
+	"naics_code",
+	"set_aside",
+	"set_aside_description",
+	"description",
+	"description_text",
+	"description_word_count",
+	"reading_minutes",
+	"active",
+	"ui_link",
+	"pop_state_code",
+	"pop_state_name",
+	"award_amount",
+	"is_latest_amendment",
+	"days_to_deadline",
+	"has_documents",
+	"freshness_score",
+}
+
+// parseColumns reads the comma-separated columns param. Nil means every
+// column; an unknown name is an error so a typo doesn't silently drop a
+// field.
+func parseColumns(r *http.Request) (map[string]bool, error) {
+	v := r.URL.Query().Get("columns")
+	if v == "" {
+		return nil, nil
+	}
+	cols := map[string]bool{}
+	for _, c := range strings.Split(v, ",") {
+		c = strings.TrimSpace(c)
+		if c == "" {
+			continue
+		}
+		if !slices.Contains(listColumns, c) {
+			return nil, fmt.Errorf("unknown column %q (want any of: %s)", c, strings.Join(listColumns, ", "))
+		}
+		cols[c] = true
+	}
+	if len(cols) == 0 {
+		return nil, errors.New("columns lists no column names")
+	}
+	return cols, nil
+}
+
+// selectColumns trims each item to the requested columns, or returns items
+// as-is when cols is nil. Keys outside listColumns, like a fuzzy score, are
+// always kept.
+func selectColumns[T any](items []T, cols map[string]bool) (any, error) {
+	if cols == nil {
+		return items, nil
+	}
+	out := make([]map[string]json.RawMessage, 0, len(items))
+	for _, it := range items {
+		b, err := json.Marshal(it)
+		if err != nil {
+			return nil, err
+		}
+		var m map[string]json.RawMessage
+		if err := json.Unmarshal(b, &m); err != nil {
+			return nil, err
+		}
+		for k := range m {
+			if !cols[k] && slices.Contains(listColumns, k) {
+				delete(m, k)
+			}
+		}
+		out = append(out, m)
+	}
+	return out, nil
+}
@@ -0,0 +1,89 @@
+package web
+
+import (
+	"encoding/json"
+	"io"
+	"log"
+	"net/http"
+	"net/url"
+	"os"
+	"strconv"
+	"time"
+
+	"github.com/go-chi/chi/v5"
+	"github.com/theognis1002/govscout/internal/db"
+	"github.com/theognis1002/govscout/internal/samgov"
+)
+
+// downloadHTTP is shared across downloads so connections are reused. It has
+// no overall timeout; the request context ends a stalled or abandoned stream.
+var downloadHTTP = samgov.ResourceHTTPClient(samgov.DefaultConnectTimeout, samgov.DefaultRequestTimeout)
+
+// handleAPIDownload streams the index-th resource link of an opportunity,
+// fetching it server-side so the SAM.gov API key never reaches the browser.
+// Links outside SAM.gov don't need the key and are redirected to directly.
+func (s *Server) handleAPIDownload(w http.ResponseWriter, r *http.Request) {
+	detail, err := db.GetOpportunity(s.db, chi.URLParam(r, "id"))
+	if err != nil {
+		log.Printf("api download: %v", err)
+		writeError(w, http.StatusInternalServerError, "internal server error")
+		return
+	}
+	if detail == nil {
+		writeError(w, http.StatusNotFound, "not found")
+		return
+	}
+
+	var links []string
+	if detail.Opp.ResourceLinks != nil {
+		if err := json.Unmarshal([]byte(*detail.Opp.ResourceLinks), &links); err != nil {
+			log.Printf("api download: decode resource_links %s: %v", detail.Opp.ID, err)
+		}
+	}
+	index, err := strconv.Atoi(r.URL.Query().Get("index"))
+	if err != nil || index < 0 || index >= len(links) {
+		writeError(w, http.StatusNotFound, "no resource at that index")
+		return
+	}
+
+	u, err := url.Parse(links[index])
+	if err != nil {
+		writeError(w, http.StatusBadGateway, "invalid resource link")
+		return
+	}
+	if !samgov.IsSAMResource(u) {
+		http.Redirect(w, r, u.String(), http.StatusFound)
+		return
+	}
+
+	client, err := samgov.NewClient(os.Getenv("SAMGOV_API_KEY"), samgov.WithHTTPClient(downloadHTTP))
+	if err != nil {
+		log.Printf("api download: %v", err)
+		writeError(w, http.StatusServiceUnavailable, "SAM.gov API key not configured")
+		return
+	}
+	resp, err := client.OpenResource(r.Context(), u.String())
+	if err != nil {
+		log.Printf("api download %s[%d]: %v", detail.Opp.ID, index, err)
+		writeError(w, http.StatusBadGateway, "resource fetch failed")
+		return
+	}
+	defer resp.Body.Close()
+
+	for _, h := range []string{"Content-Type", "Content-Length", "Content-Disposition", "Last-Modified"} {
+		if v := resp.Header.Get(h); v != "" {
+			w.Header().Set(h, v)
+		}
+	}
+	if w.Header().Get("Content-Type") == "" {
+		w.Header().Set("Content-Type", "application/octet-stream")
+	}
+	// Lift the server's WriteTimeout for this response; the client
+	// disconnecting (the request context) still ends the stream. Writers
+	// that can't do this keep the server-wide limit.
+	_ = http.NewResponseController(w).SetWriteDeadline(time.Time{})
+	w.WriteHeader(http.StatusOK)
+	if _, err := io.Copy(w, resp.Body); err != nil {
+		log.Printf("api download %s[%d]: stream: %v", detail.Opp.ID, index, err)
+	}
+}
@@ -0,0 +1,46 @@
+package web
+
+import (
+	"net/http"
+	"net/http/httptest"
+	"path/filepath"
+	"testing"
+
+	"github.com/go-chi/chi/v5"
+	"github.com/theognis1002/govscout/internal/db"
+)
+
+func TestHandleAPIDownload_IndexAndRedirect(t *testing.T) {
+	d, err := db.Open(filepath.Join(t.TempDir(), "test.db"))
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer d.Close()
+	if _, err := d.Exec(`INSERT INTO opportunities (id, title, resource_links) VALUES
+		('opp-1', 'Widgets', '["https://example.com/spec.pdf"]')`); err != nil {
+		t.Fatal(err)
+	}
+
+	s := &Server{db: d}
+	r := chi.NewRouter()
+	r.Get("/api/opportunities/{id}/download", s.handleAPIDownload)
+
+	tests := []struct {
+		path string
+		want int
+	}{
+		{"/api/opportunities/opp-1/download?index=1", http.StatusNotFound},
+		{"/api/opportunities/opp-1/download?index=-1", http.StatusNotFound},
+		{"/api/opportunities/opp-1/download", http.StatusNotFound},
+		{"/api/opportunities/missing/download?index=0", http.StatusNotFound},
+		// Not a SAM.gov host: no key needed, so the client is sent there directly.
+		{"/api/opportunities/opp-1/download?index=0", http.StatusFound},
+	}
+	for _, tt := range tests {
+		rec := httptest.NewRecorder()
+		r.ServeHTTP(rec, httptest.NewRequest("GET", tt.path, nil))
+		if rec.Code != tt.want {
+			t.Errorf("GET %s = %d, want %d", tt.path, rec.Code, tt.want)
+		}
+	}
+}
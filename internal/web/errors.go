@@ -0,0 +1,73 @@
+package web
+
+import (
+	"log"
+	"net/http"
+	"runtime/debug"
+	"strings"
+)
+
+// writeError is the JSON error body every API failure uses, so clients can
+// always read a message: {"error": "...", "status": N}.
+func writeError(w http.ResponseWriter, status int, msg string) {
+	writeJSON(w, status, apiError{Error: msg, Status: status})
+}
+
+type apiError struct {
+	Error  string `json:"error"`
+	Status int    `json:"status"`
+}
+
+// wantsJSON reports whether an error for r should be JSON rather than plain
+// text: anything under /api except the RSS feed, or a client that asks for
+// JSON.
+func wantsJSON(r *http.Request) bool {
+	if strings.Contains(r.Header.Get("Accept"), "application/json") {
+		return true
+	}
+	if strings.HasSuffix(r.URL.Path, ".rss") {
+		return false
+	}
+	return r.URL.Path == "/api" || strings.HasPrefix(r.URL.Path, "/api/")
+}
+
+// handleNotFound and handleMethodNotAllowed replace chi's empty-bodied
+// defaults for unknown routes and wrong methods.
+func handleNotFound(w http.ResponseWriter, r *http.Request) {
+	if wantsJSON(r) {
+		writeError(w, http.StatusNotFound, "not found")
+		return
+	}
+	http.Error(w, "404 page not found", http.StatusNotFound)
+}
+
+func handleMethodNotAllowed(w http.ResponseWriter, r *http.Request) {
+	if wantsJSON(r) {
+		writeError(w, http.StatusMethodNotAllowed, "method "+r.Method+" not allowed")
+		return
+	}
+	http.Error(w, "405 method not allowed", http.StatusMethodNotAllowed)
+}
+
+// recoverer turns a handler panic into a 500 with a body (JSON for API
+// requests) instead of chi's empty response, logging the stack.
+func recoverer(next http.Handler) http.Handler {
+	return http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		defer func() {
+			rec := recover()
+			if rec == nil {
+				return
+			}
+			if rec == http.ErrAbortHandler {
+				panic(rec) // net/http's signal to drop the connection quietly
+			}
+			log.Printf("panic serving %s %s: %v\n%s", r.Method, r.URL.Path, rec, debug.Stack())
+			if wantsJSON(r) {
+				writeError(w, http.StatusInternalServerError, "internal server error")
+				return
+			}
+			http.Error(w, "Internal server error", http.StatusInternalServerError)
+		}()
+		next.ServeHTTP(w, r)
+	})
+}
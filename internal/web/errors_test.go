@@ -0,0 +1,67 @@
+package web
+
+import (
+	"encoding/json"
+	"net/http"
+	"net/http/httptest"
+	"path/filepath"
+	"strings"
+	"testing"
+
+	"github.com/theognis1002/govscout/internal/db"
+)
+
+func TestRoutes_ErrorBodies(t *testing.T) {
+	d, err := db.Open(filepath.Join(t.TempDir(), "test.db"))
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer d.Close()
+	s := &Server{db: d}
+	r := s.routes()
+	r.Get("/boom", func(http.ResponseWriter, *http.Request) { panic("boom") })
+
+	tests := []struct {
+		method, path, accept string
+		status               int
+		json                 bool
+	}{
+		{"GET", "/no-such-page", "", http.StatusNotFound, false},
+		{"GET", "/no-such-page", "application/json", http.StatusNotFound, true},
+		{"PUT", "/live", "", http.StatusMethodNotAllowed, false},
+		{"PUT", "/live", "application/json", http.StatusMethodNotAllowed, true},
+		{"GET", "/boom", "", http.StatusInternalServerError, false},
+		{"GET", "/boom", "application/json", http.StatusInternalServerError, true},
+		{"GET", "/api/opportunities.rss", "", http.StatusUnauthorized, false},
+		{"GET", "/api/opportunities", "", http.StatusUnauthorized, true},
+	}
+	for _, tt := range tests {
+		t.Run(tt.method+" "+tt.path+" "+tt.accept, func(t *testing.T) {
+			req := httptest.NewRequest(tt.method, tt.path, nil)
+			if tt.accept != "" {
+				req.Header.Set("Accept", tt.accept)
+			}
+			rec := httptest.NewRecorder()
+			r.ServeHTTP(rec, req)
+			if rec.Code != tt.status {
+				t.Fatalf("status = %d, want %d", rec.Code, tt.status)
+			}
+			if !tt.json {
+				if strings.TrimSpace(rec.Body.String()) == "" {
+					t.Error("empty error body")
+				}
+				if ct := rec.Header().Get("Content-Type"); !strings.HasPrefix(ct, "text/plain") {
+					t.Errorf("Content-Type = %q, want plain text", ct)
+				}
+				return
+			}
+			var body apiError
+			if err := json.Unmarshal(rec.Body.Bytes(), &body); err != nil {
+				t.Fatalf("body %q is not JSON: %v", rec.Body.String(), err)
+			}
+			if body.Status != tt.status || body.Error == "" {
+				t.Errorf("body = %+v, want status %d with a message", body, tt.status)
+			}
+		})
+	}
+}
@@ -0,0 +1,192 @@
+package web
+
+import (
+	"crypto/hmac"
+	"crypto/sha256"
+	"encoding/hex"
+	"encoding/xml"
+	"log"
+	"net/http"
+	"net/url"
+	"strings"
+	"time"
+
+	"github.com/theognis1002/govscout/internal/db"
+)
+
+const feedItemLimit = 50
+
+type rssFeed struct {
+	XMLName xml.Name   `xml:"rss"`
+	Version string     `xml:"version,attr"`
+	Channel rssChannel `xml:"channel"`
+}
+
+type rssChannel struct {
+	Title         string    `xml:"title"`
+	Link          string    `xml:"link"`
+	Description   string    `xml:"description"`
+	LastBuildDate string    `xml:"lastBuildDate"`
+	Items         []rssItem `xml:"item"`
+}
+
+type rssItem struct {
+	Title       string  `xml:"title"`
+	Link        string  `xml:"link,omitempty"`
+	GUID        rssGUID `xml:"guid"`
+	PubDate     string  `xml:"pubDate,omitempty"`
+	Description string  `xml:"description,omitempty"`
+}
+
+type rssGUID struct {
+	Value       string `xml:",chardata"`
+	IsPermaLink bool   `xml:"isPermaLink,attr"`
+}
+
+// Feed readers can't hold a session, so the feed also accepts ?key=, a
+// per-user key of the form "username:hmac". The HMAC covers the password
+// hash, so changing the password (or deleting the user) revokes old keys.
+
+func newFeedKey(secret string) []byte {
+	k := sha256.Sum256([]byte("feed:" + secret))
+	return k[:]
+}
+
+// feedKeyFor returns user's feed key.
+func (s *Server) feedKeyFor(user *db.UserRow) string {
+	mac := hmac.New(sha256.New, s.feedKey)
+	mac.Write([]byte(user.Username + "\x00" + user.PasswordHash))
+	return user.Username + ":" + hex.EncodeToString(mac.Sum(nil))
+}
+
+// feedKeyUser returns the user a feed key belongs to, or nil if it is
+// malformed, forged, or revoked.
+func (s *Server) feedKeyUser(key string) *SessionUser {
+	i := strings.LastIndex(key, ":")
+	if i <= 0 || len(s.feedKey) == 0 {
+		return nil
+	}
+	user, err := db.GetUserByUsername(s.db, key[:i])
+	if err != nil || user == nil {
+		return nil
+	}
+	if !hmac.Equal([]byte(s.feedKeyFor(user)), []byte(key)) {
+		return nil
+	}
+	return &SessionUser{ID: user.ID, Username: user.Username, IsAdmin: user.IsAdmin}
+}
+
+// requireFeedAuth admits a session or a valid ?key=. Failures are plain
+// text: the client is a feed reader, not a JSON consumer.
+func (s *Server) requireFeedAuth(next http.Handler) http.Handler {
+	return http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		user := s.getSession(r)
+		if user == nil {
+			if key := r.URL.Query().Get("key"); key != "" {
+				user = s.feedKeyUser(key)
+			}
+		}
+		if user == nil {
+			http.Error(w, "authentication required: log in or add ?key= from /api/feed-key", http.StatusUnauthorized)
+			return
+		}
+		next.ServeHTTP(w, r.WithContext(setUser(r.Context(), user)))
+	})
+}
+
+// handleAPIFeedKey returns the session user's feed key and a subscribable
+// feed URL.
+func (s *Server) handleAPIFeedKey(w http.ResponseWriter, r *http.Request) {
+	user, err := db.GetUserByUsername(s.db, getUser(r).Username)
+	if err != nil || user == nil {
+		writeError(w, http.StatusInternalServerError, "internal server error")
+		return
+	}
+	key := s.feedKeyFor(user)
+	writeJSON(w, http.StatusOK, map[string]string{
+		"key": key,
+		"url": absoluteURL(r, "/api/opportunities.rss?key="+url.QueryEscape(key)),
+	})
+}
+
+// handleAPIFeed serves the newest opportunities matching the standard list
+// filters as an RSS 2.0 feed. encoding/xml escapes all text content.
+func (s *Server) handleAPIFeed(w http.ResponseWriter, r *http.Request) {
+	filters, err := parseFilters(r)
+	if err != nil {
+		http.Error(w, err.Error(), http.StatusBadRequest)
+		return
+	}
+	filters.Limit = feedItemLimit
+	filters.Offset = 0
+
+	result, err := db.ListOpportunities(s.db, filters)
+	if err != nil {
+		log.Printf("feed: %v", err)
+		http.Error(w, "Internal server error", http.StatusInternalServerError)
+		return
+	}
+
+	// The channel links to the web list, which has no use for the key.
+	q := r.URL.Query()
+	q.Del("key")
+	link := "/opportunities"
+	if len(q) > 0 {
+		link += "?" + q.Encode()
+	}
+	feed := rssFeed{
+		Version: "2.0",
+		Channel: rssChannel{
+			Title:         "GovScout opportunities",
+			Link:          absoluteURL(r, link),
+			Description:   "Newest SAM.gov opportunities matching your filters",
+			LastBuildDate: time.Now().UTC().Format(time.RFC1123Z),
+		},
+	}
+	for _, o := range result.Opportunities {
+		item := rssItem{
+			Title: derefOr(o.Title, "Untitled"),
+			Link:  derefOr(o.UILink, ""),
+			GUID:  rssGUID{Value: o.ID},
+		}
+		if o.PostedDate != nil {
+			if t, ok := parsePostedDate(*o.PostedDate); ok {
+				item.PubDate = t.Format(time.RFC1123Z)
+			}
+		}
+		// Upserts fill description_text; derive it for rows written any
+		// other way.
+		if o.DescriptionText != nil {
+			item.Description = *o.DescriptionText
+		} else if text := db.DescriptionText(o.Description); text != nil {
+			item.Description = *text
+		}
+		feed.Channel.Items = append(feed.Channel.Items, item)
+	}
+
+	w.Header().Set("Content-Type", "application/rss+xml; charset=utf-8")
+	w.Write([]byte(xml.Header))
+	enc := xml.NewEncoder(w)
+	enc.Indent("", "  ")
+	if err := enc.Encode(feed); err != nil {
+		log.Printf("feed encode: %v", err)
+	}
+}
+
+// parsePostedDate accepts the date shapes SAM.gov has used for postedDate.
+func parsePostedDate(s string) (time.Time, bool) {
+	for _, layout := range []string{"2006-01-02", "01/02/2006", time.RFC3339, "2006-01-02 15:04:05-07"} {
+		if t, err := time.Parse(layout, s); err == nil {
+			return t, true
+		}
+	}
+	return time.Time{}, false
+}
+
+func absoluteURL(r *http.Request, path string) string {
+	scheme := "http"
+	if r.TLS != nil || r.Header.Get("X-Forwarded-Proto") == "https" {
+		scheme = "https"
+	}
+	return scheme + "://" + r.Host + path
+}
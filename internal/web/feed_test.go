@@ -0,0 +1,111 @@
+package web
+
+import (
+	"encoding/xml"
+	"net/http"
+	"net/http/httptest"
+	"net/url"
+	"path/filepath"
+	"strings"
+	"testing"
+
+	"github.com/theognis1002/govscout/internal/db"
+)
+
+func TestHandleAPIFeed_ValidEscapedRSS(t *testing.T) {
+	d, err := db.Open(filepath.Join(t.TempDir(), "test.db"))
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer d.Close()
+	if _, err := d.Exec(`INSERT INTO opportunities (id, title, naics_code, posted_date, ui_link, description) VALUES
+		('opp-1', 'R&D <Widgets>', '541511', '2026-01-15', 'https://sam.gov/opp/opp-1/view', '<p>Build  widgets</p>'),
+		('opp-2', 'Other', '236220', '2026-01-16', NULL, NULL)`); err != nil {
+		t.Fatal(err)
+	}
+
+	s := &Server{db: d}
+	rec := httptest.NewRecorder()
+	s.handleAPIFeed(rec, httptest.NewRequest("GET", "/api/opportunities.rss?naics_code=541511", nil))
+
+	if ct := rec.Header().Get("Content-Type"); ct != "application/rss+xml; charset=utf-8" {
+		t.Errorf("Content-Type = %q", ct)
+	}
+	var feed rssFeed
+	if err := xml.Unmarshal(rec.Body.Bytes(), &feed); err != nil {
+		t.Fatalf("feed is not valid XML: %v\n%s", err, rec.Body.String())
+	}
+	if len(feed.Channel.Items) != 1 {
+		t.Fatalf("got %d items, want 1 (NAICS filter)", len(feed.Channel.Items))
+	}
+	item := feed.Channel.Items[0]
+	if item.Title != "R&D <Widgets>" {
+		t.Errorf("title = %q", item.Title)
+	}
+	if item.Link != "https://sam.gov/opp/opp-1/view" {
+		t.Errorf("link = %q", item.Link)
+	}
+	if item.PubDate != "Thu, 15 Jan 2026 00:00:00 +0000" {
+		t.Errorf("pubDate = %q", item.PubDate)
+	}
+	if item.Description != "Build widgets" {
+		t.Errorf("description = %q, want stripped text", item.Description)
+	}
+}
+
+func TestFeedRoute_RequiresSessionOrKey(t *testing.T) {
+	t.Setenv("AUTH_SECRET", "test-secret-test-secret-test-secret")
+	d, err := db.Open(filepath.Join(t.TempDir(), "test.db"))
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer d.Close()
+	if _, err := d.Exec(`INSERT INTO opportunities (id, title) VALUES ('opp-1', 'Widgets')`); err != nil {
+		t.Fatal(err)
+	}
+	if err := db.CreateUser(d, "alice", "hash-1", false); err != nil {
+		t.Fatal(err)
+	}
+	s := NewServer(d)
+
+	get := func(key string) *httptest.ResponseRecorder {
+		target := "/api/opportunities.rss"
+		if key != "" {
+			target += "?key=" + url.QueryEscape(key)
+		}
+		rec := httptest.NewRecorder()
+		s.ServeHTTP(rec, httptest.NewRequest("GET", target, nil))
+		return rec
+	}
+
+	user, err := db.GetUserByUsername(d, "alice")
+	if err != nil {
+		t.Fatal(err)
+	}
+	key := s.feedKeyFor(user)
+
+	if rec := get(""); rec.Code != http.StatusUnauthorized {
+		t.Errorf("no key: status = %d, want 401", rec.Code)
+	}
+	if rec := get("alice:" + strings.Repeat("0", 64)); rec.Code != http.StatusUnauthorized {
+		t.Errorf("forged key: status = %d, want 401", rec.Code)
+	}
+	rec := get(key)
+	if rec.Code != http.StatusOK {
+		t.Fatalf("valid key: status = %d: %s", rec.Code, rec.Body.String())
+	}
+	var feed rssFeed
+	if err := xml.Unmarshal(rec.Body.Bytes(), &feed); err != nil {
+		t.Fatal(err)
+	}
+	if len(feed.Channel.Items) != 1 || strings.Contains(feed.Channel.Link, "key=") {
+		t.Errorf("items = %d, channel link = %q; want 1 item and no key in the link", len(feed.Channel.Items), feed.Channel.Link)
+	}
+
+	if err := db.UpdatePassword(d, "alice", "hash-2"); err != nil {
+		t.Fatal(err)
+	}
+	if rec := get(key); rec.Code != http.StatusUnauthorized {
+		t.Errorf("key after password change: status = %d, want 401", rec.Code)
+	}
+}
@@ -0,0 +1,60 @@
+package web
+
+import (
+	"encoding/json"
+	"log"
+	"net/http"
+
+	"github.com/theognis1002/govscout/internal/codes"
+	"github.com/theognis1002/govscout/internal/db"
+)
+
+type geoFeatureCollection struct {
+	Type     string       `json:"type"`
+	Features []geoFeature `json:"features"`
+}
+
+type geoFeature struct {
+	Type       string          `json:"type"`
+	Geometry   *geoPoint       `json:"geometry"` // null for codes without a bundled centroid
+	Properties geoStateSummary `json:"properties"`
+}
+
+type geoPoint struct {
+	Type        string     `json:"type"`
+	Coordinates [2]float64 `json:"coordinates"` // longitude, latitude
+}
+
+type geoStateSummary struct {
+	StateCode string `json:"state_code"`
+	StateName string `json:"state_name,omitempty"`
+	Count     int64  `json:"count"`
+}
+
+// handleAPIStatesGeoJSON serves opportunity counts per place-of-performance
+// state as a GeoJSON FeatureCollection of centroid points, for choropleth
+// and marker maps. Codes not in the bundled state table keep their count
+// with a null geometry.
+func (s *Server) handleAPIStatesGeoJSON(w http.ResponseWriter, r *http.Request) {
+	counts, err := db.GetStateCounts(s.db)
+	if err != nil {
+		log.Printf("api states geojson: %v", err)
+		writeError(w, http.StatusInternalServerError, "internal server error")
+		return
+	}
+
+	fc := geoFeatureCollection{Type: "FeatureCollection", Features: make([]geoFeature, 0, len(counts))}
+	for _, c := range counts {
+		f := geoFeature{Type: "Feature", Properties: geoStateSummary{StateCode: c.Value, Count: c.Count}}
+		if st, ok := codes.LookupState(c.Value); ok {
+			f.Properties.StateName = st.Name
+			f.Geometry = &geoPoint{Type: "Point", Coordinates: [2]float64{st.Lon, st.Lat}}
+		}
+		fc.Features = append(fc.Features, f)
+	}
+
+	w.Header().Set("Content-Type", "application/geo+json")
+	if err := json.NewEncoder(w).Encode(fc); err != nil {
+		log.Printf("write geojson: %v", err)
+	}
+}
@@ -1,9 +1,13 @@
 package web
 
 import (
+	"errors"
 	"fmt"
+	"html/template"
 	"log"
+	"math"
 	"net/http"
+	"net/url"
 	"os"
 	"strconv"
 	"strings"
@@ -91,7 +95,12 @@ func (s *Server) handleOpportunities(w http.ResponseWriter, r *http.Request) {
 		}
 	}
 
-	filters := parseFilters(r)
+	filters, err := parseFilters(r)
+	if err != nil {
+		http.Error(w, err.Error(), http.StatusBadRequest)
+		return
+	}
+	filters.OmitDescription = true // the list view never shows it
 	result, err := db.ListOpportunities(s.db, filters)
 	if err != nil {
 		log.Printf("list opportunities: %v", err)
@@ -108,6 +117,7 @@ func (s *Server) handleOpportunities(w http.ResponseWriter, r *http.Request) {
 		"Stats":          stats,
 		"PageCount":      pageCount(result.Total, filters.Limit),
 		"CurrentPage":    currentPage(filters.Offset, filters.Limit),
+		"PageQuery":      pageQuery(r),
 		"SavedFilters":   savedFilters,
 		"ActiveFilterID": activeFilterID,
 	}
@@ -115,7 +125,12 @@ func (s *Server) handleOpportunities(w http.ResponseWriter, r *http.Request) {
 }
 
 func (s *Server) handleOpportunitiesPartial(w http.ResponseWriter, r *http.Request) {
-	filters := parseFilters(r)
+	filters, err := parseFilters(r)
+	if err != nil {
+		http.Error(w, err.Error(), http.StatusBadRequest)
+		return
+	}
+	filters.OmitDescription = true
 	result, err := db.ListOpportunities(s.db, filters)
 	if err != nil {
 		log.Printf("list opportunities partial: %v", err)
@@ -129,6 +144,7 @@ func (s *Server) handleOpportunitiesPartial(w http.ResponseWriter, r *http.Reque
 		"Filters":       filters,
 		"PageCount":     pageCount(result.Total, filters.Limit),
 		"CurrentPage":   currentPage(filters.Offset, filters.Limit),
+		"PageQuery":     pageQuery(r),
 	}
 	renderTemplate(w, s.templates(), "results.html", data)
 }
@@ -185,7 +201,11 @@ func (s *Server) handleOpportunitiesExport(w http.ResponseWriter, r *http.Reques
 		}
 	}
 
-	filters := parseFilters(r)
+	filters, err := parseFilters(r)
+	if err != nil {
+		http.Error(w, err.Error(), http.StatusBadRequest)
+		return
+	}
 	items, err := db.ExportOpportunities(s.db, filters)
 	if err != nil {
 		log.Printf("export opportunities: %v", err)
@@ -516,7 +536,7 @@ func (s *Server) handleFilterDelete(w http.ResponseWriter, r *http.Request) {
 
 func (s *Server) handleAdminSync(w http.ResponseWriter, r *http.Request) {
 	apiKey := os.Getenv("SAMGOV_API_KEY")
-	client, err := samgov.NewClient(apiKey)
+	client, err := samgov.NewSourceFromEnv(apiKey)
 	if err != nil {
 		setFlash(w, "error", fmt.Sprintf("Cannot start sync: %v", err))
 		http.Redirect(w, r, "/admin/sync-runs", http.StatusFound)
@@ -554,13 +574,28 @@ func (s *Server) handleAdminSync(w http.ResponseWriter, r *http.Request) {
 }
 
 func (s *Server) handleAdminSyncRuns(w http.ResponseWriter, r *http.Request) {
-	runs, err := db.ListSyncRuns(s.db, 50)
+	q := db.SyncRunQuery{
+		Limit:   queryInt(r, "limit", syncRunsPageSize),
+		Offset:  max(queryInt(r, "offset", 0), 0),
+		Context: r.URL.Query().Get("context"),
+	}
+	if q.Limit <= 0 || q.Limit > maxSyncRunsPageSize {
+		q.Limit = syncRunsPageSize
+	}
+	runs, total, err := db.QuerySyncRuns(s.db, q)
 	if err != nil {
 		log.Printf("list sync runs: %v", err)
 		http.Error(w, "Internal server error", 500)
 		return
 	}
-	s.render(w, r, "admin_sync.html", map[string]any{"Runs": runs})
+	s.render(w, r, "admin_sync.html", map[string]any{
+		"Runs":        runs,
+		"Total":       total,
+		"Query":       q,
+		"NewerOffset": max(q.Offset-q.Limit, 0),
+		"OlderOffset": q.Offset + len(runs),
+		"HasOlder":    int64(q.Offset+len(runs)) < total,
+	})
 }
 
 func (s *Server) handleAdminUsers(w http.ResponseWriter, r *http.Request) {
@@ -653,10 +688,13 @@ func (s *Server) render(w http.ResponseWriter, r *http.Request, name string, dat
 	}
 }
 
-func parseFilters(r *http.Request) db.ListFilters {
+// parseFilters reads the list filters from r's query. It fails only on
+// combinations that can never match, such as active_only with
+// inactive_only, so callers can answer 400 instead of an empty page.
+func parseFilters(r *http.Request) (db.ListFilters, error) {
 	limit := 25
 	if l := r.URL.Query().Get("limit"); l != "" {
-		if n, err := strconv.Atoi(l); err == nil && n > 0 && n <= 100 {
+		if n, err := strconv.Atoi(l); err == nil && n > 0 && n <= db.MaxPageSize() {
 			limit = n
 		}
 	}
@@ -674,15 +712,50 @@ func parseFilters(r *http.Request) db.ListFilters {
 	}
 
 	f := db.ListFilters{
-		Search:     r.URL.Query().Get("search"),
-		NAICSCode:  formMultiValue(r, "naics_code"),
-		OppType:    formMultiValue(r, "opp_type"),
-		SetAside:   formMultiValue(r, "set_aside"),
-		State:      r.URL.Query().Get("state"),
-		Department: formMultiValue(r, "department"),
-		ActiveOnly: r.URL.Query().Get("active_only") == "on" || r.URL.Query().Get("active_only") == "true",
-		Limit:      limit,
-		Offset:     offset,
+		Search:             r.URL.Query().Get("search"),
+		SolicitationNumber: r.URL.Query().Get("solicitation_number"),
+		NAICSCode:          formMultiValue(r, "naics_code"),
+		ClassificationCode: formMultiValue(r, "classification_code"),
+		OppType:            formMultiValue(r, "opp_type"),
+		SetAside:           formMultiValue(r, "set_aside"),
+		State:              r.URL.Query().Get("state"),
+		Department:         formMultiValue(r, "department"),
+		SortBy:             r.URL.Query().Get("sort_by"),
+		SortDir:            r.URL.Query().Get("sort_dir"),
+		ActiveOnly:         r.URL.Query().Get("active_only") == "on" || r.URL.Query().Get("active_only") == "true",
+		InactiveOnly:       r.URL.Query().Get("inactive_only") == "on" || r.URL.Query().Get("inactive_only") == "true",
+		LatestOnly:         r.URL.Query().Get("latest_only") == "on" || r.URL.Query().Get("latest_only") == "true",
+		HasDocuments:       r.URL.Query().Get("has_documents") == "on" || r.URL.Query().Get("has_documents") == "true",
+		Tag:                formMultiValue(r, "tag"),
+		MatchAny:           r.URL.Query().Get("match") == "any",
+		Limit:              limit,
+		Offset:             offset,
+	}
+
+	// created_after accepts the same forms as db.ParseSince (24h, 7d, 2026-01-31).
+	// Invalid values are ignored rather than failing the page.
+	if since := r.URL.Query().Get("created_after"); since != "" {
+		if ts, err := db.ParseSince(since, time.Now()); err == nil {
+			f.AddedSince = since
+			f.CreatedAfter = ts
+		}
+	}
+
+	if n, err := strconv.Atoi(r.URL.Query().Get("min_days_to_respond")); err == nil && n > 0 {
+		f.MinDaysToRespond = n
+	}
+
+	// Each w_* param overrides one DefaultFreshnessWeights weight; negative,
+	// infinite, or malformed values are ignored.
+	f.Freshness = db.DefaultFreshnessWeights
+	for key, weight := range map[string]*float64{
+		"w_recency":   &f.Freshness.Recency,
+		"w_deadline":  &f.Freshness.Deadline,
+		"w_documents": &f.Freshness.Documents,
+	} {
+		if v, err := strconv.ParseFloat(r.URL.Query().Get(key), 64); err == nil && v >= 0 && !math.IsInf(v, 0) {
+			*weight = v
+		}
 	}
 
 	// Response deadline shortcuts
@@ -702,7 +775,10 @@ func parseFilters(r *http.Request) db.ListFilters {
 		}
 	}
 
-	return f
+	if f.ActiveOnly && f.InactiveOnly {
+		return f, errors.New("active_only and inactive_only are mutually exclusive")
+	}
+	return f, nil
 }
 
 func parseID(r *http.Request) (int64, error) {
@@ -744,3 +820,22 @@ func currentPage(offset, limit int) int {
 	}
 	return offset/limit + 1
 }
+
+// pageQuery is the request's query without its paging position, so the
+// pagination links keep every filter the page was rendered with.
+func pageQuery(r *http.Request) url.Values {
+	q := r.URL.Query()
+	q.Del("page")
+	q.Del("offset")
+	return q
+}
+
+// pageURL is "?query" for page n of the listing q describes.
+func pageURL(q url.Values, n int) template.URL {
+	page := make(url.Values, len(q)+1)
+	for k, v := range q {
+		page[k] = v
+	}
+	page.Set("page", strconv.Itoa(n))
+	return template.URL("?" + page.Encode())
+}
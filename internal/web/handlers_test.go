@@ -0,0 +1,51 @@
+package web
+
+import (
+	"fmt"
+	"net/http"
+	"net/http/httptest"
+	"path/filepath"
+	"strings"
+	"testing"
+
+	"github.com/theognis1002/govscout/internal/db"
+)
+
+func TestOpportunitiesPartial_PaginationKeepsFilters(t *testing.T) {
+	d, err := db.Open(filepath.Join(t.TempDir(), "test.db"))
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer d.Close()
+	for i := 0; i < 25; i++ {
+		if _, err := d.Exec(`INSERT INTO opportunities (id, title, posted_date) VALUES (?, 'Widgets', '01/05/2026')`, fmt.Sprintf("n%02d", i)); err != nil {
+			t.Fatal(err)
+		}
+	}
+	s := &Server{db: d, tmpls: loadTemplates()}
+
+	rec := httptest.NewRecorder()
+	s.handleOpportunitiesPartial(rec, httptest.NewRequest("GET", "/opportunities/partial?created_after=24h&limit=10&page=1", nil))
+	if rec.Code != http.StatusOK {
+		t.Fatalf("status = %d, body %s", rec.Code, rec.Body)
+	}
+	body := rec.Body.String()
+	want := `href="?created_after=24h&amp;limit=10&amp;page=2"`
+	if !strings.Contains(body, want) {
+		t.Errorf("page 2 link missing %s in:\n%s", want, body)
+	}
+	if !strings.Contains(body, `hx-get="/opportunities/partial?created_after=24h&amp;limit=10&amp;page=3"`) {
+		t.Errorf("page 3 hx-get dropped the filter:\n%s", body)
+	}
+}
+
+func TestPageURL_KeepsEveryFilter(t *testing.T) {
+	// API-only params like match and inactive_only have no form control but
+	// must survive paging all the same.
+	r := httptest.NewRequest("GET", "/opportunities?has_documents=on&match=any&inactive_only=on&search=cyber+ops&page=3&offset=20", nil)
+	got := pageURL(pageQuery(r), 4)
+	want := "?has_documents=on&inactive_only=on&match=any&page=4&search=cyber+ops"
+	if string(got) != want {
+		t.Errorf("pageURL = %q, want %q", got, want)
+	}
+}
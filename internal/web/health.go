@@ -0,0 +1,35 @@
+package web
+
+import (
+	"context"
+	"log"
+	"net/http"
+	"time"
+)
+
+// readyTimeout bounds the readiness query so a wedged database fails the
+// probe instead of hanging it.
+const readyTimeout = 2 * time.Second
+
+// handleLive is the liveness probe: the process is up and serving HTTP.
+func handleLive(w http.ResponseWriter, r *http.Request) {
+	w.Write([]byte("ok"))
+}
+
+// handleReady is the readiness probe: the database answers a trivial query.
+// /health is served by the same handler.
+func (s *Server) handleReady(w http.ResponseWriter, r *http.Request) {
+	ctx, cancel := context.WithTimeout(r.Context(), readyTimeout)
+	defer cancel()
+
+	var one int
+	if err := s.db.QueryRowContext(ctx, "SELECT 1").Scan(&one); err != nil {
+		log.Printf("readiness check: %v", err)
+		writeJSON(w, http.StatusServiceUnavailable, map[string]string{
+			"status": "unavailable",
+			"error":  "database unreachable",
+		})
+		return
+	}
+	writeJSON(w, http.StatusOK, map[string]string{"status": "ok"})
+}
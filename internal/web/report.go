@@ -0,0 +1,118 @@
+package web
+
+import (
+	_ "embed"
+	"html/template"
+	"io"
+	"sort"
+	"time"
+
+	"github.com/theognis1002/govscout/internal/db"
+)
+
+//go:embed templates/report/report.html
+var reportHTML string
+
+var reportTemplate = template.Must(template.New("report").Funcs(funcMap).Parse(reportHTML))
+
+// reportTopN limits each breakdown in the report summary.
+const reportTopN = 5
+
+type reportCriterion struct {
+	Name  string
+	Value string
+}
+
+type reportData struct {
+	GeneratedAt   time.Time
+	Criteria      []reportCriterion
+	Total         int
+	Active        int
+	Types         []db.FilterStat
+	Departments   []db.FilterStat
+	NAICSCodes    []db.FilterStat
+	Opportunities []db.OpportunityListItem
+	Truncated     bool
+}
+
+// WriteReport renders a self-contained HTML report (inline CSS, no external
+// assets) of the given opportunities. All values go through html/template, so
+// titles and descriptions from SAM.gov are escaped and non-http(s) links are
+// neutralized. The summary covers every item; the table lists the first
+// limit of them (0 = all).
+func WriteReport(w io.Writer, items []db.OpportunityListItem, f db.ListFilters, limit int, generatedAt time.Time) error {
+	data := reportData{
+		GeneratedAt:   generatedAt,
+		Criteria:      reportCriteria(f),
+		Total:         len(items),
+		Opportunities: items,
+	}
+	if limit > 0 && len(items) > limit {
+		data.Opportunities = items[:limit]
+		data.Truncated = true
+	}
+
+	types := map[string]int64{}
+	departments := map[string]int64{}
+	naics := map[string]int64{}
+	for _, o := range items {
+		if o.Active == 1 {
+			data.Active++
+		}
+		if o.OppType != nil && *o.OppType != "" {
+			types[*o.OppType]++
+		}
+		if o.Department != nil && *o.Department != "" {
+			departments[*o.Department]++
+		}
+		if o.NAICSCode != nil && *o.NAICSCode != "" {
+			naics[*o.NAICSCode]++
+		}
+	}
+	data.Types = topCounts(types, reportTopN)
+	data.Departments = topCounts(departments, reportTopN)
+	data.NAICSCodes = topCounts(naics, reportTopN)
+
+	return reportTemplate.Execute(w, data)
+}
+
+func reportCriteria(f db.ListFilters) []reportCriterion {
+	var c []reportCriterion
+	add := func(name, value string) {
+		if value != "" {
+			c = append(c, reportCriterion{Name: name, Value: value})
+		}
+	}
+	add("Search", f.Search)
+	add("NAICS", f.NAICSCode)
+	add("Type", f.OppType)
+	add("Set-Aside", f.SetAside)
+	add("State", f.State)
+	add("Department", f.Department)
+	add("Added since", f.AddedSince)
+	if f.ActiveOnly {
+		add("Status", "Active only")
+	}
+	if f.InactiveOnly {
+		add("Status", "Inactive only")
+	}
+	return c
+}
+
+// topCounts returns the n most frequent values, ties broken alphabetically.
+func topCounts(counts map[string]int64, n int) []db.FilterStat {
+	stats := make([]db.FilterStat, 0, len(counts))
+	for v, c := range counts {
+		stats = append(stats, db.FilterStat{Value: v, Count: c})
+	}
+	sort.Slice(stats, func(i, j int) bool {
+		if stats[i].Count != stats[j].Count {
+			return stats[i].Count > stats[j].Count
+		}
+		return stats[i].Value < stats[j].Value
+	})
+	if len(stats) > n {
+		stats = stats[:n]
+	}
+	return stats
+}
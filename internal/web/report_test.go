@@ -0,0 +1,92 @@
+package web
+
+import (
+	"bytes"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/theognis1002/govscout/internal/db"
+)
+
+func strPtr(s string) *string { return &s }
+
+func TestWriteReport_EscapesContentAndShowsCriteria(t *testing.T) {
+	items := []db.OpportunityListItem{
+		{
+			ID:         "opp-1",
+			Title:      strPtr(`<script>alert("x")</script>`),
+			Department: strPtr("DEPT OF DEFENSE"),
+			OppType:    strPtr("Solicitation"),
+			Active:     1,
+			UILink:     strPtr("https://sam.gov/opp/opp-1/view"),
+		},
+		{
+			ID:     "opp-2",
+			Title:  strPtr("Evil link"),
+			UILink: strPtr("javascript:alert(1)"),
+		},
+	}
+	f := db.ListFilters{Search: "cyber & cloud", ActiveOnly: true}
+
+	var buf bytes.Buffer
+	if err := WriteReport(&buf, items, f, 0, time.Date(2026, 3, 2, 15, 4, 0, 0, time.UTC)); err != nil {
+		t.Fatalf("WriteReport: %v", err)
+	}
+	out := buf.String()
+
+	if strings.Contains(out, "<script>alert") {
+		t.Error("title was not escaped")
+	}
+	if !strings.Contains(out, "&lt;script&gt;") {
+		t.Error("expected escaped title in output")
+	}
+	if strings.Contains(out, "javascript:alert") {
+		t.Error("javascript: link was not neutralized")
+	}
+	if !strings.Contains(out, `href="https://sam.gov/opp/opp-1/view"`) {
+		t.Error("expected clickable ui_link")
+	}
+	if !strings.Contains(out, "Search: cyber &amp; cloud") {
+		t.Error("expected escaped search criterion in header")
+	}
+	if !strings.Contains(out, "2026-03-02 15:04 UTC") {
+		t.Error("expected generation timestamp in header")
+	}
+}
+
+func TestTopCounts_OrdersByCountThenValue(t *testing.T) {
+	got := topCounts(map[string]int64{"b": 2, "a": 2, "c": 5, "d": 1}, 3)
+	want := []string{"c", "a", "b"}
+	if len(got) != len(want) {
+		t.Fatalf("got %d stats, want %d", len(got), len(want))
+	}
+	for i, v := range want {
+		if got[i].Value != v {
+			t.Errorf("got[%d] = %q, want %q", i, got[i].Value, v)
+		}
+	}
+}
+
+func TestWriteReport_LimitOnlyTrimsTable(t *testing.T) {
+	var items []db.OpportunityListItem
+	for _, id := range []string{"opp-1", "opp-2", "opp-3"} {
+		items = append(items, db.OpportunityListItem{ID: id, Title: strPtr("Title " + id), Active: 1})
+	}
+
+	var buf bytes.Buffer
+	if err := WriteReport(&buf, items, db.ListFilters{}, 1, time.Now()); err != nil {
+		t.Fatalf("WriteReport: %v", err)
+	}
+	out := buf.String()
+
+	if !strings.Contains(out, `<div class="num">3</div>3 active`) {
+		t.Error("summary should count every match, not just the listed rows")
+	}
+	if !strings.Contains(out, "Title opp-1") || strings.Contains(out, "Title opp-2") {
+		t.Error("table should list only the first row")
+	}
+	if !strings.Contains(out, "Showing the newest 1 of 3") {
+		t.Error("expected a note that the table is truncated")
+	}
+}
@@ -0,0 +1,54 @@
+package web
+
+import (
+	"net/http"
+	"net/http/httptest"
+	"regexp"
+	"strings"
+	"testing"
+
+	"github.com/go-chi/chi/v5/middleware"
+)
+
+var uuidV4 = regexp.MustCompile(`^[0-9a-f]{8}-[0-9a-f]{4}-4[0-9a-f]{3}-[89ab][0-9a-f]{3}-[0-9a-f]{12}$`)
+
+func TestRequestID_GeneratesAndPropagates(t *testing.T) {
+	var seen string
+	h := requestID(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		seen = middleware.GetReqID(r.Context())
+	}))
+
+	rec := httptest.NewRecorder()
+	h.ServeHTTP(rec, httptest.NewRequest("GET", "/", nil))
+	got := rec.Header().Get(requestIDHeader)
+	if !uuidV4.MatchString(got) {
+		t.Errorf("generated ID %q is not a UUIDv4", got)
+	}
+	if seen != got {
+		t.Errorf("context ID = %q, header = %q", seen, got)
+	}
+
+	rec = httptest.NewRecorder()
+	req := httptest.NewRequest("GET", "/", nil)
+	req.Header.Set(requestIDHeader, "from-proxy-123")
+	h.ServeHTTP(rec, req)
+	if got := rec.Header().Get(requestIDHeader); got != "from-proxy-123" {
+		t.Errorf("incoming ID not reused: got %q", got)
+	}
+
+	for _, bad := range []string{
+		"has space",
+		`quote"d`,
+		"line\tbreak",
+		"ünïcode",
+		strings.Repeat("a", maxRequestIDLen+1),
+	} {
+		rec = httptest.NewRecorder()
+		req = httptest.NewRequest("GET", "/", nil)
+		req.Header.Set(requestIDHeader, bad)
+		h.ServeHTTP(rec, req)
+		if got := rec.Header().Get(requestIDHeader); !uuidV4.MatchString(got) {
+			t.Errorf("incoming ID %q: got %q, want a fresh UUIDv4", bad, got)
+		}
+	}
+}
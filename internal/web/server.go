@@ -22,12 +22,15 @@ type Server struct {
 	db       *sql.DB
 	tmpls    map[string]*template.Template
 	cookie   *securecookie.SecureCookie
+	feedKey  []byte // HMAC key for per-user RSS feed keys
 	router   chi.Router
 	syncing  atomic.Bool
 	devMode  bool
 	bgTasks  stdsync.WaitGroup
 	bgCtx    context.Context
 	bgCancel context.CancelFunc
+	jobMu    stdsync.Mutex
+	lastJob  *syncJob // latest POST /api/sync job
 }
 
 func NewServer(db *sql.DB, opts ...ServerOption) *Server {
@@ -42,6 +45,7 @@ func NewServer(db *sql.DB, opts ...ServerOption) *Server {
 		db:       db,
 		tmpls:    loadTemplates(),
 		cookie:   newSecureCookie(secret),
+		feedKey:  newFeedKey(secret),
 		bgCtx:    bgCtx,
 		bgCancel: cancel,
 	}
@@ -63,9 +67,13 @@ func WithDevMode(dev bool) ServerOption {
 
 func (s *Server) routes() chi.Router {
 	r := chi.NewRouter()
+	r.Use(requestID)
 	r.Use(middleware.Logger)
-	r.Use(middleware.Recoverer)
+	r.Use(recoverer)
 	r.Use(middleware.Compress(5))
+	// Set before any Route/Mount so subrouters inherit them.
+	r.NotFound(handleNotFound)
+	r.MethodNotAllowed(handleMethodNotAllowed)
 
 	// Static
 	r.Get("/static/style.css", func(w http.ResponseWriter, r *http.Request) {
@@ -84,9 +92,37 @@ func (s *Server) routes() chi.Router {
 	r.Post("/login", s.handleLogin)
 	r.Post("/logout", s.handleLogout)
 
-	// Health
-	r.Get("/health", func(w http.ResponseWriter, r *http.Request) {
-		w.Write([]byte("ok"))
+	// Health: /live for liveness, /ready (and /health) check the database
+	r.Get("/live", handleLive)
+	r.Get("/ready", s.handleReady)
+	r.Get("/health", s.handleReady)
+
+	// Sync trigger (shared-secret header instead of a session)
+	r.With(requireSyncToken).Post("/api/sync", s.handleAPISync)
+	r.With(requireSyncToken).Get("/api/sync/{id}", s.handleAPISyncJob)
+
+	// RSS (session or ?key=, since feed readers can't log in)
+	r.With(s.requireFeedAuth).Get("/api/opportunities.rss", s.handleAPIFeed)
+
+	// JSON API (session auth, 401 instead of redirect)
+	r.Route("/api", func(r chi.Router) {
+		r.Use(s.requireAPIAuth)
+		r.Get("/stats", s.handleAPIStats)
+		r.Get("/stats/completeness", s.handleAPICompleteness)
+		r.Get("/stats/states.geojson", s.handleAPIStatesGeoJSON)
+		r.Get("/stats/timeline", s.handleAPITimeline)
+		r.Get("/opportunities", s.handleAPIOpportunities)
+		r.Get("/feed-key", s.handleAPIFeedKey)
+		r.Get("/opportunities/changes", s.handleAPIChanges)
+		r.Get("/opportunities/{id}", s.handleAPIOpportunity)
+		r.Get("/opportunities/{id}/download", s.handleAPIDownload)
+		r.Get("/opportunities/{id}/tags", s.handleAPIOpportunityTags)
+		r.Get("/contacts", s.handleAPIContacts)
+		r.Get("/awardees/{name}", s.handleAPIAwardee)
+		r.Get("/views", s.handleAPIViews)
+		r.Post("/views", s.handleAPIViewCreate)
+		r.Get("/views/{name}", s.handleAPIView)
+		r.Delete("/views/{name}", s.handleAPIViewDelete)
 	})
 
 	// Auth required
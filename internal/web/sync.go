@@ -0,0 +1,151 @@
+package web
+
+import (
+	"crypto/subtle"
+	"log"
+	"net/http"
+	"os"
+	"strconv"
+	"time"
+
+	"github.com/go-chi/chi/v5"
+	"github.com/theognis1002/govscout/internal/alerts"
+	"github.com/theognis1002/govscout/internal/samgov"
+	gosync "github.com/theognis1002/govscout/internal/sync"
+)
+
+// SyncTokenEnv turns on POST /api/sync. Callers send its value in the
+// X-Sync-Token header instead of a session, so cron jobs and scripts can
+// trigger a sync; unset, the endpoint answers 404.
+const SyncTokenEnv = "GOVSCOUT_SYNC_TOKEN"
+
+const (
+	syncTokenHeader = "X-Sync-Token"
+	// maxAPISyncCalls bounds max_calls so a leaked token can't spend a
+	// whole day's SAM.gov quota in one request.
+	maxAPISyncCalls = 100
+)
+
+// syncJob is a sync started through POST /api/sync. Only the latest is
+// kept, since only one sync runs at a time.
+type syncJob struct {
+	ID         string          `json:"id"`
+	Status     string          `json:"status"` // running, done, or failed
+	MaxCalls   int             `json:"max_calls"`
+	StartedAt  time.Time       `json:"started_at"`
+	FinishedAt *time.Time      `json:"finished_at,omitempty"`
+	Summary    *gosync.Summary `json:"summary,omitempty"`
+	Error      string          `json:"error,omitempty"`
+}
+
+// requireSyncToken checks X-Sync-Token against SyncTokenEnv in constant time.
+func requireSyncToken(next http.Handler) http.Handler {
+	return http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		token := os.Getenv(SyncTokenEnv)
+		if token == "" {
+			writeError(w, http.StatusNotFound, "sync endpoint disabled (set "+SyncTokenEnv+")")
+			return
+		}
+		if subtle.ConstantTimeCompare([]byte(r.Header.Get(syncTokenHeader)), []byte(token)) != 1 {
+			writeError(w, http.StatusUnauthorized, "missing or wrong "+syncTokenHeader)
+			return
+		}
+		next.ServeHTTP(w, r)
+	})
+}
+
+// handleAPISync starts a sync of up to max_calls SAM.gov calls (default 18,
+// at most maxAPISyncCalls). With wait=true it responds with the finished
+// job, summary included; otherwise it answers 202 with the job to poll at
+// GET /api/sync/{id}. A sync already running, from here or the admin page,
+// is a 409.
+func (s *Server) handleAPISync(w http.ResponseWriter, r *http.Request) {
+	maxCalls := 18
+	if v := r.URL.Query().Get("max_calls"); v != "" {
+		n, err := strconv.Atoi(v)
+		if err != nil || n <= 0 || n > maxAPISyncCalls {
+			writeError(w, http.StatusBadRequest, "max_calls must be between 1 and "+strconv.Itoa(maxAPISyncCalls))
+			return
+		}
+		maxCalls = n
+	}
+	client, err := samgov.NewSourceFromEnv(os.Getenv("SAMGOV_API_KEY"))
+	if err != nil {
+		writeError(w, http.StatusServiceUnavailable, "cannot start sync: "+err.Error())
+		return
+	}
+	if !s.syncing.CompareAndSwap(false, true) {
+		writeError(w, http.StatusConflict, "sync already in progress")
+		return
+	}
+
+	job := &syncJob{ID: newUUID(), Status: "running", MaxCalls: maxCalls, StartedAt: time.Now().UTC()}
+	s.setSyncJob(job)
+	done := make(chan struct{})
+	s.bgTasks.Add(1)
+	go func() {
+		defer s.bgTasks.Done()
+		defer close(done)
+		defer s.syncing.Store(false)
+		s.runSyncJob(job, client)
+	}()
+
+	if r.URL.Query().Get("wait") != "true" {
+		w.Header().Set("Location", "/api/sync/"+job.ID)
+		writeJSON(w, http.StatusAccepted, s.syncJobSnapshot())
+		return
+	}
+	select {
+	case <-done:
+		writeJSON(w, http.StatusOK, s.syncJobSnapshot())
+	case <-r.Context().Done():
+		// The client gave up; the sync carries on and can be polled.
+	}
+}
+
+// runSyncJob runs the sync and the alert matcher after it, recording the
+// outcome on job.
+func (s *Server) runSyncJob(job *syncJob, client samgov.Source) {
+	summary, err := gosync.RunWithSummary(s.bgCtx, s.db, client, gosync.Options{MaxCalls: job.MaxCalls})
+	if err == nil {
+		if mErr := alerts.RunMatcherCtx(s.bgCtx, s.db); mErr != nil {
+			log.Printf("alert matcher error: %v", mErr)
+		}
+	}
+
+	s.jobMu.Lock()
+	defer s.jobMu.Unlock()
+	now := time.Now().UTC()
+	job.FinishedAt, job.Summary, job.Status = &now, summary, "done"
+	if err != nil {
+		job.Status, job.Error = "failed", err.Error()
+	}
+}
+
+func (s *Server) setSyncJob(job *syncJob) {
+	s.jobMu.Lock()
+	defer s.jobMu.Unlock()
+	s.lastJob = job
+}
+
+// syncJobSnapshot copies the latest job under the lock, or nil without one.
+func (s *Server) syncJobSnapshot() *syncJob {
+	s.jobMu.Lock()
+	defer s.jobMu.Unlock()
+	if s.lastJob == nil {
+		return nil
+	}
+	job := *s.lastJob
+	return &job
+}
+
+// handleAPISyncJob reports the job started by POST /api/sync; only the
+// latest job is kept, so older IDs are 404.
+func (s *Server) handleAPISyncJob(w http.ResponseWriter, r *http.Request) {
+	job := s.syncJobSnapshot()
+	if job == nil || job.ID != chi.URLParam(r, "id") {
+		writeError(w, http.StatusNotFound, "no such sync job")
+		return
+	}
+	writeJSON(w, http.StatusOK, job)
+}
@@ -0,0 +1,81 @@
+package web
+
+import (
+	"encoding/json"
+	"net/http"
+	"net/http/httptest"
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/theognis1002/govscout/internal/db"
+	"github.com/theognis1002/govscout/internal/samgov"
+)
+
+func TestAPISync(t *testing.T) {
+	mockDir := t.TempDir()
+	fixture := `{"totalRecords":1,"opportunitiesData":[{"noticeId":"synced-1","title":"Widgets"}]}`
+	if err := os.WriteFile(filepath.Join(mockDir, "page.json"), []byte(fixture), 0o644); err != nil {
+		t.Fatal(err)
+	}
+	t.Setenv(samgov.MockDirEnv, mockDir)
+	t.Setenv("AUTH_SECRET", "test-secret-test-secret-test-secret")
+
+	d, err := db.Open(filepath.Join(t.TempDir(), "test.db"))
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer d.Close()
+	s := NewServer(d)
+
+	do := func(method, target, token string) *httptest.ResponseRecorder {
+		req := httptest.NewRequest(method, target, nil)
+		if token != "" {
+			req.Header.Set(syncTokenHeader, token)
+		}
+		rec := httptest.NewRecorder()
+		s.ServeHTTP(rec, req)
+		return rec
+	}
+
+	t.Setenv(SyncTokenEnv, "")
+	if rec := do("POST", "/api/sync", "anything"); rec.Code != http.StatusNotFound {
+		t.Errorf("without %s: status = %d, want 404", SyncTokenEnv, rec.Code)
+	}
+
+	t.Setenv(SyncTokenEnv, "s3cret")
+	if rec := do("POST", "/api/sync", "wrong"); rec.Code != http.StatusUnauthorized {
+		t.Errorf("wrong token: status = %d, want 401", rec.Code)
+	}
+	if rec := do("POST", "/api/sync?max_calls=1000", "s3cret"); rec.Code != http.StatusBadRequest {
+		t.Errorf("max_calls over the cap: status = %d, want 400", rec.Code)
+	}
+
+	rec := do("POST", "/api/sync?max_calls=2&wait=true", "s3cret")
+	if rec.Code != http.StatusOK {
+		t.Fatalf("status = %d: %s", rec.Code, rec.Body.String())
+	}
+	var job syncJob
+	if err := json.Unmarshal(rec.Body.Bytes(), &job); err != nil {
+		t.Fatal(err)
+	}
+	if job.Status != "done" || job.Summary == nil || job.Summary.Inserted != 1 || job.MaxCalls != 2 {
+		t.Errorf("job = %+v, want done with 1 insert", job)
+	}
+	if n, _ := db.CountOpportunities(d); n != 1 {
+		t.Errorf("stored %d opportunities, want 1", n)
+	}
+
+	if rec := do("GET", "/api/sync/"+job.ID, "s3cret"); rec.Code != http.StatusOK {
+		t.Errorf("poll job: status = %d, want 200", rec.Code)
+	}
+	if rec := do("GET", "/api/sync/not-a-job", "s3cret"); rec.Code != http.StatusNotFound {
+		t.Errorf("unknown job: status = %d, want 404", rec.Code)
+	}
+
+	s.syncing.Store(true)
+	if rec := do("POST", "/api/sync", "s3cret"); rec.Code != http.StatusConflict {
+		t.Errorf("while syncing: status = %d, want 409", rec.Code)
+	}
+	s.syncing.Store(false)
+}
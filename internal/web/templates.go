@@ -9,6 +9,9 @@ import (
 	"io"
 	"path/filepath"
 	"strings"
+
+	"github.com/theognis1002/govscout/internal/codes"
+	"github.com/theognis1002/govscout/internal/db"
 )
 
 //go:embed templates/*.html templates/partials/*.html templates/alerts/*.html templates/admin/*.html templates/filters/*.html
@@ -31,12 +34,7 @@ var funcMap = template.FuncMap{
 		}
 		return *s
 	},
-	"derefOr": func(s *string, fallback string) string {
-		if s == nil || *s == "" {
-			return fallback
-		}
-		return *s
-	},
+	"derefOr": derefOr,
 	"activeLabel": func(active int) string {
 		if active == 1 {
 			return "Active"
@@ -45,6 +43,7 @@ var funcMap = template.FuncMap{
 	},
 	"add": func(a, b int) int { return a + b },
 	"sub": func(a, b int) int { return a - b },
+	"pageURL": pageURL,
 	"seq": func(n int) []int {
 		s := make([]int, n)
 		for i := range s {
@@ -74,10 +73,11 @@ var funcMap = template.FuncMap{
 		}
 		return false
 	},
-	"stripHTML":    stripHTML,
-	"naicsLabel":   naicsLabel,
-	"setAsideDesc": setAsideDesc,
-	"oppTypeDesc":  oppTypeDesc,
+	"stripHTML":    db.StripHTML,
+	"naicsLabel":   codes.NAICSLabel,
+	"pscLabel":     codes.PSCLabel,
+	"setAsideDesc": codes.SetAsideDescription,
+	"oppTypeDesc":  codes.OppTypeDescription,
 	"boolChecked": func(b bool) template.HTMLAttr {
 		if b {
 			return "checked"
@@ -138,24 +138,11 @@ func loadTemplates() map[string]*template.Template {
 	return pages
 }
 
-func stripHTML(s string) string {
-	var buf strings.Builder
-	inTag := false
-	for _, r := range s {
-		if r == '<' {
-			inTag = true
-			continue
-		}
-		if r == '>' {
-			inTag = false
-			buf.WriteRune(' ')
-			continue
-		}
-		if !inTag {
-			buf.WriteRune(r)
-		}
+func derefOr(s *string, fallback string) string {
+	if s == nil || *s == "" {
+		return fallback
 	}
-	return buf.String()
+	return *s
 }
 
 func loadTemplatesFromDisk() (map[string]*template.Template, error) {
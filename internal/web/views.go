@@ -0,0 +1,101 @@
+package web
+
+import (
+	"bytes"
+	"encoding/json"
+	"errors"
+	"log"
+	"net/http"
+	"regexp"
+
+	"github.com/go-chi/chi/v5"
+	"github.com/theognis1002/govscout/internal/db"
+)
+
+// maxViewBodyBytes caps a POST /api/views body; filter sets are tiny.
+const maxViewBodyBytes = 64 << 10
+
+// viewNamePattern keeps view names URL-safe since they appear in the path.
+var viewNamePattern = regexp.MustCompile(`^[A-Za-z0-9][A-Za-z0-9_.-]{0,63}$`)
+
+type apiViewRequest struct {
+	Name    string          `json:"name"`
+	Filters json.RawMessage `json:"filters"`
+}
+
+type apiViewList struct {
+	Views []db.SearchView `json:"views"`
+}
+
+// handleAPIViewCreate saves a named filter set for the current user. The
+// filters are stored as given, so they only need to be a JSON object.
+func (s *Server) handleAPIViewCreate(w http.ResponseWriter, r *http.Request) {
+	var req apiViewRequest
+	if err := json.NewDecoder(http.MaxBytesReader(w, r.Body, maxViewBodyBytes)).Decode(&req); err != nil {
+		writeError(w, http.StatusBadRequest, "invalid JSON body")
+		return
+	}
+	if !viewNamePattern.MatchString(req.Name) {
+		writeJSON(w, http.StatusBadRequest, map[string]string{
+			"error": "name must be 1-64 letters, digits, '.', '_' or '-', starting with a letter or digit",
+		})
+		return
+	}
+	if !bytes.HasPrefix(bytes.TrimSpace(req.Filters), []byte("{")) {
+		writeError(w, http.StatusBadRequest, "filters must be a JSON object")
+		return
+	}
+
+	view, err := db.CreateSearchView(s.db, getUser(r).ID, req.Name, req.Filters)
+	if errors.Is(err, db.ErrViewExists) {
+		writeError(w, http.StatusConflict, "a view with that name already exists")
+		return
+	}
+	if err != nil {
+		log.Printf("api create view: %v", err)
+		writeError(w, http.StatusInternalServerError, "internal server error")
+		return
+	}
+	writeJSON(w, http.StatusCreated, view)
+}
+
+func (s *Server) handleAPIViews(w http.ResponseWriter, r *http.Request) {
+	views, err := db.ListSearchViews(s.db, getUser(r).ID)
+	if err != nil {
+		log.Printf("api list views: %v", err)
+		writeError(w, http.StatusInternalServerError, "internal server error")
+		return
+	}
+	if views == nil {
+		views = []db.SearchView{}
+	}
+	writeJSON(w, http.StatusOK, apiViewList{Views: views})
+}
+
+func (s *Server) handleAPIView(w http.ResponseWriter, r *http.Request) {
+	view, err := db.GetSearchView(s.db, getUser(r).ID, chi.URLParam(r, "name"))
+	if err != nil {
+		log.Printf("api get view: %v", err)
+		writeError(w, http.StatusInternalServerError, "internal server error")
+		return
+	}
+	if view == nil {
+		writeError(w, http.StatusNotFound, "not found")
+		return
+	}
+	writeJSON(w, http.StatusOK, view)
+}
+
+func (s *Server) handleAPIViewDelete(w http.ResponseWriter, r *http.Request) {
+	ok, err := db.DeleteSearchView(s.db, getUser(r).ID, chi.URLParam(r, "name"))
+	if err != nil {
+		log.Printf("api delete view: %v", err)
+		writeError(w, http.StatusInternalServerError, "internal server error")
+		return
+	}
+	if !ok {
+		writeError(w, http.StatusNotFound, "not found")
+		return
+	}
+	w.WriteHeader(http.StatusNoContent)
+}
@@ -0,0 +1,65 @@
+package web
+
+import (
+	"net/http"
+	"net/http/httptest"
+	"path/filepath"
+	"strings"
+	"testing"
+
+	"github.com/go-chi/chi/v5"
+	"github.com/theognis1002/govscout/internal/db"
+)
+
+func TestAPIViews(t *testing.T) {
+	d, err := db.Open(filepath.Join(t.TempDir(), "test.db"))
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer d.Close()
+	if err := db.CreateUser(d, "alice", "x", false); err != nil {
+		t.Fatal(err)
+	}
+	alice, _ := db.GetUserByUsername(d, "alice")
+
+	s := &Server{db: d}
+	r := chi.NewRouter()
+	r.Use(func(next http.Handler) http.Handler {
+		return http.HandlerFunc(func(w http.ResponseWriter, req *http.Request) {
+			next.ServeHTTP(w, req.WithContext(setUser(req.Context(), &SessionUser{ID: alice.ID, Username: "alice"})))
+		})
+	})
+	r.Get("/api/views", s.handleAPIViews)
+	r.Post("/api/views", s.handleAPIViewCreate)
+	r.Get("/api/views/{name}", s.handleAPIView)
+	r.Delete("/api/views/{name}", s.handleAPIViewDelete)
+
+	steps := []struct {
+		method, path, body string
+		want               int
+		wantBody           string
+	}{
+		{"GET", "/api/views", "", http.StatusOK, `{"views":[]}`},
+		{"POST", "/api/views", `{"name":"cyber","filters":{"search":"cyber","naics_code":"541512"}}`, http.StatusCreated, `"name":"cyber"`},
+		{"POST", "/api/views", `{"name":"cyber","filters":{}}`, http.StatusConflict, "already exists"},
+		{"POST", "/api/views", `{"name":"bad name!","filters":{}}`, http.StatusBadRequest, "name must be"},
+		{"POST", "/api/views", `{"name":"ok","filters":["x"]}`, http.StatusBadRequest, "JSON object"},
+		{"POST", "/api/views", `not json`, http.StatusBadRequest, "invalid JSON"},
+		{"GET", "/api/views/cyber", "", http.StatusOK, `"filters":{"search":"cyber","naics_code":"541512"}`},
+		{"GET", "/api/views", "", http.StatusOK, `"name":"cyber"`},
+		{"GET", "/api/views/missing", "", http.StatusNotFound, "not found"},
+		{"DELETE", "/api/views/cyber", "", http.StatusNoContent, ""},
+		{"DELETE", "/api/views/cyber", "", http.StatusNotFound, "not found"},
+	}
+	for _, st := range steps {
+		rec := httptest.NewRecorder()
+		r.ServeHTTP(rec, httptest.NewRequest(st.method, st.path, strings.NewReader(st.body)))
+		if rec.Code != st.want {
+			t.Errorf("%s %s = %d, want %d (body %s)", st.method, st.path, rec.Code, st.want, rec.Body)
+			continue
+		}
+		if !strings.Contains(rec.Body.String(), st.wantBody) {
+			t.Errorf("%s %s body = %s, want it to contain %s", st.method, st.path, rec.Body, st.wantBody)
+		}
+	}
+}